@@ -1,6 +1,6 @@
 use crate::{ArrowOperator, Block, Expression, ExpressionVisitor, VisitableExpression};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum FunctionBody {
     ArrowExpression(ArrowExpression),
     Block(Block),
@@ -45,7 +45,7 @@ impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for FunctionBody {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ArrowExpression {
     pub operator: ArrowOperator,
     pub expression: Expression,
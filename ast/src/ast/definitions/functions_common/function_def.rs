@@ -1,10 +1,12 @@
-use crate::{FunctionSignature, Identifier};
+use crate::{FunctionSignature, Identifier, TypeName};
 
 use super::FunctionBody;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct FunctionDef {
     pub identifier: Identifier,
+    /// Only global functions can be generic
+    pub type_parameters: Vec<TypeName>,
     pub parameters: Vec<Identifier>,
     pub body: FunctionBody,
 }
@@ -13,6 +15,7 @@ impl FunctionDef {
     pub fn new(identifier: Identifier, parameters: Vec<Identifier>, body: FunctionBody) -> Self {
         Self {
             identifier,
+            type_parameters: Vec::new(),
             parameters,
             body,
         }
@@ -21,6 +24,7 @@ impl FunctionDef {
     pub fn from_signature(signature: FunctionSignature, body: FunctionBody) -> Self {
         Self {
             identifier: signature.identifier,
+            type_parameters: Vec::new(),
             parameters: signature.parameters,
             body,
         }
@@ -1,8 +1,8 @@
-use crate::{Identifier, Keyword};
+use crate::{Identifier, Keyword, TypeName};
 
 use super::{FunctionBody, FunctionDef, FunctionSignature};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GlobalFunctionDef {
     pub function_token: Keyword,
     pub function_def: FunctionDef,
@@ -12,23 +12,29 @@ impl GlobalFunctionDef {
     pub fn new(
         function_token: Keyword,
         identifier: Identifier,
+        type_parameters: Vec<TypeName>,
         parameters: Vec<Identifier>,
         body: FunctionBody,
     ) -> Self {
+        let mut function_def = FunctionDef::new(identifier, parameters, body);
+        function_def.type_parameters = type_parameters;
         GlobalFunctionDef {
             function_token,
-            function_def: FunctionDef::new(identifier, parameters, body),
+            function_def,
         }
     }
 
     pub fn from_signature_and_body(
         function_token: Keyword,
+        type_parameters: Vec<TypeName>,
         signature: FunctionSignature,
         body: FunctionBody,
     ) -> Self {
+        let mut function_def = FunctionDef::from_signature(signature, body);
+        function_def.type_parameters = type_parameters;
         Self {
             function_token,
-            function_def: FunctionDef::from_signature(signature, body),
+            function_def,
         }
     }
 }
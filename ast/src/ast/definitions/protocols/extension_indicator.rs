@@ -1,6 +1,6 @@
 use crate::{Keyword, TypeName};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ExtensionIndicator {
     pub extends_token: Keyword,
     pub extendee_name: TypeName,
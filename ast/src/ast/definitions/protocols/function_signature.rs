@@ -1,6 +1,6 @@
 use crate::Identifier;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct FunctionSignature {
     pub identifier: Identifier,
     pub parameters: Vec<Identifier>,
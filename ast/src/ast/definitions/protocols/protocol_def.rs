@@ -2,10 +2,11 @@ use crate::{Keyword, TypeName};
 
 use super::{ExtensionIndicator, FunctionSignature};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ProtocolDef {
     pub protocol_token: Keyword,
     pub name: TypeName,
+    pub type_parameters: Vec<TypeName>,
     pub extension_indicator: Option<ExtensionIndicator>,
     pub function_signatures: Vec<FunctionSignature>,
 }
@@ -14,12 +15,14 @@ impl ProtocolDef {
     pub fn new(
         protocol_token: Keyword,
         name: TypeName,
+        type_parameters: Vec<TypeName>,
         extension_indicator: Option<ExtensionIndicator>,
         function_signatures: Vec<FunctionSignature>,
     ) -> Self {
         Self {
             protocol_token,
             name,
+            type_parameters,
             extension_indicator,
             function_signatures,
         }
@@ -1,6 +1,6 @@
 use crate::{Expression, Keyword, TypeName};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct InheritanceIndicator {
     pub inherits_token: Keyword,
     pub parent_name: TypeName,
@@ -2,10 +2,11 @@ use crate::{FunctionDef, Identifier, Keyword, TypeName};
 
 use super::{DataMemberDef, InheritanceIndicator};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct TypeDef {
     pub type_token: Keyword,
     pub name: TypeName,
+    pub type_parameters: Vec<TypeName>,
     pub parameter_list: Vec<Identifier>,
     pub inheritance_indicator: Option<InheritanceIndicator>,
     pub data_member_defs: Vec<DataMemberDef>,
@@ -16,6 +17,7 @@ impl TypeDef {
     pub fn new(
         type_token: Keyword,
         name: TypeName,
+        type_parameters: Vec<TypeName>,
         parameter_list: Vec<Identifier>,
         inheritance_indicator: Option<InheritanceIndicator>,
         data_member_defs: Vec<DataMemberDef>,
@@ -24,6 +26,7 @@ impl TypeDef {
         Self {
             type_token,
             name,
+            type_parameters,
             parameter_list,
             inheritance_indicator,
             data_member_defs,
@@ -0,0 +1,41 @@
+use crate::{
+    Expression, ExpressionVisitor, Identifier, Keyword, VisitableExpression,
+    tokens::token_position::TokenPositionTrait, typing::TypeAnnotation,
+};
+
+/// # Description
+///
+/// `base(arguments)`, inside a method, calls the implementation the parent
+/// type has of the method being defined, on the same `self`. Once checked,
+/// `method` holds the name of that method, `parent_type` the parent type,
+/// whose own or inherited implementation is called, and `ty` the return type
+#[derive(Clone, Debug)]
+pub struct BaseCall {
+    pub base_token: Keyword,
+    pub arguments: Vec<Expression>,
+    /// `self`, on which the parent implementation is called
+    pub receiver: Identifier,
+    pub method: Option<String>,
+    pub parent_type: TypeAnnotation,
+    pub ty: TypeAnnotation,
+}
+
+impl BaseCall {
+    pub fn new(base_token: Keyword, arguments: Vec<Expression>) -> Self {
+        let position = base_token.position();
+        Self {
+            receiver: Identifier::new(position, position + "base".len(), "self"),
+            base_token,
+            arguments,
+            method: None,
+            parent_type: None,
+            ty: None,
+        }
+    }
+}
+
+impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for BaseCall {
+    fn accept(&mut self, visitor: &mut T) -> R {
+        visitor.visit_base_call(self)
+    }
+}
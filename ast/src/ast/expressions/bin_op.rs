@@ -4,7 +4,7 @@ use crate::{
     visitors::{ExpressionVisitor, visitable_expression::VisitableExpression},
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct BinOp {
     pub lhs: Box<Expression>,
     pub op: BinaryOperator,
@@ -2,7 +2,7 @@ use crate::{ExpressionVisitor, GroupingOperator, VisitableExpression};
 
 use super::BlockBodyItem;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Block {
     pub open_brace: GroupingOperator,
     pub close_brace: GroupingOperator,
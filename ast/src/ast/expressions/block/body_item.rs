@@ -2,7 +2,7 @@ use crate::{Expression, ExpressionVisitor, VisitableExpression};
 
 use super::return_statement::ReturnStatement;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum BlockBodyItem {
     Expression(Expression),
     ReturnStatement(ReturnStatement),
@@ -1,6 +1,6 @@
 use crate::{Expression, ExpressionVisitor, Keyword, VisitableExpression};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReturnStatement {
     pub return_token: Keyword,
     pub expression: Expression,
@@ -1,9 +1,12 @@
 use super::{
     function_call::FunctionCall, list_indexing::ListIndexing, list_literal::ListLiteral, *,
 };
-use crate::{ExpressionVisitor, VisitableExpression, tokens::*};
+use crate::{
+    ExpressionVisitor, VisitableExpression,
+    tokens::{token_position::TokenPositionTrait, *},
+};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Expression {
     DestructiveAssignment(DestructiveAssignment),
     BinOp(BinOp),
@@ -21,12 +24,16 @@ pub enum Expression {
     NewExpression(NewExpr),
 
     FunctionCall(FunctionCall),
+    BaseCall(BaseCall),
     DataMemberAccess(DataMemberAccess),
     FunctionMemberAccess(FunctionMemberAccess),
     ListIndexing(ListIndexing),
 
     Variable(Identifier),
     UnaryOp(UnOp),
+    Lambda(Lambda),
+    IsExpression(IsExpr),
+    AsExpression(AsExpr),
 }
 
 impl From<NewExpr> for Expression {
@@ -137,6 +144,30 @@ impl From<UnOp> for Expression {
     }
 }
 
+impl From<Lambda> for Expression {
+    fn from(v: Lambda) -> Self {
+        Self::Lambda(v)
+    }
+}
+
+impl From<IsExpr> for Expression {
+    fn from(v: IsExpr) -> Self {
+        Self::IsExpression(v)
+    }
+}
+
+impl From<BaseCall> for Expression {
+    fn from(v: BaseCall) -> Self {
+        Self::BaseCall(v)
+    }
+}
+
+impl From<AsExpr> for Expression {
+    fn from(v: AsExpr) -> Self {
+        Self::AsExpression(v)
+    }
+}
+
 impl Expression {
     pub fn as_destructive_assignment(&self) -> Option<&DestructiveAssignment> {
         if let Self::DestructiveAssignment(v) = self {
@@ -241,6 +272,14 @@ impl Expression {
         }
     }
 
+    pub fn as_base_call(&self) -> Option<&BaseCall> {
+        if let Self::BaseCall(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
     pub fn as_data_member_access(&self) -> Option<&DataMemberAccess> {
         if let Self::DataMemberAccess(v) = self {
             Some(v)
@@ -288,6 +327,63 @@ impl Expression {
             None
         }
     }
+
+    pub fn as_lambda(&self) -> Option<&Lambda> {
+        if let Self::Lambda(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_is_expression(&self) -> Option<&IsExpr> {
+        if let Self::IsExpression(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_as_expression(&self) -> Option<&AsExpr> {
+        if let Self::AsExpression(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// # Description
+    /// Where the expression starts in the source code, ignoring the parentheses around it
+    pub fn start_position(&self) -> usize {
+        match self {
+            Expression::DestructiveAssignment(node) => node.lhs.start_position(),
+            Expression::BinOp(node) => node.lhs.start_position(),
+            Expression::LetIn(node) => node.let_token.position(),
+            Expression::IfElse(node) => node.if_token.position(),
+            Expression::While(node) => node.while_token.position(),
+            Expression::For(node) => node.for_token.position(),
+            Expression::Block(node) => node.open_brace.position(),
+            Expression::NumberLiteral(node) => node.position.start,
+            Expression::BooleanLiteral(BooleanLiteral::True(position))
+            | Expression::BooleanLiteral(BooleanLiteral::False(position)) => position.start,
+            Expression::StringLiteral(node) => node.position.start,
+            Expression::ListLiteral(node) => node.left_bracket.position(),
+            Expression::NewExpression(node) => node.new_token.position(),
+            Expression::FunctionCall(node) => node.identifier.position.start,
+            Expression::BaseCall(node) => node.base_token.position(),
+            Expression::DataMemberAccess(node) => node.object.start_position(),
+            Expression::FunctionMemberAccess(node) => node.object.start_position(),
+            Expression::ListIndexing(node) => node.list.start_position(),
+            Expression::Variable(node) => node.position.start,
+            Expression::UnaryOp(node) => node.op.position(),
+            Expression::Lambda(node) => node
+                .parameters
+                .first()
+                .map_or(node.arrow.position(), |parameter| parameter.position.start),
+            Expression::IsExpression(node) => node.expression.start_position(),
+            Expression::AsExpression(node) => node.expression.start_position(),
+        }
+    }
 }
 
 impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for Expression {
@@ -309,6 +405,7 @@ impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for Expression {
             Expression::BinOp(bin_op) => bin_op.accept(visitor),
             Expression::ListLiteral(list_literal) => list_literal.accept(visitor),
             Expression::FunctionCall(function_call) => function_call.accept(visitor),
+            Expression::BaseCall(base_call) => base_call.accept(visitor),
             Expression::DataMemberAccess(data_member_access) => data_member_access.accept(visitor),
             Expression::FunctionMemberAccess(function_member_access) => {
                 function_member_access.accept(visitor)
@@ -319,6 +416,9 @@ impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for Expression {
                 visitor.visit_string_literal(string_literal)
             }
             Expression::NewExpression(new_expr) => visitor.visit_new_expr(new_expr),
+            Expression::Lambda(lambda) => lambda.accept(visitor),
+            Expression::IsExpression(is_expr) => is_expr.accept(visitor),
+            Expression::AsExpression(as_expr) => as_expr.accept(visitor),
         }
     }
 }
@@ -1,11 +1,18 @@
-use crate::{ExpressionVisitor, Identifier, VisitableExpression};
+use crate::{
+    ExpressionVisitor, Identifier, VisitableExpression,
+    typing::{Type, TypeAnnotation},
+};
 
 use super::Expression;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct FunctionCall {
     pub identifier: Identifier,
     pub arguments: Vec<Expression>,
+    pub closure_type: TypeAnnotation,
+    /// The types bound to the type parameters of a generic function, inferred
+    /// from the arguments by the semantic analysis
+    pub type_arguments: Vec<Type>,
 }
 
 impl FunctionCall {
@@ -13,6 +20,8 @@ impl FunctionCall {
         Self {
             identifier,
             arguments,
+            closure_type: None,
+            type_arguments: Vec::new(),
         }
     }
 }
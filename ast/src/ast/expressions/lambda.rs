@@ -0,0 +1,43 @@
+use crate::{
+    ArrowOperator, Expression, ExpressionVisitor, Identifier, VisitableExpression,
+    typing::{FunctorType, Type, TypeAnnotation},
+};
+
+#[derive(Clone, Debug)]
+pub struct Lambda {
+    pub parameters: Vec<Identifier>,
+    pub return_type: TypeAnnotation,
+    pub arrow: ArrowOperator,
+    pub body: Box<Expression>,
+    pub captures: Vec<Identifier>,
+}
+
+impl Lambda {
+    pub fn new(
+        parameters: Vec<Identifier>,
+        return_type: TypeAnnotation,
+        arrow: ArrowOperator,
+        body: Expression,
+    ) -> Self {
+        Self {
+            parameters,
+            return_type,
+            arrow,
+            body: Box::new(body),
+            captures: Vec::new(),
+        }
+    }
+
+    pub fn get_functor_type(&self) -> Type {
+        Type::Functor(FunctorType::new(
+            self.parameters.iter().map(|p| p.info.ty.clone()).collect(),
+            self.return_type.clone(),
+        ))
+    }
+}
+
+impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for Lambda {
+    fn accept(&mut self, visitor: &mut T) -> R {
+        visitor.visit_lambda(self)
+    }
+}
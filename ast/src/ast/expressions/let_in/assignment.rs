@@ -1,6 +1,6 @@
 use crate::{BinaryOperator, Expression, ExpressionVisitor, Identifier, VisitableExpression};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Assignment {
     pub identifier: Identifier,
     pub op: BinaryOperator,
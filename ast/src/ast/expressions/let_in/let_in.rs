@@ -6,7 +6,7 @@ use crate::tokens::*;
 use crate::visitors::ExpressionVisitor;
 use crate::visitors::visitable_expression::VisitableExpression;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct LetIn {
     pub let_token: Keyword,
     pub assignment: Box<Assignment>,
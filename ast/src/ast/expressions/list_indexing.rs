@@ -2,7 +2,7 @@ use crate::{ExpressionVisitor, GroupingOperator, VisitableExpression, typing::Ty
 
 use super::Expression;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ListIndexing {
     pub list: Box<Expression>,
     pub open_brace: GroupingOperator,
@@ -2,7 +2,7 @@ use crate::{ExpressionVisitor, VisitableExpression, tokens, typing::TypeAnnotati
 
 use super::Expression;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ListLiteral {
     pub left_bracket: tokens::GroupingOperator,
     pub right_bracket: tokens::GroupingOperator,
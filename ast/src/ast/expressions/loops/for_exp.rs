@@ -1,12 +1,16 @@
-use crate::{Block, Expression, ExpressionVisitor, Identifier, Keyword, VisitableExpression};
+use crate::{
+    Block, Expression, ExpressionVisitor, Identifier, Keyword, VisitableExpression,
+    typing::TypeAnnotation,
+};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct For {
     pub for_token: Keyword,
     pub element: Identifier,
     pub in_token: Keyword,
     pub iterable: Box<Expression>,
     pub body: Block,
+    pub iterable_type: TypeAnnotation,
 }
 
 impl For {
@@ -23,6 +27,7 @@ impl For {
             in_token,
             iterable: Box::new(iterable),
             body,
+            iterable_type: None,
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::{Block, Expression, ExpressionVisitor, Keyword, VisitableExpression};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct While {
     pub while_token: Keyword,
     pub condition: Box<Expression>,
@@ -3,7 +3,7 @@ use crate::{
     typing::TypeAnnotation,
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct FunctionMemberAccess {
     pub object: Box<Expression>,
     pub obj_type: TypeAnnotation,
@@ -32,6 +32,9 @@ pub use block::ReturnStatement;
 mod function_call;
 pub use function_call::FunctionCall;
 
+mod base_call;
+pub use base_call::BaseCall;
+
 mod list_literal;
 pub use list_literal::ListLiteral;
 
@@ -41,3 +44,10 @@ pub use list_indexing::ListIndexing;
 mod member_access;
 pub use member_access::DataMemberAccess;
 pub use member_access::FunctionMemberAccess;
+
+mod lambda;
+pub use lambda::Lambda;
+
+mod type_test;
+pub use type_test::AsExpr;
+pub use type_test::IsExpr;
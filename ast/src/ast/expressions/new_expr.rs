@@ -1,27 +1,41 @@
-use crate::{ExpressionVisitor, Keyword, VisitableExpression};
+use crate::{ExpressionVisitor, Keyword, VisitableExpression, typing::Type};
 
 use super::{Expression, FunctionCall};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct NewExpr {
     pub new_token: Keyword,
     pub type_name: String,
+    /// Written for generic types (`new Stack<Number>()`), or inferred from the
+    /// arguments by the semantic analysis when left out
+    pub type_arguments: Vec<Type>,
     pub arguments: Vec<Expression>,
 }
 
 impl NewExpr {
-    pub fn new(new_token: Keyword, type_name: String, arguments: Vec<Expression>) -> Self {
+    pub fn new(
+        new_token: Keyword,
+        type_name: String,
+        type_arguments: Vec<Type>,
+        arguments: Vec<Expression>,
+    ) -> Self {
         Self {
             new_token,
             type_name,
+            type_arguments,
             arguments,
         }
     }
 
-    pub fn from_function_call(new_token: Keyword, function_call: FunctionCall) -> Self {
+    pub fn from_function_call(
+        new_token: Keyword,
+        type_arguments: Vec<Type>,
+        function_call: FunctionCall,
+    ) -> Self {
         Self {
             new_token,
             type_name: function_call.identifier.id,
+            type_arguments,
             arguments: function_call.arguments,
         }
     }
@@ -0,0 +1,34 @@
+use crate::{
+    Expression, ExpressionVisitor, Keyword, VisitableExpression,
+    typing::{Type, TypeAnnotation},
+};
+
+/// # Description
+///
+/// `expression as Type`, a checked downcast: the value is used with type
+/// `target`, and the program aborts if its dynamic type does not conform to it.
+/// `obj_type` holds the static type of `expression`, once it has been inferred
+#[derive(Clone, Debug)]
+pub struct AsExpr {
+    pub expression: Box<Expression>,
+    pub obj_type: TypeAnnotation,
+    pub as_token: Keyword,
+    pub target: Type,
+}
+
+impl AsExpr {
+    pub fn new(expression: Expression, as_token: Keyword, target: Type) -> Self {
+        Self {
+            expression: Box::new(expression),
+            obj_type: None,
+            as_token,
+            target,
+        }
+    }
+}
+
+impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for AsExpr {
+    fn accept(&mut self, visitor: &mut T) -> R {
+        visitor.visit_as_expr(self)
+    }
+}
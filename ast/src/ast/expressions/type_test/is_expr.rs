@@ -0,0 +1,34 @@
+use crate::{
+    Expression, ExpressionVisitor, Keyword, VisitableExpression,
+    typing::{Type, TypeAnnotation},
+};
+
+/// # Description
+///
+/// `expression is Type`, evaluates to whether the dynamic type of the value
+/// conforms to `target`. `obj_type` holds the static type of `expression`,
+/// once it has been inferred
+#[derive(Clone, Debug)]
+pub struct IsExpr {
+    pub expression: Box<Expression>,
+    pub obj_type: TypeAnnotation,
+    pub is_token: Keyword,
+    pub target: Type,
+}
+
+impl IsExpr {
+    pub fn new(expression: Expression, is_token: Keyword, target: Type) -> Self {
+        Self {
+            expression: Box::new(expression),
+            obj_type: None,
+            is_token,
+            target,
+        }
+    }
+}
+
+impl<T: ExpressionVisitor<R>, R> VisitableExpression<T, R> for IsExpr {
+    fn accept(&mut self, visitor: &mut T) -> R {
+        visitor.visit_is_expr(self)
+    }
+}
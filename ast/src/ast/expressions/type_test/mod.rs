@@ -0,0 +1,5 @@
+mod is_expr;
+pub use is_expr::IsExpr;
+
+mod as_expr;
+pub use as_expr::AsExpr;
@@ -5,7 +5,7 @@ use crate::{
 
 use super::Expression;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct UnOp {
     pub op: UnaryOperator,
     pub rhs: Box<Expression>,
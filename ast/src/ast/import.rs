@@ -0,0 +1,47 @@
+use crate::{
+    Identifier, Keyword, StringLiteral,
+    tokens::token_position::{TokenPosition, TokenPositionTrait},
+};
+
+/// # Description
+///
+/// What an `import` refers to: a file, by its path relative to the importing
+/// file (`import "shapes/square.hulk";`), or a module, by its name
+/// (`import shapes;`)
+#[derive(Clone, Debug)]
+pub enum ImportTarget {
+    Path(StringLiteral),
+    Module(Identifier),
+}
+
+#[derive(Clone, Debug)]
+pub struct Import {
+    pub import_token: Keyword,
+    pub target: ImportTarget,
+    /// Id of the imported file, set once the module loader resolves the import
+    pub file_id: Option<usize>,
+}
+
+impl Import {
+    pub fn new(import_token: Keyword, target: ImportTarget) -> Self {
+        Self {
+            import_token,
+            target,
+            file_id: None,
+        }
+    }
+
+    /// # Description
+    ///
+    /// Position of the imported path or module name
+    pub fn target_position(&self) -> TokenPosition {
+        match &self.target {
+            ImportTarget::Path(path) => path.position,
+            ImportTarget::Module(module) => module.position,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.import_token.position()
+    }
+}
@@ -4,5 +4,8 @@ pub use expressions::*;
 mod definitions;
 pub use definitions::*;
 
+mod import;
+pub use import::{Import, ImportTarget};
+
 mod program;
 pub use program::Program;
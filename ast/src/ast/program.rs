@@ -1,16 +1,48 @@
-use super::{Definition, Expression};
+use super::{Definition, Expression, Import};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Program {
+    pub imports: Vec<Import>,
     pub definitions: Vec<Definition>,
     pub expressions: Vec<Expression>,
+    /// Id of the source file the program was parsed from, when it is one of
+    /// the modules of a larger program
+    pub file_id: usize,
 }
 
 impl Program {
     pub fn new(definitions: Vec<Definition>, expressions: Vec<Expression>) -> Self {
         Self {
+            imports: Vec::new(),
             definitions,
             expressions,
+            file_id: 0,
         }
     }
+
+    pub fn with_imports(mut self, imports: Vec<Import>) -> Self {
+        self.imports = imports;
+        self
+    }
+
+    pub fn with_file_id(mut self, file_id: usize) -> Self {
+        self.file_id = file_id;
+        self
+    }
+
+    /// # Description
+    ///
+    /// Joins the modules of a program into a single one, which has the
+    /// imports, definitions and expressions of all the modules in order.
+    /// Modules must come after the modules they import
+    pub fn link(modules: Vec<Program>) -> Self {
+        let mut program = Program::new(Vec::new(), Vec::new());
+        for module in modules {
+            program.imports.extend(module.imports);
+            program.definitions.extend(module.definitions);
+            program.expressions.extend(module.expressions);
+            program.file_id = module.file_id;
+        }
+        program
+    }
 }
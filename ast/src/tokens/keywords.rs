@@ -22,6 +22,10 @@ pub enum Keyword {
     Protocol(TokenPosition),
     Extends(TokenPosition),
     Return(TokenPosition),
+    Is(TokenPosition),
+    As(TokenPosition),
+    Import(TokenPosition),
+    Base(TokenPosition),
 }
 
 impl Display for Keyword {
@@ -42,12 +46,16 @@ impl Display for Keyword {
             Keyword::Return(_) => write!(f, "return"),
             Keyword::For(_) => write!(f, "for"),
             Keyword::New(_) => write!(f, "new"),
+            Keyword::Is(_) => write!(f, "is"),
+            Keyword::As(_) => write!(f, "as"),
+            Keyword::Import(_) => write!(f, "import"),
+            Keyword::Base(_) => write!(f, "base"),
         }
     }
 }
 
 impl TokenPositionTrait for Keyword {
-    fn position(&self) -> usize {
+    fn token_position(&self) -> TokenPosition {
         match self {
             Keyword::Let(pos)
             | Keyword::If(pos)
@@ -63,7 +71,11 @@ impl TokenPositionTrait for Keyword {
             | Keyword::Extends(pos)
             | Keyword::Return(pos)
             | Keyword::For(pos)
-            | Keyword::New(pos) => pos.position(),
+            | Keyword::New(pos)
+            | Keyword::Is(pos)
+            | Keyword::As(pos)
+            | Keyword::Import(pos)
+            | Keyword::Base(pos) => *pos,
         }
     }
 }
@@ -2,7 +2,7 @@ use std::{fmt::Display, usize};
 
 use super::*;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct NumberLiteral {
     pub position: TokenPosition,
     pub value: f64,
@@ -23,7 +23,7 @@ impl Display for NumberLiteral {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum BooleanLiteral {
     True(TokenPosition),
     False(TokenPosition),
@@ -51,7 +51,7 @@ impl Display for BooleanLiteral {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct StringLiteral {
     pub position: TokenPosition,
     pub string: String,
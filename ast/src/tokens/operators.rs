@@ -5,7 +5,7 @@ use crate::tokens::token_position::TokenPositionTrait;
 
 use super::*;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum BinaryOperator {
     Plus(TokenPosition),
     Minus(TokenPosition),
@@ -13,6 +13,7 @@ pub enum BinaryOperator {
     FloorDivide(TokenPosition),
     Times(TokenPosition),
     Modulo(TokenPosition),
+    Power(TokenPosition),
 
     At(TokenPosition),
     AtAt(TokenPosition),
@@ -32,26 +33,27 @@ pub enum BinaryOperator {
 }
 
 impl TokenPositionTrait for BinaryOperator {
-    fn position(&self) -> usize {
+    fn token_position(&self) -> TokenPosition {
         match self {
-            BinaryOperator::Plus(pos) => pos.start,
-            BinaryOperator::Minus(pos) => pos.start,
-            BinaryOperator::Divide(pos) => pos.start,
-            BinaryOperator::FloorDivide(pos) => pos.start,
-            BinaryOperator::Times(pos) => pos.start,
-            BinaryOperator::Modulo(pos) => pos.start,
-            BinaryOperator::At(pos) => pos.start,
-            BinaryOperator::AtAt(pos) => pos.start,
-            BinaryOperator::EqualEqual(pos) => pos.start,
-            BinaryOperator::Less(pos) => pos.start,
-            BinaryOperator::LessEqual(pos) => pos.start,
-            BinaryOperator::Greater(pos) => pos.start,
-            BinaryOperator::GreaterEqual(pos) => pos.start,
-            BinaryOperator::NotEqual(pos) => pos.start,
-            BinaryOperator::Or(pos) => pos.start,
-            BinaryOperator::And(pos) => pos.start,
-            BinaryOperator::Equal(pos) => pos.start,
-            BinaryOperator::ColonEqual(pos) => pos.start,
+            BinaryOperator::Plus(pos) => *pos,
+            BinaryOperator::Minus(pos) => *pos,
+            BinaryOperator::Divide(pos) => *pos,
+            BinaryOperator::FloorDivide(pos) => *pos,
+            BinaryOperator::Times(pos) => *pos,
+            BinaryOperator::Modulo(pos) => *pos,
+            BinaryOperator::Power(pos) => *pos,
+            BinaryOperator::At(pos) => *pos,
+            BinaryOperator::AtAt(pos) => *pos,
+            BinaryOperator::EqualEqual(pos) => *pos,
+            BinaryOperator::Less(pos) => *pos,
+            BinaryOperator::LessEqual(pos) => *pos,
+            BinaryOperator::Greater(pos) => *pos,
+            BinaryOperator::GreaterEqual(pos) => *pos,
+            BinaryOperator::NotEqual(pos) => *pos,
+            BinaryOperator::Or(pos) => *pos,
+            BinaryOperator::And(pos) => *pos,
+            BinaryOperator::Equal(pos) => *pos,
+            BinaryOperator::ColonEqual(pos) => *pos,
         }
     }
 }
@@ -62,9 +64,10 @@ impl Display for BinaryOperator {
             BinaryOperator::Plus(_) => write!(f, "+"),
             BinaryOperator::Minus(_) => write!(f, "-"),
             BinaryOperator::Divide(_) => write!(f, "/"),
-            BinaryOperator::FloorDivide(_) => write!(f, "//"),
+            BinaryOperator::FloorDivide(_) => write!(f, "div"),
             BinaryOperator::Times(_) => write!(f, "*"),
             BinaryOperator::Modulo(_) => write!(f, "%"),
+            BinaryOperator::Power(_) => write!(f, "^"),
             BinaryOperator::Equal(_) => write!(f, "="),
             BinaryOperator::ColonEqual(_) => write!(f, ":="),
             BinaryOperator::Less(_) => write!(f, "<"),
@@ -89,11 +92,11 @@ pub enum UnaryOperator {
 }
 
 impl TokenPositionTrait for UnaryOperator {
-    fn position(&self) -> usize {
+    fn token_position(&self) -> TokenPosition {
         match self {
-            UnaryOperator::Plus(pos) => pos.start,
-            UnaryOperator::Minus(pos) => pos.start,
-            UnaryOperator::Not(pos) => pos.start,
+            UnaryOperator::Plus(pos) => *pos,
+            UnaryOperator::Minus(pos) => *pos,
+            UnaryOperator::Not(pos) => *pos,
         }
     }
 }
@@ -108,7 +111,7 @@ impl Display for UnaryOperator {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum GroupingOperator {
     OpenParen(TokenPosition),
     CloseParen(TokenPosition),
@@ -119,14 +122,14 @@ pub enum GroupingOperator {
 }
 
 impl TokenPositionTrait for GroupingOperator {
-    fn position(&self) -> usize {
+    fn token_position(&self) -> TokenPosition {
         match self {
-            GroupingOperator::OpenParen(pos) => pos.start,
-            GroupingOperator::CloseParen(pos) => pos.start,
-            GroupingOperator::OpenBrace(pos) => pos.start,
-            GroupingOperator::CloseBrace(pos) => pos.start,
-            GroupingOperator::OpenBracket(pos) => pos.start,
-            GroupingOperator::CloseBracket(pos) => pos.start,
+            GroupingOperator::OpenParen(pos) => *pos,
+            GroupingOperator::CloseParen(pos) => *pos,
+            GroupingOperator::OpenBrace(pos) => *pos,
+            GroupingOperator::CloseBrace(pos) => *pos,
+            GroupingOperator::OpenBracket(pos) => *pos,
+            GroupingOperator::CloseBracket(pos) => *pos,
         }
     }
 }
@@ -144,7 +147,7 @@ impl Display for GroupingOperator {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ArrowOperator {
     pub position: TokenPosition,
 }
@@ -156,12 +159,12 @@ impl ArrowOperator {
 }
 
 impl TokenPositionTrait for ArrowOperator {
-    fn position(&self) -> usize {
-        self.position.start
+    fn token_position(&self) -> TokenPosition {
+        self.position
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct DotOperator {
     pub position: TokenPosition,
 }
@@ -179,7 +182,7 @@ impl Display for DotOperator {
 }
 
 impl TokenPositionTrait for DotOperator {
-    fn position(&self) -> usize {
-        self.position.start
+    fn token_position(&self) -> TokenPosition {
+        self.position
     }
 }
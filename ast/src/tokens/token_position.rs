@@ -1,3 +1,5 @@
+use error_handler::diagnostic::diagnostic::Span;
+
 #[derive(Copy, Clone, Debug, Hash)]
 pub struct TokenPosition {
     pub start: usize,
@@ -11,11 +13,21 @@ impl TokenPosition {
 }
 
 pub trait TokenPositionTrait {
-    fn position(&self) -> usize;
+    fn token_position(&self) -> TokenPosition;
+
+    fn position(&self) -> usize {
+        self.token_position().start
+    }
 }
 
 impl TokenPositionTrait for TokenPosition {
-    fn position(&self) -> usize {
-        self.start
+    fn token_position(&self) -> TokenPosition {
+        *self
+    }
+}
+
+impl From<TokenPosition> for Span {
+    fn from(position: TokenPosition) -> Self {
+        Span::new(position.start, position.end)
     }
 }
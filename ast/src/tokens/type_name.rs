@@ -28,7 +28,7 @@ impl PartialEq for TypeName {
 }
 
 impl TokenPositionTrait for TypeName {
-    fn position(&self) -> usize {
-        self.position.start
+    fn token_position(&self) -> TokenPosition {
+        self.position
     }
 }
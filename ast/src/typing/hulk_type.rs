@@ -1,4 +1,5 @@
 use crate::TypeName;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result};
 
 pub type TypeAnnotation = Option<Type>;
@@ -16,6 +17,8 @@ pub enum Type {
     Functor(FunctorType),
     Defined(TypeName),
     Iterable(Box<Type>),
+    /// A generic type or protocol applied to its type arguments, e.g. `Stack<Number>`
+    Generic(TypeName, Vec<Type>),
 }
 
 impl From<TypeName> for Type {
@@ -67,6 +70,45 @@ impl Type {
         }
     }
 
+    pub fn as_generic(&self) -> Option<(&TypeName, &Vec<Type>)> {
+        if let Self::Generic(name, arguments) = self {
+            Some((name, arguments))
+        } else {
+            None
+        }
+    }
+
+    /// # Description
+    ///
+    /// Name of the type or protocol definition `self` refers to, i.e. its
+    /// name without the type arguments
+    pub fn definition_name(&self) -> String {
+        match self {
+            Type::Generic(name, _) => name.id.clone(),
+            _ => self.to_string(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Replaces the type parameters named in `bindings` by the types bound to
+    /// them, at any depth
+    pub fn substitute(&self, bindings: &HashMap<String, Type>) -> Type {
+        match self {
+            Type::Defined(name) => bindings
+                .get(&name.id)
+                .cloned()
+                .unwrap_or_else(|| self.clone()),
+            Type::BuiltIn(_) => self.clone(),
+            Type::Iterable(inner) => Type::Iterable(Box::new(inner.substitute(bindings))),
+            Type::Functor(functor) => Type::Functor(functor.substitute(bindings)),
+            Type::Generic(name, arguments) => Type::Generic(
+                name.clone(),
+                arguments.iter().map(|a| a.substitute(bindings)).collect(),
+            ),
+        }
+    }
+
     pub fn is_specific(&self) -> bool {
         match self {
             Type::BuiltIn(built_in_type) => match built_in_type {
@@ -79,6 +121,7 @@ impl Type {
                 .all(|x| x.as_ref().map(|x| x.is_specific()).unwrap_or(true)),
             Type::Defined(_) => true,
             Type::Iterable(it) => it.as_ref().is_specific(),
+            Type::Generic(_, arguments) => arguments.iter().all(|a| a.is_specific()),
         }
     }
 }
@@ -90,6 +133,14 @@ impl Display for Type {
             Type::Defined(ty) => write!(f, "{}", ty.id),
             Type::Functor(ty) => write!(f, "{}", ty),
             Type::Iterable(ty) => write!(f, "{}*", ty),
+            Type::Generic(name, arguments) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|a| a.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{}<{}>", name.id, arguments)
+            }
         }
     }
 }
@@ -126,6 +177,21 @@ impl FunctorType {
             return_type: Box::new(return_type),
         }
     }
+
+    pub fn substitute(&self, bindings: &HashMap<String, Type>) -> FunctorType {
+        FunctorType::new(
+            self.parameter_types
+                .iter()
+                .map(|p| substitute(p, bindings))
+                .collect(),
+            substitute(&self.return_type, bindings),
+        )
+    }
+}
+
+/// Substitutes the type parameters of an annotation, see `Type::substitute`
+pub fn substitute(ty: &TypeAnnotation, bindings: &HashMap<String, Type>) -> TypeAnnotation {
+    ty.as_ref().map(|ty| ty.substitute(bindings))
 }
 
 impl Display for FunctorType {
@@ -135,7 +201,7 @@ impl Display for FunctorType {
             .iter()
             .map(|p| to_string(p))
             .collect::<Vec<_>>()
-            .join("->");
-        write!(f, "({}): {:?}", params, self.return_type)
+            .join(", ");
+        write!(f, "({}) -> {}", params, to_string(&self.return_type))
     }
 }
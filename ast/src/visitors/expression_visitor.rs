@@ -26,6 +26,10 @@ pub trait ExpressionVisitor<R> {
     fn visist_list_indexing(&mut self, node: &mut ListIndexing) -> R;
 
     fn visit_function_call(&mut self, node: &mut FunctionCall) -> R;
+    fn visit_base_call(&mut self, node: &mut BaseCall) -> R;
+    fn visit_lambda(&mut self, node: &mut Lambda) -> R;
+    fn visit_is_expr(&mut self, node: &mut IsExpr) -> R;
+    fn visit_as_expr(&mut self, node: &mut AsExpr) -> R;
     fn visit_variable(&mut self, node: &mut Identifier) -> R;
     fn visit_number_literal(&mut self, node: &mut NumberLiteral) -> R;
     fn visit_boolean_literal(&mut self, node: &mut BooleanLiteral) -> R;
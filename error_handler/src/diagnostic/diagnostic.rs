@@ -0,0 +1,121 @@
+use serde::Serialize;
+
+use crate::source_map::SourceMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+/// # Description
+///
+/// A range of bytes of the program text, i.e. the concatenation of the
+/// files of a `SourceMap`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// # Description
+    ///
+    /// Where the span is in the files of `source_map`
+    pub fn locate(&self, source_map: &SourceMap) -> SpanLocation {
+        let (file, start, line, column) = match source_map.line_column(self.start) {
+            Some((file, line, column)) => (file.name.clone(), file.start, line, column),
+            None => (String::new(), 0, 1, 1),
+        };
+        let (end_line, end_column) = source_map
+            .line_column(self.end)
+            .map(|(_, line, column)| (line, column))
+            .unwrap_or((line, column));
+
+        SpanLocation {
+            file,
+            start: self.start - start,
+            end: self.end.saturating_sub(start),
+            line,
+            column,
+            end_line,
+            end_column,
+        }
+    }
+}
+
+/// The empty span at `position`, for code whose end is not known (e.g. the
+/// end of the input)
+impl From<usize> for Span {
+    fn from(position: usize) -> Self {
+        Self::new(position, position)
+    }
+}
+
+/// # Description
+///
+/// A `Span` relative to the file it is in, offsets are in bytes from the
+/// start of the file, lines and columns start at 1 and `end` is exclusive
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpanLocation {
+    pub file: String,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+/// # Description
+///
+/// A secondary span of a diagnostic, explaining how it relates to the error
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+}
+
+/// # Description
+///
+/// Structured form of an error or a warning, what the JSON and SARIF emitters
+/// write. `code` is the stable code of the error (e.g. `E0301`), see
+/// `HulkError::code` and `HulkWarning::code`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: String,
+    pub message: String,
+    pub span: Span,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &str, message: String, span: Span) -> Self {
+        Self {
+            severity,
+            code: code.to_string(),
+            message,
+            span,
+            labels: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn with_label(mut self, span: Span, message: String) -> Self {
+        self.labels.push(Label { span, message });
+        self
+    }
+
+    pub fn with_note(mut self, note: String) -> Self {
+        self.notes.push(note);
+        self
+    }
+}
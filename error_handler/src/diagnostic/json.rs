@@ -0,0 +1,57 @@
+use serde::Serialize;
+
+use crate::{
+    diagnostic::diagnostic::{Diagnostic, Severity, SpanLocation},
+    source_map::SourceMap,
+};
+
+#[derive(Serialize)]
+struct JsonLabel<'a> {
+    span: SpanLocation,
+    message: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    severity: Severity,
+    code: &'a str,
+    message: &'a str,
+    span: SpanLocation,
+    labels: Vec<JsonLabel<'a>>,
+    notes: &'a [String],
+}
+
+/// # Description
+///
+/// Writes `diagnostics` as a JSON array, with their spans located in the
+/// files of `source_map`
+///
+/// # Examples
+///
+/// ```json
+/// [{"severity":"error","code":"E0313","message":"Variable `y` is not defined.",
+///   "span":{"file":"main.hulk","start":6,"end":7,"line":1,"column":7,"end_line":1,"end_column":8},
+///   "labels":[],"notes":[]}]
+/// ```
+pub fn to_json(diagnostics: &[Diagnostic], source_map: &SourceMap) -> String {
+    let diagnostics = diagnostics
+        .iter()
+        .map(|diagnostic| JsonDiagnostic {
+            severity: diagnostic.severity,
+            code: &diagnostic.code,
+            message: &diagnostic.message,
+            span: diagnostic.span.locate(source_map),
+            labels: diagnostic
+                .labels
+                .iter()
+                .map(|label| JsonLabel {
+                    span: label.span.locate(source_map),
+                    message: &label.message,
+                })
+                .collect(),
+            notes: &diagnostic.notes,
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string(&diagnostics).expect("Diagnostics are always serializable")
+}
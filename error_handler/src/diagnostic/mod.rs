@@ -0,0 +1,4 @@
+pub mod diagnostic;
+
+pub mod json;
+pub mod sarif;
@@ -0,0 +1,97 @@
+use std::collections::BTreeSet;
+
+use serde_json::{Value, json};
+
+use crate::{
+    diagnostic::diagnostic::{Diagnostic, Severity, Span},
+    source_map::SourceMap,
+};
+
+pub const SARIF_VERSION: &str = "2.1.0";
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+pub const TOOL_NAME: &str = "hulk";
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+    }
+}
+
+fn physical_location(span: &Span, source_map: &SourceMap) -> Value {
+    let location = span.locate(source_map);
+
+    json!({
+        "artifactLocation": { "uri": location.file },
+        "region": {
+            "startLine": location.line,
+            "startColumn": location.column,
+            "endLine": location.end_line,
+            "endColumn": location.end_column,
+            "byteOffset": location.start,
+            "byteLength": location.end - location.start,
+        }
+    })
+}
+
+/// # Description
+///
+/// SARIF has no notes, they are appended to the message of the result
+fn result(diagnostic: &Diagnostic, source_map: &SourceMap) -> Value {
+    let mut text = diagnostic.message.clone();
+    for note in &diagnostic.notes {
+        text += &format!("\nnote: {}", note);
+    }
+
+    let related_locations = diagnostic
+        .labels
+        .iter()
+        .enumerate()
+        .map(|(id, label)| {
+            json!({
+                "id": id,
+                "physicalLocation": physical_location(&label.span, source_map),
+                "message": { "text": label.message },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "ruleId": diagnostic.code,
+        "level": level(diagnostic.severity),
+        "message": { "text": text },
+        "locations": [{ "physicalLocation": physical_location(&diagnostic.span, source_map) }],
+        "relatedLocations": related_locations,
+    })
+}
+
+/// # Description
+///
+/// Writes `diagnostics` as a SARIF 2.1.0 log with a single run, every
+/// diagnostic code found is listed as a rule of the tool
+pub fn to_sarif(diagnostics: &[Diagnostic], source_map: &SourceMap) -> String {
+    let rules = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code.as_str())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|code| json!({ "id": code }))
+        .collect::<Vec<_>>();
+
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| result(diagnostic, source_map))
+        .collect::<Vec<_>>();
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": { "driver": { "name": TOOL_NAME, "rules": rules } },
+            "results": results,
+        }]
+    });
+
+    serde_json::to_string_pretty(&log).expect("Diagnostics are always serializable")
+}
@@ -1,19 +1,77 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
     lexical::lexical_error::LexicalError, semantic::semantic_error::SemanticError,
     sintactic::syntactic_error::SyntacticError,
 };
+use crate::warning::warning::{HulkWarning, HulkWarningTrait};
 
 #[derive(Debug, Clone)]
 pub enum HulkError {
     LexicalError(LexicalError),
     SyntacticError(SyntacticError),
     SemanticError(SemanticError),
+    /// A warning of a lint configured with the `deny` level
+    DeniedWarning(HulkWarning),
 }
 
 pub trait HulkErrorTrait: Display + Into<HulkError> {
-    fn get_position(&self) -> usize;
+    /// The code the error is about
+    fn get_span(&self) -> Span;
+
+    fn get_position(&self) -> usize {
+        self.get_span().start
+    }
+}
+
+impl HulkError {
+    /// # Description
+    ///
+    /// Stable code of the error, see the `code` method of each kind of error
+    pub fn code(&self) -> &'static str {
+        match self {
+            HulkError::LexicalError(err) => err.code(),
+            HulkError::SyntacticError(err) => err.code(),
+            HulkError::SemanticError(err) => err.code(),
+            HulkError::DeniedWarning(warning) => warning.code(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Secondary positions of the error and their messages
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        match self {
+            HulkError::SemanticError(err) => err.labels(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Extra information shown after the error
+    pub fn notes(&self) -> Vec<String> {
+        match self {
+            HulkError::SemanticError(err) => err.notes(),
+            HulkError::DeniedWarning(warning) => {
+                vec![format!("the `{}` lint is denied", warning.lint())]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// The message of the error, without the prefix naming its kind
+    pub fn message(&self) -> String {
+        match self {
+            HulkError::LexicalError(err) => err.message(),
+            HulkError::SyntacticError(err) => err.message(),
+            HulkError::SemanticError(err) => err.message(),
+            HulkError::DeniedWarning(warning) => warning.message(),
+        }
+    }
 }
 
 impl Display for HulkError {
@@ -22,16 +80,18 @@ impl Display for HulkError {
             HulkError::LexicalError(err) => write!(f, "{}", err),
             HulkError::SyntacticError(err) => write!(f, "{}", err),
             HulkError::SemanticError(err) => write!(f, "{}", err),
+            HulkError::DeniedWarning(warning) => write!(f, "Error: {}", warning.message()),
         }
     }
 }
 
 impl HulkErrorTrait for HulkError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         match self {
-            HulkError::LexicalError(err) => err.get_position(),
-            HulkError::SyntacticError(err) => err.get_position(),
-            HulkError::SemanticError(err) => err.get_position(),
+            HulkError::LexicalError(err) => err.get_span(),
+            HulkError::SyntacticError(err) => err.get_span(),
+            HulkError::SemanticError(err) => err.get_span(),
+            HulkError::DeniedWarning(warning) => warning.get_span(),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -8,20 +9,20 @@ use crate::error::{
 #[derive(Debug, Clone)]
 pub struct InvalidCharacterError {
     character: char,
-    position: usize,
+    position: Span,
 }
 
 impl InvalidCharacterError {
-    pub fn new(character: char, position: usize) -> Self {
+    pub fn new(character: char, position: impl Into<Span>) -> Self {
         Self {
             character,
-            position,
+            position: position.into(),
         }
     }
 }
 
 impl HulkErrorTrait for InvalidCharacterError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::{Display, Formatter};
 
 use crate::error::{
@@ -10,14 +11,32 @@ pub enum LexicalError {
     InvalidCharacter(InvalidCharacterError),
 }
 
-impl Display for LexicalError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl LexicalError {
+    /// # Description
+    ///
+    /// Stable code of the error, `E01xx` for lexical errors
+    pub fn code(&self) -> &'static str {
+        match self {
+            LexicalError::InvalidCharacter(_) => "E0101",
+        }
+    }
+
+    /// # Description
+    ///
+    /// The message of the error, without the `Lexical Error: ` prefix
+    pub fn message(&self) -> String {
         match self {
-            LexicalError::InvalidCharacter(err) => write!(f, "Lexical Error: {}", err),
+            LexicalError::InvalidCharacter(err) => err.to_string(),
         }
     }
 }
 
+impl Display for LexicalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Lexical Error: {}", self.message())
+    }
+}
+
 impl Into<HulkError> for LexicalError {
     fn into(self) -> HulkError {
         HulkError::LexicalError(self)
@@ -25,9 +44,9 @@ impl Into<HulkError> for LexicalError {
 }
 
 impl HulkErrorTrait for LexicalError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         match self {
-            LexicalError::InvalidCharacter(err) => err.get_position(),
+            LexicalError::InvalidCharacter(err) => err.get_span(),
         }
     }
 }
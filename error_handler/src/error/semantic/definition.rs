@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -5,12 +6,30 @@ use std::fmt;
 #[derive(Debug, Clone)]
 pub struct UndefinedVariable {
     pub name: String,
-    pub position: usize,
+    pub position: Span,
+    /// A defined name close to `name`, shown as a "did you mean" note
+    pub suggestion: Option<String>,
 }
 
 impl UndefinedVariable {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+            suggestion: None,
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.suggestion
+            .iter()
+            .map(|name| format!("did you mean `{}`?", name))
+            .collect()
     }
 }
 
@@ -21,7 +40,7 @@ impl fmt::Display for UndefinedVariable {
 }
 
 impl HulkErrorTrait for UndefinedVariable {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -35,12 +54,30 @@ impl From<UndefinedVariable> for HulkError {
 #[derive(Debug, Clone)]
 pub struct UndefinedFunction {
     pub name: String,
-    pub position: usize,
+    pub position: Span,
+    /// A defined name close to `name`, shown as a "did you mean" note
+    pub suggestion: Option<String>,
 }
 
 impl UndefinedFunction {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+            suggestion: None,
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.suggestion
+            .iter()
+            .map(|name| format!("did you mean `{}`?", name))
+            .collect()
     }
 }
 
@@ -51,7 +88,7 @@ impl fmt::Display for UndefinedFunction {
 }
 
 impl HulkErrorTrait for UndefinedFunction {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -65,12 +102,30 @@ impl From<UndefinedFunction> for HulkError {
 #[derive(Debug, Clone)]
 pub struct UndefinedType {
     pub name: String,
-    pub position: usize,
+    pub position: Span,
+    /// A defined name close to `name`, shown as a "did you mean" note
+    pub suggestion: Option<String>,
 }
 
 impl UndefinedType {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+            suggestion: None,
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.suggestion
+            .iter()
+            .map(|name| format!("did you mean `{}`?", name))
+            .collect()
     }
 }
 
@@ -81,7 +136,7 @@ impl fmt::Display for UndefinedType {
 }
 
 impl HulkErrorTrait for UndefinedType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -95,12 +150,15 @@ impl From<UndefinedType> for HulkError {
 #[derive(Debug, Clone)]
 pub struct UndefinedTypeOrProtocol {
     pub name: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl UndefinedTypeOrProtocol {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+        }
     }
 }
 
@@ -111,7 +169,7 @@ impl fmt::Display for UndefinedTypeOrProtocol {
 }
 
 impl HulkErrorTrait for UndefinedTypeOrProtocol {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
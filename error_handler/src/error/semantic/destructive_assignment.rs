@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -5,12 +6,15 @@ use std::fmt;
 #[derive(Debug, Clone)]
 pub struct InvalidAssigmentTarget {
     pub target: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl InvalidAssigmentTarget {
-    pub fn new(target: String, position: usize) -> Self {
-        Self { target, position }
+    pub fn new(target: String, position: impl Into<Span>) -> Self {
+        Self {
+            target,
+            position: position.into(),
+        }
     }
 }
 
@@ -21,7 +25,7 @@ impl fmt::Display for InvalidAssigmentTarget {
 }
 
 impl HulkErrorTrait for InvalidAssigmentTarget {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -37,16 +41,16 @@ pub struct InvalidReassignmentType {
     pub name: String,
     pub expected: String,
     pub got: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl InvalidReassignmentType {
-    pub fn new(name: String, expected: String, got: String, position: usize) -> Self {
+    pub fn new(name: String, expected: String, got: String, position: impl Into<Span>) -> Self {
         Self {
             name,
             expected,
             got,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -62,7 +66,7 @@ impl fmt::Display for InvalidReassignmentType {
 }
 
 impl HulkErrorTrait for InvalidReassignmentType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -77,12 +81,16 @@ impl From<InvalidReassignmentType> for HulkError {
 pub struct ListInvalidReassignmentType {
     pub from: String,
     pub to: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl ListInvalidReassignmentType {
-    pub fn new(from: String, to: String, position: usize) -> Self {
-        Self { from, to, position }
+    pub fn new(from: String, to: String, position: impl Into<Span>) -> Self {
+        Self {
+            from,
+            to,
+            position: position.into(),
+        }
     }
 }
 
@@ -97,7 +105,7 @@ impl fmt::Display for ListInvalidReassignmentType {
 }
 
 impl HulkErrorTrait for ListInvalidReassignmentType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -110,12 +118,14 @@ impl From<ListInvalidReassignmentType> for HulkError {
 
 #[derive(Debug, Clone)]
 pub struct InvalidReassigmentExpression {
-    pub position: usize,
+    pub position: Span,
 }
 
 impl InvalidReassigmentExpression {
-    pub fn new(position: usize) -> Self {
-        Self { position }
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
     }
 }
 
@@ -126,7 +136,7 @@ impl fmt::Display for InvalidReassigmentExpression {
 }
 
 impl HulkErrorTrait for InvalidReassigmentExpression {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
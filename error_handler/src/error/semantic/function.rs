@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -5,12 +6,37 @@ use std::fmt;
 #[derive(Debug, Clone)]
 pub struct FuncAlreadyDefined {
     name: String,
-    position: usize,
+    position: Span,
+    /// Where the name was defined first, `None` for builtins
+    pub previous_definition: Option<Span>,
 }
 
 impl FuncAlreadyDefined {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+            previous_definition: None,
+        }
+    }
+
+    pub fn with_previous_definition(mut self, position: impl Into<Span>) -> Self {
+        self.previous_definition = Some(position.into());
+        self
+    }
+
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        self.previous_definition
+            .iter()
+            .map(|position| (*position, format!("`{}` is first defined here", self.name)))
+            .collect()
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        match self.previous_definition {
+            Some(_) => Vec::new(),
+            None => vec![format!("`{}` is a builtin function", self.name)],
+        }
     }
 }
 
@@ -21,7 +47,7 @@ impl fmt::Display for FuncAlreadyDefined {
 }
 
 impl HulkErrorTrait for FuncAlreadyDefined {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -37,16 +63,16 @@ pub struct FuncParamsInvalidAmount {
     name: String,
     expected: usize,
     provided: usize,
-    position: usize,
+    position: Span,
 }
 
 impl FuncParamsInvalidAmount {
-    pub fn new(name: String, expected: usize, provided: usize, position: usize) -> Self {
+    pub fn new(name: String, expected: usize, provided: usize, position: impl Into<Span>) -> Self {
         Self {
             name,
             expected,
             provided,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -62,7 +88,7 @@ impl fmt::Display for FuncParamsInvalidAmount {
 }
 
 impl HulkErrorTrait for FuncParamsInvalidAmount {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -79,17 +105,23 @@ pub struct FuncParamInvalidType {
     pub param: usize,
     pub expected: String,
     pub got: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl FuncParamInvalidType {
-    pub fn new(name: String, param: usize, expected: String, got: String, position: usize) -> Self {
+    pub fn new(
+        name: String,
+        param: usize,
+        expected: String,
+        got: String,
+        position: impl Into<Span>,
+    ) -> Self {
         Self {
             name,
             param,
             expected,
             got,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -105,7 +137,7 @@ impl fmt::Display for FuncParamInvalidType {
 }
 
 impl HulkErrorTrait for FuncParamInvalidType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -121,16 +153,16 @@ pub struct FuncReturnTypeInvalid {
     name: String,
     expected: String,
     found: String,
-    position: usize,
+    position: Span,
 }
 
 impl FuncReturnTypeInvalid {
-    pub fn new(name: String, expected: String, found: String, position: usize) -> Self {
+    pub fn new(name: String, expected: String, found: String, position: impl Into<Span>) -> Self {
         Self {
             name,
             expected,
             found,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -146,7 +178,7 @@ impl fmt::Display for FuncReturnTypeInvalid {
 }
 
 impl HulkErrorTrait for FuncReturnTypeInvalid {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -156,3 +188,42 @@ impl From<FuncReturnTypeInvalid> for HulkError {
         HulkError::SemanticError(SemanticError::FuncReturnTypeInvalid(e))
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct LambdaReturnTypeInvalid {
+    expected: String,
+    found: String,
+    position: Span,
+}
+
+impl LambdaReturnTypeInvalid {
+    pub fn new(expected: String, found: String, position: impl Into<Span>) -> Self {
+        Self {
+            expected,
+            found,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for LambdaReturnTypeInvalid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Lambda returns `{}` but `{}` was found.",
+            self.expected, self.found
+        )
+    }
+}
+
+impl HulkErrorTrait for LambdaReturnTypeInvalid {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<LambdaReturnTypeInvalid> for HulkError {
+    fn from(e: LambdaReturnTypeInvalid) -> Self {
+        HulkError::SemanticError(SemanticError::LambdaReturnTypeInvalid(e))
+    }
+}
@@ -0,0 +1,84 @@
+use crate::diagnostic::diagnostic::Span;
+use crate::error::error::{HulkError, HulkErrorTrait};
+use crate::error::semantic::semantic_error::SemanticError;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct TypeArgumentsInvalidAmount {
+    pub name: String,
+    pub expected: usize,
+    pub provided: usize,
+    pub position: Span,
+}
+
+impl TypeArgumentsInvalidAmount {
+    pub fn new(name: String, expected: usize, provided: usize, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            expected,
+            provided,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for TypeArgumentsInvalidAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` has {} type parameters, but {} type arguments were provided.",
+            self.name, self.expected, self.provided
+        )
+    }
+}
+
+impl HulkErrorTrait for TypeArgumentsInvalidAmount {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<TypeArgumentsInvalidAmount> for HulkError {
+    fn from(e: TypeArgumentsInvalidAmount) -> Self {
+        HulkError::SemanticError(SemanticError::TypeArgumentsInvalidAmount(e))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UninferableTypeParameter {
+    pub name: String,
+    pub parameter: String,
+    pub position: Span,
+}
+
+impl UninferableTypeParameter {
+    pub fn new(name: String, parameter: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            parameter,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for UninferableTypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cannot infer type parameter `{}` of `{}` from its arguments.",
+            self.parameter, self.name
+        )
+    }
+}
+
+impl HulkErrorTrait for UninferableTypeParameter {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<UninferableTypeParameter> for HulkError {
+    fn from(e: UninferableTypeParameter) -> Self {
+        HulkError::SemanticError(SemanticError::UninferableTypeParameter(e))
+    }
+}
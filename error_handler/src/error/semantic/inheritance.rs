@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt::{self, Display};
@@ -5,12 +6,15 @@ use std::fmt::{self, Display};
 #[derive(Debug, Clone)]
 pub struct InheritanceInvalidParent {
     pub name: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl InheritanceInvalidParent {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+        }
     }
 }
 
@@ -25,7 +29,7 @@ impl fmt::Display for InheritanceInvalidParent {
 }
 
 impl HulkErrorTrait for InheritanceInvalidParent {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -36,15 +40,116 @@ impl From<InheritanceInvalidParent> for HulkError {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ProtocolInvalidParent {
+    pub name: String,
+    pub position: Span,
+}
+
+impl ProtocolInvalidParent {
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for ProtocolInvalidParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a protocol and cannot be extended by one.",
+            self.name
+        )
+    }
+}
+
+impl HulkErrorTrait for ProtocolInvalidParent {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<ProtocolInvalidParent> for HulkError {
+    fn from(e: ProtocolInvalidParent) -> Self {
+        HulkError::SemanticError(SemanticError::ProtocolInvalidParent(e))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeInheritsProtocol {
+    pub type_name: String,
+    pub protocol_name: String,
+    pub position: Span,
+}
+
+impl TypeInheritsProtocol {
+    pub fn new(type_name: String, protocol_name: String, position: impl Into<Span>) -> Self {
+        Self {
+            type_name,
+            protocol_name,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for TypeInheritsProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Type `{}` cannot inherit from protocol `{}`, types conform to protocols implicitly.",
+            self.type_name, self.protocol_name
+        )
+    }
+}
+
+impl HulkErrorTrait for TypeInheritsProtocol {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<TypeInheritsProtocol> for HulkError {
+    fn from(e: TypeInheritsProtocol) -> Self {
+        HulkError::SemanticError(SemanticError::TypeInheritsProtocol(e))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InheritanceCycle {
     cycle: Vec<String>,
-    position: usize,
+    position: Span,
+    /// Where each type of the cycle names its parent, the last type of the
+    /// cycle is its first one again
+    pub parent_positions: Vec<Span>,
 }
 
 impl InheritanceCycle {
-    pub fn new(cycle: Vec<String>, position: usize) -> Self {
-        Self { cycle, position }
+    pub fn new(cycle: Vec<String>, position: impl Into<Span>) -> Self {
+        Self {
+            cycle,
+            position: position.into(),
+            parent_positions: Vec::new(),
+        }
+    }
+
+    pub fn with_parent_positions(mut self, positions: Vec<Span>) -> Self {
+        self.parent_positions = positions;
+        self
+    }
+
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        self.parent_positions
+            .iter()
+            .zip(self.cycle.windows(2))
+            .map(|(position, pair)| {
+                (
+                    *position,
+                    format!("`{}` inherits from `{}`", pair[0], pair[1]),
+                )
+            })
+            .collect()
     }
 }
 
@@ -59,7 +164,7 @@ impl fmt::Display for InheritanceCycle {
 }
 
 impl HulkErrorTrait for InheritanceCycle {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -72,12 +177,14 @@ impl From<InheritanceCycle> for HulkError {
 
 #[derive(Debug, Clone)]
 pub struct InvalidIfElseType {
-    pub position: usize,
+    pub position: Span,
 }
 
 impl InvalidIfElseType {
-    pub fn new(position: usize) -> Self {
-        Self { position }
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
     }
 }
 
@@ -97,19 +204,21 @@ impl From<InvalidIfElseType> for HulkError {
 }
 
 impl HulkErrorTrait for InvalidIfElseType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct InvalidListLiteralType {
-    pub position: usize,
+    pub position: Span,
 }
 
 impl InvalidListLiteralType {
-    pub fn new(position: usize) -> Self {
-        Self { position }
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
     }
 }
 
@@ -129,19 +238,21 @@ impl From<InvalidListLiteralType> for HulkError {
 }
 
 impl HulkErrorTrait for InvalidListLiteralType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct ObjectAnnotationError {
-    pub position: usize,
+    pub position: Span,
 }
 
 impl ObjectAnnotationError {
-    pub fn new(position: usize) -> Self {
-        Self { position }
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
     }
 }
 
@@ -161,7 +272,7 @@ impl From<ObjectAnnotationError> for HulkError {
 }
 
 impl HulkErrorTrait for ObjectAnnotationError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
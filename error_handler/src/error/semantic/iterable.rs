@@ -1,17 +1,18 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use std::fmt::{self, Display, Formatter};
 
 #[derive(Debug, Clone)]
 pub struct NonIterableType {
     pub type_name: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl NonIterableType {
-    pub fn new(type_name: String, position: usize) -> Self {
+    pub fn new(type_name: String, position: impl Into<Span>) -> Self {
         Self {
             type_name,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -23,7 +24,7 @@ impl Display for NonIterableType {
 }
 
 impl HulkErrorTrait for NonIterableType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -39,14 +40,14 @@ impl From<NonIterableType> for HulkError {
 #[derive(Debug, Clone)]
 pub struct InvalidIndexing {
     pub index_type: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl InvalidIndexing {
-    pub fn new(index_type: String, position: usize) -> Self {
+    pub fn new(index_type: String, position: impl Into<Span>) -> Self {
         Self {
             index_type,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -62,7 +63,7 @@ impl Display for InvalidIndexing {
 }
 
 impl HulkErrorTrait for InvalidIndexing {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -6,15 +7,15 @@ use std::fmt;
 pub struct AccessingPrivateMember {
     pub member: String,
     pub type_name: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl AccessingPrivateMember {
-    pub fn new(member: String, type_name: String, position: usize) -> Self {
+    pub fn new(member: String, type_name: String, position: impl Into<Span>) -> Self {
         Self {
             member,
             type_name,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -30,7 +31,7 @@ impl fmt::Display for AccessingPrivateMember {
 }
 
 impl HulkErrorTrait for AccessingPrivateMember {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -44,12 +45,30 @@ impl From<AccessingPrivateMember> for HulkError {
 #[derive(Debug, Clone)]
 pub struct FieldNotFound {
     pub member: String,
-    pub position: usize,
+    pub position: Span,
+    /// A defined name close to `member`, shown as a "did you mean" note
+    pub suggestion: Option<String>,
 }
 
 impl FieldNotFound {
-    pub fn new(member: String, position: usize) -> Self {
-        Self { member, position }
+    pub fn new(member: String, position: impl Into<Span>) -> Self {
+        Self {
+            member,
+            position: position.into(),
+            suggestion: None,
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.suggestion
+            .iter()
+            .map(|name| format!("did you mean `{}`?", name))
+            .collect()
     }
 }
 
@@ -60,7 +79,7 @@ impl fmt::Display for FieldNotFound {
 }
 
 impl HulkErrorTrait for FieldNotFound {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -74,12 +93,30 @@ impl From<FieldNotFound> for HulkError {
 #[derive(Debug, Clone)]
 pub struct MethodNotFound {
     pub method: String,
-    pub position: usize,
+    pub position: Span,
+    /// A defined name close to `method`, shown as a "did you mean" note
+    pub suggestion: Option<String>,
 }
 
 impl MethodNotFound {
-    pub fn new(method: String, position: usize) -> Self {
-        Self { method, position }
+    pub fn new(method: String, position: impl Into<Span>) -> Self {
+        Self {
+            method,
+            position: position.into(),
+            suggestion: None,
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.suggestion
+            .iter()
+            .map(|name| format!("did you mean `{}`?", name))
+            .collect()
     }
 }
 
@@ -90,7 +127,7 @@ impl fmt::Display for MethodNotFound {
 }
 
 impl HulkErrorTrait for MethodNotFound {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
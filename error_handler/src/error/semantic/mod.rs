@@ -3,9 +3,11 @@ pub mod semantic_error;
 pub mod definition;
 pub mod destructive_assignment;
 pub mod function;
+pub mod generics;
 pub mod inheritance;
 pub mod iterable;
 pub mod member_access;
+pub mod modules;
 pub mod operator;
 pub mod override_error;
 pub mod type_constructor;
@@ -0,0 +1,141 @@
+use crate::diagnostic::diagnostic::Span;
+use crate::error::error::{HulkError, HulkErrorTrait};
+use crate::error::semantic::semantic_error::SemanticError;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct ModuleNotFound {
+    pub module: String,
+    pub position: Span,
+}
+
+impl ModuleNotFound {
+    pub fn new(module: String, position: impl Into<Span>) -> Self {
+        Self {
+            module,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for ModuleNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Module `{}` not found.", self.module)
+    }
+}
+
+impl HulkErrorTrait for ModuleNotFound {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<ModuleNotFound> for HulkError {
+    fn from(e: ModuleNotFound) -> Self {
+        HulkError::SemanticError(SemanticError::ModuleNotFound(e))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DefinitionNotImported {
+    pub name: String,
+    pub module: String,
+    pub position: Span,
+    /// Where the name is defined
+    pub definition: Span,
+}
+
+impl DefinitionNotImported {
+    pub fn new(
+        name: String,
+        module: String,
+        position: impl Into<Span>,
+        definition: impl Into<Span>,
+    ) -> Self {
+        Self {
+            name,
+            module,
+            position: position.into(),
+            definition: definition.into(),
+        }
+    }
+
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        vec![(self.definition, format!("`{}` is defined here", self.name))]
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        vec![format!(
+            "`{}` must be imported where `{}` is used",
+            self.module, self.name
+        )]
+    }
+}
+
+impl fmt::Display for DefinitionNotImported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is defined in `{}`, which is not imported.",
+            self.name, self.module
+        )
+    }
+}
+
+impl HulkErrorTrait for DefinitionNotImported {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<DefinitionNotImported> for HulkError {
+    fn from(e: DefinitionNotImported) -> Self {
+        HulkError::SemanticError(SemanticError::DefinitionNotImported(e))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AmbiguousName {
+    pub name: String,
+    /// The imported modules that define the name
+    pub modules: Vec<String>,
+    pub position: Span,
+}
+
+impl AmbiguousName {
+    pub fn new(name: String, modules: Vec<String>, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            modules,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for AmbiguousName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modules = self
+            .modules
+            .iter()
+            .map(|module| format!("`{}`", module))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "`{}` is defined in several imported modules: {}.",
+            self.name, modules
+        )
+    }
+}
+
+impl HulkErrorTrait for AmbiguousName {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<AmbiguousName> for HulkError {
+    fn from(e: AmbiguousName) -> Self {
+        HulkError::SemanticError(SemanticError::AmbiguousName(e))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -10,16 +11,21 @@ pub struct BinOpError {
     operator: String,
     left_type: String,
     right_type: String,
-    position: usize,
+    position: Span,
 }
 
 impl BinOpError {
-    pub fn new(operator: String, left_type: String, right_type: String, position: usize) -> Self {
+    pub fn new(
+        operator: String,
+        left_type: String,
+        right_type: String,
+        position: impl Into<Span>,
+    ) -> Self {
         BinOpError {
             operator,
             left_type,
             right_type,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -41,7 +47,7 @@ impl Into<HulkError> for BinOpError {
 }
 
 impl HulkErrorTrait for BinOpError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -50,15 +56,15 @@ impl HulkErrorTrait for BinOpError {
 pub struct UnOpError {
     operator: String,
     operand_type: String,
-    position: usize,
+    position: Span,
 }
 
 impl UnOpError {
-    pub fn new(operator: String, operand_type: String, position: usize) -> Self {
+    pub fn new(operator: String, operand_type: String, position: impl Into<Span>) -> Self {
         UnOpError {
             operator,
             operand_type,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -79,7 +85,7 @@ impl Into<HulkError> for UnOpError {
 }
 
 impl HulkErrorTrait for UnOpError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
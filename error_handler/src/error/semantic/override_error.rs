@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -6,17 +7,37 @@ use std::fmt;
 pub struct FieldOverride {
     pub field: String,
     pub type_name: String,
-    pub position: usize,
+    pub position: Span,
+    /// Where the parent type defines the field
+    pub parent_definition: Option<Span>,
 }
 
 impl FieldOverride {
-    pub fn new(field: String, type_name: String, position: usize) -> Self {
+    pub fn new(field: String, type_name: String, position: impl Into<Span>) -> Self {
         Self {
             field,
             type_name,
-            position,
+            position: position.into(),
+            parent_definition: None,
         }
     }
+
+    pub fn with_parent_definition(mut self, position: impl Into<Span>) -> Self {
+        self.parent_definition = Some(position.into());
+        self
+    }
+
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        self.parent_definition
+            .iter()
+            .map(|position| {
+                (
+                    *position,
+                    format!("`{}` is first defined here, in a parent type", self.field),
+                )
+            })
+            .collect()
+    }
 }
 
 impl fmt::Display for FieldOverride {
@@ -30,7 +51,7 @@ impl fmt::Display for FieldOverride {
 }
 
 impl HulkErrorTrait for FieldOverride {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -45,17 +66,37 @@ impl From<FieldOverride> for HulkError {
 pub struct InvalidMethodOverride {
     pub method: String,
     pub type_name: String,
-    pub position: usize,
+    pub position: Span,
+    /// Where the parent type defines the method
+    pub parent_definition: Option<Span>,
 }
 
 impl InvalidMethodOverride {
-    pub fn new(method: String, type_name: String, position: usize) -> Self {
+    pub fn new(method: String, type_name: String, position: impl Into<Span>) -> Self {
         Self {
             method,
             type_name,
-            position,
+            position: position.into(),
+            parent_definition: None,
         }
     }
+
+    pub fn with_parent_definition(mut self, position: impl Into<Span>) -> Self {
+        self.parent_definition = Some(position.into());
+        self
+    }
+
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        self.parent_definition
+            .iter()
+            .map(|position| {
+                (
+                    *position,
+                    format!("`{}` is first defined here, in a parent type", self.method),
+                )
+            })
+            .collect()
+    }
 }
 
 impl fmt::Display for InvalidMethodOverride {
@@ -69,7 +110,7 @@ impl fmt::Display for InvalidMethodOverride {
 }
 
 impl HulkErrorTrait for InvalidMethodOverride {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -79,3 +120,73 @@ impl From<InvalidMethodOverride> for HulkError {
         HulkError::SemanticError(SemanticError::InvalidMethodOverride(e))
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct BaseOutsideMethod {
+    pub position: Span,
+}
+
+impl BaseOutsideMethod {
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for BaseOutsideMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`base` can only be called inside a method.")
+    }
+}
+
+impl HulkErrorTrait for BaseOutsideMethod {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<BaseOutsideMethod> for HulkError {
+    fn from(e: BaseOutsideMethod) -> Self {
+        HulkError::SemanticError(SemanticError::BaseOutsideMethod(e))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BaseMethodNotFound {
+    pub method: String,
+    pub type_name: String,
+    pub position: Span,
+}
+
+impl BaseMethodNotFound {
+    pub fn new(method: String, type_name: String, position: impl Into<Span>) -> Self {
+        Self {
+            method,
+            type_name,
+            position: position.into(),
+        }
+    }
+}
+
+impl fmt::Display for BaseMethodNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cannot call `base` in method `{}` of type `{}`, as no parent type defines it.",
+            self.method, self.type_name
+        )
+    }
+}
+
+impl HulkErrorTrait for BaseMethodNotFound {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<BaseMethodNotFound> for HulkError {
+    fn from(e: BaseMethodNotFound) -> Self {
+        HulkError::SemanticError(SemanticError::BaseMethodNotFound(e))
+    }
+}
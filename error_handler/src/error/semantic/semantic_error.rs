@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -12,16 +13,20 @@ use crate::error::{
         },
         function::{
             FuncAlreadyDefined, FuncParamInvalidType, FuncParamsInvalidAmount,
-            FuncReturnTypeInvalid,
+            FuncReturnTypeInvalid, LambdaReturnTypeInvalid,
         },
+        generics::{TypeArgumentsInvalidAmount, UninferableTypeParameter},
         inheritance::{
             InheritanceCycle, InheritanceInvalidParent, InvalidIfElseType, InvalidListLiteralType,
-            ObjectAnnotationError,
+            ObjectAnnotationError, ProtocolInvalidParent, TypeInheritsProtocol,
         },
         iterable::{InvalidIndexing, NonIterableType},
         member_access::{AccessingPrivateMember, FieldNotFound, MethodNotFound},
+        modules::{AmbiguousName, DefinitionNotImported, ModuleNotFound},
         operator::{BinOpError, UnOpError},
-        override_error::{FieldOverride, InvalidMethodOverride},
+        override_error::{
+            BaseMethodNotFound, BaseOutsideMethod, FieldOverride, InvalidMethodOverride,
+        },
         type_constructor::{TypeParamInvalidType, TypeParamsInvalidAmount},
         type_definition::{TypeMemberAlreadyDefined, TypeOrProtocolAlreadyDefined},
         type_errors::{NeedsAnAnnotation, NeedsMoreSpecificType, UnknownListType},
@@ -44,11 +49,21 @@ pub enum SemanticError {
     FuncParamsInvalidAmount(FuncParamsInvalidAmount), // "Function {} expects {} parameters, but {} were provided",
     FuncParamInvalidType(FuncParamInvalidType), // "Function {} expects parameter {} of type {}, but got {}",
     FuncReturnTypeInvalid(FuncReturnTypeInvalid), // "Type mismatch: Function {} returns {} but {} was found",
+    LambdaReturnTypeInvalid(LambdaReturnTypeInvalid), // "Lambda returns {} but {} was found",
 
     // type constructor
     TypeParamsInvalidAmount(TypeParamsInvalidAmount), // "Type {} has {} parameters, but {} were provided",
     TypeParamInvalidType(TypeParamInvalidType), // "Type {} expects parameter {} of type {}, but got {}"
 
+    // generics
+    TypeArgumentsInvalidAmount(TypeArgumentsInvalidAmount), // "{} has {} type parameters, but {} type arguments were provided",
+    UninferableTypeParameter(UninferableTypeParameter), // "Cannot infer type parameter {} of {} from its arguments",
+
+    // modules
+    ModuleNotFound(ModuleNotFound), // "Module {} not found",
+    DefinitionNotImported(DefinitionNotImported), // "{} is defined in {}, which is not imported",
+    AmbiguousName(AmbiguousName),   // "{} is defined in several imported modules: {}",
+
     // definition
     UndefinedVariable(UndefinedVariable), // "Variable {} is not defined"
     UndefinedFunction(UndefinedFunction), // "Function {} is not defined",w
@@ -72,6 +87,8 @@ pub enum SemanticError {
     // inheritance
     InheritanceInvalidParent(InheritanceInvalidParent), // "Type {} is a built-in type and cannot be inherited from",
     InheritanceCycle(InheritanceCycle), // "Semantic Error: Inheritance cycle detected
+    ProtocolInvalidParent(ProtocolInvalidParent), // "{} is not a protocol and cannot be extended by one",
+    TypeInheritsProtocol(TypeInheritsProtocol),   // "Type {} cannot inherit from protocol {}",
     InvalidIfElseType(InvalidIfElseType),
     InvalidListLiteralType(InvalidListLiteralType),
     ObjectAnnotationError(ObjectAnnotationError),
@@ -88,19 +105,118 @@ pub enum SemanticError {
     // override
     FieldOverride(FieldOverride), // "Semantic Error: Cannot declare field {} in type {}, as it overrides parent definition.",
     InvalidMethodOverride(InvalidMethodOverride), // "Semantic Error: Method {} in type {}, does not properly overrides parent definition.",
+    BaseOutsideMethod(BaseOutsideMethod),         // "base can only be called inside a method",
+    BaseMethodNotFound(BaseMethodNotFound), // "Cannot call base in method {} of type {}, as no parent type defines it",
 }
 
-impl Display for SemanticError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let suffix = match self {
+impl SemanticError {
+    /// # Description
+    ///
+    /// Stable code of the error, `E03xx` for semantic errors. Codes are never
+    /// reused, new variants get the next free code
+    pub fn code(&self) -> &'static str {
+        match self {
+            SemanticError::NeedsAnAnnotation(_) => "E0301",
+            SemanticError::UnknownListType(_) => "E0302",
+            SemanticError::NeedsMoreSpecificType(_) => "E0303",
+            SemanticError::BinOpInvalidOperands(_) => "E0304",
+            SemanticError::UnOpInvalidOperands(_) => "E0305",
+            SemanticError::FuncAlreadyDefined(_) => "E0306",
+            SemanticError::FuncParamsInvalidAmount(_) => "E0307",
+            SemanticError::FuncParamInvalidType(_) => "E0308",
+            SemanticError::FuncReturnTypeInvalid(_) => "E0309",
+            SemanticError::LambdaReturnTypeInvalid(_) => "E0310",
+            SemanticError::TypeParamsInvalidAmount(_) => "E0311",
+            SemanticError::TypeParamInvalidType(_) => "E0312",
+            SemanticError::UndefinedVariable(_) => "E0313",
+            SemanticError::UndefinedFunction(_) => "E0314",
+            SemanticError::UndefinedType(_) => "E0315",
+            SemanticError::UndefinedTypeOrProtocol(_) => "E0316",
+            SemanticError::TypeOrProtocolAlreadyDefined(_) => "E0317",
+            SemanticError::TypeMemberAlreadyDefined(_) => "E0318",
+            SemanticError::VarDefinitionTypeMismatch(_) => "E0319",
+            SemanticError::VarAlreadyDefined(_) => "E0320",
+            SemanticError::InvalidAssigmentTarget(_) => "E0321",
+            SemanticError::InvalidReassignmentType(_) => "E0322",
+            SemanticError::ListInvalidReassignmentType(_) => "E0323",
+            SemanticError::InvalidReassigmentExpression(_) => "E0324",
+            SemanticError::InheritanceInvalidParent(_) => "E0325",
+            SemanticError::InheritanceCycle(_) => "E0326",
+            SemanticError::ProtocolInvalidParent(_) => "E0327",
+            SemanticError::TypeInheritsProtocol(_) => "E0328",
+            SemanticError::InvalidIfElseType(_) => "E0329",
+            SemanticError::InvalidListLiteralType(_) => "E0330",
+            SemanticError::ObjectAnnotationError(_) => "E0331",
+            SemanticError::NonIterableType(_) => "E0332",
+            SemanticError::InvalidIndexing(_) => "E0333",
+            SemanticError::AccessingPrivateMember(_) => "E0334",
+            SemanticError::FieldNotFound(_) => "E0335",
+            SemanticError::MethodNotFound(_) => "E0336",
+            SemanticError::FieldOverride(_) => "E0337",
+            SemanticError::InvalidMethodOverride(_) => "E0338",
+            SemanticError::TypeArgumentsInvalidAmount(_) => "E0339",
+            SemanticError::UninferableTypeParameter(_) => "E0340",
+            SemanticError::ModuleNotFound(_) => "E0341",
+            SemanticError::DefinitionNotImported(_) => "E0342",
+            SemanticError::BaseOutsideMethod(_) => "E0343",
+            SemanticError::BaseMethodNotFound(_) => "E0344",
+            SemanticError::AmbiguousName(_) => "E0345",
+        }
+    }
+
+    /// # Description
+    ///
+    /// Secondary positions of the error, with a message explaining how they
+    /// relate to it, e.g. where a name that is defined twice was defined first
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        match self {
+            SemanticError::FuncAlreadyDefined(e) => e.labels(),
+            SemanticError::VarAlreadyDefined(e) => e.labels(),
+            SemanticError::TypeOrProtocolAlreadyDefined(e) => e.labels(),
+            SemanticError::FieldOverride(e) => e.labels(),
+            SemanticError::InvalidMethodOverride(e) => e.labels(),
+            SemanticError::InheritanceCycle(e) => e.labels(),
+            SemanticError::DefinitionNotImported(e) => e.labels(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Extra information shown after the error, e.g. "did you mean" suggestions
+    pub fn notes(&self) -> Vec<String> {
+        match self {
+            SemanticError::FuncAlreadyDefined(e) => e.notes(),
+            SemanticError::TypeOrProtocolAlreadyDefined(e) => e.notes(),
+            SemanticError::UndefinedVariable(e) => e.notes(),
+            SemanticError::UndefinedFunction(e) => e.notes(),
+            SemanticError::UndefinedType(e) => e.notes(),
+            SemanticError::FieldNotFound(e) => e.notes(),
+            SemanticError::MethodNotFound(e) => e.notes(),
+            SemanticError::DefinitionNotImported(e) => e.notes(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// The message of the error, without the `Semantic Error: ` prefix
+    pub fn message(&self) -> String {
+        match self {
             SemanticError::BinOpInvalidOperands(e) => format!("{}", e),
             SemanticError::UnOpInvalidOperands(e) => format!("{}", e),
             SemanticError::FuncAlreadyDefined(e) => format!("{}", e),
             SemanticError::FuncParamsInvalidAmount(e) => format!("{}", e),
             SemanticError::FuncParamInvalidType(e) => format!("{}", e),
             SemanticError::FuncReturnTypeInvalid(e) => format!("{}", e),
+            SemanticError::LambdaReturnTypeInvalid(e) => format!("{}", e),
             SemanticError::TypeParamsInvalidAmount(e) => format!("{}", e),
             SemanticError::TypeParamInvalidType(e) => format!("{}", e),
+            SemanticError::TypeArgumentsInvalidAmount(e) => format!("{}", e),
+            SemanticError::UninferableTypeParameter(e) => format!("{}", e),
+            SemanticError::ModuleNotFound(e) => format!("{}", e),
+            SemanticError::DefinitionNotImported(e) => format!("{}", e),
+            SemanticError::AmbiguousName(e) => format!("{}", e),
             SemanticError::UndefinedVariable(e) => format!("{}", e),
             SemanticError::UndefinedFunction(e) => format!("{}", e),
             SemanticError::UndefinedType(e) => format!("{}", e),
@@ -115,6 +231,8 @@ impl Display for SemanticError {
             SemanticError::InvalidReassigmentExpression(e) => format!("{}", e),
             SemanticError::InheritanceInvalidParent(e) => format!("{}", e),
             SemanticError::InheritanceCycle(e) => format!("{}", e),
+            SemanticError::ProtocolInvalidParent(e) => format!("{}", e),
+            SemanticError::TypeInheritsProtocol(e) => format!("{}", e),
             SemanticError::InvalidIfElseType(e) => format!("{}", e),
             SemanticError::InvalidListLiteralType(e) => format!("{}", e),
             SemanticError::ObjectAnnotationError(e) => format!("{}", e),
@@ -125,11 +243,18 @@ impl Display for SemanticError {
             SemanticError::MethodNotFound(e) => format!("{}", e),
             SemanticError::FieldOverride(e) => format!("{}", e),
             SemanticError::InvalidMethodOverride(e) => format!("{}", e),
+            SemanticError::BaseOutsideMethod(e) => format!("{}", e),
+            SemanticError::BaseMethodNotFound(e) => format!("{}", e),
             SemanticError::NeedsAnAnnotation(e) => format!("{}", e),
             SemanticError::UnknownListType(e) => format!("{}", e),
             SemanticError::NeedsMoreSpecificType(e) => format!("{}", e),
-        };
-        write!(f, "Semantic Error: {}", suffix)
+        }
+    }
+}
+
+impl Display for SemanticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Semantic Error: {}", self.message())
     }
 }
 
@@ -140,43 +265,53 @@ impl From<SemanticError> for HulkError {
 }
 
 impl HulkErrorTrait for SemanticError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         match self {
-            SemanticError::BinOpInvalidOperands(e) => e.get_position(),
-            SemanticError::UnOpInvalidOperands(e) => e.get_position(),
-            SemanticError::FuncAlreadyDefined(e) => e.get_position(),
-            SemanticError::FuncParamsInvalidAmount(e) => e.get_position(),
-            SemanticError::FuncParamInvalidType(e) => e.get_position(),
-            SemanticError::FuncReturnTypeInvalid(e) => e.get_position(),
-            SemanticError::TypeParamsInvalidAmount(e) => e.get_position(),
-            SemanticError::TypeParamInvalidType(e) => e.get_position(),
-            SemanticError::UndefinedVariable(e) => e.get_position(),
-            SemanticError::UndefinedFunction(e) => e.get_position(),
-            SemanticError::UndefinedType(e) => e.get_position(),
-            SemanticError::UndefinedTypeOrProtocol(e) => e.get_position(),
-            SemanticError::TypeOrProtocolAlreadyDefined(e) => e.get_position(),
-            SemanticError::TypeMemberAlreadyDefined(e) => e.get_position(),
-            SemanticError::VarDefinitionTypeMismatch(e) => e.get_position(),
-            SemanticError::VarAlreadyDefined(e) => e.get_position(),
-            SemanticError::InvalidAssigmentTarget(e) => e.get_position(),
-            SemanticError::InvalidReassignmentType(e) => e.get_position(),
-            SemanticError::ListInvalidReassignmentType(e) => e.get_position(),
-            SemanticError::InvalidReassigmentExpression(e) => e.get_position(),
-            SemanticError::InheritanceInvalidParent(e) => e.get_position(),
-            SemanticError::InheritanceCycle(e) => e.get_position(),
-            SemanticError::InvalidIfElseType(e) => e.get_position(),
-            SemanticError::InvalidListLiteralType(e) => e.get_position(),
-            SemanticError::ObjectAnnotationError(e) => e.get_position(),
-            SemanticError::NonIterableType(e) => e.get_position(),
-            SemanticError::InvalidIndexing(e) => e.get_position(),
-            SemanticError::AccessingPrivateMember(e) => e.get_position(),
-            SemanticError::FieldNotFound(e) => e.get_position(),
-            SemanticError::MethodNotFound(e) => e.get_position(),
-            SemanticError::FieldOverride(e) => e.get_position(),
-            SemanticError::InvalidMethodOverride(e) => e.get_position(),
-            SemanticError::NeedsAnAnnotation(e) => e.get_position(),
-            SemanticError::UnknownListType(e) => e.get_position(),
-            SemanticError::NeedsMoreSpecificType(e) => e.get_position(),
+            SemanticError::BinOpInvalidOperands(e) => e.get_span(),
+            SemanticError::UnOpInvalidOperands(e) => e.get_span(),
+            SemanticError::FuncAlreadyDefined(e) => e.get_span(),
+            SemanticError::FuncParamsInvalidAmount(e) => e.get_span(),
+            SemanticError::FuncParamInvalidType(e) => e.get_span(),
+            SemanticError::FuncReturnTypeInvalid(e) => e.get_span(),
+            SemanticError::LambdaReturnTypeInvalid(e) => e.get_span(),
+            SemanticError::TypeParamsInvalidAmount(e) => e.get_span(),
+            SemanticError::TypeParamInvalidType(e) => e.get_span(),
+            SemanticError::TypeArgumentsInvalidAmount(e) => e.get_span(),
+            SemanticError::UninferableTypeParameter(e) => e.get_span(),
+            SemanticError::ModuleNotFound(e) => e.get_span(),
+            SemanticError::DefinitionNotImported(e) => e.get_span(),
+            SemanticError::AmbiguousName(e) => e.get_span(),
+            SemanticError::UndefinedVariable(e) => e.get_span(),
+            SemanticError::UndefinedFunction(e) => e.get_span(),
+            SemanticError::UndefinedType(e) => e.get_span(),
+            SemanticError::UndefinedTypeOrProtocol(e) => e.get_span(),
+            SemanticError::TypeOrProtocolAlreadyDefined(e) => e.get_span(),
+            SemanticError::TypeMemberAlreadyDefined(e) => e.get_span(),
+            SemanticError::VarDefinitionTypeMismatch(e) => e.get_span(),
+            SemanticError::VarAlreadyDefined(e) => e.get_span(),
+            SemanticError::InvalidAssigmentTarget(e) => e.get_span(),
+            SemanticError::InvalidReassignmentType(e) => e.get_span(),
+            SemanticError::ListInvalidReassignmentType(e) => e.get_span(),
+            SemanticError::InvalidReassigmentExpression(e) => e.get_span(),
+            SemanticError::InheritanceInvalidParent(e) => e.get_span(),
+            SemanticError::InheritanceCycle(e) => e.get_span(),
+            SemanticError::ProtocolInvalidParent(e) => e.get_span(),
+            SemanticError::TypeInheritsProtocol(e) => e.get_span(),
+            SemanticError::InvalidIfElseType(e) => e.get_span(),
+            SemanticError::InvalidListLiteralType(e) => e.get_span(),
+            SemanticError::ObjectAnnotationError(e) => e.get_span(),
+            SemanticError::NonIterableType(e) => e.get_span(),
+            SemanticError::InvalidIndexing(e) => e.get_span(),
+            SemanticError::AccessingPrivateMember(e) => e.get_span(),
+            SemanticError::FieldNotFound(e) => e.get_span(),
+            SemanticError::MethodNotFound(e) => e.get_span(),
+            SemanticError::FieldOverride(e) => e.get_span(),
+            SemanticError::InvalidMethodOverride(e) => e.get_span(),
+            SemanticError::BaseOutsideMethod(e) => e.get_span(),
+            SemanticError::BaseMethodNotFound(e) => e.get_span(),
+            SemanticError::NeedsAnAnnotation(e) => e.get_span(),
+            SemanticError::UnknownListType(e) => e.get_span(),
+            SemanticError::NeedsMoreSpecificType(e) => e.get_span(),
         }
     }
 }
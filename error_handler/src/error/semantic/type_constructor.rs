@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -7,16 +8,16 @@ pub struct TypeParamsInvalidAmount {
     pub name: String,
     pub expected: usize,
     pub provided: usize,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl TypeParamsInvalidAmount {
-    pub fn new(name: String, expected: usize, provided: usize, position: usize) -> Self {
+    pub fn new(name: String, expected: usize, provided: usize, position: impl Into<Span>) -> Self {
         Self {
             name,
             expected,
             provided,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -32,7 +33,7 @@ impl fmt::Display for TypeParamsInvalidAmount {
 }
 
 impl HulkErrorTrait for TypeParamsInvalidAmount {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -49,17 +50,23 @@ pub struct TypeParamInvalidType {
     pub param: usize,
     pub expected: String,
     pub got: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl TypeParamInvalidType {
-    pub fn new(name: String, param: usize, expected: String, got: String, position: usize) -> Self {
+    pub fn new(
+        name: String,
+        param: usize,
+        expected: String,
+        got: String,
+        position: impl Into<Span>,
+    ) -> Self {
         Self {
             name,
             param,
             expected,
             got,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -75,7 +82,7 @@ impl fmt::Display for TypeParamInvalidType {
 }
 
 impl HulkErrorTrait for TypeParamInvalidType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -5,12 +6,37 @@ use std::fmt;
 #[derive(Debug, Clone)]
 pub struct TypeOrProtocolAlreadyDefined {
     pub name: String,
-    pub position: usize,
+    pub position: Span,
+    /// Where the name was defined first, `None` for builtins
+    pub previous_definition: Option<Span>,
 }
 
 impl TypeOrProtocolAlreadyDefined {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+            previous_definition: None,
+        }
+    }
+
+    pub fn with_previous_definition(mut self, position: impl Into<Span>) -> Self {
+        self.previous_definition = Some(position.into());
+        self
+    }
+
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        self.previous_definition
+            .iter()
+            .map(|position| (*position, format!("`{}` is first defined here", self.name)))
+            .collect()
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        match self.previous_definition {
+            Some(_) => Vec::new(),
+            None => vec![format!("`{}` is a builtin type", self.name)],
+        }
     }
 }
 
@@ -21,7 +47,7 @@ impl fmt::Display for TypeOrProtocolAlreadyDefined {
 }
 
 impl HulkErrorTrait for TypeOrProtocolAlreadyDefined {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -36,15 +62,15 @@ impl From<TypeOrProtocolAlreadyDefined> for HulkError {
 pub struct TypeMemberAlreadyDefined {
     pub member: String,
     pub type_name: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl TypeMemberAlreadyDefined {
-    pub fn new(member: String, type_name: String, position: usize) -> Self {
+    pub fn new(member: String, type_name: String, position: impl Into<Span>) -> Self {
         Self {
             member,
             type_name,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -60,7 +86,7 @@ impl fmt::Display for TypeMemberAlreadyDefined {
 }
 
 impl HulkErrorTrait for TypeMemberAlreadyDefined {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::{
     error::{HulkError, HulkErrorTrait},
     semantic::semantic_error::SemanticError,
@@ -6,12 +7,15 @@ use crate::error::{
 #[derive(Debug, Clone)]
 pub struct NeedsAnAnnotation {
     name: String,
-    position: usize,
+    position: Span,
 }
 
 impl NeedsAnAnnotation {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+        }
     }
 }
 
@@ -22,7 +26,7 @@ impl std::fmt::Display for NeedsAnAnnotation {
 }
 
 impl HulkErrorTrait for NeedsAnAnnotation {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -35,12 +39,14 @@ impl From<NeedsAnAnnotation> for HulkError {
 
 #[derive(Debug, Clone)]
 pub struct UnknownListType {
-    position: usize,
+    position: Span,
 }
 
 impl UnknownListType {
-    pub fn new(position: usize) -> Self {
-        Self { position }
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
     }
 }
 
@@ -51,7 +57,7 @@ impl std::fmt::Display for UnknownListType {
 }
 
 impl HulkErrorTrait for UnknownListType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -66,15 +72,15 @@ impl From<UnknownListType> for HulkError {
 pub struct NeedsMoreSpecificType {
     name: String,
     received: String,
-    position: usize,
+    position: Span,
 }
 
 impl NeedsMoreSpecificType {
-    pub fn new(name: String, received: String, position: usize) -> Self {
+    pub fn new(name: String, received: String, position: impl Into<Span>) -> Self {
         Self {
             name,
             received,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -90,7 +96,7 @@ impl std::fmt::Display for NeedsMoreSpecificType {
 }
 
 impl HulkErrorTrait for NeedsMoreSpecificType {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
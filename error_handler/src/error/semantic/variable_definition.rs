@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::error::{HulkError, HulkErrorTrait};
 use crate::error::semantic::semantic_error::SemanticError;
 use std::fmt;
@@ -6,12 +7,16 @@ use std::fmt;
 pub struct VarDefinitionTypeMismatch {
     pub from: String,
     pub to: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl VarDefinitionTypeMismatch {
-    pub fn new(from: String, to: String, position: usize) -> Self {
-        Self { from, to, position }
+    pub fn new(from: String, to: String, position: impl Into<Span>) -> Self {
+        Self {
+            from,
+            to,
+            position: position.into(),
+        }
     }
 }
 
@@ -22,7 +27,7 @@ impl fmt::Display for VarDefinitionTypeMismatch {
 }
 
 impl HulkErrorTrait for VarDefinitionTypeMismatch {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -36,12 +41,30 @@ impl From<VarDefinitionTypeMismatch> for HulkError {
 #[derive(Debug, Clone)]
 pub struct VarAlreadyDefined {
     pub name: String,
-    pub position: usize,
+    pub position: Span,
+    /// Where the name was defined first, `None` for builtins
+    pub previous_definition: Option<Span>,
 }
 
 impl VarAlreadyDefined {
-    pub fn new(name: String, position: usize) -> Self {
-        Self { name, position }
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+            previous_definition: None,
+        }
+    }
+
+    pub fn with_previous_definition(mut self, position: impl Into<Span>) -> Self {
+        self.previous_definition = Some(position.into());
+        self
+    }
+
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        self.previous_definition
+            .iter()
+            .map(|position| (*position, format!("`{}` is first defined here", self.name)))
+            .collect()
     }
 }
 
@@ -52,7 +75,7 @@ impl fmt::Display for VarAlreadyDefined {
 }
 
 impl HulkErrorTrait for VarAlreadyDefined {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -8,17 +9,20 @@ use crate::error::{
 #[derive(Debug, Clone)]
 pub struct ExtraTokenError {
     token: String,
-    position: usize,
+    position: Span,
 }
 
 impl ExtraTokenError {
-    pub fn new(token: String, position: usize) -> Self {
-        Self { token, position }
+    pub fn new(token: String, position: impl Into<Span>) -> Self {
+        Self {
+            token,
+            position: position.into(),
+        }
     }
 }
 
 impl HulkErrorTrait for ExtraTokenError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -7,12 +8,14 @@ use crate::error::{
 
 #[derive(Debug, Clone)]
 pub struct InvalidTokenError {
-    position: usize,
+    position: Span,
 }
 
 impl InvalidTokenError {
-    pub fn new(position: usize) -> Self {
-        Self { position }
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
     }
 }
 
@@ -29,7 +32,7 @@ impl Display for InvalidTokenError {
 }
 
 impl HulkErrorTrait for InvalidTokenError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
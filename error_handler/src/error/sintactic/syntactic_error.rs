@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -19,13 +20,13 @@ pub enum SyntacticError {
 }
 
 impl HulkErrorTrait for SyntacticError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         match self {
-            SyntacticError::InvalidToken(err) => err.get_position(),
-            SyntacticError::UnrecognizedEof(err) => err.get_position(),
-            SyntacticError::UnrecognizedToken(err) => err.get_position(),
-            SyntacticError::ExtraToken(err) => err.get_position(),
-            SyntacticError::UserError(err) => err.get_position(),
+            SyntacticError::InvalidToken(err) => err.get_span(),
+            SyntacticError::UnrecognizedEof(err) => err.get_span(),
+            SyntacticError::UnrecognizedToken(err) => err.get_span(),
+            SyntacticError::ExtraToken(err) => err.get_span(),
+            SyntacticError::UserError(err) => err.get_span(),
         }
     }
 }
@@ -35,14 +36,36 @@ impl Into<HulkError> for SyntacticError {
         HulkError::SyntacticError(self)
     }
 }
-impl Display for SyntacticError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl SyntacticError {
+    /// # Description
+    ///
+    /// Stable code of the error, `E02xx` for syntactic errors
+    pub fn code(&self) -> &'static str {
+        match self {
+            SyntacticError::InvalidToken(_) => "E0201",
+            SyntacticError::UnrecognizedEof(_) => "E0202",
+            SyntacticError::UnrecognizedToken(_) => "E0203",
+            SyntacticError::ExtraToken(_) => "E0204",
+            SyntacticError::UserError(_) => "E0205",
+        }
+    }
+
+    /// # Description
+    ///
+    /// The message of the error, without the `Sintactic Error: ` prefix
+    pub fn message(&self) -> String {
         match self {
-            SyntacticError::InvalidToken(err) => write!(f, "Sintactic Error: {}", err),
-            SyntacticError::UnrecognizedEof(err) => write!(f, "Sintactic Error: {}", err),
-            SyntacticError::UnrecognizedToken(err) => write!(f, "Sintactic Error: {}", err),
-            SyntacticError::ExtraToken(err) => write!(f, "Sintactic Error: {}", err),
-            SyntacticError::UserError(err) => write!(f, "Sintactic Error: {}", err),
+            SyntacticError::InvalidToken(err) => err.to_string(),
+            SyntacticError::UnrecognizedEof(err) => err.to_string(),
+            SyntacticError::UnrecognizedToken(err) => err.to_string(),
+            SyntacticError::ExtraToken(err) => err.to_string(),
+            SyntacticError::UserError(err) => err.to_string(),
         }
     }
 }
+
+impl Display for SyntacticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Sintactic Error: {}", self.message())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -8,17 +9,20 @@ use crate::error::{
 #[derive(Debug, Clone)]
 pub struct UnrecognizedEofError {
     expected: Vec<String>,
-    position: usize,
+    position: Span,
 }
 
 impl UnrecognizedEofError {
-    pub fn new(expected: Vec<String>, position: usize) -> Self {
-        Self { expected, position }
+    pub fn new(expected: Vec<String>, position: impl Into<Span>) -> Self {
+        Self {
+            expected,
+            position: position.into(),
+        }
     }
 }
 
 impl HulkErrorTrait for UnrecognizedEofError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
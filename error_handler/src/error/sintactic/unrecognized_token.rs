@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use std::fmt::Display;
 
 use crate::error::{
@@ -9,15 +10,15 @@ use crate::error::{
 pub struct UnrecognizedTokenError {
     token: String,
     expected: Vec<String>,
-    position: usize,
+    position: Span,
 }
 
 impl UnrecognizedTokenError {
-    pub fn new(token: String, expected: Vec<String>, position: usize) -> Self {
+    pub fn new(token: String, expected: Vec<String>, position: impl Into<Span>) -> Self {
         Self {
             token,
             expected,
-            position,
+            position: position.into(),
         }
     }
 }
@@ -45,7 +46,7 @@ impl Into<HulkError> for UnrecognizedTokenError {
 }
 
 impl HulkErrorTrait for UnrecognizedTokenError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
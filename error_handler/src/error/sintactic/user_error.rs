@@ -1,3 +1,4 @@
+use crate::diagnostic::diagnostic::Span;
 use crate::error::{
     error::{HulkError, HulkErrorTrait},
     sintactic::syntactic_error::SyntacticError,
@@ -6,12 +7,15 @@ use crate::error::{
 #[derive(Debug, Clone)]
 pub struct UserError {
     pub message: String,
-    pub position: usize,
+    pub position: Span,
 }
 
 impl UserError {
-    pub fn new(message: String, position: usize) -> Self {
-        Self { message, position }
+    pub fn new(message: String, position: impl Into<Span>) -> Self {
+        Self {
+            message,
+            position: position.into(),
+        }
     }
 }
 
@@ -28,7 +32,7 @@ impl From<UserError> for HulkError {
 }
 
 impl HulkErrorTrait for UserError {
-    fn get_position(&self) -> usize {
+    fn get_span(&self) -> Span {
         self.position
     }
 }
@@ -1,39 +1,47 @@
-use crate::error::{
-    error::{HulkError, HulkErrorTrait},
-    semantic::semantic_error::SemanticError,
+use crate::{
+    diagnostic::diagnostic::{Diagnostic, Severity, Span},
+    error::error::{HulkError, HulkErrorTrait},
+    source_map::SourceMap,
+    warning::warning::{HulkWarning, HulkWarningTrait},
 };
 
+/// # Description
+///
+/// Collects the errors and warnings found in a program and formats them,
+/// pointing at the file, line and column of the source map each one is at.
+/// Code before `offset` (the library code) is not shown
 pub struct ErrorHandler {
-    program_text: String,
-    line_breaks: Vec<usize>,
+    source_map: SourceMap,
     offset: usize,
     pub errors: Vec<HulkError>,
+    pub warnings: Vec<HulkWarning>,
 }
 
 impl ErrorHandler {
+    /// # Description
+    ///
+    /// Handler for a program given as a single text, the code before `offset`
+    /// being library code
     pub fn new(program_text: &str, offset: usize) -> Self {
         Self {
-            program_text: program_text[offset..].to_string(),
-            line_breaks: Self::get_line_breaks(program_text, offset),
+            source_map: SourceMap::from_text(program_text),
             offset,
             errors: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
-    fn get_line_breaks(program_text: &str, offset: usize) -> Vec<usize> {
-        let text = &program_text[offset..];
-        let mut line_breaks = Vec::new();
-        if !text.is_empty() {
-            line_breaks.push(0);
-        }
-        line_breaks.extend(
-            text.char_indices()
-                .filter_map(|(i, c)| if c == '\n' { Some(i + 1) } else { None }),
-        );
-        if !text.ends_with('\n') {
-            line_breaks.push(text.len());
+    /// # Description
+    ///
+    /// Handler for the program made up of the files in `source_map`, whose
+    /// library files are not shown
+    pub fn from_source_map(source_map: SourceMap) -> Self {
+        Self {
+            offset: source_map.user_code_offset(),
+            source_map,
+            errors: Vec::new(),
+            warnings: Vec::new(),
         }
-        line_breaks
     }
 
     pub fn add_error(&mut self, error: HulkError) {
@@ -48,6 +56,25 @@ impl ErrorHandler {
         !self.errors.is_empty()
     }
 
+    pub fn extend_warnings(&mut self, warnings: Vec<HulkWarning>) {
+        self.warnings.extend(warnings);
+    }
+
+    /// # Description
+    ///
+    /// Formatted warnings found in the user code, warnings in library code
+    /// (before the offset) are not reported
+    pub fn get_warning_messages(&mut self) -> Vec<String> {
+        self.warnings
+            .sort_by(|a, b| a.get_position().cmp(&b.get_position()));
+
+        self.warnings
+            .iter()
+            .filter(|warning| warning.get_position() >= self.offset)
+            .map(|warning| self.format_at(&warning.to_string(), warning.get_span()))
+            .collect()
+    }
+
     pub fn get_error_messages(&mut self) -> Vec<String> {
         self.errors
             .sort_by(|a, b| a.get_position().cmp(&b.get_position()));
@@ -58,6 +85,56 @@ impl ErrorHandler {
             .collect()
     }
 
+    /// # Description
+    ///
+    /// Structured errors and warnings, sorted by position. Like formatted
+    /// warnings, warnings in library code are left out
+    pub fn get_diagnostics(&mut self) -> Vec<Diagnostic> {
+        let mut diagnostics = self
+            .errors
+            .iter()
+            .map(|error| {
+                let mut diagnostic = Diagnostic::new(
+                    Severity::Error,
+                    error.code(),
+                    error.message(),
+                    self.user_span(error.get_span()),
+                );
+                for (span, label) in error.labels() {
+                    diagnostic = diagnostic.with_label(self.user_span(span), label);
+                }
+                for note in error.notes() {
+                    diagnostic = diagnostic.with_note(note);
+                }
+                diagnostic
+            })
+            .chain(
+                self.warnings
+                    .iter()
+                    .filter(|warning| warning.get_position() >= self.offset)
+                    .map(|warning| {
+                        Diagnostic::new(
+                            Severity::Warning,
+                            warning.code(),
+                            warning.message(),
+                            self.user_span(warning.get_span()),
+                        )
+                    }),
+            )
+            .collect::<Vec<_>>();
+
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+        diagnostics
+    }
+
+    /// `span`, emptied if it is in library code
+    fn user_span(&self, span: Span) -> Span {
+        if span.start < self.offset {
+            return Span::new(span.start, span.start);
+        }
+        span
+    }
+
     pub fn get_raw_errors(&mut self) -> Vec<String> {
         self.errors
             .sort_by(|a, b| a.get_position().cmp(&b.get_position()));
@@ -65,40 +142,111 @@ impl ErrorHandler {
         self.errors.iter().map(|error| error.to_string()).collect()
     }
 
-    fn get_line_number(&self, position: usize) -> usize {
-        self.line_breaks
-            .partition_point(|&line_start| line_start <= position)
-            - 1
+    fn format_message(&self, error: &HulkError) -> String {
+        // labels in library code can not be shown
+        let labels = error
+            .labels()
+            .into_iter()
+            .filter(|(span, _)| span.start >= self.offset)
+            .collect::<Vec<_>>();
+
+        self.format_snippets(
+            &error.to_string(),
+            error.get_span(),
+            &labels,
+            &error.notes(),
+        )
     }
 
-    fn format_message(&self, error: &HulkError) -> String {
-        if let HulkError::SemanticError(SemanticError::InheritanceCycle(semantic_error)) = error {
-            return semantic_error.to_string();
+    fn format_at(&self, message: &str, span: Span) -> String {
+        self.format_snippets(message, span, &[], &[])
+    }
+
+    /// # Description
+    ///
+    /// Formats `message` followed by the line of code `span` starts in, with
+    /// `span` underlined. Each label is shown the same way after it, with its
+    /// message next to the code it points at, and the notes go at the end.
+    /// A label in another file than the code before it gets its own header
+    fn format_snippets(
+        &self,
+        message: &str,
+        span: Span,
+        labels: &[(Span, String)],
+        notes: &[String],
+    ) -> String {
+        let mut result = format!(
+            "{}
+",
+            message
+        );
+        let mut current_file = None;
+
+        if span.start >= self.offset {
+            if let Some(header) = self.format_header(span) {
+                result += &header;
+                result += &self.format_snippet(span, '^', "");
+                current_file = self.source_map.file_id_at(span.start);
+            }
         }
 
-        let pos = error.get_position() - self.offset;
-        let line_number = self.get_line_number(pos);
-        let line_start = self.line_breaks[line_number];
-        let line_end = self
-            .line_breaks
-            .get(line_number + 1)
-            .cloned()
-            .unwrap_or(self.program_text.len());
-
-        let line_text = &self.program_text[line_start..line_end]
-            .trim_end_matches('\n')
-            .trim_start_matches("\n");
-        let col = pos - line_start;
-        let pointer_line = format!("{}{}", " ".repeat(col), "^",);
-
-        format!(
-            "{}\n --> line {}:{}\n  |\n{:3} | {}\n  |   {}\n",
-            error.to_string(),
-            line_number + 1,
-            col + 1,
-            line_number + 1,
-            line_text,
-            pointer_line
-        )
+        for (label_span, label) in labels {
+            let file = self.source_map.file_id_at(label_span.start);
+            match self.format_header(*label_span) {
+                Some(header) if file != current_file => result += &header,
+                _ => result += "  |\n",
+            }
+            current_file = file;
+            result += &self.format_snippet(*label_span, '-', label);
+        }
+
+        for note in notes {
+            result += &format!("  = note: {}\n", note);
+        }
+
+        result
+    }
+
+    /// # Description
+    ///
+    /// The file, line and column `span` starts at, e.g.
+    /// ` --> main.hulk, line 2:7`, followed by an empty gutter line
+    fn format_header(&self, span: Span) -> Option<String> {
+        let (file, line, column) = self.source_map.line_column(span.start)?;
+        Some(match file.name.as_str() {
+            "" => format!(" --> line {}:{}\n  |\n", line, column),
+            name => format!(" --> {}, line {}:{}\n  |\n", name, line, column),
+        })
+    }
+
+    /// # Description
+    ///
+    /// The line `span` starts in, with `span` underlined with `marker` up to
+    /// the end of the line and followed by `label`. An empty span is shown
+    /// as a single marker
+    fn format_snippet(&self, span: Span, marker: char, label: &str) -> String {
+        let Some((_, line, column)) = self.source_map.line_column(span.start) else {
+            return String::new();
+        };
+        let line_text = self.source_map.line_text(span.start);
+        let line_end = self.source_map.line_start(span.start) + line_text.len();
+
+        let token_len = self
+            .source_map
+            .content()
+            .get(span.start..span.end.min(line_end))
+            .map_or(0, |token| token.chars().count())
+            .max(1);
+        let pointer_line = format!(
+            "{}{}",
+            " ".repeat(column - 1),
+            marker.to_string().repeat(token_len)
+        );
+        let pointer_line = match label {
+            "" => pointer_line,
+            label => format!("{} {}", pointer_line, label),
+        };
+
+        format!("{:3} | {}\n  |   {}\n", line, line_text, pointer_line)
     }
 }
@@ -1,3 +1,12 @@
 pub mod error_handler;
 
 pub mod error;
+
+pub mod warning;
+
+pub mod source_map;
+
+pub mod diagnostic;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,212 @@
+/// # Description
+///
+/// A source file that was added to a `SourceMap`, `start` is the offset of
+/// its first byte in the concatenated program text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFile {
+    pub name: String,
+    pub start: usize,
+    pub len: usize,
+    pub is_library: bool,
+}
+
+impl SourceFile {
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// # Description
+///
+/// Keeps track of the source files that make up a program. The files are
+/// concatenated (separated by a line break) into a single text, which is
+/// what the lexer and parser work on, positions in that text (byte offsets)
+/// can be mapped back to the file, line and column they belong to.
+///
+/// Library files (i.e. the standard library) must be added before any other
+/// file, errors are only reported relative to the non-library code
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    content: String,
+    files: Vec<SourceFile>,
+    /// Offset of the first byte of each line of `content`
+    line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Description
+    ///
+    /// The source map of a single unnamed file, e.g. a program given as a
+    /// string instead of read from a file
+    pub fn from_text(text: &str) -> Self {
+        let mut source_map = Self::new();
+        source_map.add_file("", text);
+        source_map
+    }
+
+    /// # Description
+    ///
+    /// Adds a library file, e.g. the standard library
+    ///
+    /// # Panics
+    ///
+    /// If a non-library file was already added
+    pub fn add_library(&mut self, name: &str, text: &str) -> usize {
+        assert!(
+            self.files.iter().all(|file| file.is_library),
+            "Library files must be added before any other source file"
+        );
+        self.add(name, text, true)
+    }
+
+    /// # Description
+    ///
+    /// Adds a source file, returning its id
+    pub fn add_file(&mut self, name: &str, text: &str) -> usize {
+        self.add(name, text, false)
+    }
+
+    fn add(&mut self, name: &str, text: &str, is_library: bool) -> usize {
+        if !self.files.is_empty() {
+            self.content.push('\n');
+        }
+        let start = self.content.len();
+
+        self.files.push(SourceFile {
+            name: name.to_string(),
+            start,
+            len: text.len(),
+            is_library,
+        });
+        self.content.push_str(text);
+
+        self.line_starts.push(start);
+        self.line_starts
+            .extend(text.match_indices('\n').map(|(index, _)| start + index + 1));
+
+        self.files.len() - 1
+    }
+
+    /// # Description
+    ///
+    /// The text of all the files, concatenated in the order they were added
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn files(&self) -> &[SourceFile] {
+        &self.files
+    }
+
+    pub fn file_text(&self, file_id: usize) -> &str {
+        let file = &self.files[file_id];
+        &self.content[file.start..file.end()]
+    }
+
+    /// # Description
+    ///
+    /// Returns the file that contains `position`, line breaks between files
+    /// belong to the file before them
+    pub fn file_at(&self, position: usize) -> Option<&SourceFile> {
+        self.files.get(self.file_id_at(position)?)
+    }
+
+    /// # Description
+    ///
+    /// Id of the file that contains `position`, see `file_at`
+    pub fn file_id_at(&self, position: usize) -> Option<usize> {
+        self.files
+            .partition_point(|file| file.start <= position)
+            .checked_sub(1)
+    }
+
+    /// # Description
+    ///
+    /// The file that contains `position`, with the line and column (both
+    /// starting at 1) `position` is at in that file. Columns count characters,
+    /// not bytes, so they are right for non-ASCII source
+    pub fn line_column(&self, position: usize) -> Option<(&SourceFile, usize, usize)> {
+        let file = self.file_at(position)?;
+        let position = position.min(file.end());
+
+        let first_line = self.line_index(file.start);
+        let line = self.line_index(position);
+        let line_start = self.line_starts[line];
+        let column = self.content[line_start..self.char_boundary(position)]
+            .chars()
+            .count();
+
+        Some((file, line - first_line + 1, column + 1))
+    }
+
+    /// # Description
+    ///
+    /// Offset of the first byte of the line `position` is in
+    pub fn line_start(&self, position: usize) -> usize {
+        self.line_starts
+            .get(self.line_index(position))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// # Description
+    ///
+    /// The text of the line `position` is in, without the line break
+    pub fn line_text(&self, position: usize) -> &str {
+        let start = self.line_start(position);
+        let end = self.content[start..]
+            .find('\n')
+            .map(|index| start + index)
+            .unwrap_or(self.content.len());
+        self.content[start..end].trim_end_matches('\r')
+    }
+
+    /// Index in `line_starts` of the line `position` is in
+    fn line_index(&self, position: usize) -> usize {
+        self.line_starts
+            .partition_point(|&line_start| line_start <= position)
+            .saturating_sub(1)
+    }
+
+    /// The greatest offset not after `position` that is at a character boundary
+    fn char_boundary(&self, position: usize) -> usize {
+        let mut position = position.min(self.content.len());
+        while !self.content.is_char_boundary(position) {
+            position -= 1;
+        }
+        position
+    }
+
+    /// # Description
+    ///
+    /// Where `position` is, as shown by runtime errors: `file:line:column`
+    /// followed by the text of that line, or the bare offset when it is not
+    /// in any file
+    pub fn location(&self, position: usize) -> String {
+        let Some((file, line, column)) = self.line_column(position) else {
+            return format!("position {position}");
+        };
+
+        format!(
+            "{}:{line}:{column}\n  | {}",
+            file.name,
+            self.line_text(position).trim_end()
+        )
+    }
+
+    /// # Description
+    ///
+    /// Offset of the first byte of non-library code, i.e. the offset an
+    /// `ErrorHandler` for this program should be created with
+    pub fn user_code_offset(&self) -> usize {
+        self.files
+            .iter()
+            .find(|file| !file.is_library)
+            .map(|file| file.start)
+            .unwrap_or(self.content.len())
+    }
+}
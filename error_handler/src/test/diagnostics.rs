@@ -0,0 +1,154 @@
+use serde_json::Value;
+
+use crate::{
+    diagnostic::{
+        diagnostic::{Diagnostic, Severity, Span},
+        json::to_json,
+        sarif::to_sarif,
+    },
+    error::semantic::{
+        definition::UndefinedVariable, modules::DefinitionNotImported,
+        variable_definition::VarAlreadyDefined,
+    },
+    error_handler::ErrorHandler,
+    source_map::SourceMap,
+};
+
+/// `program` as the main file of a program that also has a library file
+fn source_map(program: &str) -> SourceMap {
+    let mut source_map = SourceMap::new();
+    source_map.add_library("std.hulk", "function two(): Number => 2;");
+    source_map.add_file("main.hulk", program);
+    source_map
+}
+
+/// Offset of the first byte of the main file in `source_map`
+const MAIN: usize = 29;
+
+#[test]
+fn span_location() {
+    let source_map = source_map("print(two());\nprint(count + 1);");
+
+    let location = Span::new(MAIN + 20, MAIN + 25).locate(&source_map);
+
+    assert_eq!(location.file, "main.hulk");
+    assert_eq!((location.start, location.end), (20, 25));
+    assert_eq!((location.line, location.column), (2, 7));
+    assert_eq!((location.end_line, location.end_column), (2, 12));
+}
+
+#[test]
+fn underlines_the_whole_span() {
+    let mut error_handler =
+        ErrorHandler::from_source_map(source_map("print(two());\nprint(count + 1);"));
+    error_handler.add_error(
+        UndefinedVariable::new("count".to_string(), Span::new(MAIN + 20, MAIN + 25)).into(),
+    );
+
+    assert_eq!(
+        error_handler.get_error_messages(),
+        vec![
+            "Semantic Error: Variable `count` is not defined.\n \
+             --> main.hulk, line 2:7\n  |\n  \
+             2 | print(count + 1);\n  |         ^^^^^\n"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn empty_span_is_a_single_marker() {
+    let mut error_handler = ErrorHandler::from_source_map(source_map("print(x);"));
+    error_handler.add_error(UndefinedVariable::new("x".to_string(), MAIN + 6).into());
+
+    assert!(error_handler.get_error_messages()[0].ends_with("print(x);\n  |         ^\n"));
+}
+
+#[test]
+fn labels_underline_their_span() {
+    let mut error_handler =
+        ErrorHandler::from_source_map(source_map("let x = 1 in\nlet x = 2 in x;"));
+    error_handler.add_error(
+        VarAlreadyDefined::new("x".to_string(), Span::new(MAIN + 17, MAIN + 18))
+            .with_previous_definition(Span::new(MAIN + 4, MAIN + 5))
+            .into(),
+    );
+
+    let message = &error_handler.get_error_messages()[0];
+
+    assert!(message.contains("  1 | let x = 1 in\n  |       - `x` is first defined here\n"));
+}
+
+#[test]
+fn labels_in_another_file_get_a_header() {
+    let mut source_map = SourceMap::new();
+    source_map.add_file("shapes.hulk", "type Square {}");
+    let main = source_map.add_file("main.hulk", "print(new Square());");
+    let main = source_map.files()[main].start;
+    let mut error_handler = ErrorHandler::from_source_map(source_map);
+    error_handler.add_error(
+        DefinitionNotImported::new(
+            "Square".to_string(),
+            "shapes".to_string(),
+            Span::new(main + 10, main + 16),
+            Span::new(5, 11),
+        )
+        .into(),
+    );
+
+    let message = &error_handler.get_error_messages()[0];
+
+    assert!(message.contains(
+        " --> main.hulk, line 1:11\n  |\n  1 | print(new Square());\n  |             ^^^^^^\n \
+         --> shapes.hulk, line 1:6\n  |\n  1 | type Square {}\n  |        ------ `Square` is defined here\n"
+    ));
+}
+
+#[test]
+fn json_output() {
+    let source_map = source_map("print(two());\nprint(1 + \"a\");");
+    let diagnostics = vec![Diagnostic::new(
+        Severity::Error,
+        "E0304",
+        "Invalid operands.".to_string(),
+        Span::new(MAIN + 20, MAIN + 27),
+    )];
+
+    let json: Value = serde_json::from_str(&to_json(&diagnostics, &source_map)).unwrap();
+
+    let diagnostic = &json[0];
+    assert_eq!(diagnostic["severity"], "error");
+    assert_eq!(diagnostic["code"], "E0304");
+    assert_eq!(diagnostic["span"]["file"], "main.hulk");
+    assert_eq!(diagnostic["span"]["line"], 2);
+    assert_eq!(diagnostic["span"]["column"], 7);
+    assert_eq!(diagnostic["span"]["start"], 20);
+    assert_eq!(diagnostic["span"]["end"], 27);
+    assert_eq!(diagnostic["labels"], Value::Array(vec![]));
+}
+
+#[test]
+fn sarif_output() {
+    let source_map = source_map("print(two());\nprint(1 + \"a\");");
+    let diagnostics = vec![Diagnostic::new(
+        Severity::Error,
+        "E0304",
+        "Invalid operands.".to_string(),
+        Span::new(MAIN + 20, MAIN + 27),
+    )];
+
+    let sarif: Value = serde_json::from_str(&to_sarif(&diagnostics, &source_map)).unwrap();
+
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "E0304");
+
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "E0304");
+    assert_eq!(result["level"], "error");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "main.hulk");
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 7);
+    assert_eq!(location["region"]["endColumn"], 14);
+}
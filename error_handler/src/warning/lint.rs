@@ -0,0 +1,231 @@
+use crate::diagnostic::diagnostic::Span;
+use std::fmt::{self, Display};
+
+use crate::warning::warning::{HulkWarning, HulkWarningTrait};
+
+/// # Description
+///
+/// A `let` binding or a parameter that is never read
+#[derive(Debug, Clone)]
+pub struct UnusedVariable {
+    pub name: String,
+    pub is_parameter: bool,
+    pub position: Span,
+}
+
+impl UnusedVariable {
+    pub fn new(name: String, is_parameter: bool, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            is_parameter,
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for UnusedVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.is_parameter {
+            "Parameter"
+        } else {
+            "Variable"
+        };
+        write!(f, "{} `{}` is never used.", kind, self.name)
+    }
+}
+
+impl HulkWarningTrait for UnusedVariable {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<UnusedVariable> for HulkWarning {
+    fn from(w: UnusedVariable) -> Self {
+        HulkWarning::UnusedVariable(w)
+    }
+}
+
+/// # Description
+///
+/// A `return` statement followed by more items of its block, which never run
+#[derive(Debug, Clone)]
+pub struct UnreachableCode {
+    pub position: Span,
+}
+
+impl UnreachableCode {
+    pub fn new(position: impl Into<Span>) -> Self {
+        Self {
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for UnreachableCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The code after this `return` is unreachable.")
+    }
+}
+
+impl HulkWarningTrait for UnreachableCode {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<UnreachableCode> for HulkWarning {
+    fn from(w: UnreachableCode) -> Self {
+        HulkWarning::UnreachableCode(w)
+    }
+}
+
+/// # Description
+///
+/// A `let` binding with the name of a variable that is already visible
+#[derive(Debug, Clone)]
+pub struct ShadowedVariable {
+    pub name: String,
+    pub position: Span,
+}
+
+impl ShadowedVariable {
+    pub fn new(name: String, position: impl Into<Span>) -> Self {
+        Self {
+            name,
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for ShadowedVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` shadows a variable with the same name.", self.name)
+    }
+}
+
+impl HulkWarningTrait for ShadowedVariable {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<ShadowedVariable> for HulkWarning {
+    fn from(w: ShadowedVariable) -> Self {
+        HulkWarning::ShadowedVariable(w)
+    }
+}
+
+/// # Description
+///
+/// An `if` whose condition is a boolean literal, so only one of its branches
+/// can ever run
+#[derive(Debug, Clone)]
+pub struct ConstantCondition {
+    pub value: bool,
+    pub position: Span,
+}
+
+impl ConstantCondition {
+    pub fn new(value: bool, position: impl Into<Span>) -> Self {
+        Self {
+            value,
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for ConstantCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "This condition is always {}.", self.value)
+    }
+}
+
+impl HulkWarningTrait for ConstantCondition {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<ConstantCondition> for HulkWarning {
+    fn from(w: ConstantCondition) -> Self {
+        HulkWarning::ConstantCondition(w)
+    }
+}
+
+/// # Description
+///
+/// A destructive assignment of a variable or a field to itself
+#[derive(Debug, Clone)]
+pub struct SelfAssignment {
+    pub target: String,
+    pub position: Span,
+}
+
+impl SelfAssignment {
+    pub fn new(target: String, position: impl Into<Span>) -> Self {
+        Self {
+            target,
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for SelfAssignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is assigned to itself.", self.target)
+    }
+}
+
+impl HulkWarningTrait for SelfAssignment {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<SelfAssignment> for HulkWarning {
+    fn from(w: SelfAssignment) -> Self {
+        HulkWarning::SelfAssignment(w)
+    }
+}
+
+/// # Description
+///
+/// A function or method without a return type annotation whose inferred
+/// return type is `Object`, usually because its branches return unrelated types
+#[derive(Debug, Clone)]
+pub struct ObjectReturnType {
+    pub function: String,
+    pub position: Span,
+}
+
+impl ObjectReturnType {
+    pub fn new(function: String, position: impl Into<Span>) -> Self {
+        Self {
+            function,
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for ObjectReturnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The inferred return type of `{}` is `Object`, consider annotating it.",
+            self.function
+        )
+    }
+}
+
+impl HulkWarningTrait for ObjectReturnType {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<ObjectReturnType> for HulkWarning {
+    fn from(w: ObjectReturnType) -> Self {
+        HulkWarning::ObjectReturnType(w)
+    }
+}
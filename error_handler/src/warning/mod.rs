@@ -0,0 +1,4 @@
+pub mod warning;
+
+pub mod lint;
+pub mod type_test;
@@ -0,0 +1,90 @@
+use crate::diagnostic::diagnostic::Span;
+use std::fmt::{self, Display};
+
+use crate::warning::warning::{HulkWarning, HulkWarningTrait};
+
+/// # Description
+///
+/// An `is` test or `as` downcast whose operand's static type already
+/// conforms to the target type, so it can never fail
+#[derive(Debug, Clone)]
+pub struct TypeTestAlwaysTrue {
+    pub value_type: String,
+    pub target: String,
+    pub position: Span,
+}
+
+impl TypeTestAlwaysTrue {
+    pub fn new(value_type: String, target: String, position: impl Into<Span>) -> Self {
+        Self {
+            value_type,
+            target,
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for TypeTestAlwaysTrue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` always conforms to `{}`, this type test is always true.",
+            self.value_type, self.target
+        )
+    }
+}
+
+impl HulkWarningTrait for TypeTestAlwaysTrue {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<TypeTestAlwaysTrue> for HulkWarning {
+    fn from(w: TypeTestAlwaysTrue) -> Self {
+        HulkWarning::TypeTestAlwaysTrue(w)
+    }
+}
+
+/// # Description
+///
+/// An `is` test or `as` downcast between types no value can have at the same
+/// time, so it can never succeed
+#[derive(Debug, Clone)]
+pub struct TypeTestAlwaysFalse {
+    pub value_type: String,
+    pub target: String,
+    pub position: Span,
+}
+
+impl TypeTestAlwaysFalse {
+    pub fn new(value_type: String, target: String, position: impl Into<Span>) -> Self {
+        Self {
+            value_type,
+            target,
+            position: position.into(),
+        }
+    }
+}
+
+impl Display for TypeTestAlwaysFalse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` can never conform to `{}`, this type test is always false.",
+            self.value_type, self.target
+        )
+    }
+}
+
+impl HulkWarningTrait for TypeTestAlwaysFalse {
+    fn get_span(&self) -> Span {
+        self.position
+    }
+}
+
+impl From<TypeTestAlwaysFalse> for HulkWarning {
+    fn from(w: TypeTestAlwaysFalse) -> Self {
+        HulkWarning::TypeTestAlwaysFalse(w)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::diagnostic::diagnostic::Span;
+use std::fmt::Display;
+
+use crate::warning::{
+    lint::{
+        ConstantCondition, ObjectReturnType, SelfAssignment, ShadowedVariable, UnreachableCode,
+        UnusedVariable,
+    },
+    type_test::{TypeTestAlwaysFalse, TypeTestAlwaysTrue},
+};
+
+/// Names of the lints, the groups of warnings whose level can be configured,
+/// see `HulkWarning::lint`
+pub const LINT_NAMES: &[&str] = &[
+    "type-tests",
+    "unused-variables",
+    "unreachable-code",
+    "shadowed-variables",
+    "constant-conditions",
+    "self-assignments",
+    "object-return-types",
+];
+
+/// # Description
+///
+/// Problems found in a program that do not prevent it from being compiled,
+/// they are reported but never make a compilation fail
+#[derive(Debug, Clone)]
+pub enum HulkWarning {
+    // type tests
+    TypeTestAlwaysTrue(TypeTestAlwaysTrue), // "`{}` always conforms to `{}`, ..."
+    TypeTestAlwaysFalse(TypeTestAlwaysFalse), // "`{}` can never conform to `{}`, ..."
+
+    // lints over the typed AST
+    UnusedVariable(UnusedVariable),       // "{} `{}` is never used."
+    UnreachableCode(UnreachableCode),     // "The code after this `return` is unreachable."
+    ShadowedVariable(ShadowedVariable),   // "`{}` shadows a variable with the same name."
+    ConstantCondition(ConstantCondition), // "This condition is always {}."
+    SelfAssignment(SelfAssignment),       // "`{}` is assigned to itself."
+    ObjectReturnType(ObjectReturnType),   // "The inferred return type of `{}` is `Object`, ..."
+}
+
+pub trait HulkWarningTrait: Display + Into<HulkWarning> {
+    /// The code the warning is about
+    fn get_span(&self) -> Span;
+
+    fn get_position(&self) -> usize {
+        self.get_span().start
+    }
+}
+
+impl HulkWarning {
+    /// # Description
+    ///
+    /// Stable code of the warning, `W0xxx`
+    pub fn code(&self) -> &'static str {
+        match self {
+            HulkWarning::TypeTestAlwaysTrue(_) => "W0001",
+            HulkWarning::TypeTestAlwaysFalse(_) => "W0002",
+            HulkWarning::UnusedVariable(_) => "W0003",
+            HulkWarning::UnreachableCode(_) => "W0004",
+            HulkWarning::ShadowedVariable(_) => "W0005",
+            HulkWarning::ConstantCondition(_) => "W0006",
+            HulkWarning::SelfAssignment(_) => "W0007",
+            HulkWarning::ObjectReturnType(_) => "W0008",
+        }
+    }
+
+    /// # Description
+    ///
+    /// Name of the lint the warning belongs to, one of `LINT_NAMES`
+    pub fn lint(&self) -> &'static str {
+        match self {
+            HulkWarning::TypeTestAlwaysTrue(_) | HulkWarning::TypeTestAlwaysFalse(_) => {
+                "type-tests"
+            }
+            HulkWarning::UnusedVariable(_) => "unused-variables",
+            HulkWarning::UnreachableCode(_) => "unreachable-code",
+            HulkWarning::ShadowedVariable(_) => "shadowed-variables",
+            HulkWarning::ConstantCondition(_) => "constant-conditions",
+            HulkWarning::SelfAssignment(_) => "self-assignments",
+            HulkWarning::ObjectReturnType(_) => "object-return-types",
+        }
+    }
+
+    /// # Description
+    ///
+    /// The message of the warning, without the `Warning: ` prefix
+    pub fn message(&self) -> String {
+        match self {
+            HulkWarning::TypeTestAlwaysTrue(w) => format!("{}", w),
+            HulkWarning::TypeTestAlwaysFalse(w) => format!("{}", w),
+            HulkWarning::UnusedVariable(w) => format!("{}", w),
+            HulkWarning::UnreachableCode(w) => format!("{}", w),
+            HulkWarning::ShadowedVariable(w) => format!("{}", w),
+            HulkWarning::ConstantCondition(w) => format!("{}", w),
+            HulkWarning::SelfAssignment(w) => format!("{}", w),
+            HulkWarning::ObjectReturnType(w) => format!("{}", w),
+        }
+    }
+}
+
+impl Display for HulkWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Warning: {}", self.message())
+    }
+}
+
+impl HulkWarningTrait for HulkWarning {
+    fn get_span(&self) -> Span {
+        match self {
+            HulkWarning::TypeTestAlwaysTrue(w) => w.get_span(),
+            HulkWarning::TypeTestAlwaysFalse(w) => w.get_span(),
+            HulkWarning::UnusedVariable(w) => w.get_span(),
+            HulkWarning::UnreachableCode(w) => w.get_span(),
+            HulkWarning::ShadowedVariable(w) => w.get_span(),
+            HulkWarning::ConstantCondition(w) => w.get_span(),
+            HulkWarning::SelfAssignment(w) => w.get_span(),
+            HulkWarning::ObjectReturnType(w) => w.get_span(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Builds the lexer and the parser of the grammar once, at build time, and
+//! writes their tables to `OUT_DIR`, where `ProgramParser` loads them from
+//! instead of building the automata and the LR item sets on every run.
+//! The modules the grammar is defined in are compiled into this script too
+#![allow(dead_code)]
+
+use std::{env, fs, path::PathBuf};
+
+#[path = "src/types/mod.rs"]
+mod types;
+
+#[path = "src/parsing_helpers/mod.rs"]
+mod parsing_helpers;
+use parsing_helpers::*;
+
+#[path = "src/lexer_wrapper.rs"]
+mod lexer_wrapper;
+
+#[path = "src/grammar.rs"]
+mod grammar;
+
+fn main() {
+    for path in [
+        "src/grammar.rs",
+        "src/lexer_wrapper.rs",
+        "src/types",
+        "src/parsing_helpers",
+    ] {
+        println!("cargo:rerun-if-changed={path}");
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let (lexer, parser) = grammar::lexer_parser();
+
+    fs::write(
+        out_dir.join("parser_tables.bin"),
+        parser.tables().to_bytes(),
+    )
+    .unwrap();
+    fs::write(out_dir.join("lexer_tables.bin"), lexer.tables().to_bytes()).unwrap();
+}
@@ -1,12 +1,13 @@
 use ast::{
-    ArrowExpression, ArrowOperator, Assignment, Block, BlockBodyItem, ConstantDef,
-    DataMemberAccess, DataMemberDef, Definition, DestructiveAssignment, DotOperator, Expression,
-    FunctionBody, FunctionCall, FunctionDef, FunctionMemberAccess, GlobalFunctionDef,
-    GroupingOperator, IfElse, InheritanceIndicator, Keyword, LetIn, ListIndexing, ListLiteral,
-    NewExpr, ReturnStatement, UnOp, While,
+    ArrowExpression, ArrowOperator, AsExpr, Assignment, BaseCall, Block, BlockBodyItem,
+    ConstantDef, DataMemberAccess, DataMemberDef, Definition, DestructiveAssignment, DotOperator,
+    Expression, ExtensionIndicator, For, FunctionBody, FunctionCall, FunctionDef,
+    FunctionMemberAccess, FunctionSignature, GlobalFunctionDef, GroupingOperator, IfElse, Import,
+    ImportTarget, InheritanceIndicator, IsExpr, Keyword, Lambda, LetIn, ListIndexing, ListLiteral,
+    NewExpr, ProtocolDef, ReturnStatement, UnOp, While,
     typing::{self, BuiltInType},
 };
-use parser_generator::{Parser, Token, grammar};
+use parser_generator::{Parser, PrecomputedTables, Token, grammar};
 
 use crate::parsing_helpers;
 
@@ -21,7 +22,27 @@ use crate::{
     },
 };
 
+/// # Description
+///
+/// Builds the lexer and the parser of the language from scratch, which builds
+/// the lexer automata and the LR item sets of the grammar
 pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
+    define_lexer_parser(None)
+}
+
+/// # Description
+///
+/// Builds the lexer and the parser of the language from tables computed ahead
+/// of time by `lexer_parser().tables()`, which is near instant
+pub fn lexer_parser_from_tables(
+    tables: PrecomputedTables,
+) -> (LexerWrapper, Parser<TokenType, ReturnType>) {
+    define_lexer_parser(Some(tables))
+}
+
+fn define_lexer_parser(
+    tables: Option<PrecomputedTables>,
+) -> (LexerWrapper, Parser<TokenType, ReturnType>) {
     grammar!(
         token_type: TokenType,
         return_type: ReturnType,
@@ -68,6 +89,14 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                 }
             ;
 
+            Instruction -> ImportStatement
+                = |mut v| {
+                    let import = v.pop().unwrap().try_into_import().unwrap();
+
+                    ReturnType::Instruction(Instruction::Import(import))
+                }
+            ;
+
             Instruction -> Expression Semicolon
                 = |mut v| {
                     v.pop().unwrap();
@@ -77,16 +106,37 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                 }
             ;
 
+            ImportStatement -> Import StringLiteral Semicolon
+                = |mut v| {
+                    v.pop().unwrap();
+                    let path = v.pop().unwrap().try_into_string_literal().unwrap();
+                    let import_token = v.pop().unwrap().try_into_keyword().unwrap();
+
+                    ReturnType::Import(Import::new(import_token, ImportTarget::Path(path)))
+                }
+            ;
+
+            ImportStatement -> Import IdentifierNT Semicolon
+                = |mut v| {
+                    v.pop().unwrap();
+                    let module = v.pop().unwrap().try_into_identifier_nt().unwrap();
+                    let import_token = v.pop().unwrap().try_into_keyword().unwrap();
+
+                    ReturnType::Import(Import::new(import_token, ImportTarget::Module(module)))
+                }
+            ;
+
             Definition -> TypeDef = get_last;
-            // Definition -> ProtocolDef = get_last;
+            Definition -> ProtocolDef = get_last;
             Definition -> GlobalFunctionDef = get_last;
             Definition -> ConstantDef = get_last;
 
-            TypeDef -> Type Identifier OptionalParameters OptionalInheritanceIndicator Lbrace OptionalTypeMembers Rbrace
+            TypeDef -> Type Identifier OptionalTypeParameters OptionalParameters OptionalInheritanceIndicator Lbrace OptionalTypeMembers Rbrace
                 = |mut v| {
                     parsing_helpers::to_type_definition(
                         v.remove(0).try_into_keyword().unwrap(),
                         v.remove(0).try_into_default_token().unwrap(),
+                        v.remove(0).try_into_type_parameter_list().unwrap(),
                         v.remove(0).try_into_parameter_list().unwrap(),
                         v.remove(0).try_into_optional_inheritance_indicator().unwrap(),
                         v.remove(1).try_into_type_member_definition_list().unwrap(),
@@ -94,6 +144,37 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                 }
             ;
 
+            OptionalTypeParameters -> Less TypeParameterList Greater
+                = |mut v| {
+                    v.pop().unwrap();
+                    v.pop().unwrap()
+                }
+            ;
+            OptionalTypeParameters -> #Epsilon
+                = |_| {
+                    ReturnType::TypeParameterList(Vec::new())
+                }
+            ;
+
+            TypeParameterList -> TypeParameterList Comma Identifier
+                = |mut v| {
+                    let name = parsing_helpers::type_name_from_default_token(v.pop().unwrap());
+                    v.pop().unwrap();
+                    let mut l = v.pop().unwrap().try_into_type_parameter_list().unwrap();
+
+                    l.push(name);
+
+                    ReturnType::TypeParameterList(l)
+                }
+            ;
+            TypeParameterList -> Identifier
+                = |mut v| {
+                    let name = parsing_helpers::type_name_from_default_token(v.pop().unwrap());
+
+                    ReturnType::TypeParameterList(vec![name])
+                }
+            ;
+
             OptionalParameters -> Parameters = get_last;
             OptionalParameters -> #Epsilon
                 = |_| {
@@ -266,11 +347,76 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                 }
             ;
 
-            GlobalFunctionDef -> Function IdentifierNT Parameters TypeAnnotation Block
+            ProtocolDef -> Protocol Identifier OptionalTypeParameters OptionalExtensionIndicator Lbrace FunctionSignatureList Rbrace
+                = |mut v| {
+                    v.pop().unwrap();
+                    let signatures = v.pop().unwrap().try_into_function_signature_list().unwrap();
+                    v.pop().unwrap();
+                    let extension = v.pop().unwrap().try_into_optional_extension_indicator().unwrap();
+                    let type_parameters = v.pop().unwrap().try_into_type_parameter_list().unwrap();
+                    let name = parsing_helpers::type_name_from_default_token(v.pop().unwrap());
+                    let protocol_token = v.pop().unwrap().try_into_keyword().unwrap();
+
+                    ReturnType::Definition(Definition::ProtocolDef(ProtocolDef::new(
+                        protocol_token,
+                        name,
+                        type_parameters,
+                        extension,
+                        signatures,
+                    )))
+                }
+            ;
+
+            OptionalExtensionIndicator -> Extends Identifier
+                = |mut v| {
+                    let name = parsing_helpers::type_name_from_default_token(v.pop().unwrap());
+                    let extends_token = v.pop().unwrap().try_into_keyword().unwrap();
+
+                    ReturnType::OptionalExtensionIndicator(
+                        Some(ExtensionIndicator::new(extends_token, name))
+                    )
+                }
+            ;
+            OptionalExtensionIndicator -> #Epsilon = |_| ReturnType::OptionalExtensionIndicator(None);
+
+            FunctionSignatureList -> FunctionSignatureList FunctionSignature Semicolon
+                = |mut v| {
+                    v.pop().unwrap();
+                    let signature = v.pop().unwrap().try_into_function_signature().unwrap();
+                    let mut l = v.pop().unwrap().try_into_function_signature_list().unwrap();
+
+                    l.push(signature);
+
+                    ReturnType::FunctionSignatureList(l)
+                }
+            ;
+            FunctionSignatureList -> FunctionSignature Semicolon
+                = |mut v| {
+                    v.pop().unwrap();
+                    let signature = v.pop().unwrap().try_into_function_signature().unwrap();
+
+                    ReturnType::FunctionSignatureList(vec![signature])
+                }
+            ;
+
+            FunctionSignature -> IdentifierNT Parameters TypeAnnotation
+                = |mut v| {
+                    let ty = v.pop().unwrap().try_into_type().unwrap();
+                    let parameters = v.pop().unwrap().try_into_parameter_list().unwrap();
+                    let mut id = v.pop().unwrap().try_into_identifier_nt().unwrap();
+
+                    id.annotate_type(ty);
+
+                    ReturnType::FunctionSignature(FunctionSignature::new(id, parameters))
+                }
+            ;
+
+            GlobalFunctionDef -> Function IdentifierNT OptionalTypeParameters Parameters TypeAnnotation Block
                 = |mut v| {
                     let b = v.pop().unwrap().try_into_block().unwrap();
                     let ty = v.pop().unwrap().try_into_type().unwrap();
                     let parameters = v.pop().unwrap().try_into_parameter_list().unwrap();
+                    let type_parameters = v.pop().unwrap().try_into_type_parameter_list().unwrap();
                     let mut id = v.pop().unwrap().try_into_identifier_nt().unwrap();
                     let function_token = v.pop().unwrap().try_into_keyword().unwrap();
 
@@ -281,6 +427,7 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                             GlobalFunctionDef::new(
                                 function_token,
                                 id,
+                                type_parameters,
                                 parameters,
                                 FunctionBody::from(b)
                             )
@@ -288,12 +435,13 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                     )
                 }
             ;
-            GlobalFunctionDef -> Function IdentifierNT Parameters TypeAnnotation ArrowExpression Semicolon
+            GlobalFunctionDef -> Function IdentifierNT OptionalTypeParameters Parameters TypeAnnotation ArrowExpression Semicolon
                 = |mut v| {
                     v.pop().unwrap();
                     let a = v.pop().unwrap().try_into_arrow_expression().unwrap();
                     let ty = v.pop().unwrap().try_into_type().unwrap();
                     let parameters = v.pop().unwrap().try_into_parameter_list().unwrap();
+                    let type_parameters = v.pop().unwrap().try_into_type_parameter_list().unwrap();
                     let mut id = v.pop().unwrap().try_into_identifier_nt().unwrap();
                     let function_token = v.pop().unwrap().try_into_keyword().unwrap();
 
@@ -304,6 +452,7 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                             GlobalFunctionDef::new(
                                 function_token,
                                 id,
+                                type_parameters,
                                 parameters,
                                 FunctionBody::from(a)
                             )
@@ -355,6 +504,23 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                 )
             ;
 
+            Expression -> Lambda = get_last;
+
+            Lambda -> Parameters OptionalTypeAnnotation ArrowExpression
+                = |mut v| {
+                    let arrow = v.pop().unwrap().try_into_arrow_expression().unwrap();
+                    let ty = v.pop().unwrap().try_into_optional_type_annotation().unwrap();
+                    let params = v.pop().unwrap().try_into_parameter_list().unwrap();
+
+                    ReturnType::Expression(Expression::from(Lambda::new(
+                        params,
+                        ty,
+                        arrow.operator,
+                        arrow.expression,
+                    )))
+                }
+            ;
+
             DestructiveAssignment -> Atom ColonAssign Expression
                 = |mut v| {
                     let r = v.pop().unwrap().try_into_expression().unwrap();
@@ -379,8 +545,28 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
             Equation -> Comparison EqIneqOp Comparison = parsing_helpers::reduce_binop;
             Equation -> Comparison = parsing_helpers::get_last;
 
-            Comparison -> Addition ComparisonOp Addition = parsing_helpers::reduce_binop;
-            Comparison -> Addition = parsing_helpers::get_last;
+            Comparison -> TypeTest ComparisonOp TypeTest = parsing_helpers::reduce_binop;
+            Comparison -> TypeTest = parsing_helpers::get_last;
+
+            TypeTest -> TypeTest Is SimpleTypeNT
+                = |mut v| {
+                    let t = v.pop().unwrap().try_into_type().unwrap();
+                    let k = v.pop().unwrap().try_into_keyword().unwrap();
+                    let x = v.pop().unwrap().try_into_expression().unwrap();
+
+                    ReturnType::Expression(Expression::from(IsExpr::new(x, k, t)))
+                }
+            ;
+            TypeTest -> TypeTest As SimpleTypeNT
+                = |mut v| {
+                    let t = v.pop().unwrap().try_into_type().unwrap();
+                    let k = v.pop().unwrap().try_into_keyword().unwrap();
+                    let x = v.pop().unwrap().try_into_expression().unwrap();
+
+                    ReturnType::Expression(Expression::from(AsExpr::new(x, k, t)))
+                }
+            ;
+            TypeTest -> Addition = parsing_helpers::get_last;
 
             Addition -> Addition PlusMinusBinaryOp Term = parsing_helpers::reduce_binop;
             Addition -> Term = parsing_helpers::get_last;
@@ -400,12 +586,15 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                     ))
                 }
             ;
-            UnaryOperation -> CompositeExpression = get_last;
+            UnaryOperation -> Power = get_last;
+
+            Power -> CompositeExpression PowerOp UnaryOperation = parsing_helpers::reduce_binop;
+            Power -> CompositeExpression = get_last;
 
             CompositeExpression -> LetExpression = get_last;
             CompositeExpression -> IfExpression = get_last;
             CompositeExpression -> WhileExpression = get_last;
-            // CompositeExpression -> ForExpression = get_last;
+            CompositeExpression -> ForExpression = get_last;
             CompositeExpression -> Atom = get_last;
 
             LetExpression -> Let AssignmentList In CompositeExpression
@@ -465,18 +654,92 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
             ;
 
             TypeAnnotation -> Colon TypeNT = get_last;
-            TypeNT -> TypeNT Times
+            TypeNT -> IterableTypeNT = get_last;
+            TypeNT -> FunctorTypeNT = get_last;
+
+            FunctorTypeNT -> Lpar TypeList Rpar ThinArrow TypeNT
+                = |mut v| {
+                    let ret = v.pop().unwrap().try_into_type().unwrap();
+                    v.pop().unwrap();
+                    v.pop().unwrap();
+                    let params = v.pop().unwrap().try_into_type_list().unwrap();
+
+                    ReturnType::Type(typing::Type::Functor(typing::FunctorType::new(
+                        params.into_iter().map(Some).collect(),
+                        Some(ret),
+                    )))
+                }
+            ;
+            FunctorTypeNT -> Lpar Rpar ThinArrow TypeNT
+                = |mut v| {
+                    let ret = v.pop().unwrap().try_into_type().unwrap();
+
+                    ReturnType::Type(typing::Type::Functor(typing::FunctorType::new(
+                        Vec::new(),
+                        Some(ret),
+                    )))
+                }
+            ;
+
+            TypeList -> TypeList Comma TypeNT
+                = |mut v| {
+                    let ty = v.pop().unwrap().try_into_type().unwrap();
+                    v.pop().unwrap();
+                    let mut l = v.pop().unwrap().try_into_type_list().unwrap();
+
+                    l.push(ty);
+
+                    ReturnType::TypeList(l)
+                }
+            ;
+            TypeList -> TypeNT
+                = |mut v| {
+                    let ty = v.pop().unwrap().try_into_type().unwrap();
+
+                    ReturnType::TypeList(vec![ty])
+                }
+            ;
+
+            IterableTypeNT -> IterableTypeNT Times
                 = |mut v| {
                     v.pop().unwrap();
                     let ty = v.pop().unwrap().try_into_type().unwrap();
                     ReturnType::Type(typing::Type::Iterable(Box::new(ty)))
                 }
             ;
-            // TypeNT -> Object = get_last;
-            TypeNT -> Boolean = get_last;
-            TypeNT -> String = get_last;
-            TypeNT -> Number = get_last;
-            TypeNT -> Identifier
+            IterableTypeNT -> IterableTypeNT StarStar
+                = |mut v| {
+                    v.pop().unwrap();
+                    let ty = v.pop().unwrap().try_into_type().unwrap();
+                    ReturnType::Type(typing::Type::Iterable(Box::new(typing::Type::Iterable(
+                        Box::new(ty),
+                    ))))
+                }
+            ;
+            IterableTypeNT -> SimpleTypeNT = get_last;
+            IterableTypeNT -> GenericTypeNT = get_last;
+
+            GenericTypeNT -> Identifier TypeArguments
+                = |mut v| {
+                    let arguments = v.pop().unwrap().try_into_type_list().unwrap();
+                    let name = parsing_helpers::type_name_from_default_token(v.pop().unwrap());
+
+                    ReturnType::Type(typing::Type::Generic(name, arguments))
+                }
+            ;
+
+            TypeArguments -> Less TypeList Greater
+                = |mut v| {
+                    v.pop().unwrap();
+                    v.pop().unwrap()
+                }
+            ;
+
+            // SimpleTypeNT -> Object = get_last;
+            SimpleTypeNT -> Boolean = get_last;
+            SimpleTypeNT -> String = get_last;
+            SimpleTypeNT -> Number = get_last;
+            SimpleTypeNT -> Identifier
                 = |mut v| {
                     let id = v.pop().unwrap();
                     let ty = parsing_helpers::type_name_from_default_token(id);
@@ -517,22 +780,40 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                 }
             ;
 
-            // ForExpression -> For Lpar IdentifierNT In Expression Rpar Block
-            //     = |mut v| {
-            //         let body = v.pop().unwrap().try_into_block().unwrap();
-            //         v.pop().unwrap();
-            //         let iterable = v.pop().unwrap().try_into_expression().unwrap();
-            //         let in_token = v.pop().unwrap().try_into_keyword().unwrap();
-            //         let element = v.pop().unwrap().try_into_identifier_nt().unwrap();
-            //         v.pop().unwrap();
-            //         let for_token = v.pop().unwrap().try_into_keyword().unwrap();
+            ForExpression -> For Lpar Identifier In Expression Rpar Block
+                = |mut v| {
+                    let body = v.pop().unwrap().try_into_block().unwrap();
+                    v.pop().unwrap();
+                    let iterable = v.pop().unwrap().try_into_expression().unwrap();
+                    let in_token = v.pop().unwrap().try_into_keyword().unwrap();
+                    let element = parsing_helpers::identifier_from_default_token(v.pop().unwrap());
+                    v.pop().unwrap();
+                    let for_token = v.pop().unwrap().try_into_keyword().unwrap();
+
+
+                    ReturnType::Expression(Expression::from(
+                        For::new(for_token, element, in_token, iterable, body)
+                    ))
+                }
+            ;
+            ForExpression -> For Lpar Identifier TypeAnnotation In Expression Rpar Block
+                = |mut v| {
+                    let body = v.pop().unwrap().try_into_block().unwrap();
+                    v.pop().unwrap();
+                    let iterable = v.pop().unwrap().try_into_expression().unwrap();
+                    let in_token = v.pop().unwrap().try_into_keyword().unwrap();
+                    let ty = v.pop().unwrap().try_into_type().unwrap();
+                    let mut element = parsing_helpers::identifier_from_default_token(v.pop().unwrap());
+                    v.pop().unwrap();
+                    let for_token = v.pop().unwrap().try_into_keyword().unwrap();
 
+                    element.annotate_type(ty);
 
-            //         ReturnType::Expression(Expression::from(
-            //             For::new(for_token, element, in_token, iterable, body)
-            //         ))
-            //     }
-            // ;
+                    ReturnType::Expression(Expression::from(
+                        For::new(for_token, element, in_token, iterable, body)
+                    ))
+                }
+            ;
 
             Atom -> Lpar Expression Rpar
                 = |mut v| {
@@ -542,6 +823,7 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
             ;
             Atom -> NewExpression = get_last;
             Atom -> FunctionCallExpression = get_last;
+            Atom -> BaseCallExpression = get_last;
             Atom -> BlockExpression = get_last;
 
             Atom -> FunctionMemberAccess = get_last;
@@ -560,7 +842,19 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                     let k = v.pop().unwrap().try_into_keyword().unwrap();
 
                     ReturnType::Expression(Expression::from(
-                        NewExpr::from_function_call(k, f)
+                        NewExpr::from_function_call(k, Vec::new(), f)
+                    ))
+                }
+            ;
+            NewExpression -> New Identifier TypeArguments Arguments
+                = |mut v| {
+                    let args = v.pop().unwrap().try_into_argument_list().unwrap();
+                    let type_arguments = v.pop().unwrap().try_into_type_list().unwrap();
+                    let name = v.pop().unwrap().try_into_default_token().unwrap();
+                    let k = v.pop().unwrap().try_into_keyword().unwrap();
+
+                    ReturnType::Expression(Expression::from(
+                        NewExpr::new(k, name.slice, type_arguments, args)
                     ))
                 }
             ;
@@ -584,6 +878,15 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
                 }
             ;
 
+            BaseCallExpression -> Base Arguments
+                = |mut v| {
+                    let args = v.pop().unwrap().try_into_argument_list().unwrap();
+                    let k = v.pop().unwrap().try_into_keyword().unwrap();
+
+                    ReturnType::Expression(Expression::from(BaseCall::new(k, args)))
+                }
+            ;
+
             Arguments -> Lpar ArgumentList Rpar
                 = |mut v| {
                     v.pop().unwrap();
@@ -814,6 +1117,11 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
 
             MultDivOp -> Times = get_last;
             MultDivOp -> Div = get_last;
+            MultDivOp -> FloorDiv = get_last;
+            MultDivOp -> Mod = get_last;
+
+            PowerOp -> Caret = get_last;
+            PowerOp -> StarStar = get_last;
 
             PlusMinusNotUnaryOp -> PlusMinusUnaryOp = get_last;
             PlusMinusNotUnaryOp -> Not = get_last;
@@ -837,9 +1145,9 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
             (While, "while", |tok: &Token<TokenType>| {
                 ReturnType::Keyword(Keyword::While(get_pos(tok)))
             }),
-            // (For, "for", |tok: &Token<TokenType>| {
-            //     ReturnType::Keyword(Keyword::For(get_pos(tok)))
-            // }),
+            (For, "for", |tok: &Token<TokenType>| {
+                ReturnType::Keyword(Keyword::For(get_pos(tok)))
+            }),
             (In, "in", |tok: &Token<TokenType>| {
                 ReturnType::Keyword(Keyword::In(get_pos(tok)))
             }),
@@ -862,15 +1170,27 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
             (Constant, "constant", |tok: &Token<TokenType>| {
                 ReturnType::Keyword(Keyword::Constant(get_pos(tok)))
             }),
-            // (Protocol, "protocol", |tok: &Token<TokenType>| {
-            //     ReturnType::Keyword(Keyword::Protocol(get_pos(tok)))
-            // }),
+            (Protocol, "protocol", |tok: &Token<TokenType>| {
+                ReturnType::Keyword(Keyword::Protocol(get_pos(tok)))
+            }),
             (Extends, "extends", |tok: &Token<TokenType>| {
                 ReturnType::Keyword(Keyword::Extends(get_pos(tok)))
             }),
             (Return, "return", |tok: &Token<TokenType>| {
                 ReturnType::Keyword(Keyword::Return(get_pos(tok)))
             }),
+            (Import, "import", |tok: &Token<TokenType>| {
+                ReturnType::Keyword(Keyword::Import(get_pos(tok)))
+            }),
+            (Base, "base", |tok: &Token<TokenType>| {
+                ReturnType::Keyword(Keyword::Base(get_pos(tok)))
+            }),
+            (Is, "is", |tok: &Token<TokenType>| {
+                ReturnType::Keyword(Keyword::Is(get_pos(tok)))
+            }),
+            (As, "as", |tok: &Token<TokenType>| {
+                ReturnType::Keyword(Keyword::As(get_pos(tok)))
+            }),
 
             // (Object, "Object", |_: &Token<TokenType>| {
             //     ReturnType::Type(typing::Type::BuiltIn(BuiltInType::Object))
@@ -938,6 +1258,7 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
             (Arrow, "=>", |tok: &Token<TokenType>| {
                 ReturnType::ArrowOperator(ArrowOperator::new(get_pos(tok)))
             }),
+            (ThinArrow, r"\->"),
             (Less, "<", |tok: &Token<TokenType>| {
                 ReturnType::BinaryOperator(ast::BinaryOperator::Less(get_pos(tok)))
             }),
@@ -959,6 +1280,18 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
             (Div, "/", |tok: &Token<TokenType>| {
                 ReturnType::BinaryOperator(ast::BinaryOperator::Divide(get_pos(tok)))
             }),
+            (FloorDiv, "div", |tok: &Token<TokenType>| {
+                ReturnType::BinaryOperator(ast::BinaryOperator::FloorDivide(get_pos(tok)))
+            }),
+            (Mod, "%", |tok: &Token<TokenType>| {
+                ReturnType::BinaryOperator(ast::BinaryOperator::Modulo(get_pos(tok)))
+            }),
+            (Caret, r"\^", |tok: &Token<TokenType>| {
+                ReturnType::BinaryOperator(ast::BinaryOperator::Power(get_pos(tok)))
+            }),
+            (StarStar, r"\*\*", |tok: &Token<TokenType>| {
+                ReturnType::BinaryOperator(ast::BinaryOperator::Power(get_pos(tok)))
+            }),
 
             (Colon, ":"),
             (Semicolon, ";"),
@@ -972,8 +1305,17 @@ pub fn lexer_parser() -> (LexerWrapper, Parser<TokenType, ReturnType>) {
 
         skip: {
             (__Whitespace__, r"(\s|\t|\n|\r)+"),
+        }
+
+        trivia: {
             (__Comments__, r"//[^\n-\n]*"),
         }
 
+        sync: {
+            Semicolon,
+            Rbrace,
+        }
+
+        tables: tables
     )
 }
@@ -1,5 +1,7 @@
 use error_handler::error::error::HulkError;
-use lexer::lexer_generator::{lexer::Lexer, lexer_chunk::LexerChunk, rule::Rule};
+use lexer::lexer_generator::{
+    lexer::Lexer, lexer_chunk::LexerChunk, lexer_tables::LexerTables, rule::Rule,
+};
 use parser_generator::{DefineLexer, Lex, Token};
 
 use crate::types::TokenType;
@@ -13,13 +15,26 @@ impl LexerWrapper {
         Self { lexer }
     }
 
+    /// # Description
+    ///
+    /// The tables of the lexer automaton, to build it again with `compile_from_tables`
+    pub fn tables(&self) -> LexerTables {
+        self.lexer.tables()
+    }
+
     fn lexer_chunk_to_token(lexer_chunk: LexerChunk<TokenType>) -> Token<TokenType> {
+        let trivia = lexer_chunk
+            .trivia
+            .into_iter()
+            .map(Self::lexer_chunk_to_token)
+            .collect();
         Token::new(
             lexer_chunk.ty,
             lexer_chunk.slice.to_string(),
             lexer_chunk.start,
             lexer_chunk.end,
         )
+        .with_trivia(trivia)
     }
 }
 
@@ -52,7 +67,16 @@ impl DefineLexer<TokenType, LexerWrapper> for LexerDefiner {
         self.rules.push(Rule::new_skip(tok_ty, pattern));
     }
 
+    fn trivia_rule(&mut self, tok_ty: TokenType, pattern: String) {
+        self.rules.push(Rule::new_trivia(tok_ty, pattern));
+    }
+
     fn compile(self) -> LexerWrapper {
         LexerWrapper::new(Lexer::new(self.rules))
     }
+
+    fn compile_from_tables(self, tables: &[u8]) -> Result<LexerWrapper, String> {
+        let tables = LexerTables::from_bytes(tables)?;
+        Lexer::from_tables(self.rules, tables).map(LexerWrapper::new)
+    }
 }
@@ -1,4 +1,5 @@
 mod types;
+pub use types::TokenType;
 
 pub mod parser;
 pub use parser::ProgramParser;
@@ -1,13 +1,23 @@
 use ast::Program;
-use error_handler::error::{error::HulkError, sintactic::user_error::UserError};
-use parser_generator::{Lex, ParseError, Parser};
+use error_handler::{
+    diagnostic::diagnostic::Span,
+    error::{
+        error::HulkError,
+        sintactic::{unrecognized_eof::UnrecognizedEofError, user_error::UserError},
+    },
+};
+use parser_generator::{Lex, ParseError, Parser, PrecomputedTables, Token};
 
 use crate::{
-    grammar::lexer_parser,
+    grammar::lexer_parser_from_tables,
     lexer_wrapper::LexerWrapper,
     types::{ReturnType, TokenType},
 };
 
+/// Tables of the grammar computed by the build script, see `build.rs`
+const PARSER_TABLES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/parser_tables.bin"));
+const LEXER_TABLES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/lexer_tables.bin"));
+
 pub struct ProgramParser {
     lexer: LexerWrapper,
     parser: Parser<TokenType, ReturnType>,
@@ -15,28 +25,76 @@ pub struct ProgramParser {
 
 impl ProgramParser {
     pub fn new() -> Self {
-        let (lexer, parser) = lexer_parser();
+        let (lexer, parser) = lexer_parser_from_tables(PrecomputedTables {
+            parser: PARSER_TABLES,
+            lexer: LEXER_TABLES,
+        });
 
         Self { lexer, parser }
     }
 
+    /// # Description
+    ///
+    /// Runs only the lexical analysis stage, returning the tokens that would be
+    /// fed to the parser (whitespace is already skipped, and comments are kept
+    /// in the `trivia` of the token that follows them)
+    pub fn tokenize(&self, input: &str) -> Result<Vec<Token<TokenType>>, Vec<HulkError>> {
+        self.lexer.split(input)
+    }
+
+    /// # Description
+    ///
+    /// The comments of `input` in order, with the position where each one starts
+    pub fn comments(&self, input: &str) -> Result<Vec<(usize, String)>, Vec<HulkError>> {
+        let tokens = self.tokenize(input)?;
+        Ok(tokens
+            .into_iter()
+            .flat_map(|token| token.trivia)
+            .filter(|trivia| trivia.ty == TokenType::__Comments__)
+            .map(|comment| (comment.start, comment.slice.trim_end().to_string()))
+            .collect())
+    }
+
     pub fn parse(&self, input: &str) -> Result<Program, Vec<HulkError>> {
-        let tokens = self.lexer.split(input)?;
+        self.parse_at(input, 0)
+    }
+
+    /// # Description
+    ///
+    /// Parses `input` as the code found at `offset` of a larger program text,
+    /// e.g. the next line given to a REPL. Positions in the AST and in the
+    /// errors are positions in that larger text
+    pub fn parse_at(&self, input: &str, offset: usize) -> Result<Program, Vec<HulkError>> {
+        let padded = " ".repeat(offset) + input;
+        let tokens = self.tokenize(&padded)?;
 
         let parse = self.parser.parse(tokens);
-        if let Err(err) = parse {
-            let mut errors = Vec::new();
-            let position = match err {
-                ParseError::UnexpectedToken { loc, .. } => loc,
-                ParseError::UnexpectedEof => {
-                    if input.is_empty() {
-                        0
-                    } else {
-                        input.len() - 1
+        if let Err(errors) = parse {
+            let errors = errors
+                .into_iter()
+                .map(|err| match err {
+                    ParseError::UnexpectedToken {
+                        ty,
+                        loc,
+                        end,
+                        expected,
+                    } => {
+                        // the message tells the line and column in `input`
+                        let relative = ParseError::UnexpectedToken {
+                            ty,
+                            loc: loc - offset,
+                            end: end - offset,
+                            expected,
+                        };
+                        UserError::new(relative.to_string(input), Span::new(loc, end)).into()
+                    }
+                    // kept apart from other errors, it tells that the input is incomplete
+                    ParseError::UnexpectedEof { expected } => {
+                        let expected = expected.iter().map(|ty| format!("{:?}", ty)).collect();
+                        UnrecognizedEofError::new(expected, padded.len().saturating_sub(1)).into()
                     }
-                }
-            };
-            errors.push(UserError::new(err.to_string(input), position).into());
+                })
+                .collect();
 
             return Err(errors);
         }
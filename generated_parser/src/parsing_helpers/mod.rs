@@ -16,6 +16,7 @@ pub fn get_last(mut v: Vec<ReturnType>) -> ReturnType {
 }
 
 pub fn program_from_instructions(instructions: Vec<Instruction>) -> Program {
+    let mut imports = Vec::new();
     let mut definitions = Vec::new();
     let mut expressions = Vec::new();
 
@@ -23,8 +24,9 @@ pub fn program_from_instructions(instructions: Vec<Instruction>) -> Program {
         match instruction {
             Instruction::Expression(expr) => expressions.push(expr),
             Instruction::Definition(def) => definitions.push(def),
+            Instruction::Import(import) => imports.push(import),
         }
     }
 
-    return Program::new(definitions, expressions);
+    return Program::new(definitions, expressions).with_imports(imports);
 }
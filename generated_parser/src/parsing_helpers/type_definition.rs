@@ -9,6 +9,7 @@ use crate::{
 pub fn to_type_definition(
     type_token: Keyword,
     type_name: Token<TokenType>,
+    type_parameters: Vec<TypeName>,
     parameters: Vec<Identifier>,
     inheritance_indicator: Option<InheritanceIndicator>,
     members: Vec<TypeMemberDefinition>,
@@ -30,6 +31,7 @@ pub fn to_type_definition(
     ReturnType::Definition(ast::Definition::TypeDef(TypeDef::new(
         type_token,
         TypeName::new(pos.start, pos.end, type_name.slice),
+        type_parameters,
         parameters,
         inheritance_indicator,
         data_member_defs,
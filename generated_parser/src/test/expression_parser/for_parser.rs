@@ -1,7 +1,6 @@
 use crate::test::expression_parser::ExpressionParser;
 
 #[test]
-#[ignore = "for is disabled"]
 fn simple_for() {
     let p = ExpressionParser::new();
 
@@ -27,7 +26,6 @@ fn simple_for() {
 }
 
 #[test]
-#[ignore = "for is disabled"]
 fn complex_iterable() {
     let p = ExpressionParser::new();
 
@@ -64,3 +62,17 @@ fn complex_iterable() {
         "woof"
     )
 }
+
+#[test]
+fn annotated_element() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("for (x: Number in [1, 2]) { x; }").unwrap();
+
+    let for_exp = answ.as_for().unwrap();
+    assert_eq!(for_exp.element.id, "x");
+    assert_eq!(
+        for_exp.element.info.ty,
+        Some(ast::typing::Type::BuiltIn(ast::typing::BuiltInType::Number))
+    );
+}
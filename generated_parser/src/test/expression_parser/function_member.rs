@@ -64,3 +64,30 @@ fn complex_object_function() {
         "foo"
     );
 }
+
+#[test]
+fn base_call() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("base(x, 2)").unwrap();
+    let base_call = answ.as_base_call().unwrap();
+
+    assert_eq!(base_call.arguments.len(), 2);
+    assert_eq!(base_call.arguments[0].as_variable().unwrap().id, "x");
+    assert_eq!(base_call.receiver.id, "self");
+}
+
+#[test]
+fn base_call_is_an_atom() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("base().name").unwrap();
+
+    assert!(
+        answ.as_data_member_access()
+            .unwrap()
+            .object
+            .as_base_call()
+            .is_some()
+    );
+}
@@ -0,0 +1,112 @@
+use ast::typing::{BuiltInType, Type};
+
+use crate::test::expression_parser::ExpressionParser;
+
+#[test]
+fn parses_lambda() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("(x: Number): Number => x * 2").unwrap();
+    let lambda = answ.as_lambda().unwrap();
+
+    assert_eq!(lambda.parameters.len(), 1);
+    assert_eq!(lambda.parameters[0].id, "x");
+    assert_eq!(
+        lambda.parameters[0].info.ty,
+        Some(Type::BuiltIn(BuiltInType::Number))
+    );
+    assert_eq!(lambda.return_type, Some(Type::BuiltIn(BuiltInType::Number)));
+    assert!(lambda.body.as_bin_op().is_some());
+}
+
+#[test]
+fn parses_lambda_without_parameters_or_return_type() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("() => 42").unwrap();
+    let lambda = answ.as_lambda().unwrap();
+
+    assert!(lambda.parameters.is_empty());
+    assert_eq!(lambda.return_type, None);
+    assert_eq!(lambda.body.as_number_literal().unwrap().value, 42.0);
+}
+
+#[test]
+fn lambda_body_extends_as_far_as_possible() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("(a: Number, b: Number) => a + b * 2").unwrap();
+    let lambda = answ.as_lambda().unwrap();
+
+    assert_eq!(lambda.parameters.len(), 2);
+    assert!(lambda.body.as_bin_op().unwrap().rhs.as_bin_op().is_some());
+}
+
+#[test]
+fn lambda_as_argument() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("map(xs, (x: Number) => x * 2)").unwrap();
+    let call = answ.as_function_call().unwrap();
+
+    assert!(call.arguments[0].as_variable().is_some());
+    assert!(call.arguments[1].as_lambda().is_some());
+}
+
+#[test]
+fn parenthesized_expression_is_not_a_lambda() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("(x) * 2").unwrap();
+
+    assert!(answ.as_bin_op().unwrap().lhs.as_variable().is_some());
+}
+
+#[test]
+fn functor_type_annotation() {
+    let p = ExpressionParser::new();
+
+    let answ = p
+        .parse("let f: (Number, Number) -> Boolean = (a: Number, b: Number) => a < b in f(1, 2)")
+        .unwrap();
+    let assignment = &answ.as_let_in().unwrap().assignment;
+    let functor = assignment
+        .identifier
+        .info
+        .ty
+        .as_ref()
+        .unwrap()
+        .as_functor()
+        .unwrap();
+
+    assert_eq!(functor.parameter_types.len(), 2);
+    assert_eq!(*functor.return_type, Some(Type::BuiltIn(BuiltInType::Bool)));
+    assert!(assignment.rhs.as_lambda().is_some());
+}
+
+#[test]
+fn functor_type_returning_list() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("let f: () -> Number* = () => [1] in f()").unwrap();
+    let assignment = &answ.as_let_in().unwrap().assignment;
+    let functor = assignment
+        .identifier
+        .info
+        .ty
+        .as_ref()
+        .unwrap()
+        .as_functor()
+        .unwrap();
+
+    assert!(functor.parameter_types.is_empty());
+    assert!(
+        functor
+            .return_type
+            .as_ref()
+            .as_ref()
+            .unwrap()
+            .as_iterable()
+            .is_some()
+    );
+}
@@ -0,0 +1,33 @@
+use crate::ProgramParser;
+
+#[test]
+fn comments_are_kept_as_trivia() {
+    let p = ProgramParser::new();
+    let program = "// header\nprint(1); // trailing\n// footer";
+
+    assert_eq!(p.parse(program).unwrap().expressions.len(), 1);
+    assert_eq!(
+        p.comments(program).unwrap(),
+        vec![
+            (0, "// header".to_string()),
+            (20, "// trailing".to_string()),
+            (32, "// footer".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn tokens_carry_the_comments_before_them() {
+    let p = ProgramParser::new();
+
+    let tokens = p.tokenize("print(1);\n// two\nprint(2);").unwrap();
+
+    let commented = tokens
+        .iter()
+        .filter(|token| !token.trivia.is_empty())
+        .collect::<Vec<_>>();
+    assert_eq!(commented.len(), 1);
+    assert_eq!(commented[0].slice, "print");
+    assert_eq!(commented[0].start, 17);
+    assert_eq!(commented[0].trivia[0].slice, "// two");
+}
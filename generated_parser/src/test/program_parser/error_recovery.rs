@@ -0,0 +1,74 @@
+use crate::ProgramParser;
+
+#[test]
+fn reports_errors_in_several_instructions() {
+    let p = ProgramParser::new();
+
+    let errors = p
+        .parse(
+            "
+    print(1 + );
+    print(2);
+    let x = 3 print(x);
+",
+        )
+        .unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].to_string().contains("Unexpected token Rpar"));
+    assert!(
+        errors[1]
+            .to_string()
+            .contains("Unexpected token Identifier")
+    );
+}
+
+#[test]
+fn recovers_inside_blocks() {
+    let p = ProgramParser::new();
+
+    let errors = p
+        .parse(
+            "
+    {
+        let y = 2 in y + ;
+        print(y);
+        print(y *);
+    };
+    print(3);
+",
+        )
+        .unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn errors_list_expected_tokens() {
+    let p = ProgramParser::new();
+
+    let errors = p.parse("print(1 + );").unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("expected one of:"));
+    assert!(errors[0].to_string().contains("NumberLiteral"));
+}
+
+#[test]
+fn recovering_twice_reports_no_cascaded_errors() {
+    let p = ProgramParser::new();
+
+    let errors = p
+        .parse("let x = in x;\nprint(1 +);\n{ let y = 3 in y +; };\nprint(2);")
+        .unwrap_err();
+
+    let expected = "expected one of: Let, If, While, For, New, Base, BooleanLiteral, NumberLiteral, StringLiteral, Lpar, Lbrace, Lbracket, Not, Plus, Minus, Identifier";
+    assert_eq!(
+        errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec![
+            format!("Sintactic Error: Unexpected token In at 1:9, {expected}"),
+            format!("Sintactic Error: Unexpected token Rpar at 2:10, {expected}"),
+            format!("Sintactic Error: Unexpected token Semicolon at 3:19, {expected}"),
+        ]
+    );
+}
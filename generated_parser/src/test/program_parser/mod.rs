@@ -13,3 +13,11 @@ mod program_ordering;
 mod type_instantiation;
 
 mod several_expressions;
+
+mod error_recovery;
+
+mod comments;
+
+mod generics;
+
+mod imports;
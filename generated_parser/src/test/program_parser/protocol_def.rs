@@ -3,7 +3,6 @@ use ast::typing::BuiltInType;
 use crate::ProgramParser;
 
 #[test]
-#[ignore = "protocols are not implemented"]
 fn simple_protocol() {
     let p = ProgramParser::new();
 
@@ -12,7 +11,7 @@ fn simple_protocol() {
             "
 
 protocol Hash {
-    hash(o: Object): Number;
+    hash(o: String): Number;
 }
 
 42;
@@ -33,6 +32,49 @@ protocol Hash {
             .unwrap()
             .as_builtin()
             .unwrap(),
-        &BuiltInType::Object
+        &BuiltInType::String
     )
 }
+
+#[test]
+fn protocol_extension() {
+    let p = ProgramParser::new();
+
+    let answ = p
+        .parse(
+            "
+
+protocol Hashable {
+    hash(): Number;
+}
+
+protocol Equatable extends Hashable {
+    equals(other: Object): Boolean;
+    differs(other: Object): Boolean;
+}
+
+42;
+
+",
+        )
+        .unwrap();
+
+    let equatable = answ.definitions[1].as_protocol_def().unwrap();
+
+    assert_eq!(equatable.name.id, "Equatable");
+    assert_eq!(
+        equatable
+            .extension_indicator
+            .as_ref()
+            .unwrap()
+            .extendee_name
+            .id,
+        "Hashable"
+    );
+    assert_eq!(equatable.function_signatures.len(), 2);
+    assert_eq!(equatable.function_signatures[1].identifier.id, "differs");
+    assert_eq!(
+        equatable.function_signatures[1].identifier.info.ty,
+        Some(ast::typing::Type::BuiltIn(BuiltInType::Bool))
+    );
+}
@@ -1,7 +1,6 @@
 use crate::ProgramParser;
 
 #[test]
-#[ignore = "protocols are not implemented"]
 #[should_panic]
 fn empty_protocol() {
     let p = ProgramParser::new();
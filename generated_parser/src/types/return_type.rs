@@ -1,9 +1,9 @@
 use ast::{
     ArrowExpression, ArrowOperator, Assignment, BinaryOperator, Block, BlockBodyItem,
     BooleanLiteral, DataMemberDef, Definition, DestructiveAssignment, DotOperator, Expression,
-    FunctionCall, FunctionDef, FunctionSignature, GroupingOperator, Identifier,
-    InheritanceIndicator, Keyword, ListLiteral, NumberLiteral, Program, StringLiteral,
-    UnaryOperator, typing::Type,
+    ExtensionIndicator, FunctionCall, FunctionDef, FunctionSignature, GroupingOperator, Identifier,
+    Import, InheritanceIndicator, Keyword, ListLiteral, NumberLiteral, Program, StringLiteral,
+    TypeName, UnaryOperator, typing::Type,
 };
 use parser_generator::Token;
 
@@ -14,9 +14,12 @@ pub enum ReturnType {
     Program(Program),
     InstructionList(Vec<Instruction>),
     Instruction(Instruction),
+    Import(Import),
 
     Definition(Definition),
     FunctionSignature(FunctionSignature),
+    FunctionSignatureList(Vec<FunctionSignature>),
+    OptionalExtensionIndicator(Option<ExtensionIndicator>),
 
     TypeMemberDefinitionList(Vec<TypeMemberDefinition>),
     TypeMemberDefinition(TypeMemberDefinition),
@@ -35,6 +38,7 @@ pub enum ReturnType {
     Keyword(Keyword),
     DefaultToken(Token<TokenType>),
     Type(Type),
+    TypeList(Vec<Type>),
     OptionalTypeAnnotation(Option<Type>),
     IdentifierNT(Identifier),
 
@@ -55,6 +59,7 @@ pub enum ReturnType {
     ArgumentList(Vec<Expression>),
 
     ParameterList(Vec<Identifier>),
+    TypeParameterList(Vec<TypeName>),
 }
 
 impl ReturnType {
@@ -266,6 +271,14 @@ impl ReturnType {
         }
     }
 
+    pub fn try_into_import(self) -> Result<Import, Self> {
+        if let Self::Import(v) = self {
+            Ok(v)
+        } else {
+            Err(self)
+        }
+    }
+
     pub fn try_into_instruction(self) -> Result<Instruction, Self> {
         if let Self::Instruction(v) = self {
             Ok(v)
@@ -274,6 +287,14 @@ impl ReturnType {
         }
     }
 
+    pub fn try_into_type_list(self) -> Result<Vec<Type>, Self> {
+        if let Self::TypeList(v) = self {
+            Ok(v)
+        } else {
+            Err(self)
+        }
+    }
+
     pub fn try_into_parameter_list(self) -> Result<Vec<Identifier>, Self> {
         if let Self::ParameterList(v) = self {
             Ok(v)
@@ -282,6 +303,14 @@ impl ReturnType {
         }
     }
 
+    pub fn try_into_type_parameter_list(self) -> Result<Vec<TypeName>, Self> {
+        if let Self::TypeParameterList(v) = self {
+            Ok(v)
+        } else {
+            Err(self)
+        }
+    }
+
     pub fn try_into_type_member_definition_list(self) -> Result<Vec<TypeMemberDefinition>, Self> {
         if let Self::TypeMemberDefinitionList(v) = self {
             Ok(v)
@@ -322,6 +351,22 @@ impl ReturnType {
         }
     }
 
+    pub fn try_into_function_signature_list(self) -> Result<Vec<FunctionSignature>, Self> {
+        if let Self::FunctionSignatureList(v) = self {
+            Ok(v)
+        } else {
+            Err(self)
+        }
+    }
+
+    pub fn try_into_optional_extension_indicator(self) -> Result<Option<ExtensionIndicator>, Self> {
+        if let Self::OptionalExtensionIndicator(v) = self {
+            Ok(v)
+        } else {
+            Err(self)
+        }
+    }
+
     pub fn try_into_optional_inheritance_indicator(
         self,
     ) -> Result<Option<InheritanceIndicator>, Self> {
@@ -349,6 +394,7 @@ pub struct BlockBodyItemReturn {
 pub enum Instruction {
     Expression(Expression),
     Definition(Definition),
+    Import(Import),
 }
 
 #[derive(Debug)]
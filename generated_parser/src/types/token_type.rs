@@ -17,9 +17,13 @@ pub enum TokenType {
     Type,
     Inherits,
     Constant,
-    // Protocol,
+    Protocol,
     Extends,
     Return,
+    Is,
+    As,
+    Import,
+    Base,
 
     Object,
     String,
@@ -50,9 +54,14 @@ pub enum TokenType {
     Minus,
     Times,
     Div,
+    FloorDiv,
+    Mod,
+    Caret,
+    StarStar,
     At,
     AtAt,
     Arrow,
+    ThinArrow,
     Less,
     LessEqual,
     Greater,
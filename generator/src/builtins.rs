@@ -0,0 +1,115 @@
+use ast::typing::BuiltInType;
+
+/// # Description
+///
+/// How a call to a builtin is turned into LLVM code
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuiltinLowering {
+    /// `print` accepts any printable value and returns it, it has its own code generation
+    Print,
+    /// A call to an LLVM intrinsic, a libc function or a runtime helper, named without the `@`.
+    /// Its LLVM signature is the one of the builtin: `double` for numbers, `i8*` for strings
+    /// and `i1` for booleans
+    Call(&'static str),
+}
+
+/// # Description
+///
+/// A function provided by the language. Its signature is checked by the semantic analyzer
+/// like any other global function, and user code can not redefine it
+#[derive(Debug)]
+pub struct Builtin {
+    pub name: &'static str,
+    pub parameters: &'static [BuiltInType],
+    pub return_type: BuiltInType,
+    pub lowering: BuiltinLowering,
+}
+
+pub const BUILTINS: &[Builtin] = &[
+    Builtin {
+        name: "print",
+        parameters: &[BuiltInType::Object],
+        return_type: BuiltInType::Object,
+        lowering: BuiltinLowering::Print,
+    },
+    Builtin {
+        name: "sqrt",
+        parameters: &[BuiltInType::Number],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("llvm.sqrt.f64"),
+    },
+    Builtin {
+        name: "sin",
+        parameters: &[BuiltInType::Number],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("llvm.sin.f64"),
+    },
+    Builtin {
+        name: "cos",
+        parameters: &[BuiltInType::Number],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("llvm.cos.f64"),
+    },
+    Builtin {
+        name: "exp",
+        parameters: &[BuiltInType::Number],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("llvm.exp.f64"),
+    },
+    Builtin {
+        name: "log",
+        parameters: &[BuiltInType::Number],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("llvm.log.f64"),
+    },
+    Builtin {
+        name: "floor",
+        parameters: &[BuiltInType::Number],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("llvm.floor.f64"),
+    },
+    Builtin {
+        name: "ceil",
+        parameters: &[BuiltInType::Number],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("llvm.ceil.f64"),
+    },
+    Builtin {
+        name: "rand",
+        parameters: &[],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("hulk_rand"),
+    },
+    Builtin {
+        name: "length",
+        parameters: &[BuiltInType::String],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("hulk_length"),
+    },
+    Builtin {
+        name: "substring",
+        parameters: &[
+            BuiltInType::String,
+            BuiltInType::Number,
+            BuiltInType::Number,
+        ],
+        return_type: BuiltInType::String,
+        lowering: BuiltinLowering::Call("hulk_substring"),
+    },
+    Builtin {
+        name: "parse",
+        parameters: &[BuiltInType::String],
+        return_type: BuiltInType::Number,
+        lowering: BuiltinLowering::Call("hulk_parse"),
+    },
+    Builtin {
+        name: "read_line",
+        parameters: &[],
+        return_type: BuiltInType::String,
+        lowering: BuiltinLowering::Call("hulk_read_line"),
+    },
+];
+
+pub fn find_builtin(name: &str) -> Option<&'static Builtin> {
+    BUILTINS.iter().find(|builtin| builtin.name == name)
+}
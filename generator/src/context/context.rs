@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+#[derive(Clone)]
 struct Frame<T> {
     can_access_parents: bool,
     values: HashMap<String, T>,
@@ -14,6 +15,7 @@ impl<T> Frame<T> {
     }
 }
 
+#[derive(Clone)]
 pub struct Context<R> {
     scope_stack: Vec<Frame<R>>,
 }
@@ -84,6 +86,38 @@ impl<R> Context<R> {
         last_value
     }
 
+    pub fn depth(&self) -> usize {
+        self.scope_stack.len()
+    }
+
+    /// Returns the index (counting from the outermost frame) of the frame where
+    /// the definition of `id` visible from the current frame lives
+    pub fn get_frame_index(&self, id: &str) -> Option<usize> {
+        for (index, frame) in self.scope_stack.iter().enumerate().rev() {
+            if frame.values.contains_key(id) {
+                return Some(index);
+            }
+            if !frame.can_access_parents {
+                break;
+            }
+        }
+
+        None
+    }
+
+    /// Returns the ids of every definition visible from the current frame
+    pub fn visible_ids(&self) -> Vec<&str> {
+        let mut ids = Vec::new();
+        for frame in self.scope_stack.iter().rev() {
+            ids.extend(frame.values.keys().map(String::as_str));
+            if !frame.can_access_parents {
+                break;
+            }
+        }
+
+        ids
+    }
+
     pub fn get_value_mut(&mut self, id: &str) -> Option<&mut R> {
         let mut stack_iter = self.scope_stack.iter_mut().rev();
         let mut current = stack_iter
@@ -38,3 +38,25 @@ fn define_in_parent_get_in_child() {
     assert_eq!(ctx.get_value("x"), Some(&3));
     assert_eq!(ctx.get_value("y"), Some(&2));
 }
+
+#[test]
+fn frame_index() {
+    let mut ctx = Context::new_one_frame();
+
+    ctx.define("x".to_string(), 3);
+    ctx.push_open_frame();
+    ctx.define("y".to_string(), 2);
+    ctx.push_open_frame();
+    ctx.define("x".to_string(), 1);
+
+    assert_eq!(ctx.depth(), 3);
+    assert_eq!(ctx.get_frame_index("x"), Some(2));
+    assert_eq!(ctx.get_frame_index("y"), Some(1));
+
+    ctx.pop_frame();
+    assert_eq!(ctx.get_frame_index("x"), Some(0));
+
+    ctx.push_closed_frame();
+    assert_eq!(ctx.get_frame_index("x"), None);
+    assert_eq!(ctx.get_frame_index("z"), None);
+}
@@ -1,36 +1,75 @@
-use std::fmt::format;
+use crate::monomorphize::monomorphize;
+use crate::visitor::{GeneratorVisitor, GlobalDefinitionVisitor, collect_protocol_methods};
 use ast::{Definition, VisitableDefinition, VisitableExpression};
-use crate::visitor::{GeneratorVisitor, GlobalDefinitionVisitor};
-
+use error_handler::source_map::SourceMap;
+use std::fmt::format;
 
-pub struct CodeGenerator {}
+pub struct CodeGenerator {
+    runtime_checks: bool,
+    source_map: Option<SourceMap>,
+}
 
 impl CodeGenerator {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            runtime_checks: true,
+            source_map: None,
+        }
     }
 
-    pub fn generate_code_from_program_ast(self, node: &mut ast::Program) -> String {
+    /// # Description
+    ///
+    /// Whether list indexing and divisions are checked at runtime, a failed
+    /// check reports where it happened and exits with a non-zero status.
+    /// Enabled by default
+    pub fn with_runtime_checks(mut self, enabled: bool) -> Self {
+        self.runtime_checks = enabled;
+        self
+    }
+
+    /// # Description
+    ///
+    /// The source map of the program, runtime errors show the file, line and
+    /// column they happened at instead of a byte offset
+    pub fn with_source_map(mut self, source_map: SourceMap) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
+    pub fn generate_code_from_program_ast(&self, node: &mut ast::Program) -> String {
+        monomorphize(node);
         let mut generator = GeneratorVisitor::new();
+        generator.runtime_checks = self.runtime_checks;
+        generator.source_map = self.source_map.clone();
         let mut global_definition_visitor = GlobalDefinitionVisitor::new();
         let mut program = generator.instantiate_global_print_helpers();
-        
+        program += &generator.instantiate_gc_runtime();
+        program += &generator.instantiate_builtin_runtime();
+        program += &generator.instantiate_runtime_checks();
+        let protocols = collect_protocol_methods(&node.definitions);
+        generator.protocols = protocols.clone();
+        global_definition_visitor.protocols = protocols;
+
         for definition in &mut node.definitions {
             let definition_result = definition.accept(&mut global_definition_visitor);
         }
-        
+
         generator.functions_args_types = global_definition_visitor.functions_args_types.clone();
         generator.inherits = global_definition_visitor.inherits.clone();
         generator.type_members_types = global_definition_visitor.type_members_types.clone();
-        generator.function_member_def_from_type_and_name = global_definition_visitor.function_member_def_from_type_and_name.clone();
+        generator.function_member_def_from_type_and_name = global_definition_visitor
+            .function_member_def_from_type_and_name
+            .clone();
         generator.constructor_args_types = global_definition_visitor.constructor_args_types.clone();
         generator.function_member_names = global_definition_visitor.function_member_names.clone();
-        generator.original_type_for_definition = global_definition_visitor.original_type_for_definition.clone();
+        generator.original_type_for_definition = global_definition_visitor
+            .original_type_for_definition
+            .clone();
         generator.type_members_ids = global_definition_visitor.type_members_ids.clone();
-        generator.function_member_signature_types = global_definition_visitor.function_member_signature_types.clone();
-
+        generator.function_member_signature_types = global_definition_visitor
+            .function_member_signature_types
+            .clone();
 
-        
         let mut definitions_code = String::new();
         generator.is_global = true;
         let mut definitions_code = String::new();
@@ -44,39 +83,49 @@ impl CodeGenerator {
             }
         }
         generator.is_global = false;
+        // the layouts of types (and their pointer maps) can use the interface types of
+        // protocols, which must be defined before them
+        for definition in &mut node.definitions {
+            if let Definition::ProtocolDef(_) = definition {
+                let definition_result = definition.accept(&mut generator);
+                definitions_code += &definition_result.preamble;
+            }
+        }
         for definition in &mut node.definitions {
             match definition {
-                Definition::TypeDef(_) | Definition::FunctionDef(_) | Definition::ProtocolDef(_) => {
+                Definition::TypeDef(_) | Definition::FunctionDef(_) => {
                     let definition_result = definition.accept(&mut generator);
                     definitions_code += &definition_result.preamble;
                 }
                 _ => {}
             }
         }
-        
-        let mut structs_code = String::new();
-        for code in generator.general_definitions.iter(){
-            structs_code += code;
-            structs_code += "\n";
-        }
-        
-        
-        
+
         let constants_code_vec = generator.constants.clone();
-        let mut constants_code = String::new();
+        let mut constants_code = generator.gc_root_constants(&node.definitions);
 
         for c in constants_code_vec {
-            constants_code += format!("{} \n",c).as_str();
+            constants_code += format!("{} \n", c).as_str();
         }
 
         let mut expressions_code = String::new();
         if !node.expressions.is_empty() {
             for expr in node.expressions.iter_mut() {
                 let inner = expr.accept(&mut generator);
-                expressions_code += &inner.preamble;
+                expressions_code += &generator.gc_scoped(inner.preamble);
             }
         }
 
+        let type_infos = generator.generate_type_infos();
+        generator.general_definitions.push(type_infos);
+
+        // lambdas found in the expressions also emit definitions
+        let mut structs_code = String::new();
+        for code in generator.general_definitions.iter() {
+            structs_code += code;
+            structs_code += "\n";
+        }
+
         let mut global_string = String::new();
         for string_global in generator.string_constants.iter() {
             global_string.push_str(&format!("{}\n", string_global.clone()));
@@ -90,6 +139,52 @@ impl CodeGenerator {
         program += &expressions_code;
         program += "\nret i32 0\n}\n";
 
-        program
+        hoist_allocas(&program)
+    }
+}
+
+/// # Description
+///
+/// Moves every `alloca` of a function to its entry block. Variables defined
+/// inside loops are otherwise allocated again on each iteration, and long
+/// running loops overflow the stack
+fn hoist_allocas(program: &str) -> String {
+    let mut result = String::with_capacity(program.len());
+    let mut function: Option<(Vec<&str>, Vec<&str>)> = None;
+
+    for line in program.lines() {
+        let trimmed = line.trim();
+        match function.as_mut() {
+            None => {
+                if trimmed.starts_with("define ") {
+                    function = Some((vec![line], Vec::new()));
+                } else {
+                    result += line;
+                    result += "\n";
+                }
+            }
+            Some((body, allocas)) => {
+                if trimmed.starts_with('%') && trimmed.contains(" = alloca ") {
+                    allocas.push(line);
+                    continue;
+                }
+                body.push(line);
+                if trimmed == "}" {
+                    let (body, allocas) = function.take().unwrap();
+                    for line in body {
+                        result += line;
+                        result += "\n";
+                        if line.trim() == "entry:" {
+                            for alloca in &allocas {
+                                result += alloca;
+                                result += "\n";
+                            }
+                        }
+                    }
+                }
+            }
+        }
     }
+
+    result
 }
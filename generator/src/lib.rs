@@ -1,3 +1,4 @@
+pub mod builtins;
 pub mod context;
 pub mod visitor;
 pub(crate) use visitor::GeneratorVisitor;
@@ -7,5 +8,7 @@ pub use generator::CodeGenerator;
 
 pub mod llvm_types;
 
+pub mod monomorphize;
+
 #[cfg(test)]
 mod test;
@@ -5,6 +5,9 @@ pub enum LlvmType {
     String, // Now represents %string_type*
     Object,
     List(Box<LlvmType>),
+    /// A protocol fat pointer, `%<protocol>_interface`, holding the object and
+    /// the vtable of adapters the object's type uses to implement the protocol
+    Interface(String),
 }
 
 impl LlvmType {
@@ -15,6 +18,7 @@ impl LlvmType {
             LlvmType::String => "i8*".to_string(),
             LlvmType::Object => "i8*".to_string(),
             LlvmType::List(inner) => format!("{}*", inner.llvm_type_str()),
+            LlvmType::Interface(protocol) => format!("%{}_interface", protocol),
         }
     }
 }
@@ -57,7 +61,6 @@ impl HandleType {
         HandleType::Register(LlvmType::List(Box::new(inner)))
     }
 
-
     pub fn inner_type(&self) -> LlvmType {
         match self {
             HandleType::Register(t) | HandleType::Literal(t) => t.clone(),
@@ -137,4 +140,8 @@ impl LlvmHandle {
     pub fn new_list_register(inner: LlvmType, name: String) -> LlvmHandle {
         LlvmHandle::new(HandleType::register_list(inner), name)
     }
+
+    pub fn new_interface_register(protocol: String, name: String) -> LlvmHandle {
+        LlvmHandle::new(HandleType::Register(LlvmType::Interface(protocol)), name)
+    }
 }
@@ -0,0 +1,405 @@
+use std::collections::{HashMap, HashSet};
+
+use ast::{
+    AsExpr, Assignment, BaseCall, BinOp, Block, BooleanLiteral, ConstantDef, DataMemberAccess,
+    Definition, DefinitionVisitor, DestructiveAssignment, Expression, ExpressionVisitor, For,
+    FunctionCall, FunctionMemberAccess, GlobalFunctionDef, Identifier, IfElse, IsExpr, Lambda,
+    LetIn, ListIndexing, ListLiteral, NewExpr, NumberLiteral, ProtocolDef, ReturnStatement,
+    StringLiteral, TypeDef, TypeName, UnOp, VisitableDefinition, VisitableExpression, While,
+    typing::{FunctorType, Type, TypeAnnotation},
+};
+
+/// # Description
+///
+/// Replaces the generic types, protocols and functions of a typed program by
+/// one copy of them for each list of type arguments they are used with, e.g.
+/// `Stack<Number>` becomes the type `Stack_Number`, with its own
+/// `%Stack_Number_type` layout, and the call `first([1, 2])` to
+/// `first<T>(xs: T*)` becomes a call to `first_Number`.
+/// Every annotation of the program is rewritten to refer to the copies, so
+/// the code generation never sees a generic type
+pub fn monomorphize(program: &mut ast::Program) {
+    let mut generics = HashMap::new();
+    let mut definitions = Vec::new();
+    for definition in program.definitions.drain(..) {
+        match generic_name(&definition) {
+            Some(name) => {
+                generics.insert(name, definition);
+            }
+            None => definitions.push(definition),
+        }
+    }
+    if generics.is_empty() {
+        program.definitions = definitions;
+        return;
+    }
+
+    let mut monomorphizer = Monomorphizer::new();
+    for definition in &mut definitions {
+        definition.accept(&mut monomorphizer);
+    }
+    for expression in &mut program.expressions {
+        expression.accept(&mut monomorphizer);
+    }
+
+    // instances can use other instances, which are added to the pending ones
+    let mut instances = Vec::new();
+    while let Some((name, type_arguments)) = monomorphizer.pending.pop() {
+        let mut instance = generics
+            .get(&name)
+            .expect("Only generic definitions are instantiated")
+            .clone();
+        let type_parameters =
+            rename_instance(&mut instance, mangle_generic(&name, &type_arguments));
+        monomorphizer.bindings = type_parameters
+            .into_iter()
+            .map(|parameter| parameter.id)
+            .zip(type_arguments)
+            .collect();
+        instance.accept(&mut monomorphizer);
+        instances.push(instance);
+    }
+
+    // types go before the rest of the definitions, parents first
+    let types_end = definitions
+        .iter()
+        .position(|definition| definition.as_type_def().is_none())
+        .unwrap_or(definitions.len());
+    let (types, others): (Vec<_>, Vec<_>) = instances
+        .into_iter()
+        .partition(|definition| definition.as_type_def().is_some());
+    definitions.splice(types_end..types_end, types);
+    definitions.extend(others);
+    program.definitions = definitions;
+}
+
+/// The name of a generic definition, `None` if it is not generic
+fn generic_name(definition: &Definition) -> Option<String> {
+    match definition {
+        Definition::TypeDef(def) if !def.type_parameters.is_empty() => Some(def.name.id.clone()),
+        Definition::ProtocolDef(def) if !def.type_parameters.is_empty() => {
+            Some(def.name.id.clone())
+        }
+        Definition::FunctionDef(def) if !def.function_def.type_parameters.is_empty() => {
+            Some(def.function_def.identifier.id.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Renames a copy of a generic definition to `name`, returns its type parameters
+fn rename_instance(definition: &mut Definition, name: String) -> Vec<TypeName> {
+    match definition {
+        Definition::TypeDef(def) => {
+            def.name.id = name;
+            std::mem::take(&mut def.type_parameters)
+        }
+        Definition::ProtocolDef(def) => {
+            def.name.id = name;
+            std::mem::take(&mut def.type_parameters)
+        }
+        Definition::FunctionDef(def) => {
+            def.function_def.identifier.id = name;
+            std::mem::take(&mut def.function_def.type_parameters)
+        }
+        Definition::ConstantDef(_) => unreachable!("Constants are never generic"),
+    }
+}
+
+/// # Description
+///
+/// Name of the instance of the generic definition `name` for `type_arguments`,
+/// e.g. `Pair_Number_String` for `Pair<Number, String>`
+fn mangle_generic(name: &str, type_arguments: &[Type]) -> String {
+    let mut mangled = name.to_string();
+    for argument in type_arguments {
+        mangled += "_";
+        mangled += &mangle(argument);
+    }
+    mangled
+}
+
+fn mangle(ty: &Type) -> String {
+    match ty {
+        Type::BuiltIn(_) | Type::Defined(_) => ty.to_string(),
+        Type::Iterable(inner) => format!("{}_list", mangle(inner)),
+        Type::Generic(name, arguments) => mangle_generic(&name.id, arguments),
+        Type::Functor(functor) => {
+            let mut mangled = "fn".to_string();
+            for ty in functor
+                .parameter_types
+                .iter()
+                .chain([&*functor.return_type])
+            {
+                mangled += "_";
+                mangled += &ty.as_ref().map_or("none".to_string(), mangle);
+            }
+            mangled
+        }
+    }
+}
+
+/// # Description
+///
+/// Rewrites the annotations of the definitions and expressions it visits,
+/// replacing the type parameters bound in `bindings` and the uses of generic
+/// definitions by their instances. The instances that are not generated yet
+/// are left in `pending`
+struct Monomorphizer {
+    bindings: HashMap<String, Type>,
+    pending: Vec<(String, Vec<Type>)>,
+    instantiated: HashSet<String>,
+}
+
+impl Monomorphizer {
+    fn new() -> Self {
+        Self {
+            bindings: HashMap::new(),
+            pending: Vec::new(),
+            instantiated: HashSet::new(),
+        }
+    }
+
+    /// Name of the instance of `name` for `type_arguments`, which is generated if it is new
+    fn instantiate(&mut self, name: &str, type_arguments: &[Type]) -> String {
+        let type_arguments: Vec<Type> = type_arguments
+            .iter()
+            .map(|ty| ty.substitute(&self.bindings))
+            .collect();
+        let mangled = mangle_generic(name, &type_arguments);
+        if self.instantiated.insert(mangled.clone()) {
+            self.pending.push((name.to_string(), type_arguments));
+        }
+        mangled
+    }
+
+    fn rewrite(&mut self, ty: &Type) -> Type {
+        match ty {
+            Type::Defined(name) => match self.bindings.get(&name.id).cloned() {
+                Some(bound) => self.rewrite(&bound),
+                None => ty.clone(),
+            },
+            Type::BuiltIn(_) => ty.clone(),
+            Type::Iterable(inner) => Type::Iterable(Box::new(self.rewrite(inner))),
+            Type::Functor(functor) => Type::Functor(FunctorType::new(
+                functor
+                    .parameter_types
+                    .iter()
+                    .map(|ty| self.rewrite_annotation(ty))
+                    .collect(),
+                self.rewrite_annotation(&functor.return_type),
+            )),
+            Type::Generic(name, arguments) => Type::Defined(TypeName {
+                id: self.instantiate(&name.id, arguments),
+                position: name.position.clone(),
+            }),
+        }
+    }
+
+    fn rewrite_annotation(&mut self, ty: &TypeAnnotation) -> TypeAnnotation {
+        ty.as_ref().map(|ty| self.rewrite(ty))
+    }
+
+    fn rewrite_identifier(&mut self, identifier: &mut Identifier) {
+        identifier.info.ty = self.rewrite_annotation(&identifier.info.ty);
+    }
+
+    fn rewrite_function(&mut self, function: &mut ast::FunctionDef) {
+        self.rewrite_identifier(&mut function.identifier);
+        for parameter in &mut function.parameters {
+            self.rewrite_identifier(parameter);
+        }
+        function.body.accept(self);
+    }
+}
+
+impl DefinitionVisitor<()> for Monomorphizer {
+    fn visit_definition(&mut self, node: &mut Definition) -> () {
+        node.accept(self)
+    }
+
+    fn visit_type_def(&mut self, node: &mut TypeDef) -> () {
+        for parameter in &mut node.parameter_list {
+            self.rewrite_identifier(parameter);
+        }
+        if let Some(inheritance) = &mut node.inheritance_indicator {
+            for argument in &mut inheritance.argument_list {
+                argument.accept(self);
+            }
+        }
+        for member in &mut node.data_member_defs {
+            self.rewrite_identifier(&mut member.identifier);
+            member.default_value.accept(self);
+        }
+        for method in &mut node.function_member_defs {
+            self.rewrite_function(method);
+        }
+    }
+
+    fn visit_function_def(&mut self, node: &mut GlobalFunctionDef) -> () {
+        self.rewrite_function(&mut node.function_def);
+    }
+
+    fn visit_constant_def(&mut self, node: &mut ConstantDef) -> () {
+        self.rewrite_identifier(&mut node.identifier);
+        node.initializer_expression.accept(self);
+    }
+
+    fn visit_protocol_def(&mut self, node: &mut ProtocolDef) -> () {
+        for signature in &mut node.function_signatures {
+            self.rewrite_identifier(&mut signature.identifier);
+            for parameter in &mut signature.parameters {
+                self.rewrite_identifier(parameter);
+            }
+        }
+    }
+}
+
+impl ExpressionVisitor<()> for Monomorphizer {
+    fn visit_expression(&mut self, node: &mut Expression) -> () {
+        node.accept(self)
+    }
+
+    fn visit_destructive_assignment(&mut self, node: &mut DestructiveAssignment) -> () {
+        node.lhs.accept(self);
+        node.rhs.accept(self);
+    }
+
+    fn visit_bin_op(&mut self, node: &mut BinOp) -> () {
+        node.lhs.accept(self);
+        node.rhs.accept(self);
+    }
+
+    fn visit_let_in(&mut self, node: &mut LetIn) -> () {
+        node.assignment.accept(self);
+        node.body.accept(self);
+    }
+
+    fn visit_new_expr(&mut self, node: &mut NewExpr) -> () {
+        if !node.type_arguments.is_empty() {
+            let type_arguments = std::mem::take(&mut node.type_arguments);
+            node.type_name = self.instantiate(&node.type_name, &type_arguments);
+        }
+        for argument in &mut node.arguments {
+            argument.accept(self);
+        }
+    }
+
+    fn visit_assignment(&mut self, node: &mut Assignment) -> () {
+        self.rewrite_identifier(&mut node.identifier);
+        node.rhs.accept(self);
+    }
+
+    fn visit_if_else(&mut self, node: &mut IfElse) -> () {
+        node.condition.accept(self);
+        node.then_expression.accept(self);
+        node.else_expression.accept(self);
+    }
+
+    fn visit_while(&mut self, node: &mut While) -> () {
+        node.condition.accept(self);
+        node.body.accept(self);
+    }
+
+    fn visit_for(&mut self, node: &mut For) -> () {
+        self.rewrite_identifier(&mut node.element);
+        node.iterable_type = self.rewrite_annotation(&node.iterable_type);
+        node.iterable.accept(self);
+        node.body.accept(self);
+    }
+
+    fn visit_block(&mut self, node: &mut Block) -> () {
+        for item in &mut node.body_items {
+            item.accept(self);
+        }
+    }
+
+    fn visit_return_statement(&mut self, node: &mut ReturnStatement) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_un_op(&mut self, node: &mut UnOp) -> () {
+        node.rhs.accept(self);
+    }
+
+    fn visit_data_member_access(&mut self, node: &mut DataMemberAccess) -> () {
+        node.obj_type = self.rewrite_annotation(&node.obj_type);
+        self.rewrite_identifier(&mut node.member);
+        node.object.accept(self);
+    }
+
+    fn visit_function_member_access(&mut self, node: &mut FunctionMemberAccess) -> () {
+        node.obj_type = self.rewrite_annotation(&node.obj_type);
+        node.object.accept(self);
+        node.member.accept(self);
+    }
+
+    fn visist_list_indexing(&mut self, node: &mut ListIndexing) -> () {
+        node.list_type = self.rewrite_annotation(&node.list_type);
+        node.list.accept(self);
+        node.index.accept(self);
+    }
+
+    fn visit_function_call(&mut self, node: &mut FunctionCall) -> () {
+        if !node.type_arguments.is_empty() {
+            let type_arguments = std::mem::take(&mut node.type_arguments);
+            node.identifier.id = self.instantiate(&node.identifier.id, &type_arguments);
+        }
+        self.rewrite_identifier(&mut node.identifier);
+        node.closure_type = self.rewrite_annotation(&node.closure_type);
+        for argument in &mut node.arguments {
+            argument.accept(self);
+        }
+    }
+
+    fn visit_lambda(&mut self, node: &mut Lambda) -> () {
+        for parameter in &mut node.parameters {
+            self.rewrite_identifier(parameter);
+        }
+        for capture in &mut node.captures {
+            self.rewrite_identifier(capture);
+        }
+        node.return_type = self.rewrite_annotation(&node.return_type);
+        node.body.accept(self);
+    }
+
+    fn visit_is_expr(&mut self, node: &mut IsExpr) -> () {
+        node.obj_type = self.rewrite_annotation(&node.obj_type);
+        node.target = self.rewrite(&node.target);
+        node.expression.accept(self);
+    }
+
+    fn visit_as_expr(&mut self, node: &mut AsExpr) -> () {
+        node.obj_type = self.rewrite_annotation(&node.obj_type);
+        node.target = self.rewrite(&node.target);
+        node.expression.accept(self);
+    }
+
+    fn visit_base_call(&mut self, node: &mut BaseCall) -> () {
+        self.rewrite_identifier(&mut node.receiver);
+        node.parent_type = self.rewrite_annotation(&node.parent_type);
+        node.ty = self.rewrite_annotation(&node.ty);
+        for argument in &mut node.arguments {
+            argument.accept(self);
+        }
+    }
+
+    fn visit_variable(&mut self, node: &mut Identifier) -> () {
+        self.rewrite_identifier(node);
+    }
+
+    fn visit_number_literal(&mut self, _node: &mut NumberLiteral) -> () {}
+
+    fn visit_boolean_literal(&mut self, _node: &mut BooleanLiteral) -> () {}
+
+    fn visit_string_literal(&mut self, _node: &mut StringLiteral) -> () {}
+
+    fn visit_list_literal(&mut self, node: &mut ListLiteral) -> () {
+        node.list_type = self.rewrite_annotation(&node.list_type);
+        for element in &mut node.elements {
+            element.accept(self);
+        }
+    }
+
+    fn visit_empty_expression(&mut self) -> () {}
+}
@@ -0,0 +1,47 @@
+use super::generate_code;
+
+/// The definition of the function `name` in `llvm`
+fn function_definition<'a>(llvm: &'a str, name: &str) -> &'a str {
+    let start = llvm
+        .find(&format!("@{}(", name))
+        .unwrap_or_else(|| panic!("{} is not defined", name));
+    let start = llvm[..start].rfind("define").unwrap();
+    let end = start + llvm[start..].find("\n}").unwrap();
+    &llvm[start..end]
+}
+
+#[test]
+fn base_call_is_a_direct_call() {
+    let llvm = generate_code(
+        "
+        type Shape() { area(): Number => 1; }
+        type Square() inherits Shape() { area(): Number => base() + 1; }
+
+        print(new Square().area());
+        ",
+    );
+
+    println!("{}", llvm);
+    let square_area = function_definition(&llvm, "Square_area");
+    assert!(square_area.contains("call double @Shape_area(%Shape_type* "));
+    assert!(!square_area.contains("_vtable_type"));
+}
+
+#[test]
+fn base_call_calls_the_inherited_implementation() {
+    let llvm = generate_code(
+        "
+        type Shape() { scaled(factor: Number): Number => factor; }
+        type Polygon() inherits Shape() { }
+        type Square() inherits Polygon() {
+            scaled(factor: Number): Number => base(factor * 2);
+        }
+
+        print(new Square().scaled(3));
+        ",
+    );
+
+    println!("{}", llvm);
+    let square_scaled = function_definition(&llvm, "Square_scaled");
+    assert!(square_scaled.contains("call double @Shape_scaled(%Shape_type* "));
+}
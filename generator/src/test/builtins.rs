@@ -0,0 +1,81 @@
+use crate::test::lli_interface::{lli_f64, lli_string};
+
+use super::generate_code;
+
+#[test]
+fn math_builtins() {
+    let llvm = generate_code(
+        "
+        print(sqrt(16) + floor(2.7) + ceil(2.2) + exp(0) + log(1) + sin(0) + cos(0));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 11.0);
+}
+
+#[test]
+fn rand_is_in_unit_interval() {
+    let llvm = generate_code(
+        "
+        let i = 0, inside = 0 in {
+            while (i < 100) {
+                let r = rand() in if (r >= 0 && r < 1) { inside := inside + 1; } else { inside; };
+                i := i + 1;
+            };
+            print(inside);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 100.0);
+}
+
+#[test]
+fn string_length() {
+    let llvm = generate_code(
+        "
+        print(length(\"hello\" @ \"!\"));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 6.0);
+}
+
+#[test]
+fn substring_is_clamped() {
+    let llvm = generate_code(
+        "
+        print(substring(\"hello world\", 6, 100) @ substring(\"hello\", -3, 2));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "worldhe");
+}
+
+#[test]
+fn parse_number() {
+    let llvm = generate_code(
+        "
+        print(parse(\"12.5\") * 2);
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 25.0);
+}
+
+#[test]
+fn read_line_at_end_of_input() {
+    let llvm = generate_code(
+        "
+        print(\"[\" @ read_line() @ \"]\");
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "[]");
+}
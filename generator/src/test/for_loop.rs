@@ -0,0 +1,133 @@
+use crate::test::lli_interface::{lli_f64, lli_string};
+
+use super::generate_code;
+
+#[test]
+fn for_over_list() {
+    let llvm = generate_code(
+        "
+        for (x in [1,2,3]) {
+            print(x);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(
+        lli_string(&llvm).unwrap(),
+        "1.000000
+2.000000
+3.000000"
+    );
+}
+
+#[test]
+fn for_accumulates() {
+    let llvm = generate_code(
+        "
+        let sum = 0, list = [1,2,3,4] in {
+            for (x in list) {
+                sum := sum + x;
+            };
+            print(sum);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 10.0);
+}
+
+#[test]
+fn nested_for() {
+    let llvm = generate_code(
+        "
+        let sum = 0 in {
+            for (x in [1,2]) {
+                for (y: Number in [10,20]) {
+                    sum := sum + x * y;
+                };
+            };
+            print(sum);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 90.0);
+}
+
+#[test]
+fn for_over_iterator() {
+    let llvm = generate_code(
+        "
+        type Range(min: Number, max: Number) {
+            min = min;
+            max = max;
+            value = min - 1;
+
+            next(): Boolean {
+                self.value := self.value + 1;
+                return self.value < self.max;
+            }
+
+            current(): Number {
+                return self.value;
+            }
+        }
+
+        for (x in new Range(0, 3)) {
+            print(x);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(
+        lli_string(&llvm).unwrap(),
+        "0.000000
+1.000000
+2.000000"
+    );
+}
+
+#[test]
+fn for_over_inherited_iterator() {
+    let llvm = generate_code(
+        "
+        type Counter(n: Number) {
+            n = n;
+            i = 0;
+
+            next(): Boolean {
+                self.i := self.i + 1;
+                return self.i <= self.n;
+            }
+
+            index(): Number {
+                return self.i;
+            }
+
+            current(): Number {
+                return self.index();
+            }
+        }
+
+        type Squares(n: Number) inherits Counter(n) {
+            current(): Number {
+                return self.index() * self.index();
+            }
+        }
+
+        let sum = 0 in {
+            for (x in new Squares(3)) {
+                sum := sum + x;
+            };
+            print(sum);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 14.0);
+}
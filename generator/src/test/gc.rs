@@ -0,0 +1,117 @@
+use crate::test::lli_interface::{lli_f64, lli_string};
+
+use super::generate_code;
+
+#[test]
+fn strings_survive_collections() {
+    let llvm = generate_code(
+        "
+        let kept = \"kept\" @ \"!\", i = 0 in {
+            while (i < 100000) {
+                let garbage = \"garbage\" @ i in 0;
+                i := i + 1;
+            };
+            print(kept);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "kept!");
+}
+
+#[test]
+fn reassigned_variable_keeps_last_value() {
+    let llvm = generate_code(
+        "
+        let last = \"\", i = 0 in {
+            while (i < 100000) {
+                last := \"value \" @ i;
+                i := i + 1;
+            };
+            print(last);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "value 99999.000000");
+}
+
+#[test]
+fn list_elements_are_traced() {
+    let llvm = generate_code(
+        "
+        let kept = [\"a\" @ \"b\", \"c\" @ \"d\"], i = 0 in {
+            while (i < 100000) {
+                let garbage = [\"x\" @ i, \"y\" @ i] in 0;
+                i := i + 1;
+            };
+            for (s in kept) {
+                print(s);
+            };
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "ab\ncd");
+}
+
+#[test]
+fn closure_environments_are_traced() {
+    let llvm = generate_code(
+        "
+        let suffix = \"!\" @ \"!\", f = (x: String): String => x @ suffix, i = 0 in {
+            while (i < 100000) {
+                let garbage = f(\"g\" @ i) in 0;
+                i := i + 1;
+            };
+            print(f(\"hey\"));
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "hey!!");
+}
+
+#[test]
+fn values_returned_by_functions_survive() {
+    let llvm = generate_code(
+        "
+        function build(n: Number): String => if (n == 0) \"\" else build(n - 1) @ \"x\";
+
+        let i = 0, total = 0 in {
+            while (i < 20000) {
+                total := total + i;
+                let s = build(5) @ build(5) in 0;
+                i := i + 1;
+            };
+            print(total);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 199990000.0);
+}
+
+#[test]
+fn types_get_pointer_maps() {
+    let llvm = generate_code(
+        "
+        type Point(x: Number, name: String) {
+            x = x;
+            name = name;
+        }
+        print(1);
+        ",
+    );
+
+    println!("{}", llvm);
+    assert!(llvm.contains(
+        "@Point_gc_map = private unnamed_addr constant [3 x i64] [i64 1, i64 1, i64 ptrtoint (i8** getelementptr (%Point_type, %Point_type* null, i32 0, i32 2) to i64)]"
+    ));
+    assert!(llvm.contains("@hulk_gc_alloc(i64 ptrtoint (%Point_type* getelementptr (%Point_type, %Point_type* null, i32 1) to i64)"));
+}
@@ -0,0 +1,66 @@
+use crate::test::lli_interface::lli_string;
+
+use super::generate_code;
+
+#[test]
+fn generic_function_instances() {
+    let llvm = generate_code(
+        "
+        function first<T>(xs: T*): T => xs[0];
+
+        {
+            print(first([1, 2, 3]) + 1);
+            print(first([\"a\", \"b\"]));
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert!(llvm.contains("@first_Number"));
+    assert!(llvm.contains("@first_String"));
+    assert_eq!(lli_string(&llvm).unwrap(), "2.000000\na");
+}
+
+#[test]
+fn generic_type_instances() {
+    let llvm = generate_code(
+        "
+        type Box<T>(value: T) {
+            value = value;
+
+            get(): T => self.value;
+        }
+
+        let a = new Box(20), b = new Box<String>(\"hello\") in print(a.get());
+        ",
+    );
+
+    println!("{}", llvm);
+    assert!(llvm.contains("%Box_Number_type = type"));
+    assert!(llvm.contains("%Box_String_type = type"));
+    assert!(!llvm.contains("%Box_type"));
+    assert_eq!(lli_string(&llvm).unwrap(), "20.000000");
+}
+
+#[test]
+fn generic_types_with_list_parameters() {
+    let llvm = generate_code(
+        "
+        type Stack<T>(items: T*) {
+            items = items;
+
+            top(): T => self.items[0];
+            first_of(others: T*): T => others[0];
+        }
+
+        let s = new Stack([3, 2, 1]) in {
+            print(s.top());
+            print(s.first_of([5, 4]));
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert!(llvm.contains("%Stack_Number_type = type"));
+    assert_eq!(lli_string(&llvm).unwrap(), "3.000000\n5.000000");
+}
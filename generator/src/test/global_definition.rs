@@ -1,6 +1,6 @@
-use crate::test::lli_interface::{lli_f64, lli_i1};
-use crate::test::lli_interface::lli_string;
 use super::generate_code;
+use crate::test::lli_interface::lli_string;
+use crate::test::lli_interface::{lli_f64, lli_i1};
 
 #[test]
 fn data_access() {
@@ -49,7 +49,6 @@ fn data_access_bool() {
     assert_eq!(lli_f64(&llvm).unwrap(), 5.0);
 }
 
-
 #[test]
 fn function_definition() {
     let llvm = generate_code(
@@ -374,7 +373,6 @@ fn nested_type_instantiation_and_method_calls() {
     assert_eq!(result, 21.0);
 }
 
-
 #[test]
 fn mutate_fields_and_verify() {
     let llvm = generate_code(
@@ -415,7 +413,6 @@ fn complex_string_manipulation_2() {
     assert_eq!(result, "foobabaz");
 }
 
-
 #[test]
 fn x1() {
     let llvm = generate_code(
@@ -466,7 +463,6 @@ fn interleaved_number_and_string_operations_with_numbers() {
     assert_eq!(result, "1.000000 is the answer and not 20.000000");
 }
 
-
 #[test]
 fn simple_while_2() {
     let llvm = generate_code(
@@ -485,7 +481,9 @@ fn simple_while_2() {
     );
 
     println!("{}", llvm);
-    assert_eq!(lli_string(&llvm).unwrap(), "hello world
+    assert_eq!(
+        lli_string(&llvm).unwrap(),
+        "hello world
 1.000000
 hello world
 2.000000
@@ -494,10 +492,10 @@ hello world
 hello world
 4.000000
 hello world
-5.000000");
+5.000000"
+    );
 }
 
-
 #[test]
 fn list_of_numbers() {
     let llvm = generate_code(
@@ -537,7 +535,6 @@ fn list_of_strings_with_indexing() {
     assert_eq!(result, "hello world");
 }
 
-
 #[test]
 fn list_of_types() {
     let llvm = generate_code(
@@ -558,8 +555,7 @@ fn list_of_types() {
 }
 
 #[test]
-fn factorial()
-{
+fn factorial() {
     let llvm = generate_code(
         r#"
             function factorial(n: Number): Number {
@@ -578,8 +574,7 @@ fn factorial()
 }
 
 #[test]
-fn constant()
-{
+fn constant() {
     let llvm = generate_code(
         r#"
             constant y :String = "hello world";
@@ -592,8 +587,20 @@ fn constant()
 }
 
 #[test]
-fn function_returning_list_of_list_of_strings()
-{
+fn number_constant() {
+    let llvm = generate_code(
+        r#"
+            constant k: Number = 10;
+            print(k * 2);
+            "#,
+    );
+    println!("{}", llvm);
+    let result = lli_f64(&llvm).unwrap();
+    assert_eq!(result, 20.0);
+}
+
+#[test]
+fn function_returning_list_of_list_of_strings() {
     let llvm = generate_code(
         r#"
             function a(): String** => [["hello world"]];
@@ -606,8 +613,7 @@ print(let x = a() in x[0][0]);
 }
 
 #[test]
-fn function_returning_string_of_list_of_list_of_strings()
-{
+fn function_returning_string_of_list_of_list_of_strings() {
     let llvm = generate_code(
         r#"
             function a(x:String*): String => x[0];
@@ -617,4 +623,4 @@ print(let x = a(["hello world"]) in x);
     println!("{}", llvm);
     let result = lli_string(&llvm).unwrap();
     assert_eq!(result, "hello world");
-}
\ No newline at end of file
+}
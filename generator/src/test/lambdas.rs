@@ -0,0 +1,114 @@
+use crate::test::lli_interface::{lli_f64, lli_string};
+
+use super::generate_code;
+
+#[test]
+fn let_bound_lambda() {
+    let llvm = generate_code(
+        "
+        let f = (x: Number) => x * 2 in print(f(21));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 42.0);
+}
+
+#[test]
+fn lambda_with_captures() {
+    let llvm = generate_code(
+        "
+        let k = 3, f = (x: Number): Number => x * k in print(f(5));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 15.0);
+}
+
+#[test]
+fn lambda_passed_to_function() {
+    let llvm = generate_code(
+        "
+        function sum_by(xs: Number*, f: (Number) -> Number): Number {
+            let total = 0 in {
+                for (x in xs) {
+                    total := total + f(x);
+                };
+                return total;
+            };
+        }
+
+        let k = 10 in print(sum_by([1, 2, 3], (x: Number) => x * k));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 60.0);
+}
+
+#[test]
+fn lambda_returning_lambda() {
+    let llvm = generate_code(
+        "
+        let make_adder = (n: Number) => (x: Number) => x + n in
+            let add5 = make_adder(5) in print(add5(3));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 8.0);
+}
+
+#[test]
+fn captured_state_persists_between_calls() {
+    let llvm = generate_code(
+        "
+        let c = 0, counter = (): Number => c := c + 1 in {
+            counter();
+            counter();
+            print(counter());
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 3.0);
+}
+
+#[test]
+fn lambda_over_strings_and_booleans() {
+    let llvm = generate_code(
+        "
+        let greet = (name: String, loud: Boolean) => if (loud) (name @ \"!\") else name in {
+            print(greet(\"hello\", true));
+            print(greet(\"bye\", false));
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "hello!\nbye");
+}
+
+#[test]
+fn lambda_with_protocol_parameter() {
+    let llvm = generate_code(
+        "
+        protocol Shape {
+            area(): Number;
+        }
+
+        type Square(side: Number) {
+            side = side;
+
+            area(): Number => self.side * self.side;
+        }
+
+        let f: (Square) -> Number = (s: Shape) => s.area() + 1 in print(f(new Square(3)));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 10.0);
+}
@@ -5,18 +5,31 @@ use crate::CodeGenerator;
 
 pub mod lli_interface;
 
+pub mod base_calls;
 pub mod block;
 mod booleans;
+pub mod builtins;
 pub mod dassignment;
+pub mod for_loop;
+pub mod gc;
+pub mod generics;
 pub mod global_definition;
 pub mod if_else;
+pub mod lambdas;
 pub mod let_in;
 pub mod misc;
 pub mod operators;
 pub mod printer;
+pub mod protocols;
+pub mod runtime_checks;
+pub mod type_tests;
 pub mod while_loop;
 
 fn generate_code(hulk: &str) -> String {
+    generate_code_with(hulk, CodeGenerator::new())
+}
+
+fn generate_code_with(hulk: &str, code_generator: CodeGenerator) -> String {
     let p = ProgramParser::new();
     let mut ast = p.parse(hulk).unwrap();
 
@@ -31,7 +44,5 @@ fn generate_code(hulk: &str) -> String {
         println!("Semantic analysis successful.");
     }
 
-    let code_generator = CodeGenerator::new();
-
     code_generator.generate_code_from_program_ast(&mut ast)
 }
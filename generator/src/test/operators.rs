@@ -1,6 +1,7 @@
-use crate::test::lli_interface::lli_f64;
+use crate::test::lli_interface::{lli_f64, lli_string};
 
-use super::generate_code;
+use super::{generate_code, generate_code_with};
+use crate::CodeGenerator;
 
 #[test]
 fn simple_operation() {
@@ -17,3 +18,98 @@ fn unary_operations() {
     let expected = -2.0 + -2.0 + -3.0 + -7.0 * -2.0;
     assert_eq!(lli_f64(&llvm).unwrap(), expected);
 }
+
+#[test]
+fn floor_division() {
+    let llvm = generate_code(
+        "{
+            print(7 div 2);
+            print(-7 div 2);
+            print(7 div -2);
+            print(-7 div -2);
+            print(7.5 div 2);
+        };",
+    );
+    println!("{}", llvm);
+    assert_eq!(
+        lli_string(&llvm).unwrap(),
+        "3.000000
+-4.000000
+-4.000000
+3.000000
+3.000000"
+    );
+}
+
+#[test]
+fn modulo() {
+    let llvm = generate_code(
+        "{
+            print(7 % 3);
+            print(-7 % 3);
+            print(7 % -3);
+            print(-7 % -3);
+            print(6 % 3);
+            print(5.5 % 2);
+        };",
+    );
+    println!("{}", llvm);
+    assert_eq!(
+        lli_string(&llvm).unwrap(),
+        "1.000000
+2.000000
+-2.000000
+-1.000000
+0.000000
+1.500000"
+    );
+}
+
+#[test]
+fn floor_division_and_modulo_by_zero() {
+    let llvm = generate_code_with(
+        "{
+            print(7 div 0);
+            print(-7 div 0);
+            print(7 % 0);
+        };",
+        CodeGenerator::new().with_runtime_checks(false),
+    );
+    println!("{}", llvm);
+    let output = lli_string(&llvm).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "inf");
+    assert_eq!(lines[1], "-inf");
+    assert!(lines[2].contains("nan"));
+}
+
+#[test]
+fn floor_division_and_modulo_identity() {
+    let llvm = generate_code("let a = -17, b = 5 in print(b * (a div b) + a % b);");
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), -17.0);
+}
+
+#[test]
+fn power() {
+    let llvm = generate_code(
+        "{
+            print(2 ^ 10);
+            print(2 ** 3 ** 2);
+            print(-2 ^ 2);
+            print(2 ^ -1);
+            print(9 ^ 0.5);
+            print(3 * 2 ^ 2);
+        };",
+    );
+    println!("{}", llvm);
+    assert_eq!(
+        lli_string(&llvm).unwrap(),
+        "1024.000000
+512.000000
+-4.000000
+0.500000
+3.000000
+12.000000"
+    );
+}
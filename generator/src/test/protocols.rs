@@ -0,0 +1,215 @@
+use crate::test::lli_interface::{lli_f64, lli_string};
+
+use super::generate_code;
+
+#[test]
+fn protocol_typed_variable() {
+    let llvm = generate_code(
+        "
+        protocol Shape {
+            area(): Number;
+        }
+
+        type Square(side: Number) {
+            side = side;
+
+            area(): Number => self.side * self.side;
+        }
+
+        let s: Shape = new Square(3) in print(s.area());
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 9.0);
+}
+
+#[test]
+fn protocol_typed_parameter() {
+    let llvm = generate_code(
+        "
+        protocol Scalable {
+            scale(factor: Number): Number;
+        }
+
+        type Circle(radius: Number) {
+            radius = radius;
+
+            scale(factor: Number): Number => self.radius * factor;
+        }
+
+        type Segment(length: Number) {
+            length = length;
+
+            scale(factor: Number): Number => self.length * factor + 1;
+        }
+
+        function scale_twice(s: Scalable): Number => s.scale(2) + s.scale(3);
+
+        {
+            print(scale_twice(new Circle(1)));
+            print(scale_twice(new Segment(2)));
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(
+        lli_string(&llvm).unwrap(),
+        "5.000000
+12.000000"
+    );
+}
+
+#[test]
+fn extended_protocol() {
+    let llvm = generate_code(
+        "
+        protocol Named {
+            id(): Number;
+        }
+
+        protocol Measured extends Named {
+            size(): Number;
+        }
+
+        type Box(w: Number, h: Number) {
+            w = w;
+            h = h;
+
+            size(): Number => self.w * self.h;
+            id(): Number => 7;
+        }
+
+        function describe(m: Measured): Number => m.id() * 100 + m.size();
+
+        print(describe(new Box(2, 3)));
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 706.0);
+}
+
+#[test]
+fn conformance_through_inherited_methods() {
+    let llvm = generate_code(
+        "
+        protocol Valued {
+            value(): Number;
+            is_big(): Boolean;
+        }
+
+        type Base(v: Number) {
+            v = v;
+
+            value(): Number => self.v;
+            is_big(): Boolean => self.value() > 10;
+        }
+
+        type Doubled(v: Number) inherits Base(v * 2) {}
+
+        let x: Valued = new Doubled(6) in
+            if (x.is_big()) print(x.value()) else print(0);
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 12.0);
+}
+
+#[test]
+fn protocol_values_in_list() {
+    let llvm = generate_code(
+        "
+        protocol Shape {
+            area(): Number;
+        }
+
+        type Square(side: Number) {
+            side = side;
+
+            area(): Number => self.side * self.side;
+        }
+
+        type Rectangle(w: Number, h: Number) {
+            w = w;
+            h = h;
+
+            area(): Number => self.w * self.h;
+        }
+
+        let total = 0, a: Shape = new Square(2), b: Shape = new Rectangle(2, 5) in {
+            let shapes = [a, b] in
+                for (s in shapes) {
+                    total := total + s.area();
+                };
+            print(total);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 14.0);
+}
+
+#[test]
+fn protocol_typed_field() {
+    let llvm = generate_code(
+        "
+        protocol Named {
+            name(): String;
+        }
+
+        type Person(first: String) {
+            first = first;
+
+            name(): String => self.first;
+        }
+
+        type Holder() {
+            named: Named = new Person(\"cd\");
+
+            get(): Named => self.named;
+        }
+
+        print(new Holder().get().name());
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "cd");
+}
+
+#[test]
+fn protocol_typed_iterable() {
+    let llvm = generate_code(
+        "
+        protocol NumberIterable {
+            next(): Boolean;
+            current(): Number;
+        }
+
+        type Countdown(n: Number) {
+            n = n + 1;
+
+            next(): Boolean {
+                self.n := self.n - 1;
+                return self.n > 0;
+            }
+
+            current(): Number => self.n;
+        }
+
+        let sum = 0, it: NumberIterable = new Countdown(4) in {
+            for (x in it) {
+                sum := sum + x;
+            };
+            print(sum);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 10.0);
+}
@@ -0,0 +1,75 @@
+use error_handler::source_map::SourceMap;
+
+use crate::CodeGenerator;
+use crate::test::lli_interface::{call_lli, lli_f64};
+
+use super::{generate_code, generate_code_with};
+
+#[test]
+fn in_bounds_indexing() {
+    let llvm = generate_code("let xs = [1, 2, 3] in print(xs[0] + xs[2]);");
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 4.0);
+}
+
+#[test]
+fn index_out_of_bounds() {
+    for index in ["3", "-1", "100"] {
+        let llvm = generate_code(&format!("let xs = [1, 2, 3] in print(xs[{}]);", index));
+
+        println!("{}", llvm);
+        let error = call_lli(&llvm).unwrap_err();
+        assert!(error.contains("Runtime Error: List index out of bounds"));
+    }
+}
+
+#[test]
+fn non_integer_index() {
+    let llvm = generate_code("let xs = [1, 2, 3] in print(xs[1.5]);");
+
+    println!("{}", llvm);
+    let error = call_lli(&llvm).unwrap_err();
+    assert!(error.contains("Runtime Error: List index is not an integer"));
+}
+
+#[test]
+fn division_by_zero() {
+    for op in ["/", "div", "%"] {
+        let llvm = generate_code(&format!("let zero = 0 in print(7 {} zero);", op));
+
+        println!("{}", llvm);
+        let error = call_lli(&llvm).unwrap_err();
+        assert!(error.contains("Runtime Error: Division by zero"));
+    }
+}
+
+#[test]
+fn errors_show_source_location() {
+    let mut source_map = SourceMap::new();
+    source_map.add_file(
+        "main.hulk",
+        "let xs = [1, 2, 3] in {\n    print(xs[3]);\n};",
+    );
+
+    let llvm = generate_code_with(
+        source_map.content(),
+        CodeGenerator::new().with_source_map(source_map.clone()),
+    );
+
+    println!("{}", llvm);
+    let error = call_lli(&llvm).unwrap_err();
+    assert!(error.contains(" --> main.hulk:2:13\n  |     print(xs[3]);"));
+}
+
+#[test]
+fn checks_can_be_disabled() {
+    let llvm = generate_code_with(
+        "let xs = [1, 2, 3] in print(xs[1] / 2);",
+        CodeGenerator::new().with_runtime_checks(false),
+    );
+
+    println!("{}", llvm);
+    assert!(!llvm.contains("call void @hulk_runtime_error"));
+    assert_eq!(lli_f64(&llvm).unwrap(), 1.0);
+}
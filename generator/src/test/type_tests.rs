@@ -0,0 +1,102 @@
+use crate::test::lli_interface::{lli_f64, lli_string};
+
+use super::generate_code;
+
+#[test]
+fn is_walks_inheritance_chain() {
+    let llvm = generate_code(
+        "
+        type Animal() { talk(): Number => 1; }
+        type Dog() inherits Animal() { talk(): Number => 2; }
+        type Puppy() inherits Dog() { talk(): Number => 3; }
+        type Cat() inherits Animal() { talk(): Number => 4; }
+
+        let a: Animal = new Puppy(), c: Animal = new Cat() in {
+            print(a is Dog);
+            print(a is Puppy);
+            print(c is Dog);
+            print(c is Cat);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "true\ntrue\nfalse\ntrue");
+}
+
+#[test]
+fn is_against_protocol() {
+    let llvm = generate_code(
+        "
+        protocol Speaker {
+            talk(): Number;
+        }
+
+        type Animal() { }
+        type Dog() inherits Animal() { talk(): Number => 2; }
+
+        let a: Animal = new Dog(), b: Animal = new Animal() in {
+            print(a is Speaker);
+            print(b is Speaker);
+        };
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_string(&llvm).unwrap(), "true\nfalse");
+}
+
+#[test]
+fn successful_downcast() {
+    let llvm = generate_code(
+        "
+        type Animal() { talk(): Number => 1; }
+        type Dog(bones: Number) inherits Animal() {
+            bones = bones;
+
+            count(): Number => self.bones;
+        }
+
+        let a: Animal = new Dog(7) in print((a as Dog).count());
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 7.0);
+}
+
+#[test]
+fn downcast_to_protocol() {
+    let llvm = generate_code(
+        "
+        protocol Speaker {
+            talk(): Number;
+        }
+
+        type Animal() { }
+        type Dog() inherits Animal() { talk(): Number => 2; }
+
+        let a: Animal = new Dog() in print((a as Speaker).talk());
+        ",
+    );
+
+    println!("{}", llvm);
+    assert_eq!(lli_f64(&llvm).unwrap(), 2.0);
+}
+
+#[test]
+fn failed_downcast_aborts() {
+    let llvm = generate_code(
+        "
+        type Animal() { talk(): Number => 1; }
+        type Dog() inherits Animal() { talk(): Number => 2; }
+        type Cat() inherits Animal() { talk(): Number => 3; }
+
+        let a: Animal = new Cat() in print((a as Dog).talk());
+        ",
+    );
+
+    println!("{}", llvm);
+    let error = lli_f64(&llvm).unwrap_err();
+    assert!(error.contains("Cannot cast `Cat` to `Dog`"), "{}", error);
+}
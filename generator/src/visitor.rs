@@ -1,10 +1,18 @@
 mod assignment;
+mod base_call;
 mod bin_op;
 mod block;
+mod builtin_call;
 mod for_exp;
+mod gc;
 mod if_else;
+mod lambda;
 mod print;
+mod protocol;
+pub(crate) use protocol::collect_protocol_methods;
+pub mod runtime_checks;
 mod type_def;
+mod type_test;
 mod un_op;
 mod while_exp;
 
@@ -17,13 +25,16 @@ use std::cell::Cell;
 use std::collections::HashMap;
 use std::string::String;
 
+use crate::builtins::find_builtin;
 use crate::context::Context;
 use crate::llvm_types::{HandleType, LlvmHandle, LlvmType};
+use ast::tokens::token_position::TokenPositionTrait;
 use ast::typing::to_string;
 use ast::{
     Definition, DefinitionVisitor, Expression, ExpressionVisitor, ListIndexing,
     VisitableDefinition, VisitableExpression,
 };
+use error_handler::source_map::SourceMap;
 
 pub struct VisitorResult {
     pub result_handle: Option<LlvmHandle>,
@@ -83,6 +94,14 @@ impl Variable {
             llvm_name,
         }
     }
+
+    /// Creates a new variable holding a protocol fat pointer (%P_interface).
+    pub fn new_interface(llvm_name: String, protocol: String) -> Variable {
+        Variable {
+            var_type: LlvmType::Interface(protocol),
+            llvm_name,
+        }
+    }
 }
 
 /// The main code generation visitor for the Hulk language.
@@ -130,11 +149,19 @@ pub struct GeneratorVisitor {
 
     pub(crate) functions_args_types: HashMap<String, Vec<String>>,
 
+    /// Maps protocol names to their method signatures, the methods of extended protocols come first.
+    pub(crate) protocols: HashMap<String, Vec<ast::FunctionSignature>>,
+
     pub(crate) general_definitions: Vec<String>,
 
     pub(crate) constants: Vec<String>,
 
     pub(crate) is_global: bool,
+
+    /// Whether list indexing and divisions are checked at runtime, see `runtime_checks`
+    pub(crate) runtime_checks: bool,
+    /// Maps the positions in the AST to files, lines and columns for runtime errors
+    pub(crate) source_map: Option<SourceMap>,
 }
 
 pub struct GlobalDefinitionVisitor {
@@ -177,6 +204,9 @@ pub struct GlobalDefinitionVisitor {
     pub(crate) function_member_signature_types: HashMap<(String, String), String>,
 
     pub(crate) functions_args_types: HashMap<String, Vec<String>>,
+
+    /// Maps protocol names to their method signatures, the methods of extended protocols come first.
+    pub(crate) protocols: HashMap<String, Vec<ast::FunctionSignature>>,
 }
 
 impl GlobalDefinitionVisitor {
@@ -198,6 +228,7 @@ impl GlobalDefinitionVisitor {
             tmp_counter: Cell::new(0),
             function_member_signature_types: HashMap::new(),
             functions_args_types: HashMap::new(),
+            protocols: HashMap::new(),
         }
     }
 }
@@ -222,9 +253,12 @@ impl GeneratorVisitor {
             tmp_counter: Cell::new(0),
             function_member_signature_types: HashMap::new(),
             functions_args_types: HashMap::new(),
+            protocols: HashMap::new(),
             general_definitions: Vec::new(),
             constants: Vec::new(),
             is_global: false,
+            runtime_checks: true,
+            source_map: None,
         }
     }
 
@@ -289,19 +323,28 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
         match node.lhs.as_mut() {
             Expression::Variable(var) => {
                 // Handle regular variable assignment
-                let var_llvm_name = &self
-                    .context
-                    .get_value(&var.id)
-                    .expect(&format!(
-                        "Variable {} not found, SA should have caught this",
-                        var.id
-                    ))
-                    .llvm_name;
-
-                preamble += &self.store_statement(
+                let variable = &self.context.get_value(&var.id).expect(&format!(
+                    "Variable {} not found, SA should have caught this",
+                    var.id
+                ));
+                let (var_llvm_name, var_type) =
+                    (variable.llvm_name.clone(), variable.var_type.clone());
+
+                let (coercion, value) = self.coerce_value(
                     &exp_result_handle.llvm_name,
+                    &exp_result_handle.handle_type.inner_type().llvm_type_str(),
+                    &var_type.llvm_type_str(),
+                );
+                preamble += &coercion;
+                preamble += &self.gc_root_overwritten(&var_llvm_name, &var_type.llvm_type_str());
+                preamble += &self.store_statement(
+                    &value,
                     &var_llvm_name,
-                    &exp_result_handle.handle_type.inner_type(),
+                    &if coercion.is_empty() {
+                        exp_result_handle.handle_type.inner_type()
+                    } else {
+                        var_type
+                    },
                 );
             }
             Expression::DataMemberAccess(data_member_access) => {
@@ -328,6 +371,8 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
                     let field_index = idx.clone();
 
                     // Get pointer to the member field
+                    let (cast, object_ptr) = self.cast_object_to_type(&object_ptr, &type_name);
+                    preamble += &cast;
                     let member_ptr = self.generate_tmp_variable();
                     let gep_instr = format!(
                         "  {} = getelementptr inbounds %{}_type, %{}_type* {}, i32 0, i32 {}\n",
@@ -342,11 +387,19 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
                         None => LlvmType::Object,
                     };
 
-                    preamble += &self.store_statement(
+                    let (coercion, value) = self.coerce_value(
                         &exp_result_handle.llvm_name,
-                        &member_ptr,
-                        &llvm_type,
+                        &exp_result_handle.handle_type.inner_type().llvm_type_str(),
+                        &llvm_type.llvm_type_str(),
                     );
+                    preamble += &coercion;
+                    let member_llvm_type = self
+                        .type_members_types
+                        .get(&(type_name.clone(), member_id.clone()))
+                        .cloned()
+                        .unwrap_or_else(|| llvm_type.llvm_type_str());
+                    preamble += &self.gc_root_overwritten(&member_ptr, &member_llvm_type);
+                    preamble += &self.store_statement(&value, &member_ptr, &llvm_type);
                 } else {
                     panic!(
                         "Data member '{}' not found in type '{}'",
@@ -388,7 +441,15 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
     }
 
     fn visit_assignment(&mut self, node: &mut ast::Assignment) -> VisitorResult {
-        let expression_result = node.rhs.accept(self);
+        let mut expression_result = node.rhs.accept(self);
+        if let Some(ty) = &node.identifier.info.ty {
+            if let Some(handle) = expression_result.result_handle.take() {
+                let (coercion, handle) =
+                    self.coerce_handle(handle, &self.llvm_type_from_ast_type(ty));
+                expression_result.preamble += &coercion;
+                expression_result.result_handle = Some(handle);
+            }
+        }
 
         self.handle_assignment(node.identifier.id.clone(), expression_result)
     }
@@ -411,65 +472,8 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
 
     fn visit_for(&mut self, node: &mut ast::For) -> VisitorResult {
         let iterable_result = node.iterable.accept(self);
-        let element_id = node.element.id.clone();
-        let body_result = node.body.accept(self);
-
-        let iterable_result_handle = iterable_result
-            .result_handle
-            .expect("Expected a result handle for iterable expression of for loop");
-
-        let index_var = self.generate_tmp_variable();
-        let init_code = format!(
-            "  {} = alloca i64\n  store i64 0, i64* {}\n",
-            index_var, index_var
-        );
-
-        let length_ptr = self.generate_tmp_variable();
-        let length_var = self.generate_tmp_variable();
 
-        let llvm_elem_type = "i64";
-
-        let length_ptr_code = format!(
-            "  {} = getelementptr inbounds {}, {}* {}, i64 0\n",
-            length_ptr, llvm_elem_type, llvm_elem_type, iterable_result_handle.llvm_name
-        );
-
-        let length_code = format!(
-            "  {} = load {}, {}* {}\n",
-            length_var, llvm_elem_type, llvm_elem_type, length_ptr
-        );
-
-        let condition_name = self.generate_tmp_variable();
-
-        let condition_code = format!(
-            "{cond} = icmp slt i32 %{index}, %{length}",
-            cond = condition_name,
-            index = index_var,
-            length = length_var
-        );
-
-        let (loop_label, body_label, loop_exit_label) = self.generate_loop_labels();
-
-        let loop_setup = init_code.to_string()
-            + length_ptr_code.as_str()
-            + length_code.as_str()
-            + self.branch_jump_statement(&loop_label).as_str()
-            + &self.block_start(&loop_label)
-            + condition_code.as_str()
-            + &self.branch_choice_statement(&condition_name, &body_label, &loop_exit_label);
-
-        let body_code = self.block_start(&body_label)
-            + &body_result.preamble
-            + &self.branch_jump_statement(&loop_label);
-
-        let exit_code = self.block_start(&loop_exit_label);
-
-        let preamble = loop_setup + &body_code + &exit_code;
-
-        VisitorResult {
-            preamble,
-            result_handle: None,
-        }
+        self.handle_for(iterable_result, node)
     }
     //
     // pub(crate) fn handle_while(
@@ -573,41 +577,23 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
         {
             let field_index = idx.clone();
             // println!("Found member in index'{}'", field_index);
+            let (cast, object_ptr) = self.cast_object_to_type(&object_ptr, &type_name);
+            preamble += &cast;
             let gep_instr = format!(
                 "  {} = getelementptr inbounds %{}_type, %{}_type* {}, i32 0, i32 {}\n",
                 result_var, type_name, type_name, object_ptr, field_index
             );
             preamble += &gep_instr;
 
-            if matches!(llvm_type, LlvmType::F64 | LlvmType::I1) {
-                let load_var = self.generate_tmp_variable();
-                let load_instr = format!(
-                    "  {} = load {}, {}* {}, align 8\n",
-                    load_var,
-                    llvm_type.llvm_type_str(),
-                    llvm_type.llvm_type_str(),
-                    result_var
-                );
-                preamble += &load_instr;
-                return VisitorResult {
-                    preamble,
-                    result_handle: Some(LlvmHandle {
-                        handle_type: HandleType::Register(llvm_type),
-                        llvm_name: load_var,
-                    }),
-                };
-            } else if matches!(llvm_type, LlvmType::Object) {
-                let load_var = self.generate_tmp_variable();
-                let load_instr =
-                    format!("  {} = load i8*, i8** {}, align 8\n", load_var, result_var);
-                preamble += &load_instr;
-                return VisitorResult {
-                    preamble,
-                    result_handle: Some(LlvmHandle {
-                        handle_type: HandleType::Register(LlvmType::Object),
-                        llvm_name: load_var,
-                    }),
-                };
+            let (load, result_handle) = self.extract_variable_value_to_register(
+                self.generate_tmp_variable(),
+                &result_var,
+                &llvm_type,
+            );
+            preamble += &load;
+            VisitorResult {
+                preamble,
+                result_handle: Some(result_handle),
             }
         } else {
             panic!(
@@ -615,14 +601,6 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
                 member_id, type_name
             );
         }
-
-        VisitorResult {
-            preamble,
-            result_handle: Some(LlvmHandle {
-                handle_type: HandleType::Register(llvm_type),
-                llvm_name: result_var,
-            }),
-        }
     }
 
     fn visit_function_member_access(
@@ -646,6 +624,15 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             None => panic!("Object type not found for function member access"),
         };
 
+        if self.protocols.contains_key(&object_ast_type_name) {
+            return self.handle_protocol_method_call(
+                preamble,
+                object_handle,
+                object_ast_type_name,
+                node,
+            );
+        }
+
         let func_name_in_ast = node.member.identifier.id.clone();
         let mut current_type = object_ast_type_name.clone();
         let mut current_object_ptr = object_ptr_name.clone();
@@ -662,9 +649,11 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
         // call_param_llvm_types_for_sig.push("i8*".to_string());
         // call_args_values_with_llvm_types.push(format!("i8* {}", object_ptr_name));
         // Instead, use the correct type for self pointer
+        let (cast, typed_object_ptr) = self.cast_object_to_type(&object_ptr_name, &current_type);
+        preamble += &cast;
         call_param_llvm_types_for_sig.push(format!("%{}_type*", current_type));
         call_args_values_with_llvm_types
-            .push(format!("%{}_type* {}", current_type, object_ptr_name));
+            .push(format!("%{}_type* {}", current_type, typed_object_ptr));
 
         // Look up argument types from the context map
         let mut arg_types: Vec<String> = Vec::new();
@@ -684,9 +673,14 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             let arg_handle = arg_result
                 .result_handle
                 .expect("Function member argument must have a result");
+            let (coercion, value) = self.coerce_value(
+                &arg_handle.llvm_name,
+                &arg_handle.handle_type.inner_type().llvm_type_str(),
+                arg_llvm_type_str,
+            );
+            preamble += &coercion;
             call_param_llvm_types_for_sig.push(arg_llvm_type_str.clone());
-            call_args_values_with_llvm_types
-                .push(format!("{} {}", arg_llvm_type_str, arg_handle.llvm_name));
+            call_args_values_with_llvm_types.push(format!("{} {}", arg_llvm_type_str, value));
         }
 
         let func_signature_ptr_type_for_load = format!(
@@ -731,13 +725,16 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
                     .get(&(current_type.clone(), func_name_in_ast.clone()))
                     .unwrap()
             );
+            // vtable entries are kept as i8*, cast to the signature of the method
+            let loaded_entry = self.generate_tmp_variable();
+            preamble += &format!(
+                "  {} = load i8*, i8** {}, align 8\n",
+                loaded_entry, func_ptr_location_in_vtable
+            );
             let loaded_func_ptr = self.generate_tmp_variable();
             preamble += &format!(
-                "  {} = load {}, {}* {}, align 8\n",
-                loaded_func_ptr,
-                vtable_func_ptr_type,
-                vtable_func_ptr_type,
-                func_ptr_location_in_vtable
+                "  {} = bitcast i8* {} to {}\n",
+                loaded_func_ptr, loaded_entry, vtable_func_ptr_type
             );
 
             let result_reg: String;
@@ -783,9 +780,12 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
     }
 
     fn visit_function_call(&mut self, node: &mut ast::FunctionCall) -> VisitorResult {
-        if node.identifier.id == "print" && node.arguments.len() == 1 {
-            let inner_result = node.arguments[0].accept(self);
-            return self.handle_print(inner_result);
+        if node.closure_type.is_some() {
+            return self.handle_closure_call(node);
+        }
+
+        if let Some(builtin) = find_builtin(&node.identifier.id) {
+            return self.handle_builtin_call(builtin, node);
         }
 
         let mut preamble = String::new();
@@ -799,13 +799,19 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
                 .clone()
         };
 
-        for (i, arg) in node.arguments.iter_mut().enumerate() {
+        for (arg, arg_type) in node.arguments.iter_mut().zip(arg_types.iter()) {
             let arg_result = arg.accept(self);
             preamble += &arg_result.preamble;
             let handle = arg_result
                 .result_handle
                 .expect("Function argument must have a result");
-            arg_values.push(handle.llvm_name);
+            let (coercion, value) = self.coerce_value(
+                &handle.llvm_name,
+                &handle.handle_type.inner_type().llvm_type_str(),
+                arg_type,
+            );
+            preamble += &coercion;
+            arg_values.push(value);
         }
 
         let ret_type = match &node.identifier.info.ty {
@@ -825,12 +831,9 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             result_var, ret_type, node.identifier.id, call_args
         );
 
-        let handle_type = match ret_type.as_str() {
-            "double" => HandleType::Register(LlvmType::F64),
-            "i1" => HandleType::Register(LlvmType::I1),
-            "i8*" => HandleType::Register(LlvmType::String),
-            s if s.ends_with("*") && s.starts_with("%") => HandleType::Register(LlvmType::Object),
-            _ => HandleType::Register(LlvmType::Object),
+        let handle_type = match &node.identifier.info.ty {
+            Some(ty) => HandleType::Register(self.llvm_type_from_ast_type(ty)),
+            None => HandleType::Register(LlvmType::String),
         };
 
         VisitorResult {
@@ -842,6 +845,22 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
         }
     }
 
+    fn visit_lambda(&mut self, node: &mut ast::Lambda) -> VisitorResult {
+        self.handle_lambda(node)
+    }
+
+    fn visit_is_expr(&mut self, node: &mut ast::IsExpr) -> VisitorResult {
+        self.handle_is_expr(node)
+    }
+
+    fn visit_as_expr(&mut self, node: &mut ast::AsExpr) -> VisitorResult {
+        self.handle_as_expr(node)
+    }
+
+    fn visit_base_call(&mut self, node: &mut ast::BaseCall) -> VisitorResult {
+        self.handle_base_call(node)
+    }
+
     fn visit_variable(&mut self, node: &mut ast::Identifier) -> VisitorResult {
         let register_name = self.generate_tmp_variable();
         let variable = self
@@ -915,13 +934,10 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
 
         self.string_constants.push(global_str_code.clone());
 
-        // Create a heap-allocated string using malloc
+        // Create a heap-allocated string, strings hold no pointers
         let local_str_var = self.generate_tmp_variable();
-        let malloc_code = format!(
-            "{} = call i8* @malloc(i64 {})\n",
-            local_str_var,
-            str_len + 1
-        );
+        let malloc_code =
+            self.gc_alloc_statement(&local_str_var, &(str_len + 1).to_string(), "null");
 
         // Get pointer to the global string constant
         let global_ptr_var = self.generate_tmp_variable();
@@ -961,6 +977,8 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             let e_result = element.accept(self);
             preamble += &e_result.preamble;
             if let Some(handle) = e_result.result_handle {
+                let (coercion, handle) = self.coerce_handle(handle, &llvm_elem_type);
+                preamble += &coercion;
                 element_handles.push(handle);
             }
         }
@@ -971,11 +989,16 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
 
         let llvm_elem_type_str = llvm_elem_type.llvm_type_str();
 
-        // Allocate memory for the array using malloc
+        // Allocate memory for the array, traced according to its element type
         let type_size = self.llvm_type_size(&llvm_elem_type_str);
-        let total_size = type_size * list_len + 8;
+        // The length is stored in the first element slot
+        let total_size = type_size * list_len + type_size.max(8);
         let ptr_var = format!("%list_ptr_{}", tmp_var_id);
-        preamble += &format!("{ptr_var} = call i8* @malloc(i64 {})\n", total_size);
+        preamble += &self.gc_alloc_statement(
+            &ptr_var,
+            &total_size.to_string(),
+            &self.gc_list_map(&llvm_elem_type),
+        );
         // Bitcast to the appropriate pointer type
         let array_ptr = format!("%casted_list_ptr_{}", tmp_var_id);
         preamble += &format!(
@@ -1077,7 +1100,6 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             let handle = arg_result
                 .result_handle
                 .expect("Constructor argument must have a result");
-            arg_handles.push(handle.llvm_name);
             // Use the expected type from constructor_args_types if available
             let arg_type = if let Some(ref types) = expected_types {
                 if i < types.len() {
@@ -1088,10 +1110,17 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             } else {
                 "i8*".to_string() // fallback if not found
             };
+            let (coercion, value) = self.coerce_value(
+                &handle.llvm_name,
+                &handle.handle_type.inner_type().llvm_type_str(),
+                &arg_type,
+            );
+            preamble += &coercion;
+            arg_handles.push(value);
             arg_types.push(arg_type);
         }
 
-        let result_var = self.generate_tmp_variable();
+        let typed_result_var = self.generate_tmp_variable();
         let call_args = arg_handles
             .iter()
             .zip(arg_types.iter())
@@ -1100,7 +1129,12 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             .join(", ");
         preamble += &format!(
             "  {} = call %{}_type* @{}_new({})\n",
-            result_var, node.type_name, node.type_name, call_args
+            typed_result_var, node.type_name, node.type_name, call_args
+        );
+        let result_var = self.generate_tmp_variable();
+        preamble += &format!(
+            "  {} = bitcast %{}_type* {} to i8*\n",
+            result_var, node.type_name, typed_result_var
         );
         VisitorResult {
             preamble,
@@ -1134,8 +1168,8 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             None => panic!("Expected list type for list indexing"),
         };
 
-        let elem_type = match list_type {
-            LlvmType::List(inner) => inner.llvm_type_str(),
+        let (elem_type, elem_llvm_type) = match list_type {
+            LlvmType::List(inner) => (inner.llvm_type_str(), *inner),
             _ => panic!("List type not found for data member access"),
         };
 
@@ -1144,7 +1178,8 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
         let tmp_var_id = self.tmp_counter.get();
         self.tmp_counter.set(tmp_var_id + 1);
 
-        let casted_list_ptr = if list_handle.llvm_name.contains("list_ptr") {
+        // lists are pointers to their elements, other values reaching here are opaque pointers
+        let casted_list_ptr = if matches!(list_handle.handle_type.inner_type(), LlvmType::List(_)) {
             list_handle.llvm_name.clone()
         } else {
             let casted_ptr = format!("%list_elem_ptr_{}", tmp_var_id);
@@ -1167,6 +1202,16 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
 
         // Create a new temporary variable for the adjusted index
         let adjusted_index = format!("%adjusted_index_{}", tmp_var_id);
+        if self.runtime_checks {
+            preamble += &self.list_index_checks(
+                &casted_list_ptr,
+                &elem_type,
+                &index_handle.llvm_name,
+                &casted_index,
+                node.open_brace.position(),
+            );
+        }
+
         preamble += &format!("  {} = add i64 {}, 1\n", adjusted_index, casted_index);
 
         preamble += &format!(
@@ -1181,14 +1226,7 @@ impl ExpressionVisitor<VisitorResult> for GeneratorVisitor {
             loaded_val, elem_type, elem_type, elem_ptr
         );
 
-        // Create the appropriate handle type based on the element type
-        let handle_type = match elem_type.as_str() {
-            "double" => HandleType::Register(LlvmType::F64),
-            "i1" => HandleType::Register(LlvmType::I1),
-            "i8*" => HandleType::Register(LlvmType::String),
-            s if s.ends_with("*") && s.starts_with("%") => HandleType::Register(LlvmType::Object),
-            _ => HandleType::Register(LlvmType::F64),
-        };
+        let handle_type = HandleType::Register(elem_llvm_type);
 
         VisitorResult {
             preamble,
@@ -1272,6 +1310,8 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
 
         preamble += ") {\n";
         preamble += "entry:\n";
+        let (gc_save, gc_frame) = self.gc_save();
+        preamble += &gc_save;
 
         let old_context = std::mem::replace(&mut self.context, Context::new_one_frame());
 
@@ -1283,6 +1323,7 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
             let param_ptr = self.generate_tmp_variable();
             preamble += &self.alloca_statement(&param_ptr, &llvm_type);
             preamble += &self.store_statement(&format!("%{}", param.id), &param_ptr, &llvm_type);
+            preamble += &self.gc_root_slot(&param_ptr, &llvm_type);
             match &llvm_type {
                 LlvmType::F64 => self
                     .context
@@ -1300,6 +1341,10 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
                     param.id.clone(),
                     Variable::new_list_with_inner(param_ptr, *inner.clone()),
                 ),
+                LlvmType::Interface(protocol) => self.context.define(
+                    param.id.clone(),
+                    Variable::new_interface(param_ptr, protocol.clone()),
+                ),
             };
         }
 
@@ -1324,9 +1369,17 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
                 //         load_var, result_handle.llvm_name, load_var
                 //     );
                 // } else {
-                preamble += &format!("  ret {} {}\n", return_type, result_handle.llvm_name);
+                let (coercion, value) = self.coerce_value(
+                    &result_handle.llvm_name,
+                    &result_handle.handle_type.inner_type().llvm_type_str(),
+                    &return_type,
+                );
+                preamble += &coercion;
+                preamble += &self.gc_leave_function(&gc_frame, &value, &return_type);
+                preamble += &format!("  ret {} {}\n", return_type, value);
                 // }
             } else {
+                preamble += &self.gc_restore(&gc_frame);
                 match return_type.as_str() {
                     "double" => preamble += "  ret double 0.0\n",
                     "i1" => preamble += "  ret i1 0\n",
@@ -1338,6 +1391,7 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
                 }
             }
         } else {
+            preamble += &self.gc_restore(&gc_frame);
             preamble += "  ret void\n";
         }
 
@@ -1364,8 +1418,8 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
 
         let preamble_code = match ty {
             Some(ref ty) => match self.llvm_type_from_ast_type(ty) {
-                LlvmType::F64 => format!("@{} = global double null, align 8\n\n", constant_name),
-                LlvmType::I1 => format!("@{} = global i1 null, align 1\n\n", constant_name),
+                LlvmType::F64 => format!("@{} = global double 0.0, align 8\n\n", constant_name),
+                LlvmType::I1 => format!("@{} = global i1 false, align 1\n\n", constant_name),
                 LlvmType::String | LlvmType::Object => {
                     format!("@{} = global i8* null, align 8\n\n", constant_name)
                 }
@@ -1374,6 +1428,11 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
                     constant_name,
                     LlvmType::List(inner).llvm_type_str()
                 ),
+                LlvmType::Interface(protocol) => format!(
+                    "@{} = global {} zeroinitializer, align 8\n\n",
+                    constant_name,
+                    LlvmType::Interface(protocol).llvm_type_str()
+                ),
             },
             _ => panic!("Unsupported type"),
         };
@@ -1384,13 +1443,15 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
             self.define_or_shadow(constant_name.clone(), self.llvm_type_from_ast_type(t));
             let type_name = self.llvm_type_str_from_ast_type(t);
             let align_size = self._llvm_type_align(type_name.clone().as_str());
+            let (coercion, init_value) = self.coerce_value(
+                &init_value.llvm_name,
+                &init_value.handle_type.inner_type().llvm_type_str(),
+                &type_name,
+            );
+            preamble += &coercion;
             preamble += format!(
                 "store {} {}, {}* @{},align {}",
-                type_name,
-                init_value.llvm_name.clone(),
-                type_name,
-                constant_name,
-                align_size
+                type_name, init_value, type_name, constant_name, align_size
             )
             .as_str();
         } else {
@@ -1407,34 +1468,9 @@ impl DefinitionVisitor<VisitorResult> for GeneratorVisitor {
     }
 
     fn visit_protocol_def(&mut self, node: &mut ast::ProtocolDef) -> VisitorResult {
-        let mut preamble = String::new();
-        let protocol_name = &node.name.id;
-
-        preamble += &format!("%{}_vtable_type = type {{\n", protocol_name);
-
-        for (i, func_sig) in node.function_signatures.iter().enumerate() {
-            let _method_name = &func_sig.identifier.id;
-
-            preamble += "  i8* (i8*";
-
-            for _ in &func_sig.parameters {
-                preamble += ", double";
-            }
-            preamble += ")*";
-
-            if i < node.function_signatures.len() - 1 {
-                preamble += ",\n";
-            } else {
-                preamble += "\n";
-            }
-        }
-
-        preamble += "}\n\n";
-
-        preamble += &format!("%{}_interface = type {{\n", protocol_name);
-        preamble += "  i8*,                    ; Object pointer\n";
-        preamble += &format!("  %{}_vtable_type*  ; VTable pointer\n", protocol_name);
-        preamble += "}\n\n";
+        let protocol_name = node.name.id.clone();
+        let (types_code, preamble) = self.generate_protocol(&protocol_name);
+        self.general_definitions.push(types_code);
 
         VisitorResult {
             preamble,
@@ -1467,6 +1503,7 @@ impl DefinitionVisitor<VisitorResult> for GlobalDefinitionVisitor {
         // If this type inherits from a parent, copy the parent's vtable entries
         if let Some(inheritance) = &node.inheritance_indicator {
             let parent = &inheritance.parent_name.id;
+            self.inherits.insert(type_name.clone(), parent.clone());
 
             // Check if the parent has any function member definitions
             if self
@@ -1538,6 +1575,10 @@ impl DefinitionVisitor<VisitorResult> for GlobalDefinitionVisitor {
                         // Record the original type for this method definition
                         self.original_type_for_definition
                             .insert((type_name.clone(), method_name.clone()), type_name.clone());
+                        self.function_member_signature_types.insert(
+                            (type_name.clone(), method_name.clone()),
+                            ret_type_str.clone(),
+                        );
                     } else {
                         // If not overridden, copy the parent's vtable entry
                         let original_type_for_def = self
@@ -1806,9 +1847,15 @@ impl GlobalDefinitionVisitor {
                 ast::typing::BuiltInType::String => LlvmType::String,
                 ast::typing::BuiltInType::Object => LlvmType::Object,
             },
+            ast::typing::Type::Defined(type_name) if self.protocols.contains_key(&type_name.id) => {
+                LlvmType::Interface(type_name.id.clone())
+            }
             ast::typing::Type::Defined(_type_name) => LlvmType::Object,
             ast::typing::Type::Iterable(_inner_type_box) => LlvmType::Object,
             ast::typing::Type::Functor(_functor_type) => LlvmType::Object,
+            ast::typing::Type::Generic(..) => {
+                unreachable!("Generic types are monomorphized before the code generation")
+            }
         }
     }
 
@@ -1820,16 +1867,22 @@ impl GlobalDefinitionVisitor {
                 ast::typing::BuiltInType::String => "i8*".to_string(),
                 ast::typing::BuiltInType::Object => "i8*".to_string(),
             },
-            ast::typing::Type::Defined(type_name) => {
-                format!("%{}_type*", type_name.id)
+            ast::typing::Type::Defined(type_name) if self.protocols.contains_key(&type_name.id) => {
+                format!("%{}_interface", type_name.id)
             }
+            // objects are passed around as `i8*`, they are only cast to their
+            // struct type to access their fields and vtable
+            ast::typing::Type::Defined(_type_name) => "i8*".to_string(),
             ast::typing::Type::Iterable(inner_type_box) => {
                 format!(
                     "{}*",
                     self.llvm_type_str_from_ast_type(inner_type_box.as_ref())
                 )
             }
-            _ => panic!("NO implemented type"),
+            ast::typing::Type::Functor(_functor_type) => "i8*".to_string(),
+            ast::typing::Type::Generic(..) => {
+                unreachable!("Generic types are monomorphized before the code generation")
+            }
         }
     }
 
@@ -1841,6 +1894,7 @@ impl GlobalDefinitionVisitor {
             "i1" => 1,
             "i8*" => 8,
             _ if llvm_type.ends_with("*") => 8,
+            _ if llvm_type.ends_with("_interface") => 16,
             _ => 8,
         }
     }
@@ -1868,11 +1922,17 @@ impl GeneratorVisitor {
                 ast::typing::BuiltInType::String => LlvmType::String,
                 ast::typing::BuiltInType::Object => LlvmType::Object,
             },
+            ast::typing::Type::Defined(type_name) if self.protocols.contains_key(&type_name.id) => {
+                LlvmType::Interface(type_name.id.clone())
+            }
             ast::typing::Type::Defined(_type_name) => LlvmType::Object,
             ast::typing::Type::Iterable(inner_type_box) => LlvmType::List(Box::new(
                 self.llvm_type_from_ast_type(inner_type_box.as_ref()),
             )),
             ast::typing::Type::Functor(_functor_type) => LlvmType::Object,
+            ast::typing::Type::Generic(..) => {
+                unreachable!("Generic types are monomorphized before the code generation")
+            }
         }
     }
 
@@ -1884,16 +1944,22 @@ impl GeneratorVisitor {
                 ast::typing::BuiltInType::String => "i8*".to_string(),
                 ast::typing::BuiltInType::Object => "i8*".to_string(),
             },
-            ast::typing::Type::Defined(type_name) => {
-                format!("%{}_type*", type_name.id)
+            ast::typing::Type::Defined(type_name) if self.protocols.contains_key(&type_name.id) => {
+                format!("%{}_interface", type_name.id)
             }
+            // objects are passed around as `i8*`, they are only cast to their
+            // struct type to access their fields and vtable
+            ast::typing::Type::Defined(_type_name) => "i8*".to_string(),
             ast::typing::Type::Iterable(inner_type_box) => {
                 format!(
                     "{}*",
                     self.llvm_type_str_from_ast_type(inner_type_box.as_ref())
                 )
             }
-            _ => panic!("NO implemented type"),
+            ast::typing::Type::Functor(_functor_type) => "i8*".to_string(),
+            ast::typing::Type::Generic(..) => {
+                unreachable!("Generic types are monomorphized before the code generation")
+            }
         }
     }
 
@@ -1905,6 +1971,7 @@ impl GeneratorVisitor {
             "i1" => 1,
             "i8*" => 8,
             _ if llvm_type.ends_with("*") => 8,
+            _ if llvm_type.ends_with("_interface") => 16,
             _ => 8,
         }
     }
@@ -22,7 +22,8 @@ impl GeneratorVisitor {
                 &result_handle.llvm_name,
                 &var_llvm_name,
                 &result_handle.handle_type.inner_type(),
-            );
+            )
+            + &self.gc_root_slot(&var_llvm_name, &result_handle.handle_type.inner_type());
 
         VisitorResult {
             preamble,
@@ -50,10 +51,10 @@ impl GeneratorVisitor {
         }
         self.variable_ids.insert(name.clone(), id + 1);
         let mut llvm_name = format!("%{}.{}", name, id);
-        if(self.is_global){
-            llvm_name= format!("@{}", name);;
+        if (self.is_global) {
+            llvm_name = format!("@{}", name);
         }
-        
+
         match handle_type {
             LlvmType::F64 => {
                 self.context
@@ -72,8 +73,14 @@ impl GeneratorVisitor {
                     .define(name, Variable::new_object(llvm_name.clone()));
             }
             LlvmType::List(inner) => {
+                self.context.define(
+                    name,
+                    Variable::new_list_with_inner(llvm_name.clone(), *inner),
+                );
+            }
+            LlvmType::Interface(protocol) => {
                 self.context
-                    .define(name, Variable::new_list_with_inner(llvm_name.clone(), *inner));
+                    .define(name, Variable::new_interface(llvm_name.clone(), protocol));
             }
         }
 
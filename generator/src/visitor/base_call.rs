@@ -0,0 +1,104 @@
+use ast::{BaseCall, ExpressionVisitor, VisitableExpression};
+
+use super::{GeneratorVisitor, VisitorResult};
+use crate::llvm_types::{HandleType, LlvmHandle};
+
+impl GeneratorVisitor {
+    /// # Description
+    ///
+    /// Generates `base(arguments)`, a direct call to the implementation the
+    /// parent type has of the current method. Unlike method calls it does not
+    /// go through the vtable, the function is the one of the type that defines
+    /// the implementation, `@Definer_method`, and receives `self` cast to it
+    pub(crate) fn handle_base_call(&mut self, node: &mut BaseCall) -> VisitorResult {
+        let method = node
+            .method
+            .clone()
+            .expect("Base call must have a method, SA should have caught this");
+        let parent_type = node
+            .parent_type
+            .as_ref()
+            .expect("Base call must have a parent type, SA should have caught this")
+            .to_string();
+        let definer = self
+            .original_type_for_definition
+            .get(&(parent_type.clone(), method.clone()))
+            .unwrap_or_else(|| panic!("Method {} not found in type {}", method, parent_type))
+            .clone();
+
+        let self_result = self.visit_variable(&mut node.receiver);
+        let mut preamble = self_result.preamble;
+        let self_ptr = self_result
+            .result_handle
+            .expect("self must have a result")
+            .llvm_name;
+        let typed_self = self.generate_tmp_variable();
+        preamble += &format!(
+            "  {} = bitcast i8* {} to %{}_type*\n",
+            typed_self, self_ptr, definer
+        );
+        let mut call_args = vec![format!("%{}_type* {}", definer, typed_self)];
+
+        let arg_types = self
+            .function_member_def_from_type_and_name
+            .iter()
+            .find(|((type_name, method_name, _), _)| {
+                type_name == &definer && method_name == &method
+            })
+            .map(|(_, arg_types)| arg_types.clone())
+            .unwrap_or_default();
+        for (argument, arg_type) in node.arguments.iter_mut().zip(arg_types.iter()) {
+            let arg_result = argument.accept(self);
+            preamble += &arg_result.preamble;
+            let arg_handle = arg_result
+                .result_handle
+                .expect("Base call argument must have a result");
+            let (coercion, value) = self.coerce_value(
+                &arg_handle.llvm_name,
+                &arg_handle.handle_type.inner_type().llvm_type_str(),
+                arg_type,
+            );
+            preamble += &coercion;
+            call_args.push(format!("{} {}", arg_type, value));
+        }
+
+        let ret_type = self
+            .function_member_signature_types
+            .get(&(definer.clone(), method.clone()))
+            .cloned()
+            .unwrap_or_else(|| "void".to_string());
+        if ret_type == "void" {
+            preamble += &format!(
+                "  call void @{}_{}({})\n",
+                definer,
+                method,
+                call_args.join(", ")
+            );
+            return VisitorResult {
+                preamble,
+                result_handle: None,
+            };
+        }
+
+        let result = self.generate_tmp_variable();
+        preamble += &format!(
+            "  {} = call {} @{}_{}({})\n",
+            result,
+            ret_type,
+            definer,
+            method,
+            call_args.join(", ")
+        );
+        let ty = node
+            .ty
+            .as_ref()
+            .expect("Return type must be known for non-void");
+        VisitorResult {
+            preamble,
+            result_handle: Some(LlvmHandle {
+                handle_type: HandleType::Register(self.llvm_type_from_ast_type(ty)),
+                llvm_name: result,
+            }),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use ast::BinaryOperator;
 use ast::BinaryOperator::*;
+use ast::tokens::token_position::TokenPositionTrait;
 
 use crate::llvm_types::{HandleType, LlvmHandle, LlvmType};
 
@@ -51,7 +52,11 @@ impl GeneratorVisitor {
         let rhs_handle = rhs.result_handle.unwrap();
         let lhs_handle = lhs.result_handle.unwrap();
 
-        let preamble = lhs.preamble + &rhs.preamble;
+        let mut preamble = lhs.preamble + &rhs.preamble;
+
+        if self.runtime_checks && matches!(op, Divide(_) | FloorDivide(_) | Modulo(_)) {
+            preamble += &self.division_check(&rhs_handle.llvm_name, op.position());
+        }
 
         let result_handle = self.generate_tmp_variable();
 
@@ -72,8 +77,16 @@ impl GeneratorVisitor {
                 "{} = fdiv double {}, {}",
                 result_handle, lhs_handle.llvm_name, rhs_handle.llvm_name
             ),
-            FloorDivide(_) => todo!(),
-            Modulo(_) => todo!(),
+            FloorDivide(_) => {
+                self.floor_divide_code(&result_handle, &lhs_handle.llvm_name, &rhs_handle.llvm_name)
+            }
+            Modulo(_) => {
+                self.modulo_code(&result_handle, &lhs_handle.llvm_name, &rhs_handle.llvm_name)
+            }
+            Power(_) => format!(
+                "{} = call double @llvm.pow.f64(double {}, double {})",
+                result_handle, lhs_handle.llvm_name, rhs_handle.llvm_name
+            ),
             Equal(_) => panic!("= found in non-assignment, parser problem"),
             ColonEqual(_) => panic!(":= found in non-destructive assignment, parser problem"),
             EqualEqual(_) => format!(
@@ -103,20 +116,19 @@ impl GeneratorVisitor {
             _ => panic!("Unsupported numeric operator"),
         } + "\n";
 
-        let result_handle = match op {
-            EqualEqual(_) | NotEqual(_) | Less(_) | LessEqual(_) | Greater(_) | GreaterEqual(_) => {
-                Some(LlvmHandle::new_i1_register(result_handle))
-            }
+        let result_handle =
+            match op {
+                EqualEqual(_) | NotEqual(_) | Less(_) | LessEqual(_) | Greater(_)
+                | GreaterEqual(_) => Some(LlvmHandle::new_i1_register(result_handle)),
 
-            Plus(_) | Minus(_) | Times(_) | Divide(_) | FloorDivide(_) | Modulo(_) => {
-                Some(LlvmHandle::new_f64_register(result_handle))
-            }
+                Plus(_) | Minus(_) | Times(_) | Divide(_) | FloorDivide(_) | Modulo(_)
+                | Power(_) => Some(LlvmHandle::new_f64_register(result_handle)),
 
-            Equal(_) => panic!("= found in non-assignment, parser problem"),
-            ColonEqual(_) => panic!(":= found in non-destructive assignment, parser problem"),
+                Equal(_) => panic!("= found in non-assignment, parser problem"),
+                ColonEqual(_) => panic!(":= found in non-destructive assignment, parser problem"),
 
-            _ => panic!("Unsupported numeric operator"),
-        };
+                _ => panic!("Unsupported numeric operator"),
+            };
 
         VisitorResult {
             preamble: preamble + &operation,
@@ -124,6 +136,47 @@ impl GeneratorVisitor {
         }
     }
 
+    /// # Description
+    ///
+    /// Generates `lhs div rhs`, the quotient rounded towards negative infinity,
+    /// so that `-7 div 2` is `-4`. Without runtime checks, division by zero
+    /// follows `/`, giving an infinity, or NaN for `0 div 0`
+    fn floor_divide_code(&self, result_handle: &str, lhs: &str, rhs: &str) -> String {
+        let quotient = self.generate_tmp_variable();
+
+        format!("{quotient} = fdiv double {lhs}, {rhs}\n")
+            + &format!("{result_handle} = call double @llvm.floor.f64(double {quotient})")
+    }
+
+    /// # Description
+    ///
+    /// Generates `lhs % rhs`, the remainder of the floor division, it has the
+    /// sign of `rhs`, so that `-7 % 2` is `1` and `7 % -2` is `-1`. Without runtime
+    /// checks, `x % 0` is NaN.
+    ///
+    /// `frem` truncates, so its result is shifted by `rhs` when it is not zero
+    /// and its sign differs from the sign of `rhs`
+    fn modulo_code(&self, result_handle: &str, lhs: &str, rhs: &str) -> String {
+        let remainder = self.generate_tmp_variable();
+        let is_not_zero = self.generate_tmp_variable();
+        let is_negative = self.generate_tmp_variable();
+        let rhs_is_negative = self.generate_tmp_variable();
+        let signs_differ = self.generate_tmp_variable();
+        let needs_shift = self.generate_tmp_variable();
+        let shifted = self.generate_tmp_variable();
+
+        format!("{remainder} = frem double {lhs}, {rhs}\n")
+            + &format!("{is_not_zero} = fcmp one double {remainder}, 0.0\n")
+            + &format!("{is_negative} = fcmp olt double {remainder}, 0.0\n")
+            + &format!("{rhs_is_negative} = fcmp olt double {rhs}, 0.0\n")
+            + &format!("{signs_differ} = xor i1 {is_negative}, {rhs_is_negative}\n")
+            + &format!("{needs_shift} = and i1 {is_not_zero}, {signs_differ}\n")
+            + &format!("{shifted} = fadd double {remainder}, {rhs}\n")
+            + &format!(
+                "{result_handle} = select i1 {needs_shift}, double {shifted}, double {remainder}"
+            )
+    }
+
     fn get_boolean_bin_op_visitor_result(
         &mut self,
         op: &ast::BinaryOperator,
@@ -186,8 +239,8 @@ impl GeneratorVisitor {
                         let false_ptr = self.generate_tmp_variable();
                         let str_ptr = self.generate_tmp_variable();
                         let code = format!(
-                            "{true_ptr} = getelementptr [6 x i8], [6 x i8]* @.true_str, i32 0, i32 0\n\
-                             {false_ptr} = getelementptr [7 x i8], [7 x i8]* @.false_str, i32 0, i32 0\n\
+                            "{true_ptr} = getelementptr [5 x i8], [5 x i8]* @.true_str, i32 0, i32 0\n\
+                             {false_ptr} = getelementptr [6 x i8], [6 x i8]* @.false_str, i32 0, i32 0\n\
                              {str_ptr} = select i1 {cond}, i8* {true_ptr}, i8* {false_ptr}\n",
                             true_ptr = true_ptr,
                             false_ptr = false_ptr,
@@ -202,15 +255,17 @@ impl GeneratorVisitor {
                         let sn_ret = self.generate_tmp_variable();
                         let str_len = self.generate_tmp_variable();
                         let str_len_null = self.generate_tmp_variable();
+                        let str_size = self.generate_tmp_variable();
                         let result_buffer = self.generate_tmp_variable();
                         let code = format!(
                             "{num_buffer} = alloca [32 x i8], align 1\n\
                              {buffer_cast} = bitcast [32 x i8]* {num_buffer} to i8*\n\
                              {sn_ret} = call i32 (i8*, i8*, ...) @sprintf(i8* {buffer_cast}, i8* getelementptr inbounds ([3 x i8], [3 x i8]* @.fstr2, i32 0, i32 0), double {val})\n\
-                             {str_len} = call i64 @strlen(i8* {buffer_cast})\n\
-                             {str_len_null} = add i64 {str_len}, 1\n\
-                             {result_buffer} = call i8* @malloc(i64 {str_len_null})\n\
-                             call void @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
+                             {str_len} = call i32 @strlen(i8* {buffer_cast})\n\
+                             {str_len_null} = add i32 {str_len}, 1\n\
+                             {str_size} = zext i32 {str_len_null} to i64\n\
+                             {result_buffer} = call i8* @hulk_gc_alloc(i64 {str_size}, i64* null)\n\
+                             call i8* @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
                             num_buffer = num_buffer,
                             buffer_cast = buffer_cast,
                             sn_ret = sn_ret,
@@ -234,8 +289,8 @@ impl GeneratorVisitor {
                         let false_ptr = self.generate_tmp_variable();
                         let str_ptr = self.generate_tmp_variable();
                         let code = format!(
-                            "{true_ptr} = getelementptr [6 x i8], [6 x i8]* @.true_str, i32 0, i32 0\n\
-                             {false_ptr} = getelementptr [7 x i8], [7 x i8]* @.false_str, i32 0, i32 0\n\
+                            "{true_ptr} = getelementptr [5 x i8], [5 x i8]* @.true_str, i32 0, i32 0\n\
+                             {false_ptr} = getelementptr [6 x i8], [6 x i8]* @.false_str, i32 0, i32 0\n\
                              {str_ptr} = select i1 {cond}, i8* {true_ptr}, i8* {false_ptr}\n",
                             true_ptr = true_ptr,
                             false_ptr = false_ptr,
@@ -250,15 +305,17 @@ impl GeneratorVisitor {
                         let sn_ret = self.generate_tmp_variable();
                         let str_len = self.generate_tmp_variable();
                         let str_len_null = self.generate_tmp_variable();
+                        let str_size = self.generate_tmp_variable();
                         let result_buffer = self.generate_tmp_variable();
                         let code = format!(
                             "{num_buffer} = alloca [32 x i8], align 1\n\
                              {buffer_cast} = bitcast [32 x i8]* {num_buffer} to i8*\n\
                              {sn_ret} = call i32 (i8*, i8*, ...) @sprintf(i8* {buffer_cast}, i8* getelementptr inbounds ([3 x i8], [3 x i8]* @.fstr2, i32 0, i32 0), double {val})\n\
-                             {str_len} = call i64 @strlen(i8* {buffer_cast})\n\
-                             {str_len_null} = add i64 {str_len}, 1\n\
-                             {result_buffer} = call i8* @malloc(i64 {str_len_null})\n\
-                             call void @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
+                             {str_len} = call i32 @strlen(i8* {buffer_cast})\n\
+                             {str_len_null} = add i32 {str_len}, 1\n\
+                             {str_size} = zext i32 {str_len_null} to i64\n\
+                             {result_buffer} = call i8* @hulk_gc_alloc(i64 {str_size}, i64* null)\n\
+                             call i8* @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
                             num_buffer = num_buffer,
                             buffer_cast = buffer_cast,
                             sn_ret = sn_ret,
@@ -276,6 +333,7 @@ impl GeneratorVisitor {
                 let len2 = self.generate_tmp_variable();
                 let total = self.generate_tmp_variable();
                 let total_plus_one = self.generate_tmp_variable();
+                let total_size = self.generate_tmp_variable();
                 let result_ptr = self.generate_tmp_variable();
                 lhs_conv
                     + &rhs_conv
@@ -285,7 +343,8 @@ impl GeneratorVisitor {
                      {len2} = call i32 @strlen(i8* {rhs})\n\
                      {total} = add i32 {len1}, {len2}\n\
                      {total_plus_one} = add i32 {total}, 1\n\
-                     {result_ptr} = call i8* @malloc(i32 {total_plus_one})\n\
+                     {total_size} = zext i32 {total_plus_one} to i64\n\
+                     {result_ptr} = call i8* @hulk_gc_alloc(i64 {total_size}, i64* null)\n\
                      call i8* @strcpy(i8* {result_ptr}, i8* {lhs})\n\
                      {res} = call i8* @strcat(i8* {result_ptr}, i8* {rhs})\n",
                         len1 = len1,
@@ -312,8 +371,8 @@ impl GeneratorVisitor {
                         let false_ptr = self.generate_tmp_variable();
                         let str_ptr = self.generate_tmp_variable();
                         let code = format!(
-                            "{true_ptr} = getelementptr [6 x i8], [6 x i8]* @.true_str, i32 0, i32 0\n\
-                             {false_ptr} = getelementptr [7 x i8], [7 x i8]* @.false_str, i32 0, i32 0\n\
+                            "{true_ptr} = getelementptr [5 x i8], [5 x i8]* @.true_str, i32 0, i32 0\n\
+                             {false_ptr} = getelementptr [6 x i8], [6 x i8]* @.false_str, i32 0, i32 0\n\
                              {str_ptr} = select i1 {cond}, i8* {true_ptr}, i8* {false_ptr}\n",
                             true_ptr = true_ptr,
                             false_ptr = false_ptr,
@@ -328,15 +387,17 @@ impl GeneratorVisitor {
                         let sn_ret = self.generate_tmp_variable();
                         let str_len = self.generate_tmp_variable();
                         let str_len_null = self.generate_tmp_variable();
+                        let str_size = self.generate_tmp_variable();
                         let result_buffer = self.generate_tmp_variable();
                         let code = format!(
                             "{num_buffer} = alloca [32 x i8], align 1\n\
                              {buffer_cast} = bitcast [32 x i8]* {num_buffer} to i8*\n\
                              {sn_ret} = call i32 (i8*, i8*, ...) @sprintf(i8* {buffer_cast}, i8* getelementptr inbounds ([3 x i8], [3 x i8]* @.fstr2, i32 0, i32 0), double {val})\n\
-                             {str_len} = call i64 @strlen(i8* {buffer_cast})\n\
-                             {str_len_null} = add i64 {str_len}, 1\n\
-                             {result_buffer} = call i8* @malloc(i64 {str_len_null})\n\
-                             call void @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
+                             {str_len} = call i32 @strlen(i8* {buffer_cast})\n\
+                             {str_len_null} = add i32 {str_len}, 1\n\
+                             {str_size} = zext i32 {str_len_null} to i64\n\
+                             {result_buffer} = call i8* @hulk_gc_alloc(i64 {str_size}, i64* null)\n\
+                             call i8* @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
                             num_buffer = num_buffer,
                             buffer_cast = buffer_cast,
                             sn_ret = sn_ret,
@@ -360,8 +421,8 @@ impl GeneratorVisitor {
                         let false_ptr = self.generate_tmp_variable();
                         let str_ptr = self.generate_tmp_variable();
                         let code = format!(
-                            "{true_ptr} = getelementptr [6 x i8], [6 x i8]* @.true_str, i32 0, i32 0\n\
-                             {false_ptr} = getelementptr [7 x i8], [7 x i8]* @.false_str, i32 0, i32 0\n\
+                            "{true_ptr} = getelementptr [5 x i8], [5 x i8]* @.true_str, i32 0, i32 0\n\
+                             {false_ptr} = getelementptr [6 x i8], [6 x i8]* @.false_str, i32 0, i32 0\n\
                              {str_ptr} = select i1 {cond}, i8* {true_ptr}, i8* {false_ptr}\n",
                             true_ptr = true_ptr,
                             false_ptr = false_ptr,
@@ -376,15 +437,17 @@ impl GeneratorVisitor {
                         let sn_ret = self.generate_tmp_variable();
                         let str_len = self.generate_tmp_variable();
                         let str_len_null = self.generate_tmp_variable();
+                        let str_size = self.generate_tmp_variable();
                         let result_buffer = self.generate_tmp_variable();
                         let code = format!(
                             "{num_buffer} = alloca [32 x i8], align 1\n\
                              {buffer_cast} = bitcast [32 x i8]* {num_buffer} to i8*\n\
                              {sn_ret} = call i32 (i8*, i8*, ...) @sprintf(i8* {buffer_cast}, i8* getelementptr inbounds ([3 x i8], [3 x i8]* @.fstr2, i32 0, i32 0), double {val})\n\
-                             {str_len} = call i64 @strlen(i8* {buffer_cast})\n\
-                             {str_len_null} = add i64 {str_len}, 1\n\
-                             {result_buffer} = call i8* @malloc(i64 {str_len_null})\n\
-                             call void @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
+                             {str_len} = call i32 @strlen(i8* {buffer_cast})\n\
+                             {str_len_null} = add i32 {str_len}, 1\n\
+                             {str_size} = zext i32 {str_len_null} to i64\n\
+                             {result_buffer} = call i8* @hulk_gc_alloc(i64 {str_size}, i64* null)\n\
+                             call i8* @strcpy(i8* {result_buffer}, i8* {buffer_cast})\n",
                             num_buffer = num_buffer,
                             buffer_cast = buffer_cast,
                             sn_ret = sn_ret,
@@ -402,6 +465,7 @@ impl GeneratorVisitor {
                 let len2 = self.generate_tmp_variable();
                 let total = self.generate_tmp_variable();
                 let total_plus_one = self.generate_tmp_variable();
+                let total_size = self.generate_tmp_variable();
                 let result_ptr = self.generate_tmp_variable();
                 let space_ptr = self.generate_tmp_variable();
                 lhs_conv
@@ -412,7 +476,8 @@ impl GeneratorVisitor {
                      {len2} = call i32 @strlen(i8* {rhs})\n\
                      {total} = add i32 {len1}, {len2}\n\
                      {total_plus_one} = add i32 {total}, 1\n\
-                     {result_ptr} = call i8* @malloc(i32 {total_plus_one})\n\
+                     {total_size} = zext i32 {total_plus_one} to i64\n\
+                     {result_ptr} = call i8* @hulk_gc_alloc(i64 {total_size}, i64* null)\n\
                      call i8* @strcpy(i8* {result_ptr}, i8* {lhs})\n\
                      {space_ptr} = getelementptr [2 x i8], [2 x i8]* @.space_str, i32 0, i32 0\n\
                      call i8* @strcat(i8* {result_ptr}, i8* {space_ptr})\n\
@@ -11,9 +11,18 @@ impl GeneratorVisitor {
         let mut preamble = "".to_string();
         let mut result_handle = None;
 
-        for exp in body_items {
+        let item_count = body_items.len();
+        for (i, exp) in body_items.iter_mut().enumerate() {
             let result = exp.accept(self);
-            preamble = preamble + "\n" + &result.preamble;
+            // the values of the items before the last are discarded, and so
+            // are the temporaries they registered as roots
+            let discarded = i + 1 < item_count || multiple_semicolon_terminated;
+            let item_code = if discarded {
+                self.gc_scoped(result.preamble)
+            } else {
+                result.preamble
+            };
+            preamble = preamble + "\n" + &item_code;
 
             result_handle = result.result_handle;
         }
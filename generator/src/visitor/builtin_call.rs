@@ -0,0 +1,174 @@
+use ast::VisitableExpression;
+use ast::typing::{BuiltInType, Type};
+
+use super::{GeneratorVisitor, VisitorResult};
+use crate::builtins::{BUILTINS, Builtin, BuiltinLowering};
+use crate::llvm_types::{HandleType, LlvmHandle, LlvmType};
+
+impl GeneratorVisitor {
+    /// # Description
+    ///
+    /// Declares the LLVM intrinsics used by the builtins and defines the runtime
+    /// helpers backing the others. The helpers returning strings allocate them
+    /// with `@hulk_gc_alloc`, so the collector runtime must be emitted as well.
+    pub fn instantiate_builtin_runtime(&self) -> String {
+        let mut runtime = String::new();
+
+        for builtin in BUILTINS {
+            let BuiltinLowering::Call(symbol) = builtin.lowering else {
+                continue;
+            };
+            if !symbol.starts_with("llvm.") {
+                continue;
+            }
+
+            let parameters = builtin
+                .parameters
+                .iter()
+                .map(|param| self.builtin_llvm_type(param).llvm_type_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            runtime += &format!(
+                "declare {} @{}({})\n",
+                self.builtin_llvm_type(&builtin.return_type).llvm_type_str(),
+                symbol,
+                parameters
+            );
+        }
+
+        runtime
+            + r#"
+declare i32 @rand()
+declare double @strtod(i8*, i8**)
+declare i64 @getline(i8**, i64*, i8*)
+declare void @llvm.memcpy.p0i8.p0i8.i64(i8*, i8*, i64, i1)
+@stdin = external global i8*
+
+; uniform in [0, 1)
+define double @hulk_rand() {
+entry:
+  %value = call i32 @rand()
+  %as_double = sitofp i32 %value to double
+  %answ = fdiv double %as_double, 0x41E0000000000000
+  ret double %answ
+}
+
+define double @hulk_length(i8* %s) {
+entry:
+  %len = call i32 @strlen(i8* %s)
+  %answ = uitofp i32 %len to double
+  ret double %answ
+}
+
+; the longest prefix of %s that is a number, 0 when there is none
+define double @hulk_parse(i8* %s) {
+entry:
+  %answ = call double @strtod(i8* %s, i8** null)
+  ret double %answ
+}
+
+; %count characters of %s from %start, both clamped to the bounds of %s
+define i8* @hulk_substring(i8* %s, double %start, double %count) {
+entry:
+  %len32 = call i32 @strlen(i8* %s)
+  %len = zext i32 %len32 to i64
+  %start_int = fptosi double %start to i64
+  %start_negative = icmp slt i64 %start_int, 0
+  %start_low = select i1 %start_negative, i64 0, i64 %start_int
+  %start_past_end = icmp sgt i64 %start_low, %len
+  %first = select i1 %start_past_end, i64 %len, i64 %start_low
+  %available = sub i64 %len, %first
+  %count_int = fptosi double %count to i64
+  %count_negative = icmp slt i64 %count_int, 0
+  %count_low = select i1 %count_negative, i64 0, i64 %count_int
+  %count_past_end = icmp sgt i64 %count_low, %available
+  %size = select i1 %count_past_end, i64 %available, i64 %count_low
+  %alloc_size = add i64 %size, 1
+  %answ = call i8* @hulk_gc_alloc(i64 %alloc_size, i64* null)
+  %from = getelementptr inbounds i8, i8* %s, i64 %first
+  call void @llvm.memcpy.p0i8.p0i8.i64(i8* %answ, i8* %from, i64 %size, i1 false)
+  ret i8* %answ
+}
+
+; the next line of the standard input without its newline, empty at the end of the input
+define i8* @hulk_read_line() {
+entry:
+  %buffer = alloca i8*, align 8
+  %capacity = alloca i64, align 8
+  store i8* null, i8** %buffer, align 8
+  store i64 0, i64* %capacity, align 8
+  %stream = load i8*, i8** @stdin, align 8
+  %read = call i64 @getline(i8** %buffer, i64* %capacity, i8* %stream)
+  %data = load i8*, i8** %buffer, align 8
+  %failed = icmp slt i64 %read, 1
+  br i1 %failed, label %end_of_input, label %copy
+end_of_input:
+  call void @free(i8* %data)
+  %empty = call i8* @hulk_gc_alloc(i64 1, i64* null)
+  ret i8* %empty
+copy:
+  %last_index = sub i64 %read, 1
+  %last_ptr = getelementptr inbounds i8, i8* %data, i64 %last_index
+  %last = load i8, i8* %last_ptr, align 1
+  %is_newline = icmp eq i8 %last, 10
+  %line_len = select i1 %is_newline, i64 %last_index, i64 %read
+  %alloc_size = add i64 %line_len, 1
+  %answ = call i8* @hulk_gc_alloc(i64 %alloc_size, i64* null)
+  call void @llvm.memcpy.p0i8.p0i8.i64(i8* %answ, i8* %data, i64 %line_len, i1 false)
+  call void @free(i8* %data)
+  ret i8* %answ
+}
+"#
+    }
+
+    pub(crate) fn handle_builtin_call(
+        &mut self,
+        builtin: &Builtin,
+        node: &mut ast::FunctionCall,
+    ) -> VisitorResult {
+        let symbol = match builtin.lowering {
+            BuiltinLowering::Print => {
+                let inner_result = node.arguments[0].accept(self);
+                return self.handle_print(inner_result);
+            }
+            BuiltinLowering::Call(symbol) => symbol,
+        };
+
+        let mut preamble = String::new();
+        let mut call_args = Vec::new();
+        for (arg, param) in node.arguments.iter_mut().zip(builtin.parameters) {
+            let arg_result = arg.accept(self);
+            preamble += &arg_result.preamble;
+            let handle = arg_result
+                .result_handle
+                .expect("Builtin argument must have a result");
+            call_args.push(format!(
+                "{} {}",
+                self.builtin_llvm_type(param).llvm_type_str(),
+                handle.llvm_name
+            ));
+        }
+
+        let return_type = self.builtin_llvm_type(&builtin.return_type);
+        let result_var = self.generate_tmp_variable();
+        preamble += &format!(
+            "  {} = call {} @{}({})\n",
+            result_var,
+            return_type.llvm_type_str(),
+            symbol,
+            call_args.join(", ")
+        );
+
+        VisitorResult {
+            preamble,
+            result_handle: Some(LlvmHandle {
+                handle_type: HandleType::Register(return_type),
+                llvm_name: result_var,
+            }),
+        }
+    }
+
+    fn builtin_llvm_type(&self, ty: &BuiltInType) -> LlvmType {
+        self.llvm_type_from_ast_type(&Type::BuiltIn(ty.clone()))
+    }
+}
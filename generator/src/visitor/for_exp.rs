@@ -1,13 +1,220 @@
-use super::{GeneratorVisitor, VisitorResult};
-use ast;
+use super::{GeneratorVisitor, VisitorResult, gc::is_gc_pointer_type_str};
+use crate::llvm_types::{LlvmHandle, LlvmType};
+use ast::{self, VisitableExpression, typing::Type};
 
 impl GeneratorVisitor {
-    pub(crate) fn _handle_for(
+    /// # Description
+    ///
+    /// Generates a for loop, `node.iterable_type` (set by SA) tells whether we
+    /// iterate over a list or over an object implementing the iterator
+    /// protocol, i.e. with `next(): Boolean` and `current()` methods.
+    ///
+    /// The element variable is defined in a new context for the body, with the
+    /// type SA annotated it with.
+    pub(crate) fn handle_for(
         &mut self,
-        _element_name: &str,
-        _iterable_result: VisitorResult,
-        _node: &mut ast::For,
+        iterable_result: VisitorResult,
+        node: &mut ast::For,
     ) -> VisitorResult {
-        todo!()
+        let iterable_handle = iterable_result
+            .result_handle
+            .expect("Expected a result handle for iterable expression of for loop");
+
+        let element_type = match &node.element.info.ty {
+            Some(ty) => self.llvm_type_from_ast_type(ty),
+            None => panic!("Element of for loop must have a type, SA should have caught this"),
+        };
+
+        self.context.push_open_frame();
+        let element_var = self.define_or_shadow(node.element.id.clone(), element_type.clone());
+        let mut element_alloca = self.alloca_statement(&element_var, &element_type);
+        if is_gc_pointer_type_str(&element_type.llvm_type_str()) {
+            // the element is a root for the whole loop, it must not hold garbage
+            // before the first iteration stores into it
+            element_alloca += &self.store_statement("zeroinitializer", &element_var, &element_type);
+            element_alloca += &self.gc_root_slot(&element_var, &element_type);
+        }
+        // the roots registered by an iteration are dropped when it ends
+        let (gc_save, gc_frame) = self.gc_save();
+        element_alloca += &gc_save;
+
+        let loop_code = match node.iterable_type.clone() {
+            Some(Type::Iterable(_)) => self.list_for_loop(
+                &iterable_handle,
+                &element_var,
+                &element_type,
+                &gc_frame,
+                node,
+            ),
+            Some(Type::Defined(type_name)) => self.iterator_for_loop(
+                &iterable_handle,
+                &type_name.id,
+                &element_var,
+                &element_type,
+                &gc_frame,
+                node,
+            ),
+            _ => panic!(
+                "For loop iterable must be a list or an iterator, SA should have caught this"
+            ),
+        };
+
+        self.context.pop_frame();
+
+        VisitorResult {
+            preamble: iterable_result.preamble + &element_alloca + &loop_code,
+            result_handle: None,
+        }
+    }
+
+    /// # Description
+    ///
+    /// Walks the list by index, the length is stored as an i64 before the
+    /// first element (see `visit_list_literal`)
+    fn list_for_loop(
+        &mut self,
+        list_handle: &LlvmHandle,
+        element_var: &str,
+        element_type: &LlvmType,
+        gc_frame: &str,
+        node: &mut ast::For,
+    ) -> String {
+        let element_type_str = element_type.llvm_type_str();
+        let list_ptr = &list_handle.llvm_name;
+
+        let length_ptr = self.generate_tmp_variable();
+        let length = self.generate_tmp_variable();
+        let index_var = self.generate_tmp_variable();
+
+        let setup = format!("{length_ptr} = bitcast {element_type_str}* {list_ptr} to i64*\n")
+            + &format!("{length} = load i64, i64* {length_ptr}, align 8\n")
+            + &format!("{index_var} = alloca i64, align 8\n")
+            + &format!("store i64 0, i64* {index_var}, align 8\n");
+
+        let (loop_label, body_label, loop_exit_label) = self.generate_loop_labels();
+
+        let index = self.generate_tmp_variable();
+        let condition = self.generate_tmp_variable();
+        let condition_code = format!("{index} = load i64, i64* {index_var}, align 8\n")
+            + &format!("{condition} = icmp slt i64 {index}, {length}\n");
+
+        let next_index = self.generate_tmp_variable();
+        let element_ptr = self.generate_tmp_variable();
+        let element = self.generate_tmp_variable();
+        let element_code = format!("{next_index} = add i64 {index}, 1\n")
+            + &format!(
+                "{element_ptr} = getelementptr inbounds {element_type_str}, {element_type_str}* {list_ptr}, i64 {next_index}\n"
+            )
+            + &self.load_statement(&element_ptr, &element, element_type)
+            + &self.store_statement(&element, element_var, element_type)
+            + &format!("store i64 {next_index}, i64* {index_var}, align 8\n");
+
+        let body_result = node.body.accept(self);
+
+        setup
+            + &self.branch_jump_statement(&loop_label)
+            + &self.block_start(&loop_label)
+            + &condition_code
+            + &self.branch_choice_statement(&condition, &body_label, &loop_exit_label)
+            + &self.block_start(&body_label)
+            + &element_code
+            + &body_result.preamble
+            + &self.gc_restore(gc_frame)
+            + &self.branch_jump_statement(&loop_label)
+            + &self.block_start(&loop_exit_label)
+            + &self.gc_restore(gc_frame)
+    }
+
+    /// # Description
+    ///
+    /// Calls `next` on the iterator before each iteration, and stores the
+    /// result of `current` in the element variable if it returned true
+    fn iterator_for_loop(
+        &mut self,
+        iterator_handle: &LlvmHandle,
+        type_name: &str,
+        element_var: &str,
+        element_type: &LlvmType,
+        gc_frame: &str,
+        node: &mut ast::For,
+    ) -> String {
+        let (loop_label, body_label, loop_exit_label) = self.generate_loop_labels();
+
+        let (next_code, condition) =
+            self.call_method_without_arguments(&iterator_handle.llvm_name, type_name, "next", "i1");
+        let (current_code, element) = self.call_method_without_arguments(
+            &iterator_handle.llvm_name,
+            type_name,
+            "current",
+            &element_type.llvm_type_str(),
+        );
+
+        let body_result = node.body.accept(self);
+
+        self.branch_jump_statement(&loop_label)
+            + &self.block_start(&loop_label)
+            + &next_code
+            + &self.branch_choice_statement(&condition, &body_label, &loop_exit_label)
+            + &self.block_start(&body_label)
+            + &current_code
+            + &self.store_statement(&element, element_var, element_type)
+            + &body_result.preamble
+            + &self.gc_restore(gc_frame)
+            + &self.branch_jump_statement(&loop_label)
+            + &self.block_start(&loop_exit_label)
+            + &self.gc_restore(gc_frame)
+    }
+
+    /// # Description
+    ///
+    /// Calls the method `method_name` of `object_ptr` through the vtable of
+    /// `type_name`, the method must not receive arguments (besides self)
+    ///
+    /// Returns the generated code, and the register holding the result
+    fn call_method_without_arguments(
+        &self,
+        object_ptr: &str,
+        type_name: &str,
+        method_name: &str,
+        return_type: &str,
+    ) -> (String, String) {
+        if self.protocols.contains_key(type_name) {
+            return self.call_protocol_method(object_ptr, type_name, method_name, Vec::new());
+        }
+        let object_type = format!("%{type_name}_type");
+        let vtable_type = format!("%{type_name}_vtable_type");
+        let method_index = self
+            .function_member_names
+            .get(&(type_name.to_string(), method_name.to_string()))
+            .unwrap_or_else(|| panic!("Method {method_name} not found in type {type_name}"))
+            .clone();
+        let function_type = format!("{return_type} ({object_type}*)*");
+
+        let typed_object = self.generate_tmp_variable();
+        let vtable_ptr_ptr = self.generate_tmp_variable();
+        let vtable_ptr = self.generate_tmp_variable();
+        let function_ptr_ptr = self.generate_tmp_variable();
+        let raw_function_ptr = self.generate_tmp_variable();
+        let function_ptr = self.generate_tmp_variable();
+        let result = self.generate_tmp_variable();
+
+        let code = format!("{typed_object} = bitcast i8* {object_ptr} to {object_type}*\n")
+            + &format!(
+                "{vtable_ptr_ptr} = getelementptr inbounds {object_type}, {object_type}* {typed_object}, i32 0, i32 0\n"
+            )
+            + &format!(
+                "{vtable_ptr} = load {vtable_type}*, {vtable_type}** {vtable_ptr_ptr}, align 8\n"
+            )
+            + &format!(
+                "{function_ptr_ptr} = getelementptr inbounds {vtable_type}, {vtable_type}* {vtable_ptr}, i32 0, i32 {method_index}\n"
+            )
+            // vtable entries are `i8*`, cast back to the method signature
+            + &format!("{raw_function_ptr} = load i8*, i8** {function_ptr_ptr}, align 8\n")
+            + &format!("{function_ptr} = bitcast i8* {raw_function_ptr} to {function_type}\n")
+            + &format!(
+                "{result} = call {return_type} {function_ptr}({object_type}* {typed_object})\n"
+            );
+
+        (code, result)
     }
 }
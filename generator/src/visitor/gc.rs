@@ -0,0 +1,551 @@
+use ast::Definition;
+
+use super::GeneratorVisitor;
+use crate::llvm_types::LlvmType;
+
+/// Bytes before every heap value, see `%gc_header` in `instantiate_gc_runtime`
+pub const GC_HEADER_SIZE: usize = 40;
+
+/// Marks the header of the values allocated by `@hulk_gc_alloc`, so that pointers
+/// to memory the collector does not own (e.g. string constants) are never traced
+pub const GC_MAGIC: u64 = 0x48554C4B;
+
+/// Bytes allocated before the first collection, after every collection the
+/// threshold becomes twice the bytes that survived it
+pub const GC_INITIAL_THRESHOLD: usize = 1 << 20;
+
+/// Pointer map kind of a record, `[1, n, offset_1, ..., offset_n]`
+const GC_MAP_RECORD: u64 = 1;
+/// Pointer map kind of a list, `[2, stride, n, offset_1, ..., offset_n]`, the length
+/// is stored as an i64 at offset 0 and element `i` starts at `stride * (i + 1)`
+const GC_MAP_LIST: u64 = 2;
+
+/// Whether values of `llvm_type` hold a pointer to the heap (for interfaces, the
+/// object is the first field of the fat pointer)
+pub(crate) fn is_gc_pointer_type_str(llvm_type: &str) -> bool {
+    llvm_type.ends_with('*') || (llvm_type.starts_with('%') && llvm_type.ends_with("_interface"))
+}
+
+fn gc_map_definition(name: &str, entries: &[String]) -> String {
+    format!(
+        "@{name} = private unnamed_addr constant [{} x i64] [{}], align 8\n",
+        entries.len(),
+        entries
+            .iter()
+            .map(|entry| format!("i64 {entry}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn gc_map_reference(name: &str, len: usize) -> String {
+    format!("getelementptr inbounds ([{len} x i64], [{len} x i64]* @{name}, i32 0, i32 0)")
+}
+
+impl GeneratorVisitor {
+    /// # Description
+    ///
+    /// Emits the mark and sweep collector every heap value is allocated with.
+    ///
+    /// Values are allocated by `@hulk_gc_alloc(size, map)`, which prepends a
+    /// `%gc_header` linking them to `@gc_objects`. The map (null for values
+    /// without pointers, like strings) tells the collector where the pointers of
+    /// the value are, see `GC_MAP_RECORD` and `GC_MAP_LIST`.
+    ///
+    /// The roots live in a shadow stack, each entry is either a value (the low bit
+    /// clear), or the address of a variable holding one (the low bit set):
+    /// - `@hulk_gc_root_slot` registers a variable, `@hulk_gc_root_value` a value,
+    ///   every allocated value is registered, so temporaries survive until the
+    ///   statement that created them ends
+    /// - `@hulk_gc_save` and `@hulk_gc_restore` pop every root registered in
+    ///   between, they surround function bodies, statements and loop iterations
+    pub fn instantiate_gc_runtime(&self) -> String {
+        format!(
+            r#"
+%gc_header = type {{ %gc_header*, i64, i64, i64*, i64 }}
+
+@gc_objects = internal global %gc_header* null, align 8
+@gc_allocated = internal global i64 0, align 8
+@gc_threshold = internal global i64 {GC_INITIAL_THRESHOLD}, align 8
+@gc_roots = internal global i64* null, align 8
+@gc_roots_len = internal global i64 0, align 8
+@gc_roots_cap = internal global i64 0, align 8
+@gc_grey = internal global i8** null, align 8
+@gc_grey_len = internal global i64 0, align 8
+@gc_grey_cap = internal global i64 0, align 8
+
+declare i8* @calloc(i64, i64)
+declare i8* @realloc(i8*, i64)
+declare void @free(i8*)
+
+define i64 @hulk_gc_save() {{
+entry:
+  %len = load i64, i64* @gc_roots_len, align 8
+  ret i64 %len
+}}
+
+define void @hulk_gc_restore(i64 %len) {{
+entry:
+  store i64 %len, i64* @gc_roots_len, align 8
+  ret void
+}}
+
+define void @hulk_gc_push_root(i64 %root) {{
+entry:
+  %len = load i64, i64* @gc_roots_len, align 8
+  %cap = load i64, i64* @gc_roots_cap, align 8
+  %full = icmp eq i64 %len, %cap
+  br i1 %full, label %grow, label %push
+grow:
+  %doubled = mul i64 %cap, 2
+  %small = icmp ult i64 %doubled, 256
+  %new_cap = select i1 %small, i64 256, i64 %doubled
+  %roots = load i64*, i64** @gc_roots, align 8
+  %roots_raw = bitcast i64* %roots to i8*
+  %bytes = mul i64 %new_cap, 8
+  %new_roots_raw = call i8* @realloc(i8* %roots_raw, i64 %bytes)
+  %new_roots = bitcast i8* %new_roots_raw to i64*
+  store i64* %new_roots, i64** @gc_roots, align 8
+  store i64 %new_cap, i64* @gc_roots_cap, align 8
+  br label %push
+push:
+  %current = load i64*, i64** @gc_roots, align 8
+  %entry_ptr = getelementptr i64, i64* %current, i64 %len
+  store i64 %root, i64* %entry_ptr, align 8
+  %next = add i64 %len, 1
+  store i64 %next, i64* @gc_roots_len, align 8
+  ret void
+}}
+
+define void @hulk_gc_root_value(i8* %value) {{
+entry:
+  %is_null = icmp eq i8* %value, null
+  br i1 %is_null, label %done, label %push
+push:
+  %root = ptrtoint i8* %value to i64
+  call void @hulk_gc_push_root(i64 %root)
+  br label %done
+done:
+  ret void
+}}
+
+define void @hulk_gc_root_slot(i8** %slot) {{
+entry:
+  %address = ptrtoint i8** %slot to i64
+  %root = or i64 %address, 1
+  call void @hulk_gc_push_root(i64 %root)
+  ret void
+}}
+
+define %gc_header* @hulk_gc_header_of(i8* %value) {{
+entry:
+  %is_null = icmp eq i8* %value, null
+  br i1 %is_null, label %unmanaged, label %check
+check:
+  %raw = getelementptr i8, i8* %value, i64 -{GC_HEADER_SIZE}
+  %header = bitcast i8* %raw to %gc_header*
+  %magic_ptr = getelementptr inbounds %gc_header, %gc_header* %header, i32 0, i32 1
+  %magic = load i64, i64* %magic_ptr, align 8
+  %is_managed = icmp eq i64 %magic, {GC_MAGIC}
+  br i1 %is_managed, label %managed, label %unmanaged
+managed:
+  ret %gc_header* %header
+unmanaged:
+  ret %gc_header* null
+}}
+
+define void @hulk_gc_mark(i8* %value) {{
+entry:
+  %header = call %gc_header* @hulk_gc_header_of(i8* %value)
+  %is_null = icmp eq %gc_header* %header, null
+  br i1 %is_null, label %done, label %check
+check:
+  %marked_ptr = getelementptr inbounds %gc_header, %gc_header* %header, i32 0, i32 2
+  %marked = load i64, i64* %marked_ptr, align 8
+  %is_marked = icmp ne i64 %marked, 0
+  br i1 %is_marked, label %done, label %mark
+mark:
+  store i64 1, i64* %marked_ptr, align 8
+  %len = load i64, i64* @gc_grey_len, align 8
+  %cap = load i64, i64* @gc_grey_cap, align 8
+  %full = icmp eq i64 %len, %cap
+  br i1 %full, label %grow, label %push
+grow:
+  %doubled = mul i64 %cap, 2
+  %small = icmp ult i64 %doubled, 256
+  %new_cap = select i1 %small, i64 256, i64 %doubled
+  %grey = load i8**, i8*** @gc_grey, align 8
+  %grey_raw = bitcast i8** %grey to i8*
+  %bytes = mul i64 %new_cap, 8
+  %new_grey_raw = call i8* @realloc(i8* %grey_raw, i64 %bytes)
+  %new_grey = bitcast i8* %new_grey_raw to i8**
+  store i8** %new_grey, i8*** @gc_grey, align 8
+  store i64 %new_cap, i64* @gc_grey_cap, align 8
+  br label %push
+push:
+  %current = load i8**, i8*** @gc_grey, align 8
+  %entry_ptr = getelementptr i8*, i8** %current, i64 %len
+  store i8* %value, i8** %entry_ptr, align 8
+  %next = add i64 %len, 1
+  store i64 %next, i64* @gc_grey_len, align 8
+  br label %done
+done:
+  ret void
+}}
+
+; marks the pointers found at `offsets` (n of them) from `base`
+define void @hulk_gc_mark_offsets(i8* %base, i64* %offsets, i64 %n) {{
+entry:
+  br label %loop
+loop:
+  %i = phi i64 [ 0, %entry ], [ %next, %body ]
+  %more = icmp ult i64 %i, %n
+  br i1 %more, label %body, label %done
+body:
+  %offset_ptr = getelementptr i64, i64* %offsets, i64 %i
+  %offset = load i64, i64* %offset_ptr, align 8
+  %field_raw = getelementptr i8, i8* %base, i64 %offset
+  %field = bitcast i8* %field_raw to i8**
+  %pointer = load i8*, i8** %field, align 8
+  call void @hulk_gc_mark(i8* %pointer)
+  %next = add i64 %i, 1
+  br label %loop
+done:
+  ret void
+}}
+
+define void @hulk_gc_scan(i8* %value) {{
+entry:
+  %header = call %gc_header* @hulk_gc_header_of(i8* %value)
+  %map_ptr = getelementptr inbounds %gc_header, %gc_header* %header, i32 0, i32 3
+  %map = load i64*, i64** %map_ptr, align 8
+  %is_leaf = icmp eq i64* %map, null
+  br i1 %is_leaf, label %done, label %dispatch
+dispatch:
+  %kind = load i64, i64* %map, align 8
+  %map_1 = getelementptr i64, i64* %map, i64 1
+  %is_record = icmp eq i64 %kind, {GC_MAP_RECORD}
+  br i1 %is_record, label %record, label %list
+record:
+  %record_n = load i64, i64* %map_1, align 8
+  %record_offsets = getelementptr i64, i64* %map, i64 2
+  call void @hulk_gc_mark_offsets(i8* %value, i64* %record_offsets, i64 %record_n)
+  br label %done
+list:
+  %stride = load i64, i64* %map_1, align 8
+  %map_2 = getelementptr i64, i64* %map, i64 2
+  %list_n = load i64, i64* %map_2, align 8
+  %list_offsets = getelementptr i64, i64* %map, i64 3
+  %length_ptr = bitcast i8* %value to i64*
+  %length = load i64, i64* %length_ptr, align 8
+  br label %list_loop
+list_loop:
+  %i = phi i64 [ 0, %list ], [ %next, %list_body ]
+  %more = icmp ult i64 %i, %length
+  br i1 %more, label %list_body, label %done
+list_body:
+  %next = add i64 %i, 1
+  %element_offset = mul i64 %next, %stride
+  %element = getelementptr i8, i8* %value, i64 %element_offset
+  call void @hulk_gc_mark_offsets(i8* %element, i64* %list_offsets, i64 %list_n)
+  br label %list_loop
+done:
+  ret void
+}}
+
+define void @hulk_gc_collect() {{
+entry:
+  %roots = load i64*, i64** @gc_roots, align 8
+  %roots_len = load i64, i64* @gc_roots_len, align 8
+  br label %roots_loop
+roots_loop:
+  %i = phi i64 [ 0, %entry ], [ %next_root, %root_marked ]
+  %more_roots = icmp ult i64 %i, %roots_len
+  br i1 %more_roots, label %next_entry, label %drain
+next_entry:
+  %root_ptr = getelementptr i64, i64* %roots, i64 %i
+  %root = load i64, i64* %root_ptr, align 8
+  %tag = and i64 %root, 1
+  %is_slot = icmp ne i64 %tag, 0
+  br i1 %is_slot, label %root_slot, label %root_value
+root_slot:
+  %slot_address = and i64 %root, -2
+  %slot = inttoptr i64 %slot_address to i8**
+  %slot_value = load i8*, i8** %slot, align 8
+  br label %root_marked
+root_value:
+  %value = inttoptr i64 %root to i8*
+  br label %root_marked
+root_marked:
+  %rooted = phi i8* [ %slot_value, %root_slot ], [ %value, %root_value ]
+  call void @hulk_gc_mark(i8* %rooted)
+  %next_root = add i64 %i, 1
+  br label %roots_loop
+drain:
+  %grey_len = load i64, i64* @gc_grey_len, align 8
+  %grey_empty = icmp eq i64 %grey_len, 0
+  br i1 %grey_empty, label %sweep, label %scan
+scan:
+  %grey = load i8**, i8*** @gc_grey, align 8
+  %top = sub i64 %grey_len, 1
+  %top_ptr = getelementptr i8*, i8** %grey, i64 %top
+  %grey_value = load i8*, i8** %top_ptr, align 8
+  store i64 %top, i64* @gc_grey_len, align 8
+  call void @hulk_gc_scan(i8* %grey_value)
+  br label %drain
+sweep:
+  br label %sweep_loop
+sweep_loop:
+  %link = phi %gc_header** [ @gc_objects, %sweep ], [ %link, %freed ], [ %next_link, %kept ]
+  %current = load %gc_header*, %gc_header** %link, align 8
+  %at_end = icmp eq %gc_header* %current, null
+  br i1 %at_end, label %done, label %sweep_object
+sweep_object:
+  %next_link = getelementptr inbounds %gc_header, %gc_header* %current, i32 0, i32 0
+  %marked_ptr = getelementptr inbounds %gc_header, %gc_header* %current, i32 0, i32 2
+  %marked = load i64, i64* %marked_ptr, align 8
+  %is_marked = icmp ne i64 %marked, 0
+  br i1 %is_marked, label %kept, label %freed
+kept:
+  store i64 0, i64* %marked_ptr, align 8
+  br label %sweep_loop
+freed:
+  %next_object = load %gc_header*, %gc_header** %next_link, align 8
+  store %gc_header* %next_object, %gc_header** %link, align 8
+  %size_ptr = getelementptr inbounds %gc_header, %gc_header* %current, i32 0, i32 4
+  %size = load i64, i64* %size_ptr, align 8
+  %allocated = load i64, i64* @gc_allocated, align 8
+  %remaining = sub i64 %allocated, %size
+  store i64 %remaining, i64* @gc_allocated, align 8
+  %current_raw = bitcast %gc_header* %current to i8*
+  call void @free(i8* %current_raw)
+  br label %sweep_loop
+done:
+  %live = load i64, i64* @gc_allocated, align 8
+  %doubled = mul i64 %live, 2
+  %small = icmp ult i64 %doubled, {GC_INITIAL_THRESHOLD}
+  %threshold = select i1 %small, i64 {GC_INITIAL_THRESHOLD}, i64 %doubled
+  store i64 %threshold, i64* @gc_threshold, align 8
+  ret void
+}}
+
+define i8* @hulk_gc_alloc(i64 %size, i64* %map) {{
+entry:
+  %allocated = load i64, i64* @gc_allocated, align 8
+  %threshold = load i64, i64* @gc_threshold, align 8
+  %over = icmp ugt i64 %allocated, %threshold
+  br i1 %over, label %collect, label %alloc
+collect:
+  call void @hulk_gc_collect()
+  br label %alloc
+alloc:
+  %total = add i64 %size, {GC_HEADER_SIZE}
+  %raw = call i8* @calloc(i64 1, i64 %total)
+  %header = bitcast i8* %raw to %gc_header*
+  %objects = load %gc_header*, %gc_header** @gc_objects, align 8
+  %next_ptr = getelementptr inbounds %gc_header, %gc_header* %header, i32 0, i32 0
+  store %gc_header* %objects, %gc_header** %next_ptr, align 8
+  %magic_ptr = getelementptr inbounds %gc_header, %gc_header* %header, i32 0, i32 1
+  store i64 {GC_MAGIC}, i64* %magic_ptr, align 8
+  %map_ptr = getelementptr inbounds %gc_header, %gc_header* %header, i32 0, i32 3
+  store i64* %map, i64** %map_ptr, align 8
+  %size_ptr = getelementptr inbounds %gc_header, %gc_header* %header, i32 0, i32 4
+  store i64 %total, i64* %size_ptr, align 8
+  store %gc_header* %header, %gc_header** @gc_objects, align 8
+  %now_allocated = load i64, i64* @gc_allocated, align 8
+  %new_allocated = add i64 %now_allocated, %total
+  store i64 %new_allocated, i64* @gc_allocated, align 8
+  %value = getelementptr i8, i8* %raw, i64 {GC_HEADER_SIZE}
+  call void @hulk_gc_root_value(i8* %value)
+  ret i8* %value
+}}
+
+{}{}{}
+"#,
+            gc_map_definition(
+                "gc_map_list_of_pointers",
+                &[
+                    GC_MAP_LIST.to_string(),
+                    "8".to_string(),
+                    "1".to_string(),
+                    "0".to_string()
+                ]
+            ),
+            gc_map_definition(
+                "gc_map_list_of_interfaces",
+                &[
+                    GC_MAP_LIST.to_string(),
+                    "16".to_string(),
+                    "1".to_string(),
+                    "0".to_string()
+                ]
+            ),
+            gc_map_definition(
+                "gc_map_closure",
+                &[GC_MAP_RECORD.to_string(), "1".to_string(), "8".to_string()]
+            ),
+        )
+    }
+
+    /// Returns the code that allocates `size` bytes (an i64 handle) traced with
+    /// the pointer map `map` (null for values without pointers) into `target`
+    pub(crate) fn gc_alloc_statement(&self, target: &str, size: &str, map: &str) -> String {
+        format!("{target} = call i8* @hulk_gc_alloc(i64 {size}, i64* {map})\n")
+    }
+
+    /// The pointer map of a list whose elements are of `element_type`
+    pub(crate) fn gc_list_map(&self, element_type: &LlvmType) -> String {
+        match element_type {
+            LlvmType::F64 | LlvmType::I1 => "null".to_string(),
+            LlvmType::Interface(_) => gc_map_reference("gc_map_list_of_interfaces", 4),
+            _ => gc_map_reference("gc_map_list_of_pointers", 4),
+        }
+    }
+
+    /// The pointer map of the `{ i8*, i8* }` function values, only the
+    /// environment is traced since the function is a global
+    pub(crate) fn gc_closure_map(&self) -> String {
+        gc_map_reference("gc_map_closure", 3)
+    }
+
+    /// # Description
+    ///
+    /// Defines the pointer map `@<name>` of a struct of type `struct_type` whose
+    /// fields are `field_types`, the offsets are left to llvm as constant
+    /// expressions so that they match the layout it picks
+    ///
+    /// Returns the definition and a reference to the map
+    pub(crate) fn gc_struct_map(
+        &self,
+        name: &str,
+        struct_type: &str,
+        field_types: &[(usize, String)],
+    ) -> (String, String) {
+        let mut entries = vec![GC_MAP_RECORD.to_string(), String::new()];
+        for (index, field_type) in field_types {
+            if is_gc_pointer_type_str(field_type) {
+                entries.push(format!(
+                    "ptrtoint ({field_type}* getelementptr ({struct_type}, {struct_type}* null, i32 0, i32 {index}) to i64)"
+                ));
+            }
+        }
+        entries[1] = (entries.len() - 2).to_string();
+
+        (
+            gc_map_definition(name, &entries),
+            gc_map_reference(name, entries.len()),
+        )
+    }
+
+    /// # Description
+    ///
+    /// Returns the fields of `%<type_name>_type` (the inherited ones included) with
+    /// their index in the struct, sorted by index
+    pub(crate) fn gc_type_fields(&self, type_name: &str) -> Vec<(usize, String)> {
+        let mut fields: Vec<(usize, String)> = self
+            .type_members_ids
+            .iter()
+            .filter(|((name, _), _)| name == type_name)
+            .map(|((_, member), index)| {
+                let member_type = self
+                    .type_members_types
+                    .get(&(type_name.to_string(), member.clone()))
+                    .cloned()
+                    .unwrap_or_else(|| "i8*".to_string());
+                (*index as usize, member_type)
+            })
+            .collect();
+        fields.sort_by_key(|(index, _)| *index);
+        fields
+    }
+
+    /// Returns the code that registers the variable `slot` of type `var_type`
+    /// as a root, nothing for variables that do not hold heap values
+    pub(crate) fn gc_root_slot(&self, slot: &str, var_type: &LlvmType) -> String {
+        if !is_gc_pointer_type_str(&var_type.llvm_type_str()) {
+            return String::new();
+        }
+        let (type_name, _) = self.type_name_and_align_size(var_type);
+        let slot_ptr = self.generate_tmp_variable();
+        format!("{slot_ptr} = bitcast {type_name}* {slot} to i8**\n")
+            + &format!("call void @hulk_gc_root_slot(i8** {slot_ptr})\n")
+    }
+
+    /// Returns the code that registers the globals holding the constants of
+    /// `definitions` as roots
+    pub(crate) fn gc_root_constants(&self, definitions: &[Definition]) -> String {
+        definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::ConstantDef(constant) => {
+                    constant.identifier.info.ty.as_ref().map(|ty| {
+                        self.gc_root_slot(
+                            &format!("@{}", constant.identifier.id),
+                            &self.llvm_type_from_ast_type(ty),
+                        )
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the code that registers `value`, of llvm type `value_type`, as a
+    /// root, nothing for values that are not heap values
+    pub(crate) fn gc_root_value(&self, value: &str, value_type: &str) -> String {
+        if !is_gc_pointer_type_str(value_type) {
+            return String::new();
+        }
+        let object = self.generate_tmp_variable();
+        let code = if value_type.ends_with('*') {
+            format!("{object} = bitcast {value_type} {value} to i8*\n")
+        } else {
+            format!("{object} = extractvalue {value_type} {value}, 0\n")
+        };
+        code + &format!("call void @hulk_gc_root_value(i8* {object})\n")
+    }
+
+    /// # Description
+    ///
+    /// Returns the code that keeps the value in `slot`, a pointer to a value of llvm
+    /// type `slot_type`, alive before it gets overwritten, registers might still
+    /// hold it until the statement ends
+    pub(crate) fn gc_root_overwritten(&self, slot: &str, slot_type: &str) -> String {
+        if !is_gc_pointer_type_str(slot_type) {
+            return String::new();
+        }
+        let object_slot = self.generate_tmp_variable();
+        let old_value = self.generate_tmp_variable();
+        format!("{object_slot} = bitcast {slot_type}* {slot} to i8**\n")
+            + &format!("{old_value} = load i8*, i8** {object_slot}, align 8\n")
+            + &format!("call void @hulk_gc_root_value(i8* {old_value})\n")
+    }
+
+    /// Returns the code that saves the size of the shadow stack, and the
+    /// register holding it
+    pub(crate) fn gc_save(&self) -> (String, String) {
+        let frame = self.generate_tmp_variable();
+        (format!("{frame} = call i64 @hulk_gc_save()\n"), frame)
+    }
+
+    /// Returns the code that pops every root registered after `frame` was saved
+    pub(crate) fn gc_restore(&self, frame: &str) -> String {
+        format!("call void @hulk_gc_restore(i64 {frame})\n")
+    }
+
+    /// # Description
+    ///
+    /// Returns the code that leaves the function whose roots were saved in `frame`,
+    /// the returned `value` (of llvm type `value_type`) is registered again after
+    /// popping the roots of the function, so that it belongs to the caller
+    pub(crate) fn gc_leave_function(&self, frame: &str, value: &str, value_type: &str) -> String {
+        self.gc_restore(frame) + &self.gc_root_value(value, value_type)
+    }
+
+    /// Surrounds `code` with a save and restore of the shadow stack, the roots it
+    /// registers are dropped once it runs, for code whose result is discarded
+    pub(crate) fn gc_scoped(&self, code: String) -> String {
+        let (save, frame) = self.gc_save();
+        save + &code + &self.gc_restore(&frame)
+    }
+}
@@ -17,6 +17,18 @@ impl GeneratorVisitor {
         format!("{var_register_name} = alloca {type_name}, align {align_size}\n")
     }
 
+    /// # Description
+    ///
+    /// Objects are `i8*` values, returns the code that casts `object` to a
+    /// pointer to the struct of `type_name`, to access its fields and vtable,
+    /// and the name of the cast pointer
+    pub(crate) fn cast_object_to_type(&self, object: &str, type_name: &str) -> (String, String) {
+        let typed_object = self.generate_tmp_variable();
+        let code = format!("  {typed_object} = bitcast i8* {object} to %{type_name}_type*\n");
+
+        (code, typed_object)
+    }
+
     pub(crate) fn load_statement(
         &self,
         source_var_register_name: &str,
@@ -44,7 +56,12 @@ impl GeneratorVisitor {
             LlvmType::I1 => LlvmHandle::new_i1_register(target_register_name),
             LlvmType::String => LlvmHandle::new_string_register(target_register_name),
             LlvmType::Object => LlvmHandle::new_object_register(target_register_name),
-            LlvmType::List(inner) => LlvmHandle::new_list_register(*inner.clone(), target_register_name),
+            LlvmType::List(inner) => {
+                LlvmHandle::new_list_register(*inner.clone(), target_register_name)
+            }
+            LlvmType::Interface(protocol) => {
+                LlvmHandle::new_interface_register(protocol.clone(), target_register_name)
+            }
         };
 
         (preamble, result_handle)
@@ -70,6 +87,7 @@ impl GeneratorVisitor {
             LlvmType::String => ("i8*".to_string(), 8),
             LlvmType::Object => ("i8*".to_string(), 8),
             LlvmType::List(inner) => (format!("{}*", inner.llvm_type_str()), 8),
+            LlvmType::Interface(_) => (llvm_type.llvm_type_str(), 8),
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,340 @@
+use ast::{
+    FunctionCall, Lambda, VisitableExpression,
+    typing::{Type, TypeAnnotation},
+};
+
+use super::{GeneratorVisitor, Variable, VisitorResult};
+use crate::{
+    context::Context,
+    llvm_types::{LlvmHandle, LlvmType},
+};
+
+/// The layout of a function value, a pointer to the lambda function and a
+/// pointer to the environment holding its captured variables
+const CLOSURE_TYPE: &str = "{ i8*, i8* }";
+
+impl GeneratorVisitor {
+    /// # Description
+    ///
+    /// Returns the llvm type used to pass a value of type `ty` to and from a
+    /// lambda function. Numbers and booleans are passed as they are, every other
+    /// value is passed as an object pointer, so that functions with conforming
+    /// types can be called through the same pointer
+    fn closure_abi_type(&self, ty: &TypeAnnotation) -> String {
+        match ty.as_ref().map(|ty| self.llvm_type_from_ast_type(ty)) {
+            Some(LlvmType::F64) => "double".to_string(),
+            Some(LlvmType::I1) => "i1".to_string(),
+            _ => "i8*".to_string(),
+        }
+    }
+
+    /// Returns the llvm type of a lambda function taking `parameter_types` and
+    /// returning `return_type`, the environment being its first parameter
+    fn closure_function_type(
+        &self,
+        parameter_types: &[TypeAnnotation],
+        return_type: &TypeAnnotation,
+    ) -> String {
+        let mut parameters = vec!["i8*".to_string()];
+        parameters.extend(parameter_types.iter().map(|ty| self.closure_abi_type(ty)));
+
+        format!(
+            "{} ({})",
+            self.closure_abi_type(return_type),
+            parameters.join(", ")
+        )
+    }
+
+    /// # Description
+    ///
+    /// Emits the lambda function for `node` and the code that creates a function
+    /// value from it. The values of the captured variables are copied into a heap
+    /// allocated environment when the lambda is evaluated, inside the lambda
+    /// function they are read from and written to that environment
+    ///
+    /// The result is an object pointer to a `{ i8*, i8* }` holding the function
+    /// and its environment
+    pub(crate) fn handle_lambda(&mut self, node: &mut Lambda) -> VisitorResult {
+        let lambda_name = format!("lambda.{}", self.tmp_variable_id);
+        self.tmp_variable_id += 1;
+        let env_type = format!("%{}_env", lambda_name);
+
+        let captures: Vec<(String, LlvmType, String)> = node
+            .captures
+            .iter()
+            .map(|capture| {
+                let variable = self.context.get_value(&capture.id).unwrap_or_else(|| {
+                    panic!(
+                        "Captured variable {} not found, SA should have caught this",
+                        capture.id
+                    )
+                });
+                (
+                    capture.id.clone(),
+                    variable.var_type.clone(),
+                    variable.llvm_name.clone(),
+                )
+            })
+            .collect();
+
+        let env_fields = captures
+            .iter()
+            .map(|(_, var_type, _)| var_type.llvm_type_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.general_definitions
+            .push(format!("{} = type {{ {} }}\n", env_type, env_fields));
+        let (env_map_definition, env_map) = self.gc_struct_map(
+            &format!("{}_env_gc_map", lambda_name),
+            &env_type,
+            &captures
+                .iter()
+                .enumerate()
+                .map(|(i, (_, var_type, _))| (i, var_type.llvm_type_str()))
+                .collect::<Vec<_>>(),
+        );
+        self.general_definitions.push(env_map_definition);
+
+        let function = self.generate_lambda_function(node, &lambda_name, &env_type, &captures);
+        self.general_definitions.push(function);
+
+        // Creation of the function value
+        let mut preamble = String::new();
+        let env = if captures.is_empty() {
+            "null".to_string()
+        } else {
+            let size_ptr = self.generate_tmp_variable();
+            let size = self.generate_tmp_variable();
+            let env = self.generate_tmp_variable();
+            let typed_env = self.generate_tmp_variable();
+            preamble += &format!(
+                "{size_ptr} = getelementptr {env_type}, {env_type}* null, i32 1\n\
+                 {size} = ptrtoint {env_type}* {size_ptr} to i64\n\
+                 {}\
+                 {typed_env} = bitcast i8* {env} to {env_type}*\n",
+                self.gc_alloc_statement(&env, &size, &env_map)
+            );
+            for (i, (_, var_type, llvm_name)) in captures.iter().enumerate() {
+                let value = self.generate_tmp_variable();
+                let field = self.generate_tmp_variable();
+                preamble += &self.load_statement(llvm_name, &value, var_type);
+                preamble += &format!(
+                    "{field} = getelementptr {env_type}, {env_type}* {typed_env}, i32 0, i32 {i}\n"
+                );
+                preamble += &self.store_statement(&value, &field, var_type);
+            }
+            env
+        };
+
+        let function_type = self.closure_function_type(
+            &node
+                .parameters
+                .iter()
+                .map(|p| p.info.ty.clone())
+                .collect::<Vec<_>>(),
+            &node.return_type,
+        );
+        let closure = self.generate_tmp_variable();
+        let typed_closure = self.generate_tmp_variable();
+        let function_field = self.generate_tmp_variable();
+        let env_field = self.generate_tmp_variable();
+        preamble += &self.gc_alloc_statement(&closure, "16", &self.gc_closure_map());
+        preamble += &format!(
+            "{typed_closure} = bitcast i8* {closure} to {CLOSURE_TYPE}*\n\
+             {function_field} = getelementptr {CLOSURE_TYPE}, {CLOSURE_TYPE}* {typed_closure}, i32 0, i32 0\n\
+             store i8* bitcast ({function_type}* @{lambda_name} to i8*), i8** {function_field}, align 8\n\
+             {env_field} = getelementptr {CLOSURE_TYPE}, {CLOSURE_TYPE}* {typed_closure}, i32 0, i32 1\n\
+             store i8* {env}, i8** {env_field}, align 8\n"
+        );
+
+        VisitorResult {
+            preamble,
+            result_handle: Some(LlvmHandle::new_object_register(closure)),
+        }
+    }
+
+    /// Emits the definition of the function that evaluates the body of `node`
+    fn generate_lambda_function(
+        &mut self,
+        node: &mut Lambda,
+        lambda_name: &str,
+        env_type: &str,
+        captures: &[(String, LlvmType, String)],
+    ) -> String {
+        let return_type = self.closure_abi_type(&node.return_type);
+        let mut code = format!(
+            "define {} @{}(i8* %{}.env",
+            return_type, lambda_name, lambda_name
+        );
+        for (i, param) in node.parameters.iter().enumerate() {
+            code += &format!(
+                ", {} %{}.arg{}",
+                self.closure_abi_type(&param.info.ty),
+                lambda_name,
+                i
+            );
+        }
+        code += ") {\nentry:\n";
+
+        let old_context = std::mem::replace(&mut self.context, Context::new_one_frame());
+        let old_is_global = std::mem::replace(&mut self.is_global, false);
+
+        let (gc_save, gc_frame) = self.gc_save();
+        code += &gc_save;
+        let typed_env = self.generate_tmp_variable();
+        code += &format!("{typed_env} = bitcast i8* %{lambda_name}.env to {env_type}*\n");
+        for (i, (id, var_type, _)) in captures.iter().enumerate() {
+            let field = self.generate_tmp_variable();
+            code += &format!(
+                "{field} = getelementptr {env_type}, {env_type}* {typed_env}, i32 0, i32 {i}\n"
+            );
+            self.context.define(
+                id.clone(),
+                Variable {
+                    var_type: var_type.clone(),
+                    llvm_name: field,
+                },
+            );
+        }
+
+        for (i, param) in node.parameters.iter().enumerate() {
+            let var_type = match &param.info.ty {
+                Some(ty) => self.llvm_type_from_ast_type(ty),
+                None => LlvmType::Object,
+            };
+            let (coercion, value) = self.coerce_value(
+                &format!("%{}.arg{}", lambda_name, i),
+                &self.closure_abi_type(&param.info.ty),
+                &var_type.llvm_type_str(),
+            );
+            code += &coercion;
+            let var_llvm_name = self.define_or_shadow(param.id.clone(), var_type.clone());
+            code += &self.alloca_statement(&var_llvm_name, &var_type);
+            code += &self.store_statement(&value, &var_llvm_name, &var_type);
+            code += &self.gc_root_slot(&var_llvm_name, &var_type);
+        }
+
+        let body_result = node.body.accept(self);
+        code += &body_result.preamble;
+        if body_result.result_handle.is_none() {
+            code += &self.gc_restore(&gc_frame);
+        }
+        match body_result.result_handle {
+            Some(handle) => {
+                let (coercion, value) = self.coerce_value(
+                    &handle.llvm_name,
+                    &handle.handle_type.inner_type().llvm_type_str(),
+                    &return_type,
+                );
+                code += &coercion;
+                code += &self.gc_leave_function(&gc_frame, &value, &return_type);
+                code += &format!("ret {} {}\n", return_type, value);
+            }
+            None => match return_type.as_str() {
+                "double" => code += "ret double 0.0\n",
+                "i1" => code += "ret i1 0\n",
+                _ => code += "ret i8* null\n",
+            },
+        }
+        code += "}\n";
+
+        self.context = old_context;
+        self.is_global = old_is_global;
+
+        code
+    }
+
+    /// # Description
+    ///
+    /// Calls the function value stored in the variable `node.identifier`, whose
+    /// functor type is `node.closure_type`. The arguments are converted to the
+    /// lambda calling convention, and the result back to the static return type
+    pub(crate) fn handle_closure_call(&mut self, node: &mut FunctionCall) -> VisitorResult {
+        let Some(Type::Functor(functor)) = node.closure_type.clone() else {
+            panic!(
+                "Calling {} which is not a function value",
+                node.identifier.id
+            );
+        };
+
+        let mut preamble = String::new();
+        let variable = self
+            .context
+            .get_value(&node.identifier.id)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Variable {} not found, SA should have caught this",
+                    node.identifier.id
+                )
+            });
+        let closure = self.generate_tmp_variable();
+        preamble += &self.load_statement(&variable.llvm_name, &closure, &LlvmType::Object);
+
+        let mut args = vec![];
+        for (arg, ty) in node
+            .arguments
+            .iter_mut()
+            .zip(functor.parameter_types.iter())
+        {
+            let arg_result = arg.accept(self);
+            preamble += &arg_result.preamble;
+            let handle = arg_result
+                .result_handle
+                .expect("Function argument must have a result");
+            let abi_type = self.closure_abi_type(ty);
+            let (coercion, value) = self.coerce_value(
+                &handle.llvm_name,
+                &handle.handle_type.inner_type().llvm_type_str(),
+                &abi_type,
+            );
+            preamble += &coercion;
+            args.push(format!("{} {}", abi_type, value));
+        }
+
+        let function_type =
+            self.closure_function_type(&functor.parameter_types, &functor.return_type);
+        let typed_closure = self.generate_tmp_variable();
+        let function_field = self.generate_tmp_variable();
+        let function_ptr = self.generate_tmp_variable();
+        let function = self.generate_tmp_variable();
+        let env_field = self.generate_tmp_variable();
+        let env = self.generate_tmp_variable();
+        preamble += &format!(
+            "{typed_closure} = bitcast i8* {closure} to {CLOSURE_TYPE}*\n\
+             {function_field} = getelementptr {CLOSURE_TYPE}, {CLOSURE_TYPE}* {typed_closure}, i32 0, i32 0\n\
+             {function_ptr} = load i8*, i8** {function_field}, align 8\n\
+             {function} = bitcast i8* {function_ptr} to {function_type}*\n\
+             {env_field} = getelementptr {CLOSURE_TYPE}, {CLOSURE_TYPE}* {typed_closure}, i32 0, i32 1\n\
+             {env} = load i8*, i8** {env_field}, align 8\n"
+        );
+
+        let return_abi_type = self.closure_abi_type(&functor.return_type);
+        let result = self.generate_tmp_variable();
+        args.insert(0, format!("i8* {env}"));
+        preamble += &format!(
+            "{result} = call {return_abi_type} {function}({})\n",
+            args.join(", ")
+        );
+
+        let return_type = match functor.return_type.as_ref() {
+            Some(ty) => self.llvm_type_from_ast_type(ty),
+            None => LlvmType::Object,
+        };
+        let (coercion, result) =
+            self.coerce_value(&result, &return_abi_type, &return_type.llvm_type_str());
+        preamble += &coercion;
+        let result_handle = match return_type {
+            LlvmType::F64 => LlvmHandle::new_f64_register(result),
+            LlvmType::I1 => LlvmHandle::new_i1_register(result),
+            LlvmType::String => LlvmHandle::new_string_register(result),
+            LlvmType::Object => LlvmHandle::new_object_register(result),
+            LlvmType::List(inner) => LlvmHandle::new_list_register(*inner, result),
+            LlvmType::Interface(protocol) => LlvmHandle::new_interface_register(protocol, result),
+        };
+
+        VisitorResult {
+            preamble,
+            result_handle: Some(result_handle),
+        }
+    }
+}
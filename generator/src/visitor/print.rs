@@ -75,6 +75,10 @@ impl GeneratorVisitor {
             + "declare i32 @strlen(i8*)\n"
             + "declare i32 @strcmp(i8*, i8*)\n"
             + "declare i8* @malloc(i64)\n"
+            + "declare i32 @fprintf(i8*, i8*, ...)\n"
+            + "declare void @exit(i32)\n"
+            + "@stderr = external global i8*\n"
+            + "declare double @llvm.pow.f64(double, double)\n"
             + "@.fmt = private unnamed_addr constant [4 x i8] c\"%s\\0A\\00\", align 1"
             + "\n"
     }
@@ -112,19 +116,19 @@ impl GeneratorVisitor {
     }
 
     fn print_true(&mut self) -> String {
-        let element_ptr = self.generate_tmp_variable();
-        format!(
-            "{} = getelementptr [6 x i8], [6 x i8]* @.true_str, i32 0, i32 0\n",
-            element_ptr
-        ) + &format!("call i32 (i8*, ...) @printf(i8* {})\n", element_ptr)
+        self.print_constant("@.true_str", 5)
     }
 
     fn print_false(&mut self) -> String {
+        self.print_constant("@.false_str", 6)
+    }
+
+    // the constants are shared with the concatenation, so the newline comes from the format
+    fn print_constant(&mut self, constant: &str, length: usize) -> String {
         let element_ptr = self.generate_tmp_variable();
         format!(
-            "{} = getelementptr [7 x i8], [7 x i8]* @.false_str, i32 0, i32 0\n",
-            element_ptr
-        ) + &format!("call i32 (i8*, ...) @printf(i8* {})\n", element_ptr)
+            "{element_ptr} = getelementptr [{length} x i8], [{length} x i8]* {constant}, i32 0, i32 0\n"
+        ) + &self.print_string(&element_ptr)
     }
 
     fn print_boolean_register(&mut self, handle: &str) -> String {
@@ -149,11 +153,7 @@ impl GeneratorVisitor {
     }
 
     fn print_none(&mut self) -> String {
-        let element_ptr = self.generate_tmp_variable();
-        format!(
-            "{} = getelementptr [6 x i8], [6 x i8]* @.none_str, i32 0, i32 0\n",
-            element_ptr
-        ) + &format!("call i32 (i8*, ...) @printf(i8* {})\n", element_ptr)
+        self.print_constant("@.none_str", 5)
     }
 
     // FIXED: Changed parameter type from i8* to %string_type*
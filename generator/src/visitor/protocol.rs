@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+
+use ast::{Definition, FunctionSignature, ProtocolDef, VisitableExpression};
+
+use super::{GeneratorVisitor, VisitorResult};
+use crate::llvm_types::{LlvmHandle, LlvmType};
+
+/// # Description
+///
+/// Collects the method signatures of every protocol in the program. The methods
+/// of an extended protocol come first, so that the vtable of a protocol starts
+/// with the layout of the vtable of the protocol it extends
+pub fn collect_protocol_methods(
+    definitions: &[Definition],
+) -> HashMap<String, Vec<FunctionSignature>> {
+    let protocol_defs: HashMap<String, &ProtocolDef> = definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::ProtocolDef(protocol) => Some((protocol.name.id.clone(), protocol)),
+            _ => None,
+        })
+        .collect();
+
+    let mut protocols = HashMap::new();
+    for name in protocol_defs.keys() {
+        let mut methods: Vec<FunctionSignature> = Vec::new();
+        let mut chain = Vec::new();
+        let mut current = protocol_defs.get(name);
+        while let Some(protocol) = current {
+            chain.push(*protocol);
+            current = protocol
+                .extension_indicator
+                .as_ref()
+                .and_then(|extension| protocol_defs.get(&extension.extendee_name.id));
+        }
+        for protocol in chain.iter().rev() {
+            for signature in &protocol.function_signatures {
+                if !methods
+                    .iter()
+                    .any(|m| m.identifier.id == signature.identifier.id)
+                {
+                    methods.push(signature.clone());
+                }
+            }
+        }
+        protocols.insert(name.clone(), methods);
+    }
+    protocols
+}
+
+fn is_interface_type_str(llvm_type: &str) -> bool {
+    llvm_type.starts_with('%') && llvm_type.ends_with("_interface")
+}
+
+/// Object pointers and interfaces, the types that can be converted to one another
+fn is_reference_type_str(llvm_type: &str) -> bool {
+    llvm_type == "i8*"
+        || (llvm_type.starts_with('%') && llvm_type.ends_with("_type*"))
+        || is_interface_type_str(llvm_type)
+}
+
+fn is_coercible(from: &str, to: &str) -> bool {
+    from == to || (is_reference_type_str(from) && is_reference_type_str(to))
+}
+
+/// The implementation a type uses for a protocol method
+struct MethodImplementation {
+    owner_type: String,
+    args_types: Vec<String>,
+    return_type: String,
+}
+
+impl GeneratorVisitor {
+    fn protocol_method_signature(
+        &self,
+        protocol: &str,
+        method: &str,
+    ) -> (usize, &FunctionSignature) {
+        self.protocols
+            .get(protocol)
+            .unwrap_or_else(|| panic!("Protocol {protocol} not found"))
+            .iter()
+            .enumerate()
+            .find(|(_, signature)| signature.identifier.id == method)
+            .unwrap_or_else(|| panic!("Method {method} not found in protocol {protocol}"))
+    }
+
+    /// Returns the llvm types of the parameters and the return type of a protocol method
+    fn protocol_method_llvm_types(&self, signature: &FunctionSignature) -> (Vec<String>, String) {
+        let params = signature
+            .parameters
+            .iter()
+            .map(|param| match &param.info.ty {
+                Some(ty) => self.llvm_type_str_from_ast_type(ty),
+                None => "i8*".to_string(),
+            })
+            .collect();
+        let return_type = match &signature.identifier.info.ty {
+            Some(ty) => self.llvm_type_str_from_ast_type(ty),
+            None => "i8*".to_string(),
+        };
+        (params, return_type)
+    }
+
+    fn protocol_function_ptr_type(&self, signature: &FunctionSignature) -> String {
+        let (params, return_type) = self.protocol_method_llvm_types(signature);
+        let mut all_params = vec!["i8*".to_string()];
+        all_params.extend(params);
+        format!("{} ({})*", return_type, all_params.join(", "))
+    }
+
+    /// # Description
+    ///
+    /// Finds how `type_name` implements each method of `protocol`. Types conform to
+    /// protocols structurally, so every type having methods with the names, amount
+    /// of parameters and convertible llvm types of the protocol's methods gets an
+    /// adapter vtable, SA guarantees only conforming types reach protocol values.
+    ///
+    /// Returns None if the type cannot implement the protocol
+    fn find_protocol_implementation(
+        &self,
+        type_name: &str,
+        protocol: &str,
+    ) -> Option<Vec<MethodImplementation>> {
+        let mut implementations = Vec::new();
+        for signature in &self.protocols[protocol] {
+            let method = &signature.identifier.id;
+            let owner_type = self
+                .original_type_for_definition
+                .get(&(type_name.to_string(), method.clone()))?;
+            let return_type = self
+                .function_member_signature_types
+                .get(&(type_name.to_string(), method.clone()))?;
+            let args_types = self
+                .function_member_def_from_type_and_name
+                .iter()
+                .find(|((t, m, _), _)| t == type_name && m == method)
+                .map(|(_, args)| args.clone())?;
+
+            let (protocol_params, protocol_return) = self.protocol_method_llvm_types(signature);
+            if protocol_params.len() != args_types.len()
+                || !protocol_params
+                    .iter()
+                    .zip(args_types.iter())
+                    .all(|(p, a)| is_coercible(p, a))
+                || !is_coercible(return_type, &protocol_return)
+            {
+                return None;
+            }
+
+            implementations.push(MethodImplementation {
+                owner_type: owner_type.clone(),
+                args_types,
+                return_type: return_type.clone(),
+            });
+        }
+        Some(implementations)
+    }
+
+    /// # Description
+    ///
+    /// Generates the code of a protocol:
+    /// - `%P_vtable_type`, with a typed function pointer for each method, receiving
+    ///   the object as i8*
+    /// - `%P_interface`, the fat pointer `{ i8*, %P_vtable_type* }` protocol values use
+    /// - For each type implementing the protocol, adapters `@T_as_P_method` that call
+    ///   the type's implementation converting arguments and return values, and the
+    ///   `@T_as_P_vtable` holding them
+    /// - `@P_vtable_for`, which finds the vtable of the protocol for an object, by
+    ///   comparing its vtable with the vtable of each implementing type
+    ///
+    /// Returns the type definitions, and the global definitions
+    pub(crate) fn generate_protocol(&mut self, protocol: &str) -> (String, String) {
+        let vtable_type = format!("%{protocol}_vtable_type");
+        let signatures = self.protocols[protocol].clone();
+
+        let function_ptr_types: Vec<String> = signatures
+            .iter()
+            .map(|signature| self.protocol_function_ptr_type(signature))
+            .collect();
+        let types_code = format!(
+            "{vtable_type} = type {{ {} }}\n\n",
+            function_ptr_types.join(", ")
+        ) + &format!("%{protocol}_interface = type {{ i8*, {vtable_type}* }}\n");
+
+        let mut type_names: Vec<String> = self.constructor_args_types.keys().cloned().collect();
+        type_names.sort();
+
+        let mut code = String::new();
+        let mut implementing_types = Vec::new();
+        for type_name in type_names {
+            let Some(implementations) = self.find_protocol_implementation(&type_name, protocol)
+            else {
+                continue;
+            };
+
+            let mut vtable_entries = Vec::new();
+            for ((signature, implementation), function_ptr_type) in signatures
+                .iter()
+                .zip(implementations.iter())
+                .zip(function_ptr_types.iter())
+            {
+                let adapter_name =
+                    format!("@{}_as_{}_{}", type_name, protocol, signature.identifier.id);
+                code += &self.generate_protocol_adapter(
+                    &adapter_name,
+                    &signature.identifier.id,
+                    signature,
+                    implementation,
+                );
+                vtable_entries.push(format!("{function_ptr_type} {adapter_name}"));
+            }
+
+            code += &if vtable_entries.is_empty() {
+                format!(
+                    "@{type_name}_as_{protocol}_vtable = private unnamed_addr constant {vtable_type} zeroinitializer, align 8\n\n"
+                )
+            } else {
+                format!(
+                    "@{type_name}_as_{protocol}_vtable = private unnamed_addr constant {vtable_type} {{ {} }}, align 8\n\n",
+                    vtable_entries.join(", ")
+                )
+            };
+            implementing_types.push(type_name);
+        }
+
+        code += &self.generate_vtable_for(protocol, &implementing_types);
+
+        (types_code, code)
+    }
+
+    fn generate_protocol_adapter(
+        &self,
+        adapter_name: &str,
+        method: &str,
+        signature: &FunctionSignature,
+        implementation: &MethodImplementation,
+    ) -> String {
+        let (protocol_params, protocol_return) = self.protocol_method_llvm_types(signature);
+        let owner_type = &implementation.owner_type;
+
+        let mut params = vec!["i8* %self".to_string()];
+        params.extend(
+            protocol_params
+                .iter()
+                .enumerate()
+                .map(|(i, param_type)| format!("{param_type} %arg{i}")),
+        );
+
+        let mut body = format!("%typed_self = bitcast i8* %self to %{owner_type}_type*\n");
+        let mut call_args = vec![format!("%{owner_type}_type* %typed_self")];
+        for (i, (param_type, arg_type)) in protocol_params
+            .iter()
+            .zip(implementation.args_types.iter())
+            .enumerate()
+        {
+            let (coercion, value) = self.coerce_value(&format!("%arg{i}"), param_type, arg_type);
+            body += &coercion;
+            call_args.push(format!("{arg_type} {value}"));
+        }
+
+        let return_type = &implementation.return_type;
+        body += &format!(
+            "%result = call {return_type} @{owner_type}_{method}({})\n",
+            call_args.join(", ")
+        );
+        let (coercion, result) = self.coerce_value("%result", return_type, &protocol_return);
+        body += &coercion;
+        body += &format!("ret {protocol_return} {result}\n");
+
+        format!(
+            "define {protocol_return} {adapter_name}({}) {{\nentry:\n{body}}}\n\n",
+            params.join(", ")
+        )
+    }
+
+    fn generate_vtable_for(&self, protocol: &str, implementing_types: &[String]) -> String {
+        let vtable_type = format!("%{protocol}_vtable_type");
+        let mut code = format!("define {vtable_type}* @{protocol}_vtable_for(i8* %object) {{\n")
+            + "entry:\n"
+            + "%vtable_field = bitcast i8* %object to i8**\n"
+            + "%vtable = load i8*, i8** %vtable_field, align 8\n"
+            + &self.branch_jump_statement("check.0");
+
+        for (i, type_name) in implementing_types.iter().enumerate() {
+            code += &self.block_start(&format!("check.{i}"));
+            code += &format!(
+                "%is.{i} = icmp eq i8* %vtable, bitcast (%{type_name}_vtable_type* @{type_name}_vtable to i8*)\n"
+            );
+            code += &self.branch_choice_statement(
+                &format!("%is.{i}"),
+                &format!("found.{i}"),
+                &format!("check.{}", i + 1),
+            );
+            code += &self.block_start(&format!("found.{i}"));
+            code += &format!("ret {vtable_type}* @{type_name}_as_{protocol}_vtable\n");
+        }
+
+        code += &self.block_start(&format!("check.{}", implementing_types.len()));
+        code += &format!("ret {vtable_type}* null\n");
+        code + "}\n\n"
+    }
+
+    /// # Description
+    ///
+    /// Converts `value` from the llvm type `from` to the llvm type `to`, when a
+    /// protocol interface is involved:
+    /// - object to interface: looks up the protocol vtable of the object's type
+    /// - interface to object: takes the object out of the fat pointer
+    /// - interface to a different interface: takes the object out and looks up the
+    ///   vtable of the other protocol
+    ///
+    /// Any other value is returned as is.
+    ///
+    /// Returns the generated code and the name of the converted value
+    pub(crate) fn coerce_value(&self, value: &str, from: &str, to: &str) -> (String, String) {
+        if from == to || !(is_interface_type_str(from) || is_interface_type_str(to)) {
+            return (String::new(), value.to_string());
+        }
+        if !is_coercible(from, to) {
+            panic!("Cannot convert {from} to {to}, SA should have caught this");
+        }
+
+        let mut code = String::new();
+        let object = if is_interface_type_str(from) {
+            let object = self.generate_tmp_variable();
+            code += &format!("{object} = extractvalue {from} {value}, 0\n");
+            object
+        } else if from != "i8*" {
+            let object = self.generate_tmp_variable();
+            code += &format!("{object} = bitcast {from} {value} to i8*\n");
+            object
+        } else {
+            value.to_string()
+        };
+
+        if !is_interface_type_str(to) {
+            if to == "i8*" {
+                return (code, object);
+            }
+            let typed_object = self.generate_tmp_variable();
+            code += &format!("{typed_object} = bitcast i8* {object} to {to}\n");
+            return (code, typed_object);
+        }
+
+        let protocol = &to[1..to.len() - "_interface".len()];
+        let vtable = self.generate_tmp_variable();
+        let partial = self.generate_tmp_variable();
+        let interface = self.generate_tmp_variable();
+        code += &format!(
+            "{vtable} = call %{protocol}_vtable_type* @{protocol}_vtable_for(i8* {object})\n"
+        );
+        code += &format!("{partial} = insertvalue {to} undef, i8* {object}, 0\n");
+        code += &format!(
+            "{interface} = insertvalue {to} {partial}, %{protocol}_vtable_type* {vtable}, 1\n"
+        );
+        (code, interface)
+    }
+
+    /// Converts the value of `handle` to `to`, see `coerce_value`
+    pub(crate) fn coerce_handle(&self, handle: LlvmHandle, to: &LlvmType) -> (String, LlvmHandle) {
+        let from = handle.handle_type.inner_type().llvm_type_str();
+        let (code, value) = self.coerce_value(&handle.llvm_name, &from, &to.llvm_type_str());
+        if code.is_empty() {
+            return (code, handle);
+        }
+        let handle = match to {
+            LlvmType::Interface(protocol) => {
+                LlvmHandle::new_interface_register(protocol.clone(), value)
+            }
+            _ => LlvmHandle::new_object_register(value),
+        };
+        (code, handle)
+    }
+
+    /// # Description
+    ///
+    /// Calls `method` on a protocol value through the protocol vtable stored in the
+    /// fat pointer, `args` must already have the protocol's parameter types, and
+    /// be formatted as `type value`
+    ///
+    /// Returns the generated code, and the register holding the result
+    pub(crate) fn call_protocol_method(
+        &self,
+        interface_value: &str,
+        protocol: &str,
+        method: &str,
+        args: Vec<String>,
+    ) -> (String, String) {
+        let (index, signature) = self.protocol_method_signature(protocol, method);
+        let (_, return_type) = self.protocol_method_llvm_types(signature);
+        let function_ptr_type = self.protocol_function_ptr_type(signature);
+        let interface_type = format!("%{protocol}_interface");
+        let vtable_type = format!("%{protocol}_vtable_type");
+
+        let object = self.generate_tmp_variable();
+        let vtable = self.generate_tmp_variable();
+        let function_ptr_ptr = self.generate_tmp_variable();
+        let function_ptr = self.generate_tmp_variable();
+        let result = self.generate_tmp_variable();
+
+        let mut call_args = vec![format!("i8* {object}")];
+        call_args.extend(args);
+
+        let code = format!("{object} = extractvalue {interface_type} {interface_value}, 0\n")
+            + &format!("{vtable} = extractvalue {interface_type} {interface_value}, 1\n")
+            + &format!(
+                "{function_ptr_ptr} = getelementptr inbounds {vtable_type}, {vtable_type}* {vtable}, i32 0, i32 {index}\n"
+            )
+            + &format!(
+                "{function_ptr} = load {function_ptr_type}, {function_ptr_type}* {function_ptr_ptr}, align 8\n"
+            )
+            + &format!(
+                "{result} = call {return_type} {function_ptr}({})\n",
+                call_args.join(", ")
+            );
+
+        (code, result)
+    }
+
+    /// # Description
+    ///
+    /// Generates a method call on an object whose static type is a protocol,
+    /// converting the arguments to the protocol's parameter types
+    pub(crate) fn handle_protocol_method_call(
+        &mut self,
+        mut preamble: String,
+        object_handle: LlvmHandle,
+        protocol: String,
+        node: &mut ast::FunctionMemberAccess,
+    ) -> VisitorResult {
+        let (coercion, interface) =
+            self.coerce_handle(object_handle, &LlvmType::Interface(protocol.clone()));
+        preamble += &coercion;
+
+        let method = node.member.identifier.id.clone();
+        let (_, signature) = self.protocol_method_signature(&protocol, &method);
+        let (params_types, _) = self.protocol_method_llvm_types(signature);
+        let return_type = signature.identifier.info.ty.clone();
+
+        let mut args = Vec::new();
+        for (arg, param_type) in node.member.arguments.iter_mut().zip(params_types.iter()) {
+            let arg_result = arg.accept(self);
+            preamble += &arg_result.preamble;
+            let arg_handle = arg_result
+                .result_handle
+                .expect("Function member argument must have a result");
+            let (coercion, value) = self.coerce_value(
+                &arg_handle.llvm_name,
+                &arg_handle.handle_type.inner_type().llvm_type_str(),
+                param_type,
+            );
+            preamble += &coercion;
+            args.push(format!("{param_type} {value}"));
+        }
+
+        let (call_code, result) =
+            self.call_protocol_method(&interface.llvm_name, &protocol, &method, args);
+        preamble += &call_code;
+
+        let result_type = match &return_type {
+            Some(ty) => self.llvm_type_from_ast_type(ty),
+            None => LlvmType::Object,
+        };
+
+        VisitorResult {
+            preamble,
+            result_handle: Some(LlvmHandle::new(
+                crate::llvm_types::HandleType::Register(result_type),
+                result,
+            )),
+        }
+    }
+}
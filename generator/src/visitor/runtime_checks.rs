@@ -0,0 +1,163 @@
+use super::GeneratorVisitor;
+
+pub const INDEX_OUT_OF_BOUNDS_MESSAGE: &str = "List index out of bounds";
+pub const NON_INTEGER_INDEX_MESSAGE: &str = "List index is not an integer";
+pub const DIVISION_BY_ZERO_MESSAGE: &str = "Division by zero";
+
+const RUNTIME_ERROR_FORMAT: &str = "Runtime Error: %s\n --> %s\n";
+
+/// Escapes `text` for an LLVM `c"..."` constant. Quotes are escaped as well,
+/// the IR is piped through a shell in the tests
+fn llvm_escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for b in text.bytes() {
+        match b {
+            0x20..=0x21 | 0x23..=0x26 | 0x28..=0x5B | 0x5D..=0x7E => escaped.push(b as char),
+            _ => escaped.push_str(&format!("\\{:02X}", b)),
+        }
+    }
+    escaped
+}
+
+fn constant_definition(name: &str, text: &str) -> String {
+    format!(
+        "@{name} = private unnamed_addr constant [{} x i8] c\"{}\\00\", align 1\n",
+        text.len() + 1,
+        llvm_escape(text)
+    )
+}
+
+fn constant_pointer(name: &str, text: &str) -> String {
+    let len = text.len() + 1;
+    format!("i8* getelementptr inbounds ([{len} x i8], [{len} x i8]* @{name}, i32 0, i32 0)")
+}
+
+impl GeneratorVisitor {
+    /// # Description
+    ///
+    /// Defines `@hulk_runtime_error(i8* message, i8* location)`, which reports a
+    /// failed runtime check on the standard error and exits with status 1, along
+    /// with the messages of the checks. It expects `fprintf`, `exit` and `@stderr`
+    /// to be declared by `instantiate_global_print_helpers`
+    pub fn instantiate_runtime_checks(&self) -> String {
+        let mut runtime = constant_definition(".runtime_error_fmt", RUNTIME_ERROR_FORMAT);
+        runtime += &constant_definition(".index_out_of_bounds", INDEX_OUT_OF_BOUNDS_MESSAGE);
+        runtime += &constant_definition(".non_integer_index", NON_INTEGER_INDEX_MESSAGE);
+        runtime += &constant_definition(".division_by_zero", DIVISION_BY_ZERO_MESSAGE);
+
+        runtime
+            + &format!(
+                "
+define void @hulk_runtime_error(i8* %message, i8* %location) {{
+entry:
+  %stderr = load i8*, i8** @stderr, align 8
+  call i32 (i8*, i8*, ...) @fprintf(i8* %stderr, {}, i8* %message, i8* %location)
+  call void @exit(i32 1)
+  unreachable
+}}
+",
+                constant_pointer(".runtime_error_fmt", RUNTIME_ERROR_FORMAT)
+            )
+    }
+
+    /// # Description
+    ///
+    /// Where `position` is in the source code, as shown by runtime errors,
+    /// see `SourceMap::location`. Only the byte offset is known without a
+    /// source map
+    fn source_location(&self, position: usize) -> String {
+        match &self.source_map {
+            Some(source_map) => source_map.location(position),
+            None => format!("position {position}"),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Generates a check that calls `@hulk_runtime_error` with the message
+    /// constant `message_name` (see `instantiate_runtime_checks`) when the i1
+    /// `condition` is false. The code that follows is emitted in a new block
+    pub(crate) fn runtime_check(
+        &mut self,
+        condition: &str,
+        message_name: &str,
+        message: &str,
+        position: usize,
+    ) -> String {
+        let id = self.generate_tmp_variable();
+        let id = &id["%tmp".len()..];
+        let passed_label = format!("check_passed_{id}");
+        let failed_label = format!("check_failed_{id}");
+
+        let location_name = format!(".location_{id}");
+        let location = self.source_location(position);
+        self.string_constants
+            .push(constant_definition(&location_name, &location));
+
+        self.branch_choice_statement(condition, &passed_label, &failed_label)
+            + &self.block_start(&failed_label)
+            + &format!(
+                "call void @hulk_runtime_error({}, {})\n",
+                constant_pointer(message_name, message),
+                constant_pointer(&location_name, &location)
+            )
+            + "unreachable\n"
+            + &self.block_start(&passed_label)
+    }
+
+    /// # Description
+    ///
+    /// Generates the checks of `list[index]`: `index` must be an integer
+    /// within `[0, length)`. `list_ptr` points to the elements, whose length is
+    /// stored as an i64 in the first slot
+    pub(crate) fn list_index_checks(
+        &mut self,
+        list_ptr: &str,
+        elem_type: &str,
+        index: &str,
+        casted_index: &str,
+        position: usize,
+    ) -> String {
+        let truncated = self.generate_tmp_variable();
+        let is_integer = self.generate_tmp_variable();
+        let mut code = format!("{truncated} = sitofp i64 {casted_index} to double\n")
+            + &format!("{is_integer} = fcmp oeq double {truncated}, {index}\n");
+        code += &self.runtime_check(
+            &is_integer,
+            ".non_integer_index",
+            NON_INTEGER_INDEX_MESSAGE,
+            position,
+        );
+
+        let length_ptr = self.generate_tmp_variable();
+        let length = self.generate_tmp_variable();
+        let not_negative = self.generate_tmp_variable();
+        let below_length = self.generate_tmp_variable();
+        let in_bounds = self.generate_tmp_variable();
+        code += &format!("{length_ptr} = bitcast {elem_type}* {list_ptr} to i64*\n");
+        code += &format!("{length} = load i64, i64* {length_ptr}, align 8\n");
+        code += &format!("{not_negative} = icmp sge i64 {casted_index}, 0\n");
+        code += &format!("{below_length} = icmp slt i64 {casted_index}, {length}\n");
+        code += &format!("{in_bounds} = and i1 {not_negative}, {below_length}\n");
+        code + &self.runtime_check(
+            &in_bounds,
+            ".index_out_of_bounds",
+            INDEX_OUT_OF_BOUNDS_MESSAGE,
+            position,
+        )
+    }
+
+    /// # Description
+    ///
+    /// Generates the check that the divisor `rhs` of `/`, `div` or `%` is not zero
+    pub(crate) fn division_check(&mut self, rhs: &str, position: usize) -> String {
+        let is_not_zero = self.generate_tmp_variable();
+        format!("{is_not_zero} = fcmp une double {rhs}, 0.0\n")
+            + &self.runtime_check(
+                &is_not_zero,
+                ".division_by_zero",
+                DIVISION_BY_ZERO_MESSAGE,
+                position,
+            )
+    }
+}
@@ -108,6 +108,10 @@ pub fn generate_vtable_type(visitor: &mut GeneratorVisitor, node: &mut ast::Type
                     visitor
                         .original_type_for_definition
                         .insert((type_name.clone(), method_name.clone()), type_name.clone());
+                    visitor.function_member_signature_types.insert(
+                        (type_name.clone(), method_name.clone()),
+                        ret_type_str.clone(),
+                    );
                 } else {
                     // If not overridden, copy the parent's vtable entry
                     let original_type_for_def = visitor
@@ -343,6 +347,13 @@ pub fn generate_object_struct_type(
         preamble += &format!("  {}\n", field_llvm_types_str.join(",\n  "));
     }
     preamble += "}\n\n";
+    // The pointer map the collector traces the objects of this type with
+    let (gc_map, _) = visitor.gc_struct_map(
+        &format!("{}_gc_map", type_name),
+        &format!("%{}_type", type_name),
+        &visitor.gc_type_fields(type_name),
+    );
+    preamble += &gc_map;
     preamble
 }
 
@@ -376,6 +387,8 @@ pub fn generate_constructor(visitor: &mut GeneratorVisitor, node: &mut ast::Type
         .insert(type_name.clone(), ctor_param_types);
     preamble += &format!("{}) {{\n", ctor_param_defs.join(", "));
     preamble += "entry:\n";
+    let (gc_save, gc_frame) = visitor.gc_save();
+    preamble += &gc_save;
 
     // Set up a new context frame for constructor parameters
     let old_context = std::mem::replace(
@@ -399,6 +412,7 @@ pub fn generate_constructor(visitor: &mut GeneratorVisitor, node: &mut ast::Type
             &param_alloca,
             &llvm_param_type_enum,
         );
+        preamble += &visitor.gc_root_slot(&param_alloca, &llvm_param_type_enum);
         // Define the parameter in the context for later lookup
         match llvm_param_type_enum {
             LlvmType::F64 => visitor
@@ -415,34 +429,31 @@ pub fn generate_constructor(visitor: &mut GeneratorVisitor, node: &mut ast::Type
                 param_name,
                 crate::visitor::Variable::new_object(param_alloca),
             ),
-            _ => panic!("Invalid LLVM type for constructor parameter"),
+            LlvmType::List(inner) => visitor.context.define(
+                param_name,
+                crate::visitor::Variable::new_list_with_inner(param_alloca, *inner),
+            ),
+            LlvmType::Interface(protocol) => visitor.context.define(
+                param_name,
+                crate::visitor::Variable::new_interface(param_alloca, protocol),
+            ),
         }
     }
 
-    // Collect all type members for this type (including inherited ones)
-    let mut field_llvm_types_str: Vec<String> = Vec::new();
-    let type_members: Vec<(String, String)> = visitor
-        .type_members_types
-        .iter()
-        .filter(|((name, _), _)| name == type_name)
-        .map(|((_, member_name), member_type)| (member_name.clone(), member_type.clone()))
-        .collect();
-    for (_member_name, member_type) in type_members {
-        // println!("member_name: {} type {:?}", member_name, member_type);
-        field_llvm_types_str.push(member_type);
-    }
-
-    // Calculate the approximate struct size (8 bytes for vtable pointer + sum of field sizes)
-    let struct_size_bytes = 8 + field_llvm_types_str
-        .iter()
-        .map(|t| visitor.llvm_type_size(t))
-        .sum::<usize>();
+    // The size of the struct as laid out by llvm, and the pointer map of its fields
+    // (including the inherited ones)
+    let struct_size_bytes = format!(
+        "ptrtoint (%{0}_type* getelementptr (%{0}_type, %{0}_type* null, i32 1) to i64)",
+        type_name
+    );
+    let (_, gc_map) = visitor.gc_struct_map(
+        &format!("{}_gc_map", type_name),
+        &format!("%{}_type", type_name),
+        &visitor.gc_type_fields(type_name),
+    );
     // Allocate memory for the object
     let obj_raw_ptr = visitor.generate_tmp_variable();
-    preamble += &format!(
-        "  {} = call i8* @malloc(i64 {}) ; Approx size\n",
-        obj_raw_ptr, struct_size_bytes
-    );
+    preamble += &visitor.gc_alloc_statement(&obj_raw_ptr, &struct_size_bytes, &gc_map);
     // Cast the raw pointer to the typed object pointer
     let obj_typed_ptr = visitor.generate_tmp_variable();
     preamble += &format!(
@@ -483,7 +494,13 @@ pub fn generate_constructor(visitor: &mut GeneratorVisitor, node: &mut ast::Type
                 .cloned()
                 .unwrap_or_else(|| "i8*".to_string());
             if let Some(handle) = eval_result.result_handle {
-                parent_ctor_call_args.push(format!("{} {}", parent_arg_type, handle.llvm_name));
+                let (coercion, value) = visitor.coerce_value(
+                    &handle.llvm_name,
+                    &handle.handle_type.inner_type().llvm_type_str(),
+                    &parent_arg_type,
+                );
+                preamble += &coercion;
+                parent_ctor_call_args.push(format!("{} {}", parent_arg_type, value));
             } else {
                 // eprintln!("Warning: Parent constructor argument {} has no result handle", i);
                 parent_ctor_call_args.push(format!("{} null", parent_arg_type));
@@ -595,15 +612,24 @@ pub fn generate_constructor(visitor: &mut GeneratorVisitor, node: &mut ast::Type
             .expect(&format!(
                 "Member type for {}.{} not found",
                 type_name, data_member.identifier.id
-            ));
+            ))
+            .clone();
+        let (coercion, default_value) = visitor.coerce_value(
+            &default_handle.llvm_name,
+            &default_handle.handle_type.inner_type().llvm_type_str(),
+            &member_type,
+        );
+        preamble += &coercion;
         preamble += &format!(
             "  store {} {}, {}* {}, align 8\n",
-            member_type, default_handle.llvm_name, member_type, child_member_ptr
+            member_type, default_value, member_type, child_member_ptr
         );
     }
     // Restore the previous context frame
     let _ = std::mem::replace(&mut visitor.context, old_context);
     // Return the constructed object pointer
+    preamble +=
+        &visitor.gc_leave_function(&gc_frame, &obj_typed_ptr, &format!("%{}_type*", type_name));
     preamble += &format!("  ret %{}_type* {}\n", type_name, obj_typed_ptr);
     preamble += "}\n\n";
     preamble
@@ -654,6 +680,8 @@ pub fn generate_method_definitions(
             method_param_defs.join(", ")
         );
         preamble += "entry:\n";
+        let (gc_save, gc_frame) = visitor.gc_save();
+        preamble += &gc_save;
 
         // Set up a new context frame for method parameters
         let old_context = std::mem::replace(
@@ -661,13 +689,16 @@ pub fn generate_method_definitions(
             crate::context::Context::new_one_frame(),
         );
 
-        // Allocate and store the self pointer - FIXED: Use correct type
-        let self_alloca = visitor.generate_tmp_variable();
-        preamble += &format!("  {} = alloca %{}_type*, align 8\n", self_alloca, type_name);
+        // Allocate and store the self pointer, as an object it is kept as an i8*
+        let self_object = visitor.generate_tmp_variable();
         preamble += &format!(
-            "  store %{}_type* %self, %{}_type** {}, align 8\n",
-            type_name, type_name, self_alloca
+            "  {} = bitcast %{}_type* %self to i8*\n",
+            self_object, type_name
         );
+        let self_alloca = visitor.generate_tmp_variable();
+        preamble += &visitor.alloca_statement(&self_alloca, &LlvmType::Object);
+        preamble += &visitor.store_statement(&self_object, &self_alloca, &LlvmType::Object);
+        preamble += &visitor.gc_root_slot(&self_alloca, &LlvmType::Object);
 
         visitor.context.define(
             "self".to_string(),
@@ -691,6 +722,7 @@ pub fn generate_method_definitions(
                 &param_alloca,
                 &llvm_param_type_enum,
             );
+            preamble += &visitor.gc_root_slot(&param_alloca, &llvm_param_type_enum);
 
             // Define the parameter in the context for later lookup
             match llvm_param_type_enum {
@@ -708,7 +740,14 @@ pub fn generate_method_definitions(
                     param_name,
                     crate::visitor::Variable::new_object(param_alloca),
                 ),
-                _ => panic!("Invalid LLVM type for method parameter"),
+                LlvmType::List(inner) => visitor.context.define(
+                    param_name,
+                    crate::visitor::Variable::new_list_with_inner(param_alloca, *inner),
+                ),
+                LlvmType::Interface(protocol) => visitor.context.define(
+                    param_name,
+                    crate::visitor::Variable::new_interface(param_alloca, protocol),
+                ),
             }
         }
 
@@ -722,39 +761,16 @@ pub fn generate_method_definitions(
         // Emit the return statement - FIXED: Handle return values correctly
         if ret_type_str != "void" {
             if let Some(res_handle) = body_result.result_handle {
-                // If the return type is i8* and the result is a register (pointer), emit a load
-                if ret_type_str == "i8*" {
-                    use crate::llvm_types::{HandleType, LlvmType};
-                    match &res_handle.handle_type {
-                        HandleType::Register(LlvmType::Object) => {
-                            let load_var = visitor.generate_tmp_variable();
-                            preamble += &format!(
-                                "  {} = load i8*, i8** {}, align 8\n  ret i8* {}\n",
-                                load_var, res_handle.llvm_name, load_var
-                            );
-                        }
-                        HandleType::Register(LlvmType::String) => {
-                            let load_var = visitor.generate_tmp_variable();
-                            preamble += &format!(
-                                "  {} = load i8*, i8** {}, align 8\n  ret i8* {}\n",
-                                load_var, res_handle.llvm_name, load_var
-                            );
-                        }
-                        HandleType::Register(LlvmType::List(inner)) => {
-                            let load_var = visitor.generate_tmp_variable();
-                            preamble += &format!(
-                                "  {} = load {}*, {}** {}, align 8\n  ret {}* {}\n",
-                                load_var,inner.llvm_type_str(),inner.llvm_type_str(), res_handle.llvm_name,inner.llvm_type_str(), load_var
-                            );
-                        }
-                        _ => {
-                            preamble += &format!("  ret {} {}\n", ret_type_str, res_handle.llvm_name);
-                        }
-                    }
-                } else {
-                    preamble += &format!("  ret {} {}\n", ret_type_str, res_handle.llvm_name);
-                }
+                let (coercion, value) = visitor.coerce_value(
+                    &res_handle.llvm_name,
+                    &res_handle.handle_type.inner_type().llvm_type_str(),
+                    &ret_type_str,
+                );
+                preamble += &coercion;
+                preamble += &visitor.gc_leave_function(&gc_frame, &value, &ret_type_str);
+                preamble += &format!("  ret {} {}\n", ret_type_str, value);
             } else {
+                preamble += &visitor.gc_restore(&gc_frame);
                 // If no return value is produced, emit a default value for the return type
                 let default_ret_val = match ret_type_str.as_str() {
                     "double" => "0.0",
@@ -765,6 +781,7 @@ pub fn generate_method_definitions(
                 preamble += &format!("  ret {} {}\n", ret_type_str, default_ret_val);
             }
         } else {
+            preamble += &visitor.gc_restore(&gc_frame);
             preamble += "  ret void\n";
         }
         preamble += "}\n\n";
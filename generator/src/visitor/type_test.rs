@@ -0,0 +1,311 @@
+use ast::{
+    AsExpr, IsExpr, VisitableExpression,
+    typing::{BuiltInType, Type},
+};
+
+use super::{GeneratorVisitor, VisitorResult};
+use crate::llvm_types::{HandleType, LlvmHandle, LlvmType};
+
+/// The runtime information of a type: its id, the information of its parent
+/// (null for types that only inherit from `Object`) and its name
+const TYPE_INFO_TYPE: &str = "%type_info = type { i32, %type_info*, i8* }";
+
+const CAST_ERROR_MESSAGE: &str = "Runtime Error: Cannot cast `%s` to `%s`.\n";
+
+impl GeneratorVisitor {
+    /// The defined types, sorted by name, the id of a type is its position plus one
+    fn sorted_type_names(&self) -> Vec<String> {
+        let mut type_names: Vec<String> = self.constructor_args_types.keys().cloned().collect();
+        type_names.sort();
+        type_names
+    }
+
+    fn type_id(&self, type_name: &str) -> usize {
+        self.sorted_type_names()
+            .iter()
+            .position(|name| name == type_name)
+            .unwrap_or_else(|| panic!("Type {} not found, SA should have caught this", type_name))
+            + 1
+    }
+
+    /// Returns a constant expression pointing to the name of `type_name`, see
+    /// `generate_type_infos`
+    fn type_name_ptr(type_name: &str) -> String {
+        let len = type_name.len() + 1;
+        format!(
+            "getelementptr inbounds ([{len} x i8], [{len} x i8]* @{type_name}_type_name, i32 0, i32 0)"
+        )
+    }
+
+    /// # Description
+    ///
+    /// Emits the runtime type information used by `is` and `as`:
+    /// - `@T_type_info`, a `%type_info` for each type `T`, linked to the one of its parent
+    /// - `@T_type_name`, the name of every type, protocol and builtin type
+    /// - `@type_info_of`, which finds the type information of an object by comparing its
+    ///   vtable with the vtable of each type
+    /// - `@type_name_of`, the name of the dynamic type of an object
+    /// - `@conforms_to_type`, which walks the `inherits` chain of an object's type
+    ///   comparing the type ids with the id of the target type
+    /// - `@downcast_failed`, which reports a failed `as` and aborts the program
+    pub(crate) fn generate_type_infos(&self) -> String {
+        let type_names = self.sorted_type_names();
+        let mut names: Vec<String> = type_names.clone();
+        names.extend(self.protocols.keys().cloned());
+        names.extend(
+            [
+                BuiltInType::Object,
+                BuiltInType::Number,
+                BuiltInType::String,
+                BuiltInType::Bool,
+            ]
+            .iter()
+            .map(|ty| Type::BuiltIn(ty.clone()).to_string()),
+        );
+
+        let mut code = format!("{TYPE_INFO_TYPE}\n\n");
+        for name in &names {
+            code += &format!(
+                "@{name}_type_name = private unnamed_addr constant [{} x i8] c\"{name}\\00\", align 1\n",
+                name.len() + 1
+            );
+        }
+        code += "\n";
+
+        for (i, type_name) in type_names.iter().enumerate() {
+            let parent = match self.inherits.get(type_name) {
+                Some(parent) if type_names.contains(parent) => format!("@{parent}_type_info"),
+                _ => "null".to_string(),
+            };
+            code += &format!(
+                "@{type_name}_type_info = private unnamed_addr constant %type_info {{ i32 {}, %type_info* {parent}, i8* {} }}, align 8\n",
+                i + 1,
+                Self::type_name_ptr(type_name)
+            );
+        }
+        code += "\n";
+
+        code += "define %type_info* @type_info_of(i8* %object) {\nentry:\n";
+        code += "%vtable_field = bitcast i8* %object to i8**\n";
+        code += "%vtable = load i8*, i8** %vtable_field, align 8\n";
+        code += "br label %check.0\n";
+        for (i, type_name) in type_names.iter().enumerate() {
+            code += &format!("check.{i}:\n");
+            code += &format!(
+                "%is.{i} = icmp eq i8* %vtable, bitcast (%{type_name}_vtable_type* @{type_name}_vtable to i8*)\n"
+            );
+            code += &format!("br i1 %is.{i}, label %found.{i}, label %check.{}\n", i + 1);
+            code += &format!("found.{i}:\n");
+            code += &format!("ret %type_info* @{type_name}_type_info\n");
+        }
+        code += &format!("check.{}:\n", type_names.len());
+        code += "ret %type_info* null\n}\n\n";
+
+        code += &format!(
+            "define i8* @type_name_of(i8* %object) {{
+entry:
+%info = call %type_info* @type_info_of(i8* %object)
+%is_null = icmp eq %type_info* %info, null
+br i1 %is_null, label %object_name, label %type_name
+object_name:
+ret i8* {}
+type_name:
+%name_field = getelementptr inbounds %type_info, %type_info* %info, i32 0, i32 2
+%name = load i8*, i8** %name_field, align 8
+ret i8* %name
+}}
+
+",
+            Self::type_name_ptr(&Type::BuiltIn(BuiltInType::Object).to_string())
+        );
+
+        code += "define i1 @conforms_to_type(i8* %object, i32 %target) {
+entry:
+%info = call %type_info* @type_info_of(i8* %object)
+br label %loop
+loop:
+%current = phi %type_info* [ %info, %entry ], [ %parent, %next ]
+%is_null = icmp eq %type_info* %current, null
+br i1 %is_null, label %no, label %check
+check:
+%id_field = getelementptr inbounds %type_info, %type_info* %current, i32 0, i32 0
+%id = load i32, i32* %id_field, align 4
+%found = icmp eq i32 %id, %target
+br i1 %found, label %yes, label %next
+next:
+%parent_field = getelementptr inbounds %type_info, %type_info* %current, i32 0, i32 1
+%parent = load %type_info*, %type_info** %parent_field, align 8
+br label %loop
+yes:
+ret i1 true
+no:
+ret i1 false
+}
+
+";
+
+        let message = CAST_ERROR_MESSAGE.replace('\n', "\\0A");
+        let message_len = CAST_ERROR_MESSAGE.len() + 1;
+        code += &format!(
+            "@.cast_error = private unnamed_addr constant [{message_len} x i8] c\"{message}\\00\", align 1
+
+define void @downcast_failed(i8* %value_type, i8* %target) {{
+entry:
+%stderr = load i8*, i8** @stderr, align 8
+%fmt = getelementptr inbounds [{message_len} x i8], [{message_len} x i8]* @.cast_error, i32 0, i32 0
+call i32 (i8*, i8*, ...) @fprintf(i8* %stderr, i8* %fmt, i8* %value_type, i8* %target)
+call void @exit(i32 1)
+unreachable
+}}
+"
+        );
+
+        code
+    }
+
+    /// Returns the code that takes the object pointer out of `handle`, if it
+    /// holds an object or a protocol value
+    fn object_of_handle(&mut self, handle: &LlvmHandle) -> Option<(String, String)> {
+        match handle.handle_type.inner_type() {
+            LlvmType::Object => Some((String::new(), handle.llvm_name.clone())),
+            interface @ LlvmType::Interface(_) => {
+                Some(self.coerce_value(&handle.llvm_name, &interface.llvm_type_str(), "i8*"))
+            }
+            _ => None,
+        }
+    }
+
+    /// # Description
+    ///
+    /// Generates the test of whether the value in `handle` conforms to `target`.
+    /// Numbers, strings and booleans are not boxed, so testing them (or testing
+    /// an object against them) is decided statically, objects are tested at runtime:
+    /// - against a type, walking the `inherits` chain of the object's type
+    /// - against a protocol, looking up the vtable the object's type uses to implement it
+    ///
+    /// # Returns
+    ///
+    /// The generated code and the `i1` handle holding the result
+    fn generate_type_test(&mut self, handle: &LlvmHandle, target: &Type) -> (String, LlvmHandle) {
+        if let Type::BuiltIn(BuiltInType::Object) = target {
+            return (String::new(), LlvmHandle::new_i1_literal(true));
+        }
+
+        let value_type = handle.handle_type.inner_type();
+        match (self.object_of_handle(handle), target) {
+            (Some((mut code, object)), Type::Defined(name)) => {
+                let result = self.generate_tmp_variable();
+                if self.protocols.contains_key(&name.id) {
+                    let vtable = self.generate_tmp_variable();
+                    code += &format!(
+                        "{vtable} = call %{0}_vtable_type* @{0}_vtable_for(i8* {object})\n",
+                        name.id
+                    );
+                    code += &format!(
+                        "{result} = icmp ne %{}_vtable_type* {vtable}, null\n",
+                        name.id
+                    );
+                } else {
+                    code += &format!(
+                        "{result} = call i1 @conforms_to_type(i8* {object}, i32 {})\n",
+                        self.type_id(&name.id)
+                    );
+                }
+                (code, LlvmHandle::new_i1_register(result))
+            }
+            (None, Type::BuiltIn(builtin)) => {
+                let conforms = matches!(
+                    (value_type, builtin),
+                    (LlvmType::F64, BuiltInType::Number)
+                        | (LlvmType::I1, BuiltInType::Bool)
+                        | (LlvmType::String, BuiltInType::String)
+                );
+                (String::new(), LlvmHandle::new_i1_literal(conforms))
+            }
+            _ => (String::new(), LlvmHandle::new_i1_literal(false)),
+        }
+    }
+
+    pub(crate) fn handle_is_expr(&mut self, node: &mut IsExpr) -> VisitorResult {
+        let inner = node.expression.accept(self);
+        let handle = inner
+            .result_handle
+            .expect("Type tested expression must have a result");
+
+        let (test, result) = self.generate_type_test(&handle, &node.target);
+
+        VisitorResult {
+            preamble: inner.preamble + &test,
+            result_handle: Some(result),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Generates a checked downcast, when the value does not conform to the target
+    /// type the names of its type and the target type are reported through
+    /// `@downcast_failed`, which aborts the program
+    pub(crate) fn handle_as_expr(&mut self, node: &mut AsExpr) -> VisitorResult {
+        let inner = node.expression.accept(self);
+        let handle = inner
+            .result_handle
+            .expect("Downcast expression must have a result");
+        let mut preamble = inner.preamble;
+
+        let (test, conforms) = self.generate_type_test(&handle, &node.target);
+        preamble += &test;
+
+        let ok_label = format!("cast.ok.{}", self.tmp_variable_id);
+        let fail_label = format!("cast.fail.{}", self.tmp_variable_id);
+        self.tmp_variable_id += 1;
+
+        preamble += &format!(
+            "br i1 {}, label %{ok_label}, label %{fail_label}\n",
+            conforms.llvm_name
+        );
+        preamble += &format!("{fail_label}:\n");
+        let value_type_name = match self.object_of_handle(&handle) {
+            Some((code, object)) => {
+                preamble += &code;
+                let name = self.generate_tmp_variable();
+                preamble += &format!("{name} = call i8* @type_name_of(i8* {object})\n");
+                name
+            }
+            None => {
+                let static_type = match &node.obj_type {
+                    Some(ty @ Type::BuiltIn(_)) => ty.to_string(),
+                    _ => Type::BuiltIn(BuiltInType::Object).to_string(),
+                };
+                Self::type_name_ptr(&static_type)
+            }
+        };
+        preamble += &format!(
+            "call void @downcast_failed(i8* {value_type_name}, i8* {})\n",
+            Self::type_name_ptr(&node.target.to_string())
+        );
+        preamble += "unreachable\n";
+        preamble += &format!("{ok_label}:\n");
+
+        let target_type = self.llvm_type_from_ast_type(&node.target);
+        let value_type = handle.handle_type.inner_type();
+        let result_handle = match (&target_type, &value_type) {
+            (LlvmType::Object, LlvmType::Interface(_))
+            | (LlvmType::Interface(_), LlvmType::Object | LlvmType::Interface(_)) => {
+                let (code, result) = self.coerce_handle(handle, &target_type);
+                preamble += &code;
+                result
+            }
+            (LlvmType::Object, _)
+            | (LlvmType::F64, LlvmType::F64)
+            | (LlvmType::I1, LlvmType::I1)
+            | (LlvmType::String, LlvmType::String) => handle,
+            // the downcast always fails, so the value is never used
+            _ => LlvmHandle::new(HandleType::Literal(target_type), "undef".to_string()),
+        };
+
+        VisitorResult {
+            preamble,
+            result_handle: Some(result_handle),
+        }
+    }
+}
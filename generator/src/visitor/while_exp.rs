@@ -12,9 +12,13 @@ impl GeneratorVisitor {
 
         let (loop_label, body_label, loop_exit_label) = self.generate_loop_labels();
 
+        // the roots registered by an iteration are dropped when it ends
+        let (gc_save, gc_frame) = self.gc_save();
+
         // here we assume the type of the handle returned by the condition is i1, SA is
         // responsible for this
-        let loop_setup = self.branch_jump_statement(&loop_label)
+        let loop_setup = gc_save
+            + &self.branch_jump_statement(&loop_label)
             + &self.block_start(&loop_label)
             + &condition_result.preamble
             + &self.branch_choice_statement(
@@ -25,9 +29,10 @@ impl GeneratorVisitor {
 
         let body_code = self.block_start(&body_label)
             + &body_result.preamble
+            + &self.gc_restore(&gc_frame)
             + &self.branch_jump_statement(&loop_label);
 
-        let exit_code = self.block_start(&loop_exit_label);
+        let exit_code = self.block_start(&loop_exit_label) + &self.gc_restore(&gc_frame);
 
         let preamble = loop_setup + &body_code + &exit_code;
 
@@ -0,0 +1,104 @@
+use std::io::{BufRead, Write};
+
+use generator::builtins::Builtin;
+
+use crate::{
+    interpreter::{Evaluation, Interpreter},
+    value::Value,
+};
+
+fn number(value: &Value) -> f64 {
+    match value {
+        Value::Number(number) => *number,
+        value => panic!(
+            "Expected a number, found {}, SA should have caught this",
+            value
+        ),
+    }
+}
+
+fn string(value: &Value) -> &str {
+    match value {
+        Value::String(string) => string,
+        value => panic!(
+            "Expected a string, found {}, SA should have caught this",
+            value
+        ),
+    }
+}
+
+/// # Description
+///
+/// `count` bytes of `text` from `start`, both clamped to the bounds of `text`
+fn substring(text: &str, start: f64, count: f64) -> String {
+    let bytes = text.as_bytes();
+    let first = (start as i64).clamp(0, bytes.len() as i64) as usize;
+    let available = bytes.len() - first;
+    let size = (count as i64).clamp(0, available as i64) as usize;
+    String::from_utf8_lossy(&bytes[first..first + size]).into_owned()
+}
+
+/// # Description
+///
+/// The longest prefix of `text` that is a number, after its leading white
+/// space, like C's `strtod`. `0` when there is none
+fn parse_prefix(text: &str) -> f64 {
+    let text = text.trim_start();
+    (1..=text.len())
+        .rev()
+        .filter(|end| text.is_char_boundary(*end))
+        .find_map(|end| text[..end].parse::<f64>().ok())
+        .unwrap_or(0.0)
+}
+
+impl<W: Write> Interpreter<W> {
+    /// # Description
+    ///
+    /// Calls `builtin` with arguments of the types it expects. `print`
+    /// writes its argument followed by a line break and returns it
+    pub(crate) fn call_builtin(&mut self, builtin: &Builtin, arguments: Vec<Value>) -> Evaluation {
+        let value = match (builtin.name, arguments.as_slice()) {
+            ("print", [value]) => {
+                let _ = writeln!(self.output, "{}", value);
+                value.clone()
+            }
+            ("sqrt", [x]) => Value::Number(number(x).sqrt()),
+            ("sin", [x]) => Value::Number(number(x).sin()),
+            ("cos", [x]) => Value::Number(number(x).cos()),
+            ("exp", [x]) => Value::Number(number(x).exp()),
+            ("log", [x]) => Value::Number(number(x).ln()),
+            ("floor", [x]) => Value::Number(number(x).floor()),
+            ("ceil", [x]) => Value::Number(number(x).ceil()),
+            ("rand", []) => Value::Number(self.next_random()),
+            ("length", [s]) => Value::Number(string(s).len() as f64),
+            ("substring", [s, start, count]) => {
+                Value::string(&substring(string(s), number(start), number(count)))
+            }
+            ("parse", [s]) => Value::Number(parse_prefix(string(s))),
+            ("read_line", []) => {
+                // what is printed before must be seen before waiting for the input
+                let _ = self.output.flush();
+                let mut line = String::new();
+                let _ = std::io::stdin().lock().read_line(&mut line);
+                Value::string(line.strip_suffix('\n').unwrap_or(&line))
+            }
+            (name, arguments) => panic!(
+                "Builtin {} called with {} arguments, SA should have caught this",
+                name,
+                arguments.len()
+            ),
+        };
+
+        Ok(value)
+    }
+
+    /// A pseudo random number in `[0, 1)`, the sequence is the same in
+    /// every run (xorshift64*)
+    fn next_random(&mut self) -> f64 {
+        self.random_state ^= self.random_state >> 12;
+        self.random_state ^= self.random_state << 25;
+        self.random_state ^= self.random_state >> 27;
+        let random = self.random_state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        (random >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
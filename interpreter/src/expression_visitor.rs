@@ -0,0 +1,344 @@
+use std::{cell::RefCell, io::Write, rc::Rc};
+
+use ast::{
+    AsExpr, Assignment, BaseCall, BinOp, Block, BlockBodyItem, BooleanLiteral, DataMemberAccess,
+    DestructiveAssignment, Expression, ExpressionVisitor, For, FunctionCall, FunctionMemberAccess,
+    Identifier, IfElse, IsExpr, Lambda, LetIn, ListIndexing, ListLiteral, NewExpr, NumberLiteral,
+    ReturnStatement, StringLiteral, UnOp, UnaryOperator, VisitableExpression, While,
+    token_position::TokenPositionTrait,
+    typing::{BuiltInType, Type},
+};
+use generator::builtins::find_builtin;
+
+use crate::{
+    interpreter::{Evaluation, Interpreter, Unwind},
+    runtime_error::{INDEX_OUT_OF_BOUNDS_MESSAGE, NON_INTEGER_INDEX_MESSAGE, RuntimeError},
+    value::{Closure, Value},
+};
+
+pub(crate) const ITERATOR_NEXT_METHOD: &str = "next";
+pub(crate) const ITERATOR_CURRENT_METHOD: &str = "current";
+
+impl<W: Write> Interpreter<W> {
+    pub(crate) fn boolean(&mut self, expression: &mut Expression) -> Result<bool, Unwind> {
+        match expression.accept(self)? {
+            Value::Boolean(value) => Ok(value),
+            value => panic!(
+                "Expected a boolean, found {}, SA should have caught this",
+                value
+            ),
+        }
+    }
+
+    /// # Description
+    ///
+    /// The position of `index` in a list of `length` elements
+    ///
+    /// # Errors
+    ///
+    /// If `index` is not an integer within `[0, length)`, reported at `position`
+    fn list_position(&self, index: Value, length: usize, position: usize) -> Result<usize, Unwind> {
+        let Value::Number(index) = index else {
+            panic!("Expected a number index, SA should have caught this")
+        };
+        if index.trunc() != index {
+            return Err(self.check_failed(NON_INTEGER_INDEX_MESSAGE, position));
+        }
+        if index < 0.0 || index >= length as f64 {
+            return Err(self.check_failed(INDEX_OUT_OF_BOUNDS_MESSAGE, position));
+        }
+        Ok(index as usize)
+    }
+
+    /// Whether `value` has the type `target`, for `is` and `as`
+    fn conforms(&self, value: &Value, target: &Type) -> bool {
+        match (target, value) {
+            (Type::BuiltIn(BuiltInType::Object), _) => true,
+            (Type::BuiltIn(BuiltInType::Number), Value::Number(_))
+            | (Type::BuiltIn(BuiltInType::Bool), Value::Boolean(_))
+            | (Type::BuiltIn(BuiltInType::String), Value::String(_))
+            | (Type::Iterable(_), Value::List(_))
+            | (Type::Functor(_), Value::Function(_)) => true,
+            (Type::Defined(name), Value::Object(object)) if self.is_protocol(&name.id) => {
+                self.implements(&object.type_name, &name.id)
+            }
+            (Type::Defined(name), Value::Object(object)) => {
+                self.inherits_from(&object.type_name, &name.id)
+            }
+            _ => false,
+        }
+    }
+
+    /// Runs `body` with `element` bound to `value` in a new frame
+    fn iterate(&mut self, element: &str, value: Value, body: &mut Block) -> Result<(), Unwind> {
+        self.in_frame(true, |this| {
+            this.context.define(element.to_string(), value);
+            this.visit_block(body).map(|_| ())
+        })
+    }
+}
+
+impl<W: Write> ExpressionVisitor<Evaluation> for Interpreter<W> {
+    fn visit_expression(&mut self, node: &mut Expression) -> Evaluation {
+        node.accept(self)
+    }
+
+    fn visit_destructive_assignment(&mut self, node: &mut DestructiveAssignment) -> Evaluation {
+        let value = node.rhs.accept(self)?;
+
+        match node.lhs.as_mut() {
+            Expression::Variable(identifier) => {
+                let variable = self
+                    .context
+                    .get_value_mut(&identifier.id)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Variable {} not found, SA should have caught this",
+                            identifier.id
+                        )
+                    });
+                *variable = value.clone();
+            }
+            Expression::DataMemberAccess(access) => {
+                let Value::Object(object) = access.object.accept(self)? else {
+                    panic!("Field assigned on a non object, SA should have caught this")
+                };
+                object
+                    .fields
+                    .borrow_mut()
+                    .insert(access.member.id.clone(), value.clone());
+            }
+            Expression::ListIndexing(indexing) => {
+                let Value::List(elements) = indexing.list.accept(self)? else {
+                    panic!("Indexing a non list, SA should have caught this")
+                };
+                let index = indexing.index.accept(self)?;
+                let length = elements.borrow().len();
+                let position = self.list_position(index, length, indexing.open_brace.position())?;
+                elements.borrow_mut()[position] = value.clone();
+            }
+            _ => panic!("Invalid assignment target, SA should have caught this"),
+        }
+
+        Ok(value)
+    }
+
+    fn visit_bin_op(&mut self, node: &mut BinOp) -> Evaluation {
+        let lhs = node.lhs.accept(self)?;
+        let rhs = node.rhs.accept(self)?;
+        self.binary_operation(&node.op, lhs, rhs)
+    }
+
+    fn visit_let_in(&mut self, node: &mut LetIn) -> Evaluation {
+        self.in_frame(true, |this| {
+            this.visit_assignment(&mut node.assignment)?;
+            node.body.accept(this)
+        })
+    }
+
+    fn visit_new_expr(&mut self, node: &mut NewExpr) -> Evaluation {
+        let arguments = self.evaluate_all(&mut node.arguments)?;
+        self.instantiate(&node.type_name, arguments)
+    }
+
+    fn visit_assignment(&mut self, node: &mut Assignment) -> Evaluation {
+        let value = node.rhs.accept(self)?;
+        self.context
+            .define(node.identifier.id.clone(), value.clone());
+        Ok(value)
+    }
+
+    fn visit_if_else(&mut self, node: &mut IfElse) -> Evaluation {
+        if self.boolean(&mut node.condition)? {
+            node.then_expression.accept(self)
+        } else {
+            node.else_expression.accept(self)
+        }
+    }
+
+    fn visit_while(&mut self, node: &mut While) -> Evaluation {
+        while self.boolean(&mut node.condition)? {
+            self.visit_block(&mut node.body)?;
+        }
+        Ok(Value::None)
+    }
+
+    fn visit_for(&mut self, node: &mut For) -> Evaluation {
+        let iterable = node.iterable.accept(self)?;
+
+        match iterable {
+            Value::List(elements) => {
+                // the elements the list has when the loop starts
+                let elements = elements.borrow().clone();
+                for element in elements {
+                    self.iterate(&node.element.id, element, &mut node.body)?;
+                }
+            }
+            iterator @ Value::Object(_) => loop {
+                let next = self.call_method(iterator.clone(), ITERATOR_NEXT_METHOD, Vec::new())?;
+                if !matches!(next, Value::Boolean(true)) {
+                    break;
+                }
+                let current =
+                    self.call_method(iterator.clone(), ITERATOR_CURRENT_METHOD, Vec::new())?;
+                self.iterate(&node.element.id, current, &mut node.body)?;
+            },
+            value => panic!("Iterating over {}, SA should have caught this", value),
+        }
+
+        Ok(Value::None)
+    }
+
+    fn visit_block(&mut self, node: &mut Block) -> Evaluation {
+        let mut last = Value::None;
+        for item in &mut node.body_items {
+            last = match item {
+                BlockBodyItem::Expression(expression) => expression.accept(self)?,
+                BlockBodyItem::ReturnStatement(return_statement) => {
+                    self.visit_return_statement(return_statement)?
+                }
+            };
+        }
+
+        if node.multiple_semicolon_terminated {
+            Ok(Value::None)
+        } else {
+            Ok(last)
+        }
+    }
+
+    fn visit_return_statement(&mut self, node: &mut ReturnStatement) -> Evaluation {
+        let value = node.expression.accept(self)?;
+        Err(Unwind::Return(value))
+    }
+
+    fn visit_un_op(&mut self, node: &mut UnOp) -> Evaluation {
+        let value = node.rhs.accept(self)?;
+        match (node.op, value) {
+            (UnaryOperator::Plus(_), Value::Number(number)) => Ok(Value::Number(number)),
+            (UnaryOperator::Minus(_), Value::Number(number)) => Ok(Value::Number(-number)),
+            (UnaryOperator::Not(_), Value::Boolean(boolean)) => Ok(Value::Boolean(!boolean)),
+            (op, value) => panic!(
+                "Operator {:?} applied to {}, SA should have caught this",
+                op, value
+            ),
+        }
+    }
+
+    fn visit_data_member_access(&mut self, node: &mut DataMemberAccess) -> Evaluation {
+        let Value::Object(object) = node.object.accept(self)? else {
+            panic!("Field accessed on a non object, SA should have caught this")
+        };
+        let value = object.fields.borrow().get(&node.member.id).cloned();
+        Ok(value.unwrap_or_else(|| {
+            panic!(
+                "Field {} not found in {}, SA should have caught this",
+                node.member.id, object.type_name
+            )
+        }))
+    }
+
+    fn visit_function_member_access(&mut self, node: &mut FunctionMemberAccess) -> Evaluation {
+        let receiver = node.object.accept(self)?;
+        let arguments = self.evaluate_all(&mut node.member.arguments)?;
+        self.call_method(receiver, &node.member.identifier.id, arguments)
+    }
+
+    fn visist_list_indexing(&mut self, node: &mut ListIndexing) -> Evaluation {
+        let Value::List(elements) = node.list.accept(self)? else {
+            panic!("Indexing a non list, SA should have caught this")
+        };
+        let index = node.index.accept(self)?;
+        let length = elements.borrow().len();
+        let position = self.list_position(index, length, node.open_brace.position())?;
+        Ok(elements.borrow()[position].clone())
+    }
+
+    fn visit_function_call(&mut self, node: &mut FunctionCall) -> Evaluation {
+        let arguments = self.evaluate_all(&mut node.arguments)?;
+        let name = &node.identifier.id;
+
+        if node.closure_type.is_some() {
+            let Value::Function(closure) = self.lookup(name) else {
+                panic!("{} is not a function, SA should have caught this", name)
+            };
+            return self.call_closure(&closure, arguments);
+        }
+        if self.is_function(name) {
+            return self.call_global_function(name, arguments);
+        }
+        match find_builtin(name) {
+            Some(builtin) => self.call_builtin(builtin, arguments),
+            None => panic!("Function {} not found, SA should have caught this", name),
+        }
+    }
+
+    fn visit_lambda(&mut self, node: &mut Lambda) -> Evaluation {
+        // constants are not copied, they stay visible from the body
+        let environment = node
+            .captures
+            .iter()
+            .filter_map(|capture| {
+                let value = self.context.get_value(&capture.id)?;
+                Some((capture.id.clone(), value.clone()))
+            })
+            .collect();
+
+        Ok(Value::Function(Rc::new(Closure {
+            parameters: node.parameters.iter().map(|p| p.id.clone()).collect(),
+            body: node.body.as_ref().clone(),
+            environment: RefCell::new(environment),
+        })))
+    }
+
+    fn visit_is_expr(&mut self, node: &mut IsExpr) -> Evaluation {
+        let value = node.expression.accept(self)?;
+        Ok(Value::Boolean(self.conforms(&value, &node.target)))
+    }
+
+    fn visit_as_expr(&mut self, node: &mut AsExpr) -> Evaluation {
+        let value = node.expression.accept(self)?;
+        if self.conforms(&value, &node.target) {
+            Ok(value)
+        } else {
+            Err(Unwind::Error(RuntimeError::Cast {
+                value_type: value.type_name(),
+                target: node.target.to_string(),
+            }))
+        }
+    }
+
+    fn visit_base_call(&mut self, node: &mut BaseCall) -> Evaluation {
+        let receiver = self.lookup(&node.receiver.id);
+        let arguments = self.evaluate_all(&mut node.arguments)?;
+        let (Some(method), Some(parent_type)) = (&node.method, &node.parent_type) else {
+            panic!("Base call was not checked, SA should have caught this")
+        };
+        self.call_base(receiver, &parent_type.definition_name(), method, arguments)
+    }
+
+    fn visit_variable(&mut self, node: &mut Identifier) -> Evaluation {
+        Ok(self.lookup(&node.id))
+    }
+
+    fn visit_number_literal(&mut self, node: &mut NumberLiteral) -> Evaluation {
+        Ok(Value::Number(node.value))
+    }
+
+    fn visit_boolean_literal(&mut self, node: &mut BooleanLiteral) -> Evaluation {
+        Ok(Value::Boolean(matches!(node, BooleanLiteral::True(_))))
+    }
+
+    fn visit_string_literal(&mut self, node: &mut StringLiteral) -> Evaluation {
+        Ok(Value::string(&node.string))
+    }
+
+    fn visit_list_literal(&mut self, node: &mut ListLiteral) -> Evaluation {
+        let elements = self.evaluate_all(&mut node.elements)?;
+        Ok(Value::list(elements))
+    }
+
+    fn visit_empty_expression(&mut self) -> Evaluation {
+        Ok(Value::None)
+    }
+}
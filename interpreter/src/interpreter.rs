@@ -0,0 +1,488 @@
+use std::{collections::HashMap, io::Write, rc::Rc};
+
+use ast::{
+    ConstantDef, Definition, DefinitionVisitor, Expression, ExpressionVisitor, FunctionBody,
+    FunctionDef, GlobalFunctionDef, Program, ProtocolDef, TypeDef, VisitableDefinition,
+    VisitableExpression,
+};
+use error_handler::source_map::SourceMap;
+use generator::context::Context;
+
+use crate::{
+    runtime_error::RuntimeError,
+    value::{Closure, Object, Value},
+};
+
+pub(crate) const SELF: &str = "self";
+
+/// # Description
+///
+/// Why the evaluation of an expression stopped before producing its value
+#[derive(Debug)]
+pub(crate) enum Unwind {
+    /// A `return` statement, its value is the result of the function being run
+    Return(Value),
+    Error(RuntimeError),
+}
+
+impl From<RuntimeError> for Unwind {
+    fn from(err: RuntimeError) -> Self {
+        Unwind::Error(err)
+    }
+}
+
+pub(crate) type Evaluation = Result<Value, Unwind>;
+
+/// # Description
+///
+/// What the interpreter keeps of a type definition: the expressions are
+/// evaluated again every time an instance is created
+#[derive(Debug)]
+pub(crate) struct TypeInfo {
+    pub parent: Option<String>,
+    pub parameters: Vec<String>,
+    pub parent_arguments: Vec<Expression>,
+    pub fields: Vec<(String, Expression)>,
+    pub methods: HashMap<String, Rc<FunctionDef>>,
+}
+
+/// # Description
+///
+/// Runs a semantically analyzed program by walking its AST, without
+/// generating any code. It follows the semantics of the generated code, so
+/// the output of both can be compared, and writes what the program prints
+/// to `output`.
+///
+/// Function bodies are cloned before each call: the visitors need mutable
+/// access to the nodes, and a recursive call visits a body that is already
+/// being visited
+pub struct Interpreter<W: Write> {
+    pub(crate) output: W,
+    source_map: Option<SourceMap>,
+    pub(crate) runtime_checks: bool,
+
+    pub(crate) context: Context<Value>,
+    constants: HashMap<String, Value>,
+    functions: HashMap<String, Rc<FunctionDef>>,
+    types: HashMap<String, Rc<TypeInfo>>,
+    /// The protocol each protocol extends, if any, and the methods it adds
+    protocols: HashMap<String, (Option<String>, Vec<String>)>,
+    pub(crate) random_state: u64,
+}
+
+impl<W: Write> Interpreter<W> {
+    pub fn new(output: W) -> Self {
+        Self {
+            output,
+            source_map: None,
+            runtime_checks: true,
+            context: Context::new_one_frame(),
+            constants: HashMap::new(),
+            functions: HashMap::new(),
+            types: HashMap::new(),
+            protocols: HashMap::new(),
+            random_state: 0x2545_F491_4F6C_DD1D,
+        }
+    }
+
+    /// # Description
+    ///
+    /// Shows where runtime errors happened with the file, line and column of
+    /// `source_map` instead of the byte offset
+    pub fn with_source_map(mut self, source_map: SourceMap) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
+    /// # Description
+    ///
+    /// Whether divisions by zero are runtime errors, enabled by default.
+    /// Without the checks they produce infinities and NaNs like the
+    /// unchecked generated code, list indexing is always checked
+    pub fn with_runtime_checks(mut self, enabled: bool) -> Self {
+        self.runtime_checks = enabled;
+        self
+    }
+
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    pub fn into_output(self) -> W {
+        self.output
+    }
+
+    /// # Description
+    ///
+    /// Runs `program`: its definitions are registered first, then the
+    /// constants are evaluated in order, and last the expressions
+    ///
+    /// # Errors
+    ///
+    /// The first runtime error found, the program stops there
+    pub fn run_program(&mut self, program: &mut Program) -> Result<(), RuntimeError> {
+        let result = self.run_definitions_and_expressions(program);
+        let _ = self.output.flush();
+        result
+    }
+
+    fn run_definitions_and_expressions(
+        &mut self,
+        program: &mut Program,
+    ) -> Result<(), RuntimeError> {
+        let (constants, definitions): (Vec<_>, Vec<_>) = program
+            .definitions
+            .iter_mut()
+            .partition(|definition| matches!(definition, Definition::ConstantDef(_)));
+        for definition in definitions.into_iter().chain(constants) {
+            definition.accept(self)?;
+        }
+
+        for expression in &mut program.expressions {
+            match expression.accept(self) {
+                Ok(_) | Err(Unwind::Return(_)) => {}
+                Err(Unwind::Error(err)) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    /// # Description
+    ///
+    /// Where `position` is in the source code, see `SourceMap::location`
+    pub(crate) fn location(&self, position: usize) -> String {
+        match &self.source_map {
+            Some(source_map) => source_map.location(position),
+            None => format!("position {position}"),
+        }
+    }
+
+    pub(crate) fn check_failed(&self, message: &str, position: usize) -> Unwind {
+        Unwind::Error(RuntimeError::check(message, self.location(position)))
+    }
+
+    /// The value of a variable or, if there is none visible, of a constant
+    pub(crate) fn lookup(&self, id: &str) -> Value {
+        self.context
+            .get_value(id)
+            .or_else(|| self.constants.get(id))
+            .cloned()
+            .unwrap_or_else(|| panic!("Variable {} not found, SA should have caught this", id))
+    }
+
+    /// Runs `evaluate` in a new frame, which can see the variables of its
+    /// parents if it is `open`
+    pub(crate) fn in_frame<T>(
+        &mut self,
+        open: bool,
+        evaluate: impl FnOnce(&mut Self) -> Result<T, Unwind>,
+    ) -> Result<T, Unwind> {
+        if open {
+            self.context.push_open_frame();
+        } else {
+            self.context.push_closed_frame();
+        }
+        let result = evaluate(self);
+        self.context.pop_frame();
+        result
+    }
+
+    pub(crate) fn evaluate_all(
+        &mut self,
+        expressions: &mut [Expression],
+    ) -> Result<Vec<Value>, Unwind> {
+        expressions
+            .iter_mut()
+            .map(|expression| expression.accept(self))
+            .collect()
+    }
+
+    pub(crate) fn is_function(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    pub(crate) fn call_global_function(&mut self, name: &str, arguments: Vec<Value>) -> Evaluation {
+        let function = self.functions[name].clone();
+        self.call_function(&function, None, arguments)
+    }
+
+    /// # Description
+    ///
+    /// Calls `function` on a copy of its body, with `self` bound to the
+    /// `receiver` of methods. Only the parameters are visible in its body
+    fn call_function(
+        &mut self,
+        function: &FunctionDef,
+        receiver: Option<Value>,
+        arguments: Vec<Value>,
+    ) -> Evaluation {
+        let mut body = function.body.clone();
+        let result = self.in_frame(false, |this| {
+            if let Some(receiver) = receiver {
+                this.context.define(SELF.to_string(), receiver);
+            }
+            for (parameter, argument) in function.parameters.iter().zip(arguments) {
+                this.context.define(parameter.id.clone(), argument);
+            }
+
+            match &mut body {
+                FunctionBody::ArrowExpression(arrow) => arrow.expression.accept(this),
+                FunctionBody::Block(block) => this.visit_block(block),
+            }
+        });
+        returned(result)
+    }
+
+    /// # Description
+    ///
+    /// Calls the function value `closure`. Its body sees the parameters and
+    /// the captured variables, whose values are stored back in the closure
+    /// when the call ends
+    pub(crate) fn call_closure(&mut self, closure: &Closure, arguments: Vec<Value>) -> Evaluation {
+        let mut body = closure.body.clone();
+        let captured = closure.environment.borrow().clone();
+
+        let result = self.in_frame(false, |this| {
+            for (id, value) in captured {
+                this.context.define(id, value);
+            }
+            for (parameter, argument) in closure.parameters.iter().zip(arguments) {
+                this.context.define(parameter.clone(), argument);
+            }
+
+            let result = body.accept(this);
+            for (id, value) in closure.environment.borrow_mut().iter_mut() {
+                if let Some(current) = this.context.get_value(id) {
+                    *value = current.clone();
+                }
+            }
+            result
+        });
+        returned(result)
+    }
+
+    /// # Description
+    ///
+    /// Calls `method` on `receiver`, the method is looked up starting from
+    /// the dynamic type of the receiver
+    pub(crate) fn call_method(
+        &mut self,
+        receiver: Value,
+        method: &str,
+        arguments: Vec<Value>,
+    ) -> Evaluation {
+        let Value::Object(object) = &receiver else {
+            panic!(
+                "Method {} called on {}, SA should have caught this",
+                method, receiver
+            )
+        };
+        let function = self
+            .find_method(&object.type_name, method)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Method {} not found in {}, SA should have caught this",
+                    method, object.type_name
+                )
+            });
+        self.call_function(&function, Some(receiver), arguments)
+    }
+
+    /// # Description
+    ///
+    /// Calls the implementation `parent_type` has of `method` on `receiver`,
+    /// regardless of the dynamic type of the receiver, as `base` does
+    pub(crate) fn call_base(
+        &mut self,
+        receiver: Value,
+        parent_type: &str,
+        method: &str,
+        arguments: Vec<Value>,
+    ) -> Evaluation {
+        let function = self.find_method(parent_type, method).unwrap_or_else(|| {
+            panic!(
+                "Method {} not found in {}, SA should have caught this",
+                method, parent_type
+            )
+        });
+        self.call_function(&function, Some(receiver), arguments)
+    }
+
+    fn find_method(&self, type_name: &str, method: &str) -> Option<Rc<FunctionDef>> {
+        let mut current = Some(type_name);
+        while let Some(type_name) = current {
+            let info = self.types.get(type_name)?;
+            if let Some(function) = info.methods.get(method) {
+                return Some(function.clone());
+            }
+            current = info.parent.as_deref();
+        }
+        None
+    }
+
+    /// Whether `type_name` is `ancestor` or inherits from it
+    pub(crate) fn inherits_from(&self, type_name: &str, ancestor: &str) -> bool {
+        let mut current = Some(type_name);
+        while let Some(type_name) = current {
+            if type_name == ancestor {
+                return true;
+            }
+            current = self
+                .types
+                .get(type_name)
+                .and_then(|info| info.parent.as_deref());
+        }
+        false
+    }
+
+    pub(crate) fn is_protocol(&self, name: &str) -> bool {
+        self.protocols.contains_key(name)
+    }
+
+    /// Whether `type_name` has every method `protocol` and the protocols it
+    /// extends require
+    pub(crate) fn implements(&self, type_name: &str, protocol: &str) -> bool {
+        let mut current = Some(protocol);
+        while let Some(protocol) = current {
+            let Some((extended, methods)) = self.protocols.get(protocol) else {
+                return true;
+            };
+            if !methods
+                .iter()
+                .all(|method| self.find_method(type_name, method).is_some())
+            {
+                return false;
+            }
+            current = extended.as_deref();
+        }
+        true
+    }
+
+    /// # Description
+    ///
+    /// Creates an instance of `type_name`, running the constructors of its
+    /// ancestors first
+    pub(crate) fn instantiate(&mut self, type_name: &str, arguments: Vec<Value>) -> Evaluation {
+        let object = Rc::new(Object::new(type_name));
+        self.initialize(&object, type_name, arguments)?;
+        Ok(Value::Object(object))
+    }
+
+    /// # Description
+    ///
+    /// Sets the fields `type_name` defines in `object`. The arguments of the
+    /// parent's constructor and the initial values of the fields are
+    /// evaluated with only the parameters of `type_name` visible. A type
+    /// without parameters nor parent arguments passes its arguments to its
+    /// parent
+    fn initialize(
+        &mut self,
+        object: &Rc<Object>,
+        type_name: &str,
+        arguments: Vec<Value>,
+    ) -> Result<(), Unwind> {
+        let Some(info) = self.types.get(type_name).cloned() else {
+            return Ok(());
+        };
+
+        self.in_frame(false, |this| {
+            for (parameter, argument) in info.parameters.iter().zip(arguments.iter()) {
+                this.context.define(parameter.clone(), argument.clone());
+            }
+
+            if let Some(parent) = &info.parent {
+                let parent_arguments =
+                    if info.parameters.is_empty() && info.parent_arguments.is_empty() {
+                        arguments
+                    } else {
+                        this.evaluate_all(&mut info.parent_arguments.clone())?
+                    };
+                this.initialize(object, parent, parent_arguments)?;
+            }
+
+            for (field, initializer) in &info.fields {
+                let value = returned(initializer.clone().accept(this))?;
+                object.fields.borrow_mut().insert(field.clone(), value);
+            }
+            Ok(())
+        })
+    }
+}
+
+/// The result of a function whose body ended with `result`
+fn returned(result: Evaluation) -> Evaluation {
+    match result {
+        Err(Unwind::Return(value)) => Ok(value),
+        result => result,
+    }
+}
+
+impl<W: Write> DefinitionVisitor<Result<(), RuntimeError>> for Interpreter<W> {
+    fn visit_definition(&mut self, node: &mut Definition) -> Result<(), RuntimeError> {
+        node.accept(self)
+    }
+
+    fn visit_type_def(&mut self, node: &mut TypeDef) -> Result<(), RuntimeError> {
+        let (parent, parent_arguments) = match &node.inheritance_indicator {
+            Some(inheritance) => (
+                Some(inheritance.parent_name.id.clone()),
+                inheritance.argument_list.clone(),
+            ),
+            None => (None, Vec::new()),
+        };
+
+        let info = TypeInfo {
+            parent,
+            parameters: node.parameter_list.iter().map(|p| p.id.clone()).collect(),
+            parent_arguments,
+            fields: node
+                .data_member_defs
+                .iter()
+                .map(|field| (field.identifier.id.clone(), field.default_value.clone()))
+                .collect(),
+            methods: node
+                .function_member_defs
+                .iter()
+                .map(|method| (method.identifier.id.clone(), Rc::new(method.clone())))
+                .collect(),
+        };
+        self.types.insert(node.name.id.clone(), Rc::new(info));
+        Ok(())
+    }
+
+    fn visit_function_def(&mut self, node: &mut GlobalFunctionDef) -> Result<(), RuntimeError> {
+        let function = &node.function_def;
+        self.functions
+            .insert(function.identifier.id.clone(), Rc::new(function.clone()));
+        Ok(())
+    }
+
+    fn visit_constant_def(&mut self, node: &mut ConstantDef) -> Result<(), RuntimeError> {
+        let value = self.in_frame(false, |this| {
+            returned(node.initializer_expression.accept(this))
+        });
+        match value {
+            Ok(value) => {
+                self.constants.insert(node.identifier.id.clone(), value);
+                Ok(())
+            }
+            Err(Unwind::Error(err)) => Err(err),
+            Err(Unwind::Return(_)) => unreachable!("Returns are caught by `returned`"),
+        }
+    }
+
+    fn visit_protocol_def(&mut self, node: &mut ProtocolDef) -> Result<(), RuntimeError> {
+        let extended = node
+            .extension_indicator
+            .as_ref()
+            .map(|extension| extension.extendee_name.id.clone());
+        let methods = node
+            .function_signatures
+            .iter()
+            .map(|signature| signature.identifier.id.clone())
+            .collect();
+        self.protocols
+            .insert(node.name.id.clone(), (extended, methods));
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+pub mod interpreter;
+pub use interpreter::Interpreter;
+
+mod builtins;
+mod expression_visitor;
+mod operators;
+
+pub mod runtime_error;
+pub use runtime_error::RuntimeError;
+
+pub mod value;
+pub use value::Value;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,97 @@
+use std::io::Write;
+
+use ast::{BinaryOperator, token_position::TokenPositionTrait};
+
+use crate::{
+    interpreter::{Evaluation, Interpreter},
+    runtime_error::DIVISION_BY_ZERO_MESSAGE,
+    value::Value,
+};
+
+fn number(value: &Value) -> f64 {
+    match value {
+        Value::Number(number) => *number,
+        value => panic!(
+            "Expected a number, found {}, SA should have caught this",
+            value
+        ),
+    }
+}
+
+fn boolean(value: &Value) -> bool {
+    match value {
+        Value::Boolean(boolean) => *boolean,
+        value => panic!(
+            "Expected a boolean, found {}, SA should have caught this",
+            value
+        ),
+    }
+}
+
+/// The remainder of the floored division, it has the sign of `rhs`
+fn floor_remainder(lhs: f64, rhs: f64) -> f64 {
+    let remainder = lhs % rhs;
+    if remainder != 0.0 && (remainder < 0.0) != (rhs < 0.0) {
+        remainder + rhs
+    } else {
+        remainder
+    }
+}
+
+impl<W: Write> Interpreter<W> {
+    /// # Description
+    ///
+    /// Applies `op` to the values of its operands, which are both evaluated
+    /// before, `&` and `|` included
+    pub(crate) fn binary_operation(
+        &self,
+        op: &BinaryOperator,
+        lhs: Value,
+        rhs: Value,
+    ) -> Evaluation {
+        let is_division = matches!(
+            op,
+            BinaryOperator::Divide(_) | BinaryOperator::FloorDivide(_) | BinaryOperator::Modulo(_)
+        );
+        if is_division && self.runtime_checks && number(&rhs) == 0.0 {
+            return Err(self.check_failed(DIVISION_BY_ZERO_MESSAGE, op.position()));
+        }
+
+        let value = match op {
+            BinaryOperator::Plus(_) => Value::Number(number(&lhs) + number(&rhs)),
+            BinaryOperator::Minus(_) => Value::Number(number(&lhs) - number(&rhs)),
+            BinaryOperator::Times(_) => Value::Number(number(&lhs) * number(&rhs)),
+            BinaryOperator::Divide(_) => Value::Number(number(&lhs) / number(&rhs)),
+            BinaryOperator::FloorDivide(_) => Value::Number((number(&lhs) / number(&rhs)).floor()),
+            BinaryOperator::Modulo(_) => Value::Number(floor_remainder(number(&lhs), number(&rhs))),
+            BinaryOperator::Power(_) => Value::Number(number(&lhs).powf(number(&rhs))),
+
+            BinaryOperator::At(_) => Value::string(&format!(
+                "{}{}",
+                lhs.concatenation_text(),
+                rhs.concatenation_text()
+            )),
+            BinaryOperator::AtAt(_) => Value::string(&format!(
+                "{} {}",
+                lhs.concatenation_text(),
+                rhs.concatenation_text()
+            )),
+
+            BinaryOperator::EqualEqual(_) => Value::Boolean(lhs.equals(&rhs)),
+            BinaryOperator::NotEqual(_) => Value::Boolean(!lhs.equals(&rhs)),
+            BinaryOperator::Less(_) => Value::Boolean(number(&lhs) < number(&rhs)),
+            BinaryOperator::LessEqual(_) => Value::Boolean(number(&lhs) <= number(&rhs)),
+            BinaryOperator::Greater(_) => Value::Boolean(number(&lhs) > number(&rhs)),
+            BinaryOperator::GreaterEqual(_) => Value::Boolean(number(&lhs) >= number(&rhs)),
+
+            BinaryOperator::And(_) => Value::Boolean(boolean(&lhs) && boolean(&rhs)),
+            BinaryOperator::Or(_) => Value::Boolean(boolean(&lhs) || boolean(&rhs)),
+
+            BinaryOperator::Equal(_) | BinaryOperator::ColonEqual(_) => {
+                unreachable!("Assignment operators are not binary operations")
+            }
+        };
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+pub use generator::visitor::runtime_checks::{
+    DIVISION_BY_ZERO_MESSAGE, INDEX_OUT_OF_BOUNDS_MESSAGE, NON_INTEGER_INDEX_MESSAGE,
+};
+
+/// # Description
+///
+/// An error that stops the execution of a program, reported like the
+/// runtime checks of the generated code report theirs
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuntimeError {
+    /// A failed runtime check, `location` is where it happened in the source
+    /// code, see `SourceMap::location`
+    Check { message: String, location: String },
+    /// A failed `as`, with the dynamic type of the value and the target type
+    Cast { value_type: String, target: String },
+}
+
+impl RuntimeError {
+    pub fn check(message: &str, location: String) -> Self {
+        RuntimeError::Check {
+            message: message.to_string(),
+            location,
+        }
+    }
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuntimeError::Check { message, location } => {
+                write!(f, "Runtime Error: {}\n --> {}", message, location)
+            }
+            RuntimeError::Cast { value_type, target } => {
+                write!(
+                    f,
+                    "Runtime Error: Cannot cast `{}` to `{}`.",
+                    value_type, target
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
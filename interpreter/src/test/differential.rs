@@ -0,0 +1,157 @@
+//! Runs programs with the interpreter and with the code generated for them,
+//! their outputs must be the same
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use generator::CodeGenerator;
+
+use super::{analyze, run};
+
+fn run_llvm(hulk: &str) -> String {
+    let mut program = analyze(hulk);
+    let llvm = CodeGenerator::new().generate_code_from_program_ast(&mut program);
+
+    let mut lli = Command::new("lli")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("lli must be installed");
+    lli.stdin
+        .take()
+        .unwrap()
+        .write_all(llvm.as_bytes())
+        .unwrap();
+    let output = lli.wait_with_output().unwrap();
+    assert!(output.status.success(), "lli failed:\n{}", llvm);
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn assert_same_output(hulk: &str) {
+    let interpreted = run(hulk).unwrap();
+    assert!(!interpreted.is_empty());
+    assert_eq!(interpreted, run_llvm(hulk));
+}
+
+#[test]
+fn arithmetic() {
+    assert_same_output(
+        "{
+            print(1 + 2 * 3 - 4 / 8);
+            print(7 div 2 + 7 % 3 + 2 ^ 3);
+            print(-7 % 3);
+            print(sqrt(16) + floor(2.5) + ceil(2.5));
+        };",
+    );
+}
+
+#[test]
+fn loops_and_conditionals() {
+    assert_same_output(
+        "let i = 0, evens = 0 in {
+            while (i < 10) {
+                if (i % 2 == 0) {
+                    evens := evens + 1;
+                } else {
+                    evens;
+                };
+                i := i + 1;
+            };
+            print(evens);
+        };",
+    );
+}
+
+#[test]
+fn recursive_functions() {
+    assert_same_output(
+        "function fact(n: Number): Number => if (n <= 1) 1 else (n * fact(n - 1));
+
+        for (n in [1, 5, 10]) {
+            print(fact(n));
+        };",
+    );
+}
+
+#[test]
+fn strings() {
+    assert_same_output(
+        "let greeting = \"hello\" @@ \"world\" in {
+            print(greeting);
+            print(greeting @ 42);
+            print(length(greeting));
+            print(substring(greeting, 6, 3));
+        };",
+    );
+}
+
+#[test]
+fn booleans() {
+    assert_same_output(
+        "let small = 1 < 2 in {
+            print(true);
+            print(1);
+            print(false);
+            print(small);
+            print(!small);
+            print(\"small: \" @ small);
+        };",
+    );
+}
+
+#[test]
+fn lists() {
+    assert_same_output(
+        "let xs = [3, 10, 2], total = 0 in {
+            for (x in xs) {
+                total := total + x;
+            };
+            print(total);
+            print(xs[1]);
+        };",
+    );
+}
+
+#[test]
+fn objects_with_inheritance() {
+    assert_same_output(
+        "type Shape(scale: Number) {
+            scale = scale;
+
+            unit(): Number {
+                return 1;
+            }
+            area(): Number {
+                return self.unit() * self.scale;
+            }
+        }
+
+        type Square(side: Number) inherits Shape(side * side) {
+            side = side;
+
+            unit(): Number {
+                return self.side;
+            }
+        }
+
+        let shape = new Shape(3), square = new Square(2) in {
+            print(shape.area());
+            print(square.area());
+        };",
+    );
+}
+
+#[test]
+fn lambdas() {
+    assert_same_output(
+        "function apply_twice(f: (Number) -> Number, x: Number): Number => f(f(x));
+
+        let k = 3 in {
+            print(apply_twice((x: Number) => x * k, 2));
+            print(apply_twice((x: Number) => x + k, 2));
+        };",
+    );
+}
@@ -0,0 +1,29 @@
+use generated_parser::ProgramParser;
+use semantic_analyzer::semantic_analyzer::SemanticAnalyzer;
+
+use crate::{Interpreter, RuntimeError};
+
+mod differential;
+mod objects;
+mod programs;
+mod runtime_errors;
+
+fn analyze(hulk: &str) -> ast::Program {
+    let mut program = ProgramParser::new().parse(hulk).unwrap();
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    if let Err(errors) = semantic_analyzer.analyze_program_ast(&mut program) {
+        for error in errors {
+            println!("Error: {}", error);
+        }
+        panic!("Semantic analysis failed");
+    }
+    program
+}
+
+/// Runs `hulk`, returning what it printed
+fn run(hulk: &str) -> Result<String, RuntimeError> {
+    let mut program = analyze(hulk);
+    let mut interpreter = Interpreter::new(Vec::new());
+    interpreter.run_program(&mut program)?;
+    Ok(String::from_utf8(interpreter.into_output()).unwrap())
+}
@@ -0,0 +1,171 @@
+use super::run;
+
+const SHAPES: &str = "
+    type Shape(name: String) {
+        name = name;
+
+        area(): Number => 0;
+        describe(): String => self.name @@ \"of area\" @@ self.area();
+    }
+
+    type Square(side: Number) inherits Shape(\"square\") {
+        side = side;
+
+        area(): Number => self.side * self.side;
+        get_side(): Number => self.side;
+    }
+
+    type Rectangle(width: Number, height: Number) inherits Shape(\"rectangle\") {
+        width = width;
+        height = height;
+
+        area(): Number => self.width * self.height;
+    }
+";
+
+#[test]
+fn fields_are_initialized_from_the_arguments() {
+    let output = run(&format!(
+        "{SHAPES} let r = new Rectangle(2, 3) in print(r.area());"
+    ))
+    .unwrap();
+
+    assert_eq!(output, "6.000000\n");
+}
+
+#[test]
+fn methods_are_dispatched_on_the_dynamic_type() {
+    let output = run(&format!(
+        "{SHAPES}
+        for (shape in [new Square(2), new Rectangle(2, 3)]) {{
+            print(shape.describe());
+        }};"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        output,
+        "square of area 4.000000\nrectangle of area 6.000000\n"
+    );
+}
+
+#[test]
+fn fields_can_be_assigned_in_methods() {
+    let output = run("
+        type Counter {
+            count = 0;
+
+            increment(): Number => self.count := self.count + 1;
+        }
+
+        let c = new Counter(), d = c in {
+            c.increment();
+            d.increment();
+            print(c.increment());
+        };
+    ")
+    .unwrap();
+
+    assert_eq!(output, "3.000000\n");
+}
+
+#[test]
+fn base_calls_the_parent_implementation_on_self() {
+    let output = run(&format!(
+        "{SHAPES}
+        type Cube(side: Number) inherits Square(side) {{
+            area(): Number => 6 * base();
+            describe(): String => \"cube,\" @@ base();
+        }}
+
+        print(new Cube(2).describe());"
+    ))
+    .unwrap();
+
+    assert_eq!(output, "cube, square of area 24.000000\n");
+}
+
+#[test]
+fn type_tests_and_casts() {
+    let output = run(&format!(
+        "{SHAPES}
+        let s: Shape = new Square(2) in {{
+            print(s is Square);
+            print(s is Rectangle);
+            print(s is Shape);
+            print((s as Square).get_side());
+        }};"
+    ))
+    .unwrap();
+
+    assert_eq!(output, "true\nfalse\ntrue\n2.000000\n");
+}
+
+#[test]
+fn protocols_are_structural() {
+    let output = run("
+        protocol HasArea {
+            area(): Number;
+        }
+
+        type Thing {}
+
+        type Circle(radius: Number) inherits Thing {
+            radius = radius;
+
+            area(): Number => 3 * self.radius * self.radius;
+        }
+
+        type Point inherits Thing {}
+
+        let x: Thing = new Circle(1), y: Thing = new Point() in {
+            print(x is HasArea);
+            print(y is HasArea);
+            print((x as HasArea).area());
+        };
+    ")
+    .unwrap();
+
+    assert_eq!(output, "true\nfalse\n3.000000\n");
+}
+
+#[test]
+fn for_over_iterators() {
+    let output = run("
+        type Range(start: Number, end: Number) {
+            value = start - 1;
+            end = end;
+
+            next(): Boolean => (self.value := self.value + 1) < self.end;
+            current(): Number => self.value;
+        }
+
+        for (i in new Range(0, 3)) {
+            print(i);
+        };
+    ")
+    .unwrap();
+
+    assert_eq!(output, "0.000000\n1.000000\n2.000000\n");
+}
+
+#[test]
+fn generic_types_and_functions() {
+    let output = run("
+        type Box<T>(value: T) {
+            value = value;
+
+            get(): T => self.value;
+        }
+
+        function first<T>(xs: T*): T => xs[0];
+
+        let a = new Box(20), b = new Box<String>(\"hello\") in {
+            print(a.get() + first([1, 2]));
+            print(b.get() @ first([\"!\"]));
+        };
+    ")
+    .unwrap();
+
+    assert_eq!(output, "21.000000\nhello!\n");
+}
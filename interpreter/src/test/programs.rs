@@ -0,0 +1,129 @@
+use super::run;
+
+#[test]
+fn prints_each_kind_of_value() {
+    let output = run("{ print(42); print(\"hello\"); print(1 < 2); };").unwrap();
+
+    assert_eq!(output, "42.000000\nhello\ntrue\n");
+}
+
+#[test]
+fn print_returns_its_argument() {
+    assert_eq!(run("print(print(3) + 1);").unwrap(), "3.000000\n4.000000\n");
+}
+
+#[test]
+fn arithmetic_operators() {
+    let output = run("{
+            print(7 div 2);
+            print(-7 % 3);
+            print(7 % -3);
+            print(2 ^ 10);
+            print(-(1 + 2) * 3 / 4);
+        };")
+    .unwrap();
+
+    assert_eq!(
+        output,
+        "3.000000\n2.000000\n-2.000000\n1024.000000\n-2.250000\n"
+    );
+}
+
+#[test]
+fn string_operations() {
+    let output = run("let s = \"hulk\" in {
+            print(s @ 1);
+            print(s @@ (1 == 1));
+            print(length(s));
+            print(substring(s, 1, 10));
+            print(parse(\"2.5xyz\") + parse(\"xyz\"));
+            print(s == (\"hu\" @ \"lk\"));
+        };")
+    .unwrap();
+
+    assert_eq!(
+        output,
+        "hulk1.000000\nhulk true\n4.000000\nulk\n2.500000\ntrue\n"
+    );
+}
+
+#[test]
+fn let_in_shadows_and_restores() {
+    let output = run("let x = 1 in { let x = x + 1 in print(x); print(x); };").unwrap();
+
+    assert_eq!(output, "2.000000\n1.000000\n");
+}
+
+#[test]
+fn destructive_assignment_in_loops() {
+    let output = run("let i = 0, total = 0 in {
+            while (i < 5) {
+                i := i + 1;
+                total := total + i;
+            };
+            print(total);
+        };")
+    .unwrap();
+
+    assert_eq!(output, "15.000000\n");
+}
+
+#[test]
+fn lists_are_shared() {
+    let output = run("let xs = [1, 2, 3], ys = xs in {
+            ys[0] := 10;
+            for (x in xs) {
+                print(x);
+            };
+        };")
+    .unwrap();
+
+    assert_eq!(output, "10.000000\n2.000000\n3.000000\n");
+}
+
+#[test]
+fn recursive_functions_and_early_return() {
+    let output = run(
+        "function fib(n: Number): Number => if (n < 2) n else (fib(n - 1) + fib(n - 2));
+        function first_above(xs: Number*, k: Number): Number {
+            for (x in xs) {
+                if (x > k) { return x; } else { 0; };
+            };
+            return -1;
+        }
+
+        {
+            print(fib(15));
+            print(first_above([1, 5, 9], 4));
+        };",
+    )
+    .unwrap();
+
+    assert_eq!(output, "610.000000\n5.000000\n");
+}
+
+#[test]
+fn constants_are_visible_everywhere() {
+    let output = run("constant k: Number = 21 * 2;
+        function get(): Number => k;
+
+        print(get());")
+    .unwrap();
+
+    assert_eq!(output, "42.000000\n");
+}
+
+#[test]
+fn lambdas_keep_their_captures() {
+    let output = run(
+        "let count = 0, next = (): Number => { count := count + 1; count; } in {
+            next();
+            next();
+            print(next());
+            print(count);
+        };",
+    )
+    .unwrap();
+
+    assert_eq!(output, "3.000000\n0.000000\n");
+}
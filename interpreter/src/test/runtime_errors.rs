@@ -0,0 +1,76 @@
+use error_handler::source_map::SourceMap;
+
+use super::{analyze, run};
+use crate::{Interpreter, RuntimeError};
+
+#[test]
+fn division_by_zero() {
+    for op in ["/", "div", "%"] {
+        let error = run(&format!("let zero = 0 in print(7 {} zero);", op)).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Runtime Error: Division by zero\n --> position 24"
+        );
+    }
+}
+
+#[test]
+fn division_by_zero_without_checks() {
+    let mut program = analyze("let zero = 0 in print(1 / zero);");
+    let mut interpreter = Interpreter::new(Vec::new()).with_runtime_checks(false);
+    interpreter.run_program(&mut program).unwrap();
+
+    assert_eq!(interpreter.output(), b"inf\n");
+}
+
+#[test]
+fn list_index_checks() {
+    for (index, message) in [
+        ("3", "List index out of bounds"),
+        ("-1", "List index out of bounds"),
+        ("1.5", "List index is not an integer"),
+    ] {
+        let error = run(&format!("let xs = [1, 2, 3] in print(xs[{}]);", index)).unwrap_err();
+
+        assert!(matches!(error, RuntimeError::Check { message: m, .. } if m == message));
+    }
+}
+
+#[test]
+fn failed_cast() {
+    let error = run("
+        type A {}
+        type B inherits A {}
+
+        let a: A = new A() in print((a as B) is A);
+    ")
+    .unwrap_err();
+
+    assert_eq!(error.to_string(), "Runtime Error: Cannot cast `A` to `B`.");
+}
+
+#[test]
+fn errors_show_source_location() {
+    let text = "let xs = [1, 2, 3] in {\n    print(xs[3]);\n};";
+    let mut source_map = SourceMap::new();
+    source_map.add_file("main.hulk", text);
+
+    let mut program = analyze(text);
+    let mut interpreter = Interpreter::new(Vec::new()).with_source_map(source_map);
+    let error = interpreter.run_program(&mut program).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Runtime Error: List index out of bounds\n --> main.hulk:2:13\n  |     print(xs[3]);"
+    );
+}
+
+#[test]
+fn output_before_the_error_is_kept() {
+    let mut program = analyze("{ print(1); print([1][1]); print(2); };");
+    let mut interpreter = Interpreter::new(Vec::new());
+
+    assert!(interpreter.run_program(&mut program).is_err());
+    assert_eq!(interpreter.output(), b"1.000000\n");
+}
@@ -0,0 +1,151 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Display, rc::Rc};
+
+use ast::{
+    Expression,
+    typing::{BuiltInType, Type},
+};
+
+/// # Description
+///
+/// A value computed by the interpreter. Numbers, booleans and strings are
+/// copied, while lists, objects and functions are shared: every copy points
+/// to the same instance, like the pointers of the generated code
+#[derive(Debug, Clone)]
+pub enum Value {
+    Number(f64),
+    Boolean(bool),
+    String(Rc<str>),
+    List(Rc<RefCell<Vec<Value>>>),
+    Object(Rc<Object>),
+    Function(Rc<Closure>),
+    /// The result of expressions that produce nothing, like loops
+    None,
+}
+
+/// # Description
+///
+/// An instance of a defined type, it holds its own fields and the ones of
+/// the types it inherits from
+#[derive(Debug)]
+pub struct Object {
+    pub type_name: String,
+    pub fields: RefCell<HashMap<String, Value>>,
+}
+
+impl Object {
+    pub fn new(type_name: &str) -> Self {
+        Self {
+            type_name: type_name.to_string(),
+            fields: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+/// # Description
+///
+/// The value of a lambda. The captured variables are copied into its
+/// environment when the lambda is evaluated, the calls read and write them
+/// there, so changes made by a call are seen by the next ones
+#[derive(Debug)]
+pub struct Closure {
+    pub parameters: Vec<String>,
+    pub body: Expression,
+    pub environment: RefCell<HashMap<String, Value>>,
+}
+
+impl Value {
+    pub fn string(text: &str) -> Self {
+        Value::String(Rc::from(text))
+    }
+
+    pub fn list(elements: Vec<Value>) -> Self {
+        Value::List(Rc::new(RefCell::new(elements)))
+    }
+
+    /// # Description
+    ///
+    /// The name of the dynamic type of the value, as shown by failed casts
+    pub fn type_name(&self) -> String {
+        match self {
+            Value::Number(_) => Type::BuiltIn(BuiltInType::Number).to_string(),
+            Value::Boolean(_) => Type::BuiltIn(BuiltInType::Bool).to_string(),
+            Value::String(_) => Type::BuiltIn(BuiltInType::String).to_string(),
+            Value::List(_) => "Iterable".to_string(),
+            Value::Object(object) => object.type_name.clone(),
+            Value::Function(_) => "Function".to_string(),
+            Value::None => "none".to_string(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Compares two values like `==` does: numbers, booleans and strings by
+    /// their contents, every other value by identity
+    pub fn equals(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::Boolean(a), Value::Boolean(b)) => a == b,
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::List(a), Value::List(b)) => Rc::ptr_eq(a, b),
+            (Value::Object(a), Value::Object(b)) => Rc::ptr_eq(a, b),
+            (Value::Function(a), Value::Function(b)) => Rc::ptr_eq(a, b),
+            (Value::None, Value::None) => true,
+            _ => false,
+        }
+    }
+
+    /// # Description
+    ///
+    /// The text `@` and `@@` concatenate for the value: numbers are written
+    /// like C's `%f` does, unlike `print` without a line break
+    pub fn concatenation_text(&self) -> String {
+        match self {
+            Value::Number(number) => format_number(*number),
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// # Description
+///
+/// Writes `number` like C's `%f` conversion: six decimals, with `nan` and
+/// `inf` for the values that are not finite
+pub fn format_number(number: f64) -> String {
+    if number.is_nan() {
+        if number.is_sign_negative() {
+            "-nan".to_string()
+        } else {
+            "nan".to_string()
+        }
+    } else if number.is_infinite() {
+        if number < 0.0 {
+            "-inf".to_string()
+        } else {
+            "inf".to_string()
+        }
+    } else {
+        format!("{:.6}", number)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", format_number(*number)),
+            Value::Boolean(boolean) => write!(f, "{}", boolean),
+            Value::String(string) => write!(f, "{}", string),
+            Value::List(elements) => {
+                let elements = elements
+                    .borrow()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "[{}]", elements)
+            }
+            Value::Object(object) => write!(f, "<{}>", object.type_name),
+            Value::Function(closure) => write!(f, "<function/{}>", closure.parameters.len()),
+            Value::None => write!(f, "none"),
+        }
+    }
+}
@@ -4,7 +4,9 @@ use std::{
     hash::Hash,
 };
 
-use error_handler::error::lexical::invalid_character::InvalidCharacterError;
+use error_handler::{
+    diagnostic::diagnostic::Span, error::lexical::invalid_character::InvalidCharacterError,
+};
 
 use crate::{
     automata_utils::{
@@ -51,6 +53,7 @@ where
 
     /// Scans an input string and returns a vector of `LexerChunk` instances representing recognized tokens,
     /// or a vector of error messages if any lexical errors are encountered.
+    /// Positions are byte offsets into `input`, which a `SourceMap` resolves to lines and columns.
     /// # Arguments
     /// - `input`: A string slice that contains the input to be scanned.
     /// # Returns
@@ -59,22 +62,17 @@ where
         let mut tokens = Vec::new();
         let mut errors = Vec::new();
         let mut pos = 0;
-        let chars: Vec<char> = input.chars().collect();
+        let chars: Vec<(usize, char)> = input.char_indices().collect();
         let len = chars.len();
-        let mut line = (0, 0); // (line number, line start)
+        // byte offset of the character at index `i`, or of the end of the input
+        let offset = |i: usize| chars.get(i).map_or(input.len(), |(offset, _)| *offset);
 
         while pos < len {
             let mut state = self.q0;
             let mut last_accepting: Option<(usize, &TokenKind)> = None;
-            let mut current_line = line;
             let mut i = pos;
             while i < len {
-                let c = chars[i];
-                // Si es un salto de línea, actualiza la línea
-                if c == '\n' {
-                    current_line.0 += 1;
-                    current_line.1 = i;
-                }
+                let c = chars[i].1;
                 if let Some(&next_state) = self.d.get(&(state, c)) {
                     state = next_state;
                     if let Some(kind) = self.qf.get(&state) {
@@ -86,21 +84,20 @@ where
                 }
             }
             if let Some((end, kind)) = last_accepting {
-                let slice = &input[pos..end];
+                let (start, end_offset) = (offset(pos), offset(end));
                 tokens.push(LexerChunk {
                     ty: kind.clone(),
-                    slice,
-                    line: line.0,
-                    start: pos,
-                    end,
+                    slice: &input[start..end_offset],
+                    start,
+                    end: end_offset,
+                    trivia: Vec::new(),
                 });
-                line = current_line;
                 pos = end;
             } else {
-                if pos == 0 || chars[pos - 1] != chars[pos] {
-                    errors.push(InvalidCharacterError::new(chars[pos], pos).into());
+                if pos == 0 || chars[pos - 1].1 != chars[pos].1 {
+                    let span = Span::new(offset(pos), offset(pos + 1));
+                    errors.push(InvalidCharacterError::new(chars[pos].1, span).into());
                 }
-                line = current_line;
                 pos += 1;
             }
         }
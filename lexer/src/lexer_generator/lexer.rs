@@ -7,6 +7,7 @@ use crate::{
     lexer_generator::{
         automata::{super_dfa::SuperDFA, super_nfa::SuperNFA},
         lexer_chunk::LexerChunk,
+        lexer_tables::LexerTables,
         rule::Rule,
     },
     regex_engine::automata::{nfa::NFA, nfa_builder::NFABuilder},
@@ -22,6 +23,8 @@ where
     TokenKind: Clone + PartialEq + Hash + Eq + Debug,
 {
     rules: HashMap<TokenKind, Rule<TokenKind>>,
+    /// token kinds of the rules in the order they were given, which is how the tables refer to them
+    order: Vec<TokenKind>,
     engine: SuperDFA<TokenKind>,
 }
 
@@ -48,14 +51,97 @@ where
         let nfa = SuperNFA::new(&attributed_nfas);
         let engine = SuperDFA::new(&nfa);
 
+        Self::with_engine(rules, engine)
+    }
+
+    /// Creates a new `Lexer` instance from a vector of `Rule`s and the tables of their DFA,
+    /// computed ahead of time with `tables`, which skips building the automata.
+    /// # Arguments
+    /// * `rules`: The rules the tables were computed from, in the same order.
+    /// * `tables`: The tables of the DFA of the rules.
+    /// # Returns
+    /// A new `Lexer` instance, or an error message if the tables were not computed from the given rules.
+    pub fn from_tables(rules: Vec<Rule<TokenKind>>, tables: LexerTables) -> Result<Self, String> {
+        let patterns_match = rules.len() == tables.patterns.len()
+            && rules
+                .iter()
+                .zip(&tables.patterns)
+                .all(|(rule, pattern)| &rule.pattern == pattern);
+        if !patterns_match {
+            return Err("The lexer tables were not computed from the given rules".to_string());
+        }
+        if tables.finals.iter().any(|(_, rule)| *rule >= rules.len()) {
+            return Err("A final state of the lexer tables accepts an unknown rule".to_string());
+        }
+
+        let qf = tables
+            .finals
+            .iter()
+            .map(|(state, rule)| (*state, rules[*rule].token_kind.clone()))
+            .collect();
+        let d = tables
+            .transitions
+            .iter()
+            .map(|(state, c, next)| ((*state, *c), *next))
+            .collect();
+        let engine = SuperDFA {
+            q0: tables.q0,
+            qf,
+            d,
+        };
+
+        Ok(Self::with_engine(rules, engine))
+    }
+
+    fn with_engine(rules: Vec<Rule<TokenKind>>, engine: SuperDFA<TokenKind>) -> Self {
+        let order = rules.iter().map(|rule| rule.token_kind.clone()).collect();
         let rules = rules
             .into_iter()
             .map(|rule| (rule.token_kind.clone(), rule))
             .collect::<HashMap<_, _>>();
-        Lexer { rules, engine }
+        Lexer {
+            rules,
+            order,
+            engine,
+        }
+    }
+
+    /// Returns the tables of the DFA of the lexer, to build it again with `from_tables`.
+    /// Entries are sorted, so the same automaton always gives the same tables.
+    pub fn tables(&self) -> LexerTables {
+        let rule_index = |kind: &TokenKind| self.order.iter().position(|k| k == kind).unwrap();
+
+        let mut finals: Vec<(usize, usize)> = self
+            .engine
+            .qf
+            .iter()
+            .map(|(state, kind)| (*state, rule_index(kind)))
+            .collect();
+        finals.sort();
+
+        let mut transitions: Vec<(usize, char, usize)> = self
+            .engine
+            .d
+            .iter()
+            .map(|((state, c), next)| (*state, *c, *next))
+            .collect();
+        transitions.sort();
+
+        LexerTables {
+            patterns: self
+                .order
+                .iter()
+                .map(|kind| self.rules[kind].pattern.clone())
+                .collect(),
+            q0: self.engine.q0,
+            finals,
+            transitions,
+        }
     }
 
     /// Splits the input string into tokens based on the defined rules.
+    /// Chunks of trivia rules are attached to the `trivia` of the next token,
+    /// or of the last token if there is none after them.
     /// # Arguments
     /// * `input`: The input string to be tokenized.
     /// # Returns
@@ -66,11 +152,22 @@ where
     ) -> Result<Tokens<'a, TokenKind>, (Tokens<'a, TokenKind>, Vec<HulkError>)> {
         let mut result = self.engine.scan(input);
 
-        result.tokens = result
-            .tokens
-            .into_iter()
-            .filter(|token| self.rules.get(&token.ty).map_or(false, |rule| !rule.skip))
-            .collect();
+        let mut tokens: Tokens<'a, TokenKind> = Vec::new();
+        let mut trivia = Vec::new();
+        for mut token in result.tokens {
+            match self.rules.get(&token.ty) {
+                Some(rule) if rule.trivia => trivia.push(token),
+                Some(rule) if !rule.skip => {
+                    token.trivia = std::mem::take(&mut trivia);
+                    tokens.push(token);
+                }
+                _ => {}
+            }
+        }
+        if let Some(last) = tokens.last_mut() {
+            last.trivia.extend(trivia);
+        }
+        result.tokens = tokens;
         if result.errors.is_empty() {
             Ok(result.tokens)
         } else {
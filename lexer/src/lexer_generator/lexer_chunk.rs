@@ -5,11 +5,11 @@ use std::hash::Hash;
 /// ## Fields:
 /// - `ty`: The type of token this chunk represents.
 /// - `slice`: The actual text slice that this chunk represents.
-/// - `line`: The line number in the source text where this chunk starts.
-/// - `start`: The starting index of this chunk in the source text.
-/// - `end`: The ending index of this chunk in the source text.
+/// - `start`: The starting byte offset of this chunk in the source text.
+/// - `end`: The ending byte offset of this chunk in the source text.
+/// - `trivia`: The trivia chunks (e.g. comments) found right before this chunk.
 /// ## Methods:
-/// - `new`: Creates a new `LexerChunk` with the specified type, slice, line, start, and end indices.
+/// - `new`: Creates a new `LexerChunk` with the specified type, slice, start, and end offsets.
 /// - `is_empty`: Checks if the chunk is empty (i.e., start and end indices are the same).
 #[derive(Debug)]
 pub struct LexerChunk<'a, TokenKind>
@@ -18,31 +18,30 @@ where
 {
     pub ty: TokenKind,
     pub slice: &'a str,
-    pub line: usize,
     pub start: usize,
     pub end: usize,
+    pub trivia: Vec<LexerChunk<'a, TokenKind>>,
 }
 
 impl<'a, TokenKind> LexerChunk<'a, TokenKind>
 where
     TokenKind: Clone + PartialEq + Hash + Eq,
 {
-    /// Creates a new `LexerChunk` with the specified type, slice, line, start, and end indices.
+    /// Creates a new `LexerChunk` with the specified type, slice, start, and end offsets.
     /// # Arguments
     /// * `ty`: The type of token this chunk represents.
     /// * `slice`: The actual text slice that this chunk represents.
-    /// * `line`: The line number in the source text where this chunk starts.
-    /// * `start`: The starting index of this chunk in the source text.
-    /// * `end`: The ending index of this chunk in the source text.
+    /// * `start`: The starting byte offset of this chunk in the source text.
+    /// * `end`: The ending byte offset of this chunk in the source text.
     /// # Returns
     /// A new `LexerChunk` instance.
-    pub fn new(ty: TokenKind, slice: &'a str, line: usize, start: usize, end: usize) -> Self {
+    pub fn new(ty: TokenKind, slice: &'a str, start: usize, end: usize) -> Self {
         LexerChunk {
             ty,
             slice,
-            line,
             start,
             end,
+            trivia: Vec::new(),
         }
     }
 
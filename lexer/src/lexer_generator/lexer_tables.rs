@@ -0,0 +1,129 @@
+/// Identifies the binary format written by `LexerTables::to_bytes`
+const MAGIC: &[u8; 4] = b"HLT1";
+
+/// # Description
+/// This module defines a `LexerTables` struct that holds the DFA of a `Lexer`, so it can be
+/// computed ahead of time (e.g. in a build script) instead of on every run.
+/// ## Fields:
+/// - `patterns`: The patterns of the rules the DFA was built from, in order, used to check that the
+///   tables still match the rules they are loaded with.
+/// - `q0`: The initial state of the DFA.
+/// - `finals`: The final states of the DFA, with the index of the rule each one accepts.
+/// - `transitions`: The transitions of the DFA, as (state, character, next state).
+/// ## Methods:
+/// - `to_bytes`: Serializes the tables into a compact binary format.
+/// - `from_bytes`: Reads tables serialized by `to_bytes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexerTables {
+    pub patterns: Vec<String>,
+    pub q0: usize,
+    pub finals: Vec<(usize, usize)>,
+    pub transitions: Vec<(usize, char, usize)>,
+}
+
+impl LexerTables {
+    /// Serializes the tables into a compact binary format.
+    /// # Returns
+    /// The bytes of the tables, which `from_bytes` reads back.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+
+        write_usize(&mut bytes, self.patterns.len());
+        for pattern in &self.patterns {
+            write_usize(&mut bytes, pattern.len());
+            bytes.extend(pattern.as_bytes());
+        }
+
+        write_usize(&mut bytes, self.q0);
+
+        write_usize(&mut bytes, self.finals.len());
+        for (state, rule) in &self.finals {
+            write_usize(&mut bytes, *state);
+            write_usize(&mut bytes, *rule);
+        }
+
+        write_usize(&mut bytes, self.transitions.len());
+        for (state, c, next) in &self.transitions {
+            write_usize(&mut bytes, *state);
+            write_usize(&mut bytes, *c as usize);
+            write_usize(&mut bytes, *next);
+        }
+
+        bytes
+    }
+
+    /// Reads tables serialized by `to_bytes`.
+    /// # Arguments
+    /// * `bytes`: The serialized tables.
+    /// # Returns
+    /// The tables, or an error message if the bytes are not valid serialized tables.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if !bytes.starts_with(MAGIC) {
+            return Err("The given bytes are not lexer tables".to_string());
+        }
+        let mut reader = Reader {
+            bytes,
+            position: MAGIC.len(),
+        };
+
+        let mut patterns = Vec::new();
+        for _ in 0..reader.usize()? {
+            let len = reader.usize()?;
+            let pattern = String::from_utf8(reader.take(len)?.to_vec())
+                .map_err(|_| "A pattern of the lexer tables is not valid UTF-8".to_string())?;
+            patterns.push(pattern);
+        }
+
+        let q0 = reader.usize()?;
+
+        let mut finals = Vec::new();
+        for _ in 0..reader.usize()? {
+            finals.push((reader.usize()?, reader.usize()?));
+        }
+
+        let mut transitions = Vec::new();
+        for _ in 0..reader.usize()? {
+            let state = reader.usize()?;
+            let c = char::from_u32(reader.usize()? as u32)
+                .ok_or("A transition of the lexer tables is not on a character".to_string())?;
+            transitions.push((state, c, reader.usize()?));
+        }
+
+        if reader.position != bytes.len() {
+            return Err("The lexer tables have trailing bytes".to_string());
+        }
+
+        Ok(LexerTables {
+            patterns,
+            q0,
+            finals,
+            transitions,
+        })
+    }
+}
+
+fn write_usize(bytes: &mut Vec<u8>, value: usize) {
+    bytes.extend((value as u32).to_le_bytes());
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.position + len;
+        if end > self.bytes.len() {
+            return Err("The lexer tables end unexpectedly".to_string());
+        }
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn usize(&mut self) -> Result<usize, String> {
+        let slice = self.take(4)?;
+        Ok(u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]) as usize)
+    }
+}
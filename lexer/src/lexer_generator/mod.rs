@@ -2,4 +2,5 @@ pub mod automata;
 pub mod lexer;
 pub mod lexer_chunk;
 pub mod lexer_result;
+pub mod lexer_tables;
 pub mod rule;
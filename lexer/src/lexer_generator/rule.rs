@@ -6,9 +6,11 @@ use std::hash::Hash;
 /// - `token_kind`: The kind of token this rule matches.
 /// - `pattern`: The regex pattern that defines the rule.
 /// - `skip`: A boolean indicating whether this rule should be skipped during tokenization.
+/// - `trivia`: A boolean indicating whether the skipped chunks are kept as trivia of the next token.
 /// ## Methods:
 /// - `new`: Creates a new `Rule` with the specified token kind and pattern.
 /// - `new_skip`: Creates a new `Rule` that should be skipped during tokenization.
+/// - `new_trivia`: Creates a new `Rule` whose chunks are attached to the next token as trivia.
 pub struct Rule<TokenKind>
 where
     TokenKind: Clone + PartialEq + Hash + Eq,
@@ -16,6 +18,7 @@ where
     pub token_kind: TokenKind,
     pub pattern: String,
     pub skip: bool,
+    pub trivia: bool,
 }
 
 impl<TokenKind> Rule<TokenKind>
@@ -33,6 +36,7 @@ where
             token_kind,
             pattern,
             skip: false,
+            trivia: false,
         }
     }
 
@@ -47,6 +51,23 @@ where
             token_kind,
             pattern,
             skip: true,
+            trivia: false,
+        }
+    }
+
+    /// Creates a new `Rule` that is skipped during tokenization, but whose chunks
+    /// are kept in the `trivia` of the next token (e.g. comments).
+    /// # Arguments
+    /// * `token_kind`: The kind of token this rule matches.
+    /// * `pattern`: The regex pattern that defines the rule.
+    /// # Returns
+    /// A new `Rule` instance that is marked as trivia.
+    pub fn new_trivia(token_kind: TokenKind, pattern: String) -> Self {
+        Rule {
+            token_kind,
+            pattern,
+            skip: true,
+            trivia: true,
         }
     }
 }
@@ -1,8 +1,9 @@
 use std::vec;
 
+use error_handler::error::error::HulkErrorTrait;
 use error_handler::error_handler::ErrorHandler;
 
-use crate::lexer_generator::{lexer::Lexer, rule::Rule};
+use crate::lexer_generator::{lexer::Lexer, lexer_tables::LexerTables, rule::Rule};
 
 #[test]
 pub fn lex_some_tokens() {
@@ -148,6 +149,35 @@ pub fn unrecognized_character() {
     );
 }
 
+#[test]
+pub fn positions_are_byte_offsets() {
+    let rules = vec![
+        Rule::new("A".to_string(), r"a".to_string()),
+        Rule::new("N".to_string(), r"ñ".to_string()),
+        Rule::new_skip("WhiteSpace".to_string(), r"\s+".to_string()),
+    ];
+
+    let lexer = Lexer::new(rules);
+
+    let tokens = lexer.split("ñaña a").ok().unwrap();
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|t| (t.slice, t.start, t.end))
+            .collect::<Vec<_>>(),
+        vec![
+            ("ñ", 0, 2),
+            ("a", 2, 3),
+            ("ñ", 3, 5),
+            ("a", 5, 6),
+            ("a", 7, 8)
+        ]
+    );
+
+    let errors = lexer.split("ñ é").err().unwrap().1;
+    assert_eq!(errors[0].get_position(), 3);
+}
+
 #[test]
 pub fn error_recovery() {
     let rules = vec![
@@ -432,3 +462,76 @@ pub fn empty_match() {
         vec!["Lexical Error: Invalid character `b`",]
     );
 }
+
+#[test]
+pub fn trivia_is_attached_to_the_next_token() {
+    let rules = vec![
+        Rule::new("A", r"a".to_string()),
+        Rule::new("B", r"b".to_string()),
+        Rule::new_skip("WhiteSpace", r"(\s|\t|\n)+".to_string()),
+        Rule::new_trivia("Comment", r"#[^\n-\n]*".to_string()),
+    ];
+
+    let lexer = Lexer::new(rules);
+    let result = lexer.split("# first\na # second\n# third\nb\n# last");
+
+    let tokens = result.ok().unwrap();
+    assert_eq!(
+        tokens.iter().map(|t| t.ty).collect::<Vec<_>>(),
+        vec!["A", "B"]
+    );
+    assert_eq!(
+        tokens[0].trivia.iter().map(|t| t.slice).collect::<Vec<_>>(),
+        vec!["# first"]
+    );
+    assert_eq!(
+        tokens[1]
+            .trivia
+            .iter()
+            .map(|t| (t.slice, t.start))
+            .collect::<Vec<_>>(),
+        vec![("# second", 10), ("# third", 19), ("# last", 29)]
+    );
+}
+
+fn tables_rules() -> Vec<Rule<&'static str>> {
+    vec![
+        Rule::new("Let", r"let".to_string()),
+        Rule::new("Identifier", r"[a-z]+".to_string()),
+        Rule::new("Number", r"[0-9]+".to_string()),
+        Rule::new_skip("WhiteSpace", r"\s+".to_string()),
+    ]
+}
+
+#[test]
+pub fn lexer_from_tables_splits_as_the_original() {
+    let lexer = Lexer::new(tables_rules());
+    let bytes = lexer.tables().to_bytes();
+
+    let tables = LexerTables::from_bytes(&bytes).unwrap();
+    assert_eq!(tables, lexer.tables());
+    let precomputed = Lexer::from_tables(tables_rules(), tables).unwrap();
+
+    let input = "let x 42 lets";
+    let expected = lexer.split(input).ok().unwrap();
+    let tokens = precomputed.split(input).ok().unwrap();
+    assert_eq!(
+        tokens.iter().map(|t| (t.ty, t.slice)).collect::<Vec<_>>(),
+        expected.iter().map(|t| (t.ty, t.slice)).collect::<Vec<_>>(),
+    );
+    assert_eq!(
+        tokens.iter().map(|t| t.ty).collect::<Vec<_>>(),
+        vec!["Let", "Identifier", "Number", "Identifier"]
+    );
+}
+
+#[test]
+pub fn tables_of_other_rules_are_rejected() {
+    let tables = Lexer::new(tables_rules()).tables();
+
+    let mut rules = tables_rules();
+    rules[2] = Rule::new("Number", r"[0-9]+\.[0-9]+".to_string());
+
+    assert!(Lexer::from_tables(rules, tables).is_err());
+    assert!(LexerTables::from_bytes(b"not tables").is_err());
+}
@@ -0,0 +1,193 @@
+use error_handler::{
+    diagnostic::diagnostic::{Diagnostic, Severity, Span},
+    source_map::SourceMap,
+};
+use runner::Compiler;
+use serde_json::{Value, json};
+
+use crate::{
+    line_index::LineIndex,
+    symbol_index::{Definitions, SymbolIndex},
+};
+
+/// # Description
+///
+/// A library file every document is analyzed with, i.e. the standard library
+#[derive(Debug, Clone)]
+pub struct Library {
+    pub uri: String,
+    pub text: String,
+}
+
+/// # Description
+///
+/// A document open in the editor. Each version is analyzed together with the
+/// library, as the runner would compile it, so positions in the program text
+/// are `offset` bytes ahead of the positions in the document
+pub struct Document {
+    pub uri: String,
+    pub version: i64,
+    pub text: String,
+    pub source_map: SourceMap,
+    pub offset: usize,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Index of the last version that could be parsed, while the document is
+    /// being edited it is often not valid HULK
+    pub index: Option<SymbolIndex>,
+}
+
+impl Document {
+    pub fn new(
+        compiler: &Compiler,
+        library: Option<&Library>,
+        uri: String,
+        version: i64,
+        text: String,
+    ) -> Self {
+        let mut document = Self {
+            uri,
+            version,
+            text: String::new(),
+            source_map: SourceMap::new(),
+            offset: 0,
+            diagnostics: Vec::new(),
+            index: None,
+        };
+        document.update(compiler, library, version, text);
+        document
+    }
+
+    /// # Description
+    ///
+    /// Replaces the text of the document and analyzes it
+    pub fn update(
+        &mut self,
+        compiler: &Compiler,
+        library: Option<&Library>,
+        version: i64,
+        text: String,
+    ) {
+        let mut source_map = SourceMap::new();
+        if let Some(library) = library {
+            source_map.add_library(&library.uri, &library.text);
+        }
+        source_map.add_file(&self.uri, &text);
+        let offset = source_map.user_code_offset();
+
+        let mut session = compiler.session(source_map.clone());
+        let delimiters = session
+            .tokens()
+            .map(|tokens| {
+                tokens
+                    .iter()
+                    .filter_map(|token| match token.slice.as_str() {
+                        "{" => Some((token.start, '{')),
+                        "}" => Some((token.start, '}')),
+                        ";" => Some((token.start, ';')),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        // the errors are collected by the session
+        let _ = session.analyze();
+
+        self.diagnostics = session.structured_diagnostics();
+        let definitions = Definitions::from_analyzer(session.semantic_analyzer());
+        if let Some(mut program) = session.into_program() {
+            self.index = Some(SymbolIndex::build(
+                &mut program,
+                definitions,
+                source_map.content(),
+                &delimiters,
+                offset,
+            ));
+        }
+
+        self.version = version;
+        self.text = text;
+        self.source_map = source_map;
+        self.offset = offset;
+    }
+
+    /// # Description
+    ///
+    /// The position in the program text of an LSP `Position` of the document
+    pub fn position_of(&self, position: &Value) -> Option<usize> {
+        let line = position.get("line")?.as_u64()? as usize;
+        let character = position.get("character")?.as_u64()? as usize;
+        Some(self.offset + LineIndex::new(&self.text).offset(line, character))
+    }
+
+    /// # Description
+    ///
+    /// The LSP `Range` of a span of the document
+    pub fn range(&self, span: Span) -> Value {
+        LineIndex::new(&self.text).range(
+            span.start.saturating_sub(self.offset),
+            span.end.saturating_sub(self.offset),
+        )
+    }
+
+    /// # Description
+    ///
+    /// The LSP `Location` of a span of the program, which may be in the library
+    pub fn location(&self, span: Span) -> Option<Value> {
+        if span.start >= self.offset {
+            return Some(json!({ "uri": self.uri, "range": self.range(span) }));
+        }
+
+        let file = self.source_map.file_at(span.start)?;
+        let text = &self.source_map.content()[file.start..file.end()];
+        let range = LineIndex::new(text).range(span.start - file.start, span.end - file.start);
+        Some(json!({ "uri": file.name, "range": range }))
+    }
+
+    /// # Description
+    ///
+    /// The diagnostics of the document as the `textDocument/publishDiagnostics`
+    /// notification carries them. Errors in the library are shown at the
+    /// start of the document
+    pub fn lsp_diagnostics(&self) -> Value {
+        let diagnostics = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let severity = match diagnostic.severity {
+                    Severity::Error => 1,
+                    Severity::Warning => 2,
+                    Severity::Note => 3,
+                };
+                let mut message = diagnostic.message.clone();
+                for note in &diagnostic.notes {
+                    message += &format!("\nnote: {}", note);
+                }
+                let related_information = diagnostic
+                    .labels
+                    .iter()
+                    .filter_map(|label| {
+                        Some(json!({
+                            "location": self.location(label.span)?,
+                            "message": label.message,
+                        }))
+                    })
+                    .collect::<Vec<_>>();
+
+                json!({
+                    "range": self.range(diagnostic.span),
+                    "severity": severity,
+                    "code": diagnostic.code,
+                    "source": "hulk",
+                    "message": message,
+                    "relatedInformation": related_information,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "uri": self.uri,
+            "version": self.version,
+            "diagnostics": diagnostics,
+        })
+    }
+}
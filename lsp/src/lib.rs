@@ -0,0 +1,18 @@
+pub mod transport;
+
+pub mod line_index;
+
+pub mod symbol_index;
+pub use symbol_index::SymbolIndex;
+
+pub mod symbol_visitor;
+
+pub mod outline;
+
+pub mod document;
+
+pub mod server;
+pub use server::Server;
+
+#[cfg(test)]
+mod test;
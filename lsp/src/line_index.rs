@@ -0,0 +1,69 @@
+use serde_json::{Value, json};
+
+/// # Description
+///
+/// Converts between byte offsets in a text and the positions of the Language
+/// Server Protocol: a line and a character, both starting at 0, where
+/// characters are counted in UTF-16 code units
+pub struct LineIndex<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { text, line_starts }
+    }
+
+    /// # Description
+    ///
+    /// The position `offset` is at, offsets past the end of the text are
+    /// moved to its end
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.text.len());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_text = &self.text[self.line_starts[line]..];
+
+        let character = line_text
+            .char_indices()
+            .take_while(|(index, _)| self.line_starts[line] + index < offset)
+            .map(|(_, c)| c.len_utf16())
+            .sum();
+        (line, character)
+    }
+
+    /// # Description
+    ///
+    /// The offset of the position at `line` and `character`, positions past
+    /// the end of their line are moved to its end
+    pub fn offset(&self, line: usize, character: usize) -> usize {
+        let Some(&line_start) = self.line_starts.get(line) else {
+            return self.text.len();
+        };
+        let line_text = self.text[line_start..].split('\n').next().unwrap_or("");
+
+        let mut units = 0;
+        for (index, c) in line_text.char_indices() {
+            if units >= character {
+                return line_start + index;
+            }
+            units += c.len_utf16();
+        }
+        line_start + line_text.len()
+    }
+
+    /// # Description
+    ///
+    /// The LSP `Range` from `start` to `end`
+    pub fn range(&self, start: usize, end: usize) -> Value {
+        let (start_line, start_character) = self.position(start);
+        let (end_line, end_character) = self.position(end);
+        json!({
+            "start": { "line": start_line, "character": start_character },
+            "end": { "line": end_line, "character": end_character },
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use lsp::Server;
+use runner::{cli::DEFAULT_STD_LIBRARY, exit_code};
+
+const USAGE: &str = "\
+Usage: lsp [--std <PATH> | --no-std]
+
+Language server for HULK, speaking the Language Server Protocol over the
+standard input and output.
+
+Options:
+  --std <PATH>  Standard library every document is analyzed with (defaults to
+                `std.hulk` when it exists)
+  --no-std      Analyze the documents without standard library
+  -h, --help    Print this help
+";
+
+fn main() {
+    let mut std_library = Some(DEFAULT_STD_LIBRARY.to_string());
+    let mut std_given = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--std" => match args.next() {
+                Some(path) => {
+                    std_library = Some(path);
+                    std_given = true;
+                }
+                None => usage_error("`--std` expects a path"),
+            },
+            "--no-std" => std_library = None,
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                std::process::exit(exit_code::SUCCESS);
+            }
+            _ => usage_error(&format!("Unknown option `{}`", arg)),
+        }
+    }
+
+    let stdin = std::io::stdin();
+    let mut server = Server::new(stdin.lock(), std::io::stdout().lock());
+    if let Some(path) = std_library {
+        match std::fs::read_to_string(&path) {
+            Ok(text) => server = server.with_std_library(file_uri(Path::new(&path)), text),
+            // the default library is optional, an explicit one is not
+            Err(err) if std_given => {
+                eprintln!("Error: {}: {}", path, err);
+                std::process::exit(exit_code::IO_FAILURE);
+            }
+            Err(_) => {}
+        }
+    }
+
+    let code = server.run().unwrap_or_else(|err| {
+        eprintln!("Error: {}", err);
+        exit_code::IO_FAILURE
+    });
+    std::process::exit(code);
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("Error: {}\n\n{}", message, USAGE);
+    std::process::exit(exit_code::USAGE_FAILURE);
+}
+
+/// `file://` URI of `path`, which editors use to open the library on go-to-definition
+fn file_uri(path: &Path) -> String {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    format!("file://{}", path.display())
+}
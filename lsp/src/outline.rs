@@ -0,0 +1,168 @@
+use ast::{
+    Definition, Identifier, Program, token_position::TokenPositionTrait, typing::TypeAnnotation,
+};
+use error_handler::diagnostic::diagnostic::Span;
+
+use crate::symbol_index::{Definitions, OutlineItem, OutlineKind, span_of, typed};
+
+/// # Description
+///
+/// The outline of the definitions in the user code, in source order.
+/// `delimiters` are the positions of the `{`, `}` and `;` tokens of the
+/// program, which tell where each definition ends
+pub fn outline(
+    program: &Program,
+    definitions: &Definitions,
+    delimiters: &[(usize, char)],
+    user_code_offset: usize,
+) -> Vec<OutlineItem> {
+    let mut items = program
+        .definitions
+        .iter()
+        .map(|definition| definition_item(definition, definitions, delimiters))
+        .filter(|item| item.span.start >= user_code_offset)
+        .collect::<Vec<_>>();
+    items.sort_by_key(|item| item.span.start);
+    items
+}
+
+fn definition_item(
+    definition: &Definition,
+    definitions: &Definitions,
+    delimiters: &[(usize, char)],
+) -> OutlineItem {
+    match definition {
+        Definition::TypeDef(type_def) => {
+            let start = type_def.type_token.position();
+            let fields = type_def.data_member_defs.iter().map(|field| {
+                let detail = type_detail(&field.identifier.info.ty);
+                member_item(&field.identifier, OutlineKind::Field, detail, delimiters)
+            });
+            let methods = type_def.function_member_defs.iter().map(|method| {
+                let detail = type_detail(&method.identifier.info.ty);
+                member_item(&method.identifier, OutlineKind::Method, detail, delimiters)
+            });
+            let mut children = fields.chain(methods).collect::<Vec<_>>();
+            children.sort_by_key(|child| child.span.start);
+
+            OutlineItem {
+                name: type_def.name.id.clone(),
+                kind: OutlineKind::Type,
+                detail: definitions
+                    .type_definition(&type_def.name.id)
+                    .map(|definition| definition.detail.clone())
+                    .unwrap_or_else(|| format!("type {}", type_def.name.id)),
+                span: Span::new(start, definition_end(delimiters, start)),
+                selection: Span::new(type_def.name.position.start, type_def.name.position.end),
+                children,
+            }
+        }
+        Definition::FunctionDef(function_def) => {
+            let start = function_def.function_token.position();
+            let identifier = &function_def.function_def.identifier;
+            OutlineItem {
+                name: identifier.id.clone(),
+                kind: OutlineKind::Function,
+                detail: definitions
+                    .function(&identifier.id)
+                    .map(|definition| definition.detail.clone())
+                    .unwrap_or_else(|| format!("function {}", identifier.id)),
+                span: Span::new(start, definition_end(delimiters, start)),
+                selection: span_of(identifier),
+                children: Vec::new(),
+            }
+        }
+        Definition::ConstantDef(constant_def) => {
+            let start = constant_def.constant_token.position();
+            let identifier = &constant_def.identifier;
+            OutlineItem {
+                name: identifier.id.clone(),
+                kind: OutlineKind::Constant,
+                detail: format!("constant {}", typed(&identifier.id, &identifier.info.ty)),
+                span: Span::new(start, definition_end(delimiters, start)),
+                selection: span_of(identifier),
+                children: Vec::new(),
+            }
+        }
+        Definition::ProtocolDef(protocol_def) => {
+            let start = protocol_def.protocol_token.position();
+            let children = protocol_def
+                .function_signatures
+                .iter()
+                .map(|signature| {
+                    let detail = type_detail(&signature.identifier.info.ty);
+                    member_item(
+                        &signature.identifier,
+                        OutlineKind::Method,
+                        detail,
+                        delimiters,
+                    )
+                })
+                .collect();
+
+            OutlineItem {
+                name: protocol_def.name.id.clone(),
+                kind: OutlineKind::Protocol,
+                detail: definitions
+                    .type_definition(&protocol_def.name.id)
+                    .map(|definition| definition.detail.clone())
+                    .unwrap_or_else(|| format!("protocol {}", protocol_def.name.id)),
+                span: Span::new(start, definition_end(delimiters, start)),
+                selection: Span::new(
+                    protocol_def.name.position.start,
+                    protocol_def.name.position.end,
+                ),
+                children,
+            }
+        }
+    }
+}
+
+fn member_item(
+    identifier: &Identifier,
+    kind: OutlineKind,
+    detail: String,
+    delimiters: &[(usize, char)],
+) -> OutlineItem {
+    let start = identifier.position.start;
+    OutlineItem {
+        name: identifier.id.clone(),
+        kind,
+        detail,
+        span: Span::new(start, definition_end(delimiters, start)),
+        selection: span_of(identifier),
+        children: Vec::new(),
+    }
+}
+
+/// The type shown next to a member, empty when it is unknown
+fn type_detail(ty: &TypeAnnotation) -> String {
+    ty.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+/// # Description
+///
+/// Where the definition starting at `start` ends: right after the `;` that
+/// terminates it, or after the `}` that closes its body
+fn definition_end(delimiters: &[(usize, char)], start: usize) -> usize {
+    let first = delimiters.partition_point(|(position, _)| *position < start);
+    let mut depth = 0usize;
+
+    for &(position, delimiter) in &delimiters[first..] {
+        match delimiter {
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return position + 1;
+                }
+            }
+            ';' if depth == 0 => return position + 1,
+            _ => {}
+        }
+    }
+
+    delimiters
+        .last()
+        .map_or(start, |(position, _)| position + 1)
+}
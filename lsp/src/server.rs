@@ -0,0 +1,343 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+};
+
+use runner::Compiler;
+use serde_json::{Value, json};
+
+use crate::{
+    document::{Document, Library},
+    symbol_index::{MemberKind, OutlineItem, OutlineKind},
+    transport::{read_message, write_message},
+};
+
+// JSON-RPC error codes
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+
+type RequestResult = Result<Value, (i64, String)>;
+
+/// # Description
+///
+/// Language server for HULK. It reads JSON-RPC messages from `input` and
+/// writes its responses and notifications to `output`, usually the standard
+/// input and output of the process.
+///
+/// Documents are fully synchronized: every change sends the whole text, which
+/// is parsed and analyzed again, and its diagnostics are published right away
+pub struct Server<R: BufRead, W: Write> {
+    input: R,
+    output: W,
+    compiler: Compiler,
+    std_library: Option<Library>,
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+}
+
+impl<R: BufRead, W: Write> Server<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            output,
+            compiler: Compiler::new(),
+            std_library: None,
+            documents: HashMap::new(),
+            shutdown: false,
+        }
+    }
+
+    /// # Description
+    ///
+    /// Analyzes every document with the library at `uri` loaded as a module of
+    /// its own, like the runner does with the standard library
+    pub fn with_std_library(mut self, uri: String, text: String) -> Self {
+        self.std_library = Some(Library { uri, text });
+        self
+    }
+
+    /// # Description
+    ///
+    /// Handles messages until the `exit` notification or the end of the
+    /// input, returning the exit code of the server: `0` if the client asked
+    /// it to shut down first, `1` otherwise
+    pub fn run(&mut self) -> io::Result<i32> {
+        loop {
+            let message = match read_message(&mut self.input) {
+                Ok(Some(message)) => message,
+                Ok(None) => return Ok(if self.shutdown { 0 } else { 1 }),
+                Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                    self.respond(Value::Null, Err((PARSE_ERROR, err.to_string())))?;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            let method = message.get("method").and_then(Value::as_str);
+            let params = message.get("params").cloned().unwrap_or(Value::Null);
+            match (method, message.get("id")) {
+                (Some("exit"), _) => return Ok(if self.shutdown { 0 } else { 1 }),
+                (Some(method), Some(id)) => {
+                    let result = self.handle_request(method, &params);
+                    self.respond(id.clone(), result)?;
+                }
+                (Some(method), None) => self.handle_notification(method, &params)?,
+                // responses to requests of the server, it sends none
+                (None, _) => {}
+            }
+        }
+    }
+
+    fn handle_request(&mut self, method: &str, params: &Value) -> RequestResult {
+        if self.shutdown {
+            return Err((INVALID_REQUEST, "The server is shutting down".to_string()));
+        }
+
+        match method {
+            "initialize" => Ok(initialize_result()),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            "textDocument/hover" => self.hover(params),
+            "textDocument/definition" => self.definition(params),
+            "textDocument/completion" => self.completion(params),
+            "textDocument/documentSymbol" => self.document_symbols(params),
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method `{}`", method))),
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) -> io::Result<()> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let version = params
+            .pointer("/textDocument/version")
+            .and_then(Value::as_i64)
+            .unwrap_or(0);
+
+        match (method, uri) {
+            ("textDocument/didOpen", Some(uri)) => {
+                let text = params
+                    .pointer("/textDocument/text")
+                    .and_then(Value::as_str)
+                    .unwrap_or("")
+                    .to_string();
+                let document = Document::new(
+                    &self.compiler,
+                    self.std_library.as_ref(),
+                    uri.clone(),
+                    version,
+                    text,
+                );
+                self.documents.insert(uri.clone(), document);
+                self.publish_diagnostics(&uri)
+            }
+            ("textDocument/didChange", Some(uri)) => {
+                // the changes hold the whole text, the last one is the current text
+                let text = params
+                    .get("contentChanges")
+                    .and_then(Value::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Value::as_str);
+                let (Some(text), Some(document)) = (text, self.documents.get_mut(&uri)) else {
+                    return Ok(());
+                };
+                document.update(
+                    &self.compiler,
+                    self.std_library.as_ref(),
+                    version,
+                    text.to_string(),
+                );
+                self.publish_diagnostics(&uri)
+            }
+            ("textDocument/didClose", Some(uri)) => {
+                self.documents.remove(&uri);
+                self.notify(
+                    "textDocument/publishDiagnostics",
+                    json!({ "uri": uri, "diagnostics": [] }),
+                )
+            }
+            // `initialized`, `$/cancelRequest`, `didSave` and the like need no answer
+            _ => Ok(()),
+        }
+    }
+
+    fn publish_diagnostics(&mut self, uri: &str) -> io::Result<()> {
+        let Some(document) = self.documents.get(uri) else {
+            return Ok(());
+        };
+        let diagnostics = document.lsp_diagnostics();
+        self.notify("textDocument/publishDiagnostics", diagnostics)
+    }
+
+    fn respond(&mut self, id: Value, result: RequestResult) -> io::Result<()> {
+        let response = match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message },
+            }),
+        };
+        write_message(&mut self.output, &response)
+    }
+
+    fn notify(&mut self, method: &str, params: Value) -> io::Result<()> {
+        let notification = json!({ "jsonrpc": "2.0", "method": method, "params": params });
+        write_message(&mut self.output, &notification)
+    }
+
+    /// The document and the position in its program text a request is about
+    fn document_position(&self, params: &Value) -> Result<(&Document, usize), (i64, String)> {
+        let document = self.document(params)?;
+        let position = params
+            .get("position")
+            .and_then(|position| document.position_of(position))
+            .ok_or((INVALID_PARAMS, "Missing position".to_string()))?;
+        Ok((document, position))
+    }
+
+    fn document(&self, params: &Value) -> Result<&Document, (i64, String)> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .ok_or((INVALID_PARAMS, "Missing text document".to_string()))?;
+        self.documents
+            .get(uri)
+            .ok_or((INVALID_PARAMS, format!("Document `{}` is not open", uri)))
+    }
+
+    fn hover(&self, params: &Value) -> RequestResult {
+        let (document, position) = self.document_position(params)?;
+        let Some(symbol) = document
+            .index
+            .as_ref()
+            .and_then(|index| index.symbol_at(position))
+        else {
+            return Ok(Value::Null);
+        };
+
+        Ok(json!({
+            "contents": {
+                "kind": "markdown",
+                "value": format!("```hulk\n{}\n```", symbol.detail),
+            },
+            "range": document.range(symbol.span),
+        }))
+    }
+
+    fn definition(&self, params: &Value) -> RequestResult {
+        let (document, position) = self.document_position(params)?;
+        let location = document
+            .index
+            .as_ref()
+            .and_then(|index| index.symbol_at(position))
+            .and_then(|symbol| symbol.definition)
+            .and_then(|definition| document.location(definition));
+
+        Ok(location.unwrap_or(Value::Null))
+    }
+
+    /// # Description
+    ///
+    /// Completes the members of the object before a `.`. The document is
+    /// usually invalid while the member is being written, so the object is
+    /// looked up by name in the index of the last version that was valid.
+    /// Fields are private, they are only offered on `self`
+    fn completion(&self, params: &Value) -> RequestResult {
+        let (document, position) = self.document_position(params)?;
+        let Some(index) = &document.index else {
+            return Ok(json!([]));
+        };
+
+        let before = &document.text[..position - document.offset];
+        let is_name = |c: char| c.is_alphanumeric() || c == '_';
+        let before = before.trim_end_matches(is_name);
+        let Some(before) = before.strip_suffix('.') else {
+            return Ok(json!([]));
+        };
+        let receiver = &before[before.trim_end_matches(is_name).len()..];
+        if receiver.is_empty() {
+            return Ok(json!([]));
+        }
+
+        let receiver_position = document.offset + before.len() - receiver.len();
+        let ty = index
+            .symbol_at(receiver_position)
+            .filter(|symbol| symbol.name == receiver)
+            .and_then(|symbol| symbol.ty.as_ref())
+            .or_else(|| index.type_of_name_before(receiver, receiver_position));
+        let Some(ty) = ty else {
+            return Ok(json!([]));
+        };
+
+        let items = index
+            .definitions
+            .members(&ty.to_string())
+            .into_iter()
+            .filter(|member| member.kind == MemberKind::Method || receiver == "self")
+            .map(|member| {
+                let kind = match member.kind {
+                    MemberKind::Field => 5,
+                    MemberKind::Method => 2,
+                };
+                json!({ "label": member.name, "kind": kind, "detail": member.detail })
+            })
+            .collect::<Vec<_>>();
+        Ok(Value::Array(items))
+    }
+
+    fn document_symbols(&self, params: &Value) -> RequestResult {
+        let document = self.document(params)?;
+        let symbols = document
+            .index
+            .iter()
+            .flat_map(|index| &index.outline)
+            .map(|item| document_symbol(document, item))
+            .collect();
+        Ok(Value::Array(symbols))
+    }
+}
+
+fn document_symbol(document: &Document, item: &OutlineItem) -> Value {
+    // LSP `SymbolKind`s
+    let kind = match item.kind {
+        OutlineKind::Type => 5,
+        OutlineKind::Method => 6,
+        OutlineKind::Field => 8,
+        OutlineKind::Protocol => 11,
+        OutlineKind::Function => 12,
+        OutlineKind::Constant => 14,
+    };
+    json!({
+        "name": item.name,
+        "detail": item.detail,
+        "kind": kind,
+        "range": document.range(item.span),
+        "selectionRange": document.range(item.selection),
+        "children": item
+            .children
+            .iter()
+            .map(|child| document_symbol(document, child))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn initialize_result() -> Value {
+    json!({
+        "capabilities": {
+            // full synchronization
+            "textDocumentSync": 1,
+            "hoverProvider": true,
+            "definitionProvider": true,
+            "completionProvider": { "triggerCharacters": ["."] },
+            "documentSymbolProvider": true,
+        },
+        "serverInfo": { "name": "hulk-lsp", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
@@ -0,0 +1,355 @@
+use std::collections::{HashMap, HashSet};
+
+use ast::{
+    Identifier, Program, VisitableDefinition, VisitableExpression,
+    typing::{Type, TypeAnnotation, to_string},
+};
+use error_handler::diagnostic::diagnostic::Span;
+use semantic_analyzer::{
+    def_info::{DefinitionInfo, FuncInfo, TypeInfo},
+    semantic_analyzer::SemanticAnalyzer,
+};
+
+use crate::{outline::outline, symbol_visitor::SymbolVisitor};
+
+/// # Description
+///
+/// An occurrence of a name in the source code: a variable, a function, a
+/// type or a member, and what it refers to
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub span: Span,
+    /// Type of the value the name stands for, the return type for functions
+    pub ty: TypeAnnotation,
+    /// How the symbol is shown on hover, e.g. `x: Number`
+    pub detail: String,
+    pub definition: Option<Span>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+    Field,
+    Method,
+}
+
+/// # Description
+///
+/// A field or a method of a type, or a method of a protocol
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub name: String,
+    pub kind: MemberKind,
+    pub ty: TypeAnnotation,
+    pub detail: String,
+    pub definition: Span,
+}
+
+/// # Description
+///
+/// A global function, type or protocol, as the semantic analyzer left it
+#[derive(Debug, Clone)]
+pub struct GlobalDefinition {
+    pub ty: TypeAnnotation,
+    pub detail: String,
+    pub definition: Span,
+}
+
+#[derive(Debug, Clone)]
+struct TypeEntry {
+    definition: GlobalDefinition,
+    parent: Option<String>,
+    members: Vec<Member>,
+}
+
+/// # Description
+///
+/// The global definitions of a program, read from the contexts of the
+/// `SemanticAnalyzer` once it is done
+#[derive(Debug, Clone, Default)]
+pub struct Definitions {
+    functions: HashMap<String, GlobalDefinition>,
+    types: HashMap<String, TypeEntry>,
+}
+
+impl Definitions {
+    pub fn from_analyzer(analyzer: &SemanticAnalyzer) -> Self {
+        let functions = analyzer
+            .func_definitions
+            .visible_ids()
+            .into_iter()
+            .filter_map(|id| analyzer.func_definitions.get_value(id))
+            .map(|info| {
+                let definition = GlobalDefinition {
+                    ty: info.name.info.ty.clone(),
+                    detail: format!("function {}", signature(&info.name.id, info)),
+                    definition: span_of(&info.name),
+                };
+                (info.name.id.clone(), definition)
+            })
+            .collect();
+
+        let types = analyzer
+            .type_definitions
+            .visible_ids()
+            .into_iter()
+            .filter_map(|id| {
+                let entry = match analyzer.type_definitions.get_value(id)? {
+                    TypeInfo::Defined(info) => {
+                        let parent = parent_name(analyzer.type_hierarchy.get(id));
+                        let mut members = info
+                            .members
+                            .values()
+                            .map(|member| match member {
+                                DefinitionInfo::Var(field) => Member {
+                                    name: field.name.clone(),
+                                    kind: MemberKind::Field,
+                                    ty: field.ty.clone(),
+                                    detail: format!("{}.{}", id, typed(&field.name, &field.ty)),
+                                    definition: Span::new(field.position.start, field.position.end),
+                                },
+                                DefinitionInfo::Func(method) => method_member(id, method),
+                                DefinitionInfo::Type(_) => {
+                                    unreachable!("Types have no nested types")
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        members.sort_by_key(|member| member.definition.start);
+
+                        let detail = match &parent {
+                            Some(parent) => format!("type {} inherits {}", id, parent),
+                            None => format!("type {}", id),
+                        };
+                        TypeEntry {
+                            definition: GlobalDefinition {
+                                ty: Some(Type::Defined(info.name.clone())),
+                                detail,
+                                definition: Span::new(
+                                    info.name.position.start,
+                                    info.name.position.end,
+                                ),
+                            },
+                            parent,
+                            members,
+                        }
+                    }
+                    TypeInfo::Protocol(info) => {
+                        let parent = info.parent.as_ref().map(|parent| parent.id.clone());
+                        let mut members = info
+                            .methods
+                            .values()
+                            .map(|method| method_member(id, method))
+                            .collect::<Vec<_>>();
+                        members.sort_by_key(|member| member.definition.start);
+
+                        let detail = match &parent {
+                            Some(parent) => format!("protocol {} extends {}", id, parent),
+                            None => format!("protocol {}", id),
+                        };
+                        TypeEntry {
+                            definition: GlobalDefinition {
+                                ty: Some(Type::Defined(info.name.clone())),
+                                detail,
+                                definition: Span::new(
+                                    info.name.position.start,
+                                    info.name.position.end,
+                                ),
+                            },
+                            parent,
+                            members,
+                        }
+                    }
+                    TypeInfo::BuiltIn(_) | TypeInfo::Parameter(_) => return None,
+                };
+                Some((id.to_string(), entry))
+            })
+            .collect();
+
+        Self { functions, types }
+    }
+
+    pub fn function(&self, name: &str) -> Option<&GlobalDefinition> {
+        self.functions.get(name)
+    }
+
+    /// # Description
+    ///
+    /// The definition of a type or a protocol, `None` for built-in types
+    pub fn type_definition(&self, name: &str) -> Option<&GlobalDefinition> {
+        self.types.get(name).map(|entry| &entry.definition)
+    }
+
+    /// # Description
+    ///
+    /// The members of the type or protocol `type_name`, including the ones it
+    /// inherits. Members overridden by the type are only listed once
+    pub fn members(&self, type_name: &str) -> Vec<&Member> {
+        let mut members = Vec::new();
+        let mut names = HashSet::new();
+        let mut visited = HashSet::new();
+
+        let mut current = Some(type_name);
+        while let Some(name) = current {
+            // cycles are reported by the semantic analyzer, the chain is cut here
+            if !visited.insert(name) {
+                break;
+            }
+            let Some(entry) = self.types.get(name) else {
+                break;
+            };
+            members.extend(
+                entry
+                    .members
+                    .iter()
+                    .filter(|member| names.insert(member.name.as_str())),
+            );
+            current = entry.parent.as_deref();
+        }
+
+        members
+    }
+
+    pub fn member(&self, type_name: &str, name: &str, kind: MemberKind) -> Option<&Member> {
+        self.members(type_name)
+            .into_iter()
+            .find(|member| member.name == name && member.kind == kind)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlineKind {
+    Type,
+    Protocol,
+    Function,
+    Constant,
+    Field,
+    Method,
+}
+
+/// # Description
+///
+/// A definition of the document outline. `span` covers the whole definition
+/// and `selection` only its name
+#[derive(Debug, Clone)]
+pub struct OutlineItem {
+    pub name: String,
+    pub kind: OutlineKind,
+    pub detail: String,
+    pub span: Span,
+    pub selection: Span,
+    pub children: Vec<OutlineItem>,
+}
+
+/// # Description
+///
+/// Everything the language server knows about the names of an analyzed
+/// program. Spans are positions in the program text, which includes the
+/// standard library, only the symbols in the user code are kept
+#[derive(Debug, Clone, Default)]
+pub struct SymbolIndex {
+    pub definitions: Definitions,
+    /// Sorted by position
+    pub symbols: Vec<Symbol>,
+    pub outline: Vec<OutlineItem>,
+}
+
+impl SymbolIndex {
+    pub fn build(
+        program: &mut Program,
+        definitions: Definitions,
+        text: &str,
+        delimiters: &[(usize, char)],
+        user_code_offset: usize,
+    ) -> Self {
+        let mut symbols = Vec::new();
+        let mut visitor = SymbolVisitor::new(text, &definitions, &mut symbols);
+        program
+            .definitions
+            .iter_mut()
+            .for_each(|x| x.accept(&mut visitor));
+        program
+            .expressions
+            .iter_mut()
+            .for_each(|x| x.accept(&mut visitor));
+
+        symbols.retain(|symbol| symbol.span.start >= user_code_offset);
+        symbols.sort_by_key(|symbol| symbol.span.start);
+        // inferred type annotations may point to a name that was already indexed
+        symbols.dedup_by_key(|symbol| symbol.span.start);
+
+        let outline = outline(program, &definitions, delimiters, user_code_offset);
+
+        Self {
+            definitions,
+            symbols,
+            outline,
+        }
+    }
+
+    /// # Description
+    ///
+    /// The symbol under `position`, a position right after a name also
+    /// counts as being on it
+    pub fn symbol_at(&self, position: usize) -> Option<&Symbol> {
+        let index = self
+            .symbols
+            .partition_point(|symbol| symbol.span.start <= position);
+        self.symbols[..index]
+            .last()
+            .filter(|symbol| position <= symbol.span.end)
+    }
+
+    /// # Description
+    ///
+    /// The type of the last symbol named `name` that starts before `position`
+    pub fn type_of_name_before(&self, name: &str, position: usize) -> Option<&Type> {
+        self.symbols
+            .iter()
+            .rev()
+            .filter(|symbol| symbol.span.start < position && symbol.name == name)
+            .find_map(|symbol| symbol.ty.as_ref())
+    }
+}
+
+pub(crate) fn span_of(identifier: &Identifier) -> Span {
+    Span::new(identifier.position.start, identifier.position.end)
+}
+
+/// # Description
+///
+/// `name: Type`, or just `name` when the type is unknown
+pub(crate) fn typed(name: &str, ty: &TypeAnnotation) -> String {
+    match ty {
+        Some(ty) => format!("{}: {}", name, ty),
+        None => name.to_string(),
+    }
+}
+
+/// # Description
+///
+/// `name(a: Number, b: Number): Number` for the function of `info`
+pub(crate) fn signature(name: &str, info: &FuncInfo) -> String {
+    let parameters = info
+        .parameters
+        .iter()
+        .map(|parameter| typed(&parameter.id, &parameter.info.ty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    typed(&format!("{}({})", name, parameters), &info.name.info.ty)
+}
+
+fn method_member(type_name: &str, method: &FuncInfo) -> Member {
+    Member {
+        name: method.name.id.clone(),
+        kind: MemberKind::Method,
+        ty: method.name.info.ty.clone(),
+        detail: format!("{}.{}", type_name, signature(&method.name.id, method)),
+        definition: span_of(&method.name),
+    }
+}
+
+/// Name of the parent of a type, `None` when it only inherits from `Object`
+fn parent_name(parent: Option<&TypeAnnotation>) -> Option<String> {
+    let parent = to_string(parent?);
+    (parent != "Object" && parent != "none").then_some(parent)
+}
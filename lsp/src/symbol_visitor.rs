@@ -0,0 +1,462 @@
+use ast::{
+    DefinitionVisitor, Expression, ExpressionVisitor, FunctionDef, Identifier, TypeName,
+    VisitableDefinition, VisitableExpression,
+    token_position::TokenPositionTrait,
+    typing::{Type, TypeAnnotation},
+};
+use error_handler::diagnostic::diagnostic::Span;
+use generator::builtins::find_builtin;
+
+use crate::symbol_index::{Definitions, MemberKind, Symbol, span_of, typed};
+
+/// # Description
+/// Visitor that walks the typed AST collecting every name it finds, with its type and
+/// where it is defined. Variables are resolved by the semantic analyzer, which leaves
+/// their definition in `Identifier.info`; functions, types and members are looked up in
+/// the global `Definitions`.
+/// # Arguments
+/// * `text` - The program text, used to locate the names the AST keeps no position for.
+/// * `definitions` - The global definitions of the program.
+/// * `symbols` - A mutable reference to a vector that holds the symbols found.
+/// * `scopes` - The variables visible from the current expression, innermost scope last.
+pub struct SymbolVisitor<'a> {
+    text: &'a str,
+    definitions: &'a Definitions,
+    pub symbols: &'a mut Vec<Symbol>,
+    scopes: Vec<Vec<(String, Span, TypeAnnotation)>>,
+}
+
+impl<'a> SymbolVisitor<'a> {
+    pub fn new(text: &'a str, definitions: &'a Definitions, symbols: &'a mut Vec<Symbol>) -> Self {
+        SymbolVisitor {
+            text,
+            definitions,
+            symbols,
+            scopes: Vec::new(),
+        }
+    }
+
+    fn push(
+        &mut self,
+        name: &str,
+        span: Span,
+        ty: TypeAnnotation,
+        detail: String,
+        definition: Option<Span>,
+    ) {
+        self.symbols.push(Symbol {
+            name: name.to_string(),
+            span,
+            ty,
+            detail,
+            definition,
+        });
+    }
+
+    /// Indexes a variable or a parameter where it is defined, it is visible in the current scope
+    fn define(&mut self, identifier: &Identifier) {
+        let span = span_of(identifier);
+        self.push(
+            &identifier.id,
+            span,
+            identifier.info.ty.clone(),
+            typed(&identifier.id, &identifier.info.ty),
+            Some(span),
+        );
+        if let Some(ty) = &identifier.info.ty {
+            self.type_reference(ty);
+        }
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((identifier.id.clone(), span, identifier.info.ty.clone()));
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&(String, Span, TypeAnnotation)> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(id, _, _)| id == name)
+    }
+
+    /// Indexes the names of the types written in a type annotation
+    fn type_reference(&mut self, ty: &Type) {
+        match ty {
+            Type::Defined(name) => self.type_name_reference(name),
+            Type::Functor(functor) => {
+                functor
+                    .parameter_types
+                    .iter()
+                    .flatten()
+                    .for_each(|ty| self.type_reference(ty));
+                if let Some(ty) = functor.return_type.as_ref() {
+                    self.type_reference(ty);
+                }
+            }
+            Type::Iterable(inner) => self.type_reference(inner),
+            Type::Generic(name, arguments) => {
+                self.type_name_reference(name);
+                arguments.iter().for_each(|ty| self.type_reference(ty));
+            }
+            Type::BuiltIn(_) => {}
+        }
+    }
+
+    fn type_name_reference(&mut self, name: &TypeName) {
+        // inferred types carry the position of some other annotation, if any
+        let written = self.text.get(name.position.start..name.position.end);
+        if written != Some(name.id.as_str()) {
+            return;
+        }
+        let Some(definition) = self.definitions.type_definition(&name.id) else {
+            return;
+        };
+        let (ty, detail, definition) = (
+            definition.ty.clone(),
+            definition.detail.clone(),
+            definition.definition,
+        );
+        self.push(
+            &name.id,
+            Span::new(name.position.start, name.position.end),
+            ty,
+            detail,
+            Some(definition),
+        );
+    }
+
+    /// Indexes a member accessed on an object of type `object_type`
+    fn member_reference(
+        &mut self,
+        identifier: &Identifier,
+        object_type: &TypeAnnotation,
+        kind: MemberKind,
+    ) {
+        let member = object_type.as_ref().and_then(|ty| {
+            self.definitions
+                .member(&ty.to_string(), &identifier.id, kind)
+        });
+        let (ty, detail, definition) = match member {
+            Some(member) => (
+                identifier.info.ty.clone().or(member.ty.clone()),
+                member.detail.clone(),
+                Some(member.definition),
+            ),
+            None => (
+                identifier.info.ty.clone(),
+                typed(&identifier.id, &identifier.info.ty),
+                None,
+            ),
+        };
+        self.push(&identifier.id, span_of(identifier), ty, detail, definition);
+    }
+
+    fn handle_fn_def(&mut self, fn_def: &mut FunctionDef, type_name: Option<&str>) {
+        let identifier = &fn_def.identifier;
+        let global = match type_name {
+            Some(type_name) => self
+                .definitions
+                .member(type_name, &identifier.id, MemberKind::Method)
+                .map(|member| member.detail.clone()),
+            None => self
+                .definitions
+                .function(&identifier.id)
+                .map(|function| function.detail.clone()),
+        };
+        let detail = global.unwrap_or_else(|| typed(&identifier.id, &identifier.info.ty));
+        self.push(
+            &identifier.id,
+            span_of(identifier),
+            identifier.info.ty.clone(),
+            detail,
+            Some(span_of(identifier)),
+        );
+        if let Some(ty) = &identifier.info.ty {
+            self.type_reference(ty);
+        }
+
+        self.scopes.push(Vec::new());
+        fn_def.parameters.iter().for_each(|x| self.define(x));
+        fn_def.body.accept(self);
+        self.scopes.pop();
+    }
+}
+
+impl<'a> ExpressionVisitor<()> for SymbolVisitor<'a> {
+    fn visit_expression(&mut self, node: &mut Expression) -> () {
+        node.accept(self);
+    }
+
+    fn visit_destructive_assignment(&mut self, node: &mut ast::DestructiveAssignment) -> () {
+        node.lhs.accept(self);
+        node.rhs.accept(self);
+    }
+
+    fn visit_bin_op(&mut self, node: &mut ast::BinOp) -> () {
+        node.lhs.accept(self);
+        node.rhs.accept(self);
+    }
+
+    fn visit_let_in(&mut self, node: &mut ast::LetIn) -> () {
+        // the variable is not visible in its own initializer
+        node.assignment.accept(self);
+
+        self.scopes.push(Vec::new());
+        self.define(&node.assignment.identifier);
+        node.body.accept(self);
+        self.scopes.pop();
+    }
+
+    fn visit_new_expr(&mut self, node: &mut ast::NewExpr) -> () {
+        // the AST only keeps where `new` is, the type name is the next word
+        let after_new = node.new_token.position() + "new".len();
+        let rest = self.text.get(after_new..).unwrap_or("");
+        let start = after_new + (rest.len() - rest.trim_start().len());
+        let name = TypeName::new(start, start + node.type_name.len(), node.type_name.clone());
+        self.type_name_reference(&name);
+
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_assignment(&mut self, node: &mut ast::Assignment) -> () {
+        node.rhs.accept(self);
+    }
+
+    fn visit_if_else(&mut self, node: &mut ast::IfElse) -> () {
+        node.condition.accept(self);
+        node.then_expression.accept(self);
+        node.else_expression.accept(self);
+    }
+
+    fn visit_while(&mut self, node: &mut ast::While) -> () {
+        node.condition.accept(self);
+        node.body.accept(self);
+    }
+
+    fn visit_for(&mut self, node: &mut ast::For) -> () {
+        node.iterable.accept(self);
+        self.scopes.push(Vec::new());
+        self.define(&node.element);
+        node.body.accept(self);
+        self.scopes.pop();
+    }
+
+    fn visit_block(&mut self, node: &mut ast::Block) -> () {
+        node.body_items.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_return_statement(&mut self, node: &mut ast::ReturnStatement) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_un_op(&mut self, node: &mut ast::UnOp) -> () {
+        node.rhs.accept(self);
+    }
+
+    fn visit_data_member_access(&mut self, node: &mut ast::DataMemberAccess) -> () {
+        node.object.accept(self);
+        self.member_reference(&node.member, &node.obj_type, MemberKind::Field);
+    }
+
+    fn visit_function_member_access(&mut self, node: &mut ast::FunctionMemberAccess) -> () {
+        node.object.accept(self);
+        self.member_reference(&node.member.identifier, &node.obj_type, MemberKind::Method);
+        node.member
+            .arguments
+            .iter_mut()
+            .for_each(|x| x.accept(self));
+    }
+
+    fn visist_list_indexing(&mut self, node: &mut ast::ListIndexing) -> () {
+        node.list.accept(self);
+        node.index.accept(self);
+    }
+
+    fn visit_function_call(&mut self, node: &mut ast::FunctionCall) -> () {
+        let identifier = &node.identifier;
+        let span = span_of(identifier);
+        // variables holding functions shadow global functions
+        if let Some((_, definition, ty)) = self.lookup(&identifier.id).cloned() {
+            self.push(
+                &identifier.id,
+                span,
+                identifier.info.ty.clone(),
+                typed(&identifier.id, &ty),
+                Some(definition),
+            );
+        } else if let Some(function) = self.definitions.function(&identifier.id) {
+            let (ty, detail, definition) = (
+                function.ty.clone(),
+                function.detail.clone(),
+                function.definition,
+            );
+            self.push(&identifier.id, span, ty, detail, Some(definition));
+        } else if let Some(builtin) = find_builtin(&identifier.id) {
+            let parameters = builtin
+                .parameters
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let detail = format!(
+                "function {}({}): {}",
+                builtin.name, parameters, builtin.return_type
+            );
+            let ty = Some(Type::BuiltIn(builtin.return_type.clone()));
+            self.push(&identifier.id, span, ty, detail, None);
+        }
+
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_lambda(&mut self, node: &mut ast::Lambda) -> () {
+        self.scopes.push(Vec::new());
+        node.parameters.iter().for_each(|x| self.define(x));
+        if let Some(ty) = &node.return_type {
+            self.type_reference(ty);
+        }
+        node.body.accept(self);
+        self.scopes.pop();
+    }
+
+    fn visit_base_call(&mut self, node: &mut ast::BaseCall) -> () {
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_is_expr(&mut self, node: &mut ast::IsExpr) -> () {
+        node.expression.accept(self);
+        self.type_reference(&node.target);
+    }
+
+    fn visit_as_expr(&mut self, node: &mut ast::AsExpr) -> () {
+        node.expression.accept(self);
+        self.type_reference(&node.target);
+    }
+
+    fn visit_variable(&mut self, node: &mut Identifier) -> () {
+        let definition = match &node.info.definition_pos {
+            Some(position) => Some(Span::new(position.start, position.end)),
+            None => self.lookup(&node.id).map(|(_, span, _)| *span),
+        };
+        self.push(
+            &node.id,
+            span_of(node),
+            node.info.ty.clone(),
+            typed(&node.id, &node.info.ty),
+            definition,
+        );
+    }
+
+    fn visit_number_literal(&mut self, _node: &mut ast::NumberLiteral) -> () {}
+
+    fn visit_boolean_literal(&mut self, _node: &mut ast::BooleanLiteral) -> () {}
+
+    fn visit_string_literal(&mut self, _node: &mut ast::StringLiteral) -> () {}
+
+    fn visit_list_literal(&mut self, node: &mut ast::ListLiteral) -> () {
+        node.elements.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_empty_expression(&mut self) -> () {}
+}
+
+impl<'a> DefinitionVisitor<()> for SymbolVisitor<'a> {
+    fn visit_definition(&mut self, node: &mut ast::Definition) -> () {
+        node.accept(self);
+    }
+
+    fn visit_type_def(&mut self, node: &mut ast::TypeDef) -> () {
+        let type_name = node.name.id.clone();
+        self.type_name_reference(&node.name);
+
+        // the parameters of a type are only visible in the arguments of its parent and in
+        // the initializers of its fields
+        self.scopes.push(Vec::new());
+        node.parameter_list.iter().for_each(|x| self.define(x));
+        if let Some(inheritance) = &mut node.inheritance_indicator {
+            self.type_name_reference(&inheritance.parent_name);
+            inheritance
+                .argument_list
+                .iter_mut()
+                .for_each(|x| x.accept(self));
+        }
+        for field in &mut node.data_member_defs {
+            field.default_value.accept(self);
+
+            let identifier = &field.identifier;
+            let detail = self
+                .definitions
+                .member(&type_name, &identifier.id, MemberKind::Field)
+                .map(|member| member.detail.clone())
+                .unwrap_or_else(|| typed(&identifier.id, &identifier.info.ty));
+            let span = span_of(identifier);
+            self.push(
+                &identifier.id,
+                span,
+                identifier.info.ty.clone(),
+                detail,
+                Some(span),
+            );
+            if let Some(ty) = &identifier.info.ty {
+                self.type_reference(ty);
+            }
+        }
+        self.scopes.pop();
+
+        for method in &mut node.function_member_defs {
+            self.handle_fn_def(method, Some(&type_name));
+        }
+    }
+
+    fn visit_function_def(&mut self, node: &mut ast::GlobalFunctionDef) -> () {
+        self.handle_fn_def(&mut node.function_def, None);
+    }
+
+    fn visit_constant_def(&mut self, node: &mut ast::ConstantDef) -> () {
+        node.initializer_expression.accept(self);
+
+        let identifier = &node.identifier;
+        let span = span_of(identifier);
+        self.push(
+            &identifier.id,
+            span,
+            identifier.info.ty.clone(),
+            format!("constant {}", typed(&identifier.id, &identifier.info.ty)),
+            Some(span),
+        );
+        if let Some(ty) = &identifier.info.ty {
+            self.type_reference(ty);
+        }
+    }
+
+    fn visit_protocol_def(&mut self, node: &mut ast::ProtocolDef) -> () {
+        let protocol_name = node.name.id.clone();
+        self.type_name_reference(&node.name);
+        if let Some(extension) = &node.extension_indicator {
+            self.type_name_reference(&extension.extendee_name);
+        }
+
+        for signature in &node.function_signatures {
+            let identifier = &signature.identifier;
+            let detail = self
+                .definitions
+                .member(&protocol_name, &identifier.id, MemberKind::Method)
+                .map(|member| member.detail.clone())
+                .unwrap_or_else(|| typed(&identifier.id, &identifier.info.ty));
+            let span = span_of(identifier);
+            self.push(
+                &identifier.id,
+                span,
+                identifier.info.ty.clone(),
+                detail,
+                Some(span),
+            );
+            signature
+                .parameters
+                .iter()
+                .chain(std::iter::once(identifier))
+                .filter_map(|x| x.info.ty.clone())
+                .for_each(|ty| self.type_reference(&ty));
+        }
+    }
+}
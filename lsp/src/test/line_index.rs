@@ -0,0 +1,34 @@
+use crate::line_index::LineIndex;
+
+#[test]
+fn positions_of_offsets() {
+    let index = LineIndex::new("let x = 1 in\nprint(x);\n");
+
+    assert_eq!(index.position(0), (0, 0));
+    assert_eq!(index.position(4), (0, 4));
+    assert_eq!(index.position(13), (1, 0));
+    assert_eq!(index.position(19), (1, 6));
+    assert_eq!(index.position(100), (2, 0));
+}
+
+#[test]
+fn offsets_of_positions() {
+    let index = LineIndex::new("let x = 1 in\nprint(x);\n");
+
+    assert_eq!(index.offset(0, 4), 4);
+    assert_eq!(index.offset(1, 6), 19);
+    // past the end of the line
+    assert_eq!(index.offset(0, 50), 12);
+    assert_eq!(index.offset(7, 0), 23);
+}
+
+#[test]
+fn characters_are_utf16_code_units() {
+    let text = "print(\"ñ😀\"); x";
+    let index = LineIndex::new(text);
+    let x = text.find('x').unwrap();
+
+    // `ñ` is one code unit and the emoji two
+    assert_eq!(index.position(x), (0, 14));
+    assert_eq!(index.offset(0, 14), x);
+}
@@ -0,0 +1,142 @@
+use std::io::Cursor;
+
+use serde_json::{Value, json};
+
+use crate::{Server, transport::read_message, transport::write_message};
+
+mod line_index;
+mod server;
+
+const URI: &str = "file:///main.hulk";
+
+/// # Description
+///
+/// Scripted JSON-RPC client: the messages are queued, then sent to a fresh
+/// server all at once and everything the server wrote is collected
+struct Client {
+    messages: Vec<Value>,
+    next_id: i64,
+    std_library: Option<(String, String)>,
+}
+
+impl Client {
+    /// A client that already initialized the server
+    fn new() -> Self {
+        let mut client = Self {
+            messages: Vec::new(),
+            next_id: 0,
+            std_library: None,
+        };
+        client.request("initialize", json!({ "capabilities": {} }));
+        client.notify("initialized", json!({}));
+        client
+    }
+
+    fn with_std_library(mut self, uri: &str, text: &str) -> Self {
+        self.std_library = Some((uri.to_string(), text.to_string()));
+        self
+    }
+
+    fn request(&mut self, method: &str, params: Value) -> i64 {
+        self.next_id += 1;
+        self.messages.push(json!({
+            "jsonrpc": "2.0",
+            "id": self.next_id,
+            "method": method,
+            "params": params,
+        }));
+        self.next_id
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        self.messages
+            .push(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    fn open(&mut self, text: &str) {
+        self.notify(
+            "textDocument/didOpen",
+            json!({
+                "textDocument": { "uri": URI, "languageId": "hulk", "version": 1, "text": text },
+            }),
+        );
+    }
+
+    fn change(&mut self, version: i64, text: &str) {
+        self.notify(
+            "textDocument/didChange",
+            json!({
+                "textDocument": { "uri": URI, "version": version },
+                "contentChanges": [{ "text": text }],
+            }),
+        );
+    }
+
+    /// Sends a request about the position at `line` and `character` of the document
+    fn at(&mut self, method: &str, line: usize, character: usize) -> i64 {
+        self.request(
+            method,
+            json!({
+                "textDocument": { "uri": URI },
+                "position": { "line": line, "character": character },
+            }),
+        )
+    }
+
+    /// Shuts the server down and runs the script
+    fn run(mut self) -> Transcript {
+        self.request("shutdown", Value::Null);
+        self.notify("exit", Value::Null);
+
+        let mut input = Vec::new();
+        for message in &self.messages {
+            write_message(&mut input, message).unwrap();
+        }
+        let mut output = Vec::new();
+        let mut server = Server::new(Cursor::new(input), &mut output);
+        if let Some((uri, text)) = self.std_library {
+            server = server.with_std_library(uri, text);
+        }
+        let exit_code = server.run().unwrap();
+
+        let mut output = Cursor::new(output);
+        let mut messages = Vec::new();
+        while let Some(message) = read_message(&mut output).unwrap() {
+            messages.push(message);
+        }
+        Transcript {
+            messages,
+            exit_code,
+        }
+    }
+}
+
+struct Transcript {
+    messages: Vec<Value>,
+    exit_code: i32,
+}
+
+impl Transcript {
+    /// The result of the request with `id`
+    fn result(&self, id: i64) -> &Value {
+        let response = self.response(id);
+        assert!(response.get("error").is_none(), "Error: {}", response);
+        &response["result"]
+    }
+
+    fn response(&self, id: i64) -> &Value {
+        self.messages
+            .iter()
+            .find(|message| message["id"] == id && message.get("method").is_none())
+            .unwrap_or_else(|| panic!("No response to request {}", id))
+    }
+
+    /// The diagnostics of every `textDocument/publishDiagnostics` notification, in order
+    fn diagnostics(&self) -> Vec<&Vec<Value>> {
+        self.messages
+            .iter()
+            .filter(|message| message["method"] == "textDocument/publishDiagnostics")
+            .map(|message| message["params"]["diagnostics"].as_array().unwrap())
+            .collect()
+    }
+}
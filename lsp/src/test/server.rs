@@ -0,0 +1,179 @@
+use serde_json::{Value, json};
+
+use super::{Client, URI};
+
+const PROGRAM: &str = "\
+type Point(x: Number, y: Number) {
+    x = x;
+    y = y;
+
+    norm(): Number => sqrt(self.x ^ 2 + self.y ^ 2);
+}
+
+function twice(n: Number): Number => n * 2;
+
+let p = new Point(3, 4) in print(twice(p.norm()));
+";
+
+fn range(start: (usize, usize), end: (usize, usize)) -> Value {
+    json!({
+        "start": { "line": start.0, "character": start.1 },
+        "end": { "line": end.0, "character": end.1 },
+    })
+}
+
+fn labels(items: &Value) -> Vec<&str> {
+    let mut labels = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    labels.sort();
+    labels
+}
+
+#[test]
+fn initialize_and_shutdown() {
+    let mut client = Client::new();
+    let unknown = client.request("workspace/symbol", json!({ "query": "" }));
+    let transcript = client.run();
+
+    let capabilities = &transcript.result(1)["capabilities"];
+    assert_eq!(capabilities["textDocumentSync"], 1);
+    assert_eq!(capabilities["hoverProvider"], true);
+    assert_eq!(
+        capabilities["completionProvider"]["triggerCharacters"],
+        json!(["."])
+    );
+    assert_eq!(transcript.response(unknown)["error"]["code"], -32601);
+    assert_eq!(transcript.exit_code, 0);
+}
+
+#[test]
+fn publishes_diagnostics_on_change() {
+    let mut client = Client::new();
+    client.open("print(y);");
+    client.change(2, "print(1);");
+    let transcript = client.run();
+
+    let diagnostics = transcript.diagnostics();
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].len(), 1);
+    let diagnostic = &diagnostics[0][0];
+    assert_eq!(diagnostic["severity"], 1);
+    assert_eq!(diagnostic["code"], "E0313");
+    assert_eq!(diagnostic["range"], range((0, 6), (0, 7)));
+    assert!(diagnostics[1].is_empty());
+}
+
+#[test]
+fn hover_shows_inferred_types() {
+    let mut client = Client::new();
+    client.open(PROGRAM);
+    let variable = client.at("textDocument/hover", 9, 4);
+    let function = client.at("textDocument/hover", 9, 33);
+    let method = client.at("textDocument/hover", 9, 42);
+    let nothing = client.at("textDocument/hover", 9, 1);
+    let transcript = client.run();
+
+    let hover = transcript.result(variable);
+    assert_eq!(hover["contents"]["value"], "```hulk\np: Point\n```");
+    assert_eq!(hover["range"], range((9, 4), (9, 5)));
+    assert!(
+        transcript.result(function)["contents"]["value"]
+            .as_str()
+            .unwrap()
+            .contains("function twice(n: Number): Number")
+    );
+    assert!(
+        transcript.result(method)["contents"]["value"]
+            .as_str()
+            .unwrap()
+            .contains("Point.norm(): Number")
+    );
+    assert_eq!(*transcript.result(nothing), Value::Null);
+}
+
+#[test]
+fn goes_to_definitions() {
+    let mut client = Client::new();
+    client.open(PROGRAM);
+    let function = client.at("textDocument/definition", 9, 33);
+    let variable = client.at("textDocument/definition", 9, 39);
+    let method = client.at("textDocument/definition", 9, 41);
+    let type_name = client.at("textDocument/definition", 9, 12);
+    let field = client.at("textDocument/definition", 4, 32);
+    let transcript = client.run();
+
+    let location = |id| {
+        let location = transcript.result(id);
+        assert_eq!(location["uri"], URI);
+        location["range"].clone()
+    };
+    assert_eq!(location(function), range((7, 9), (7, 14)));
+    assert_eq!(location(variable), range((9, 4), (9, 5)));
+    assert_eq!(location(method), range((4, 4), (4, 8)));
+    assert_eq!(location(type_name), range((0, 5), (0, 10)));
+    assert_eq!(location(field), range((1, 4), (1, 5)));
+}
+
+#[test]
+fn goes_to_definitions_in_the_std_library() {
+    let std_uri = "file:///std.hulk";
+    let mut client =
+        Client::new().with_std_library(std_uri, "function double(x: Number): Number => x * 2;");
+    client.open("print(double(2));");
+    let definition = client.at("textDocument/definition", 0, 7);
+    let transcript = client.run();
+
+    assert!(transcript.diagnostics()[0].is_empty());
+    let location = transcript.result(definition);
+    assert_eq!(location["uri"], std_uri);
+    assert_eq!(location["range"], range((0, 9), (0, 15)));
+}
+
+#[test]
+fn completes_members_after_a_dot() {
+    let mut client = Client::new();
+    client.open(PROGRAM);
+    let on_self = client.at("textDocument/completion", 4, 32);
+    // while the member is written the program can not be parsed
+    let unfinished = PROGRAM.replace("print(twice(p.norm()));", "print(p.");
+    client.change(2, &unfinished);
+    let on_object = client.at("textDocument/completion", 9, 35);
+    let transcript = client.run();
+
+    assert_eq!(labels(transcript.result(on_self)), vec!["norm", "x", "y"]);
+    // fields are private
+    assert_eq!(labels(transcript.result(on_object)), vec!["norm"]);
+    assert!(!transcript.diagnostics()[1].is_empty());
+}
+
+#[test]
+fn lists_document_symbols() {
+    let mut client = Client::new();
+    client.open(PROGRAM);
+    let symbols = client.request(
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": URI } }),
+    );
+    let transcript = client.run();
+
+    let symbols = transcript.result(symbols).as_array().unwrap();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0]["name"], "Point");
+    assert_eq!(symbols[0]["kind"], 5);
+    assert_eq!(symbols[0]["range"], range((0, 0), (5, 1)));
+    assert_eq!(symbols[0]["selectionRange"], range((0, 5), (0, 10)));
+    let children = symbols[0]["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|child| child["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(children, vec!["x", "y", "norm"]);
+    assert_eq!(symbols[1]["name"], "twice");
+    assert_eq!(symbols[1]["kind"], 12);
+    assert_eq!(symbols[1]["range"], range((7, 0), (7, 43)));
+}
@@ -0,0 +1,58 @@
+use std::io::{self, BufRead, Write};
+
+use serde_json::Value;
+
+/// # Description
+///
+/// Reads the next JSON-RPC message, framed by a `Content-Length` header as
+/// the Language Server Protocol mandates. Returns `None` at the end of the
+/// input
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let Some(content_length) = content_length else {
+        return Err(invalid_data(
+            "Message without a valid Content-Length header",
+        ));
+    };
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|err| invalid_data(&err.to_string()))
+}
+
+/// # Description
+///
+/// Writes `message` with its `Content-Length` header
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let content = message.to_string();
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    writer.flush()
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
@@ -1,10 +1,13 @@
 use ast::Program;
-use error_handler::error::{
-    error::HulkError,
-    sintactic::{
-        extra_token::ExtraTokenError, invalid_token::InvalidTokenError,
-        unrecognized_eof::UnrecognizedEofError, unrecognized_token::UnrecognizedTokenError,
-        user_error::UserError,
+use error_handler::{
+    diagnostic::diagnostic::Span,
+    error::{
+        error::HulkError,
+        sintactic::{
+            extra_token::ExtraTokenError, invalid_token::InvalidTokenError,
+            unrecognized_eof::UnrecognizedEofError, unrecognized_token::UnrecognizedTokenError,
+            user_error::UserError,
+        },
     },
 };
 use lalrpop_util::ParseError;
@@ -37,13 +40,20 @@ impl Parser {
                 }
                 ParseError::UnrecognizedToken { token, expected } => {
                     errors.push(
-                        UnrecognizedTokenError::new(token.1.1.to_string(), expected, token.0)
-                            .into(),
+                        UnrecognizedTokenError::new(
+                            token.1.1.to_string(),
+                            expected,
+                            Span::new(token.0, token.2),
+                        )
+                        .into(),
                     );
                     Err(errors)
                 }
                 ParseError::ExtraToken { token } => {
-                    errors.push(ExtraTokenError::new(token.1.1.to_string(), token.0).into());
+                    errors.push(
+                        ExtraTokenError::new(token.1.1.to_string(), Span::new(token.0, token.2))
+                            .into(),
+                    );
                     Err(errors)
                 }
                 ParseError::User { error } => {
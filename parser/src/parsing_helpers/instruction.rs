@@ -1,8 +1,9 @@
-use ast::{Definition, Expression, Program};
+use ast::{Definition, Expression, Import, Program};
 
 pub enum Instruction {
     Expression(Expression),
     Definition(Definition),
+    Import(Import),
 }
 
 impl From<Definition> for Instruction {
@@ -11,6 +12,12 @@ impl From<Definition> for Instruction {
     }
 }
 
+impl From<Import> for Instruction {
+    fn from(v: Import) -> Self {
+        Self::Import(v)
+    }
+}
+
 impl From<Expression> for Instruction {
     fn from(v: Expression) -> Self {
         Self::Expression(v)
@@ -18,6 +25,7 @@ impl From<Expression> for Instruction {
 }
 
 pub fn program_from_instructions(instructions: Vec<Instruction>) -> Program {
+    let mut imports = Vec::new();
     let mut definitions = Vec::new();
     let mut expressions = Vec::new();
 
@@ -25,8 +33,9 @@ pub fn program_from_instructions(instructions: Vec<Instruction>) -> Program {
         match instruction {
             Instruction::Expression(expr) => expressions.push(expr),
             Instruction::Definition(def) => definitions.push(def),
+            Instruction::Import(import) => imports.push(import),
         }
     }
 
-    return Program::new(definitions, expressions);
+    return Program::new(definitions, expressions).with_imports(imports);
 }
@@ -204,3 +204,20 @@ fn parenthesis_list_indexing() {
         1.0
     );
 }
+
+#[test]
+fn nested_list_type_annotation() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("let x: Number** = [[1]] in x").unwrap();
+    let ty = answ
+        .as_let_in()
+        .unwrap()
+        .assignment
+        .identifier
+        .info
+        .ty
+        .as_ref();
+
+    assert_eq!(ty.unwrap().to_string(), "Number**");
+}
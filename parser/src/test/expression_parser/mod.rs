@@ -15,6 +15,8 @@ mod while_parser;
 mod lists;
 
 mod functions;
+mod lambdas;
+mod type_tests;
 
 mod data_member;
 mod function_member;
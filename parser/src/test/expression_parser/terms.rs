@@ -53,3 +53,60 @@ fn parses_term_with_unary_operator() {
         panic!("Expected BinOp");
     }
 }
+
+#[test]
+fn parses_floor_division_and_modulo() {
+    let p = grammar::ExpressionParser::new();
+
+    let answ = p.parse("a div b % c").unwrap();
+    let binop = answ.as_bin_op().unwrap();
+    assert!(matches!(binop.op, ast::BinaryOperator::Modulo(_)));
+    assert_eq!(binop.rhs.as_variable().unwrap().id, "c");
+
+    let left = binop.lhs.as_bin_op().unwrap();
+    assert!(matches!(left.op, ast::BinaryOperator::FloorDivide(_)));
+    assert_eq!(left.lhs.as_variable().unwrap().id, "a");
+    assert_eq!(left.rhs.as_variable().unwrap().id, "b");
+}
+
+#[test]
+fn parses_power_right_associative() {
+    let p = grammar::ExpressionParser::new();
+
+    let answ = p.parse("a ^ b ** c").unwrap();
+    let binop = answ.as_bin_op().unwrap();
+    assert!(matches!(binop.op, ast::BinaryOperator::Power(_)));
+    assert_eq!(binop.lhs.as_variable().unwrap().id, "a");
+
+    let right = binop.rhs.as_bin_op().unwrap();
+    assert!(matches!(right.op, ast::BinaryOperator::Power(_)));
+    assert_eq!(right.lhs.as_variable().unwrap().id, "b");
+    assert_eq!(right.rhs.as_variable().unwrap().id, "c");
+}
+
+#[test]
+fn parses_power_above_term() {
+    let p = grammar::ExpressionParser::new();
+
+    let answ = p.parse("a * b ^ c").unwrap();
+    let binop = answ.as_bin_op().unwrap();
+    assert!(matches!(binop.op, ast::BinaryOperator::Times(_)));
+    assert_eq!(binop.lhs.as_variable().unwrap().id, "a");
+    assert!(matches!(
+        binop.rhs.as_bin_op().unwrap().op,
+        ast::BinaryOperator::Power(_)
+    ));
+}
+
+#[test]
+fn parses_power_above_unary_operator() {
+    let p = grammar::ExpressionParser::new();
+
+    let answ = p.parse("-a ^ -b").unwrap();
+    let unop = answ.as_unary_op().unwrap();
+
+    let power = unop.rhs.as_bin_op().unwrap();
+    assert!(matches!(power.op, ast::BinaryOperator::Power(_)));
+    assert_eq!(power.lhs.as_variable().unwrap().id, "a");
+    assert!(matches!(*power.rhs, Expression::UnaryOp(_)));
+}
@@ -0,0 +1,79 @@
+use ast::{
+    VisitableExpression,
+    typing::{BuiltInType, Type},
+};
+
+use crate::{grammar::ExpressionParser, visitors::echo_visitor::EchoVisitor};
+
+#[test]
+fn parses_is() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("animal is Dog").unwrap();
+    let is_expr = answ.as_is_expression().unwrap();
+
+    assert_eq!(is_expr.expression.as_variable().unwrap().id, "animal");
+    assert_eq!(is_expr.target.to_string(), "Dog");
+}
+
+#[test]
+fn parses_as() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("animal as Dog").unwrap();
+    let as_expr = answ.as_as_expression().unwrap();
+
+    assert_eq!(as_expr.expression.as_variable().unwrap().id, "animal");
+    assert_eq!(as_expr.target.to_string(), "Dog");
+}
+
+#[test]
+fn parses_builtin_target() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("x is Number").unwrap();
+    let is_expr = answ.as_is_expression().unwrap();
+
+    assert_eq!(is_expr.target, Type::BuiltIn(BuiltInType::Number));
+}
+
+#[test]
+fn type_tests_bind_looser_than_arithmetic() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("a + b is Number").unwrap();
+    let is_expr = answ.as_is_expression().unwrap();
+
+    assert!(is_expr.expression.as_bin_op().is_some());
+}
+
+#[test]
+fn type_tests_bind_tighter_than_comparisons() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("a is Dog == b is Dog").unwrap();
+    let bin_op = answ.as_bin_op().unwrap();
+
+    assert!(bin_op.lhs.as_is_expression().is_some());
+    assert!(bin_op.rhs.as_is_expression().is_some());
+}
+
+#[test]
+fn chained_type_tests() {
+    let p = ExpressionParser::new();
+
+    let answ = p.parse("animal as Dog is Puppy").unwrap();
+    let is_expr = answ.as_is_expression().unwrap();
+
+    assert!(is_expr.expression.as_as_expression().is_some());
+}
+
+#[test]
+fn echoes_type_tests() {
+    let p = ExpressionParser::new();
+
+    let mut answ = p.parse("(a as Dog) is Puppy").unwrap();
+    let mut v = EchoVisitor::new();
+
+    assert_eq!(answ.accept(&mut v), "((a as Dog) is Puppy)");
+}
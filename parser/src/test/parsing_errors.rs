@@ -22,7 +22,7 @@ fn test_invalid_token() {
     assert_eq!(
         errors,
         vec![
-            "Sintactic Error: Unrecognized token `@` found, expected: r#``(?:[^`]|.)*``#, r#`[0-9]+(.[0-9]+)?`#, r#`[A-Za-z][A-Za-z_0-9]*`#, `!`, `(`, `+`, `-`, `[`, `constant`, `false`, `function`, `if`, `let`, `new`, `true`, `type`, `while`, `{`"
+            "Sintactic Error: Unrecognized token `@` found, expected: r#``(?:[^`]|.)*``#, r#`[0-9]+(.[0-9]+)?`#, r#`[A-Za-z][A-Za-z_0-9]*`#, `!`, `(`, `+`, `-`, `[`, `base`, `constant`, `false`, `for`, `function`, `if`, `import`, `let`, `new`, `protocol`, `true`, `type`, `while`, `{`"
         ]
     );
 }
@@ -53,7 +53,7 @@ fn test_extra_token() {
     assert_eq!(
         errors,
         vec![
-            "Sintactic Error: Unrecognized EOF found, expected: `!=`, `%`, `&&`, `(`, `*`, `+`, `-`, `.`, `/`, `:=`, `;`, `<`, `<=`, `==`, `>`, `>=`, `@`, `@@`, `[`, `||`"
+            "Sintactic Error: Unrecognized EOF found, expected: `!=`, `%`, `&&`, `(`, `*`, `**`, `+`, `-`, `.`, `/`, `:=`, `;`, `<`, `<=`, `==`, `>`, `>=`, `@`, `@@`, `[`, `^`, `as`, `div`, `is`, `||`"
         ]
     );
 }
@@ -0,0 +1,121 @@
+use ast::typing::BuiltInType;
+
+use crate::ProgramParser;
+
+#[test]
+fn generic_type_def() {
+    let p = ProgramParser::new();
+
+    let answ = p
+        .parse(
+            "
+type Pair<T, U>(first: T, second: U) {
+    first = first;
+    second = second;
+
+    getFirst(): T => self.first;
+}
+
+42;
+",
+        )
+        .unwrap();
+
+    let type_def = answ.definitions[0].as_type_def().unwrap();
+    assert_eq!(type_def.name.id, "Pair");
+    assert_eq!(type_def.type_parameters[0].id, "T");
+    assert_eq!(type_def.type_parameters[1].id, "U");
+}
+
+#[test]
+fn generic_protocol_def() {
+    let p = ProgramParser::new();
+
+    let answ = p
+        .parse(
+            "
+protocol Container<T> {
+    get(index: Number): T;
+}
+
+42;
+",
+        )
+        .unwrap();
+
+    let protocol_def = answ.definitions[0].as_protocol_def().unwrap();
+    assert_eq!(protocol_def.name.id, "Container");
+    assert_eq!(protocol_def.type_parameters[0].id, "T");
+}
+
+#[test]
+fn generic_function_def() {
+    let p = ProgramParser::new();
+
+    let answ = p
+        .parse(
+            "
+function first<T>(xs: T*): T => xs[0];
+
+first([1, 2, 3]);
+",
+        )
+        .unwrap();
+
+    let function_def = &answ.definitions[0].as_function_def().unwrap().function_def;
+    assert_eq!(function_def.type_parameters[0].id, "T");
+    assert_eq!(
+        function_def.parameters[0]
+            .info
+            .ty
+            .as_ref()
+            .unwrap()
+            .as_iterable()
+            .unwrap()
+            .as_defined()
+            .unwrap()
+            .id,
+        "T"
+    );
+}
+
+#[test]
+fn generic_annotation_and_instantiation() {
+    let p = ProgramParser::new();
+
+    let answ = p
+        .parse(
+            "
+let s: Stack<Number> = new Stack<Number>([1, 2]) in print(s);
+",
+        )
+        .unwrap();
+
+    let assignment = &answ.expressions[0].as_let_in().unwrap().assignment;
+    let (name, arguments) = assignment
+        .identifier
+        .info
+        .ty
+        .as_ref()
+        .unwrap()
+        .as_generic()
+        .unwrap();
+    assert_eq!(name.id, "Stack");
+    assert_eq!(arguments[0].as_builtin().unwrap(), &BuiltInType::Number);
+
+    let new_expr = assignment.rhs.as_new_expression().unwrap();
+    assert_eq!(new_expr.type_name, "Stack");
+    assert_eq!(
+        new_expr.type_arguments[0].as_builtin().unwrap(),
+        &BuiltInType::Number
+    );
+}
+
+#[test]
+fn less_than_is_still_a_comparison() {
+    let p = ProgramParser::new();
+
+    let answ = p.parse("let a = 1, b = 2 in print(a < b);").unwrap();
+
+    assert!(answ.expressions[0].as_let_in().is_some());
+}
@@ -0,0 +1,45 @@
+use ast::ImportTarget;
+
+use crate::ProgramParser;
+
+#[test]
+fn import_path_and_module() {
+    let p = ProgramParser::new();
+
+    let answ = p
+        .parse(
+            "
+import \"shapes/square.hulk\";
+import utils;
+
+print(42);
+",
+        )
+        .unwrap();
+
+    assert_eq!(answ.imports.len(), 2);
+    let ImportTarget::Path(path) = &answ.imports[0].target else {
+        panic!("Expected a path import");
+    };
+    assert_eq!(path.string, "shapes/square.hulk");
+    let ImportTarget::Module(module) = &answ.imports[1].target else {
+        panic!("Expected a module import");
+    };
+    assert_eq!(module.id, "utils");
+    assert!(answ.imports.iter().all(|import| import.file_id.is_none()));
+    assert_eq!(answ.expressions.len(), 1);
+}
+
+#[test]
+fn import_needs_semicolon() {
+    let p = ProgramParser::new();
+
+    assert!(p.parse("import utils print(1);").is_err());
+}
+
+#[test]
+fn import_is_a_keyword() {
+    let p = ProgramParser::new();
+
+    assert!(p.parse("let import = 1 in print(import);").is_err());
+}
@@ -11,3 +11,7 @@ mod constant_def;
 mod program_ordering;
 
 mod type_instantiation;
+
+mod generics;
+
+mod imports;
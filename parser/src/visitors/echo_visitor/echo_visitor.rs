@@ -17,7 +17,10 @@ impl EchoVisitor {
             | ast::Expression::While(_)
             | ast::Expression::For(_)
             | ast::Expression::NewExpression(_)
-            | ast::Expression::UnaryOp(_) => {
+            | ast::Expression::UnaryOp(_)
+            | ast::Expression::Lambda(_)
+            | ast::Expression::IsExpression(_)
+            | ast::Expression::AsExpression(_) => {
                 format!("({})", object.accept(self))
             }
             ast::Expression::Block(_)
@@ -26,6 +29,7 @@ impl EchoVisitor {
             | ast::Expression::StringLiteral(_)
             | ast::Expression::ListLiteral(_)
             | ast::Expression::FunctionCall(_)
+            | ast::Expression::BaseCall(_)
             | ast::Expression::DataMemberAccess(_)
             | ast::Expression::FunctionMemberAccess(_)
             | ast::Expression::ListIndexing(_)
@@ -151,6 +155,60 @@ impl ExpressionVisitor<String> for EchoVisitor {
         )
     }
 
+    fn visit_base_call(&mut self, node: &mut ast::BaseCall) -> String {
+        format!(
+            "{}({})",
+            node.base_token,
+            node.arguments
+                .iter_mut()
+                .map(|e| e.accept(self))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    fn visit_lambda(&mut self, node: &mut ast::Lambda) -> String {
+        let parameters = node
+            .parameters
+            .iter()
+            .map(|p| match &p.info.ty {
+                Some(ty) => format!("{}: {}", p, ty),
+                None => p.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let return_type = match &node.return_type {
+            Some(ty) => format!(": {}", ty),
+            None => String::new(),
+        };
+
+        format!(
+            "({}){} => {}",
+            parameters,
+            return_type,
+            node.body.accept(self)
+        )
+    }
+
+    fn visit_is_expr(&mut self, node: &mut ast::IsExpr) -> String {
+        format!(
+            "({} {} {})",
+            node.expression.accept(self),
+            node.is_token,
+            node.target
+        )
+    }
+
+    fn visit_as_expr(&mut self, node: &mut ast::AsExpr) -> String {
+        format!(
+            "({} {} {})",
+            node.expression.accept(self),
+            node.as_token,
+            node.target
+        )
+    }
+
     fn visit_variable(&mut self, node: &mut tokens::Identifier) -> String {
         format!("{}", node)
     }
@@ -0,0 +1,783 @@
+use std::collections::VecDeque;
+
+use ast::{
+    BlockBodyItem, DataMemberDef, Definition, DefinitionVisitor, Expression, ExpressionVisitor,
+    FunctionBody, FunctionCall, FunctionDef, Identifier, Import, ImportTarget, Program,
+    VisitableDefinition, VisitableExpression, token_position::TokenPositionTrait, tokens,
+    typing::TypeAnnotation,
+};
+
+use super::precedence::{ATOM, COMPOSITE, TYPE_TEST, UNARY, binary_operator_levels, precedence};
+
+/// Line width used when none is given
+pub const DEFAULT_WIDTH: usize = 80;
+
+const INDENT_WIDTH: usize = 4;
+
+/// # Description
+/// Visitor that pretty-prints programs: one statement per line, blocks and type bodies
+/// indented with four spaces, and only the parentheses the grammar needs. Argument lists,
+/// parameter lists and `let` bindings are split one per line when they do not fit in the
+/// line width.
+///
+/// Comments are written before the statement, member or definition that follows them,
+/// or at the end of the previous line if they were at the end of a line of code.
+/// # Arguments
+/// * `source` - The code the program was parsed from, used to place comments and keep blank lines.
+/// * `comments` - The comments not written yet, with the position where each one starts, in order.
+/// * `comment_ranges` - Where every comment starts and ends.
+/// * `width` - The maximum width of a line, which may be exceeded when nothing can be split.
+/// * `indent` - The current indentation level.
+/// * `flat` - Whether everything is being written on a single line, to measure it.
+/// * `flat_block` - Whether a block was written while writing on a single line.
+/// * `body_end` - Where the code of the definition being written ends, at most.
+pub struct FormatVisitor<'a> {
+    source: &'a str,
+    comments: VecDeque<(usize, String)>,
+    comment_ranges: Vec<(usize, usize)>,
+    width: usize,
+    indent: usize,
+    flat: bool,
+    flat_block: bool,
+    body_end: usize,
+}
+
+enum ProgramItem<'p> {
+    Import(&'p Import),
+    Definition(&'p mut Definition),
+    Expression(&'p mut Expression),
+}
+
+enum TypeMember<'d> {
+    Field(&'d mut DataMemberDef),
+    Method(&'d mut FunctionDef),
+}
+
+impl<'a> FormatVisitor<'a> {
+    pub fn new(source: &'a str, comments: Vec<(usize, String)>) -> Self {
+        let comment_ranges = comments
+            .iter()
+            .map(|(start, text)| (*start, start + text.len()))
+            .collect();
+
+        FormatVisitor {
+            source,
+            comments: comments.into(),
+            comment_ranges,
+            width: DEFAULT_WIDTH,
+            indent: 0,
+            flat: false,
+            flat_block: false,
+            body_end: usize::MAX,
+        }
+    }
+
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// # Description
+    /// Formats `program`, which must not have been through the semantic analysis:
+    /// the types it infers would be written as annotations.
+    pub fn format_program(&mut self, program: &mut Program) -> String {
+        let mut items = program
+            .imports
+            .iter()
+            .map(|import| (import.position(), ProgramItem::Import(import)))
+            .chain(program.definitions.iter_mut().map(|definition| {
+                (
+                    definition_position(definition),
+                    ProgramItem::Definition(definition),
+                )
+            }))
+            .chain(program.expressions.iter_mut().map(|expression| {
+                (
+                    expression.start_position(),
+                    ProgramItem::Expression(expression),
+                )
+            }))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|(start, _)| *start);
+
+        let ends = items
+            .iter()
+            .skip(1)
+            .map(|(start, _)| *start)
+            .chain(std::iter::once(self.source.len()))
+            .collect::<Vec<_>>();
+
+        let mut lines = Vec::new();
+        let mut previous_is_definition = false;
+        for ((start, item), end) in items.into_iter().zip(ends) {
+            let is_definition = matches!(item, ProgramItem::Definition(_));
+            self.push_item(
+                &mut lines,
+                start,
+                previous_is_definition || is_definition,
+                |visitor| match item {
+                    ProgramItem::Import(import) => format_import(import),
+                    ProgramItem::Definition(definition) => {
+                        visitor.body_end = end;
+                        definition.accept(visitor)
+                    }
+                    ProgramItem::Expression(expression) => {
+                        format!("{};", expression.accept(visitor))
+                    }
+                },
+            );
+            previous_is_definition = is_definition;
+        }
+        self.emit_comments(&mut lines, usize::MAX, false);
+
+        if lines.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", lines.join("\n"))
+        }
+    }
+
+    fn indentation(&self) -> String {
+        " ".repeat(self.indent * INDENT_WIDTH)
+    }
+
+    fn fits(&self, width: usize) -> bool {
+        self.indent * INDENT_WIDTH + width <= self.width
+    }
+
+    /// # Description
+    /// The width of the last line of `text`, without the current indentation
+    fn last_line_width(&self, text: &str) -> usize {
+        match text.rsplit_once('\n') {
+            Some((_, last)) => last.len().saturating_sub(self.indent * INDENT_WIDTH),
+            None => text.len(),
+        }
+    }
+
+    /// # Description
+    /// Whether there is code before `position` in its line
+    fn is_trailing(&self, position: usize) -> bool {
+        let line_start = self.source[..position].rfind('\n').map_or(0, |i| i + 1);
+        !self.source[line_start..position].trim().is_empty()
+    }
+
+    /// # Description
+    /// Whether there is an empty line right before `position`, ignoring the opening
+    /// parentheses in between
+    fn blank_line_before(&self, position: usize) -> bool {
+        let before = self.source[..position]
+            .trim_end_matches(|c: char| c == '(' || c.is_whitespace())
+            .len();
+        self.source[before..position].matches('\n').count() >= 2
+    }
+
+    /// # Description
+    /// Where the closing brace of the definition that ends before `end` is
+    fn closing_brace_before(&self, end: usize) -> usize {
+        let mut end = end.min(self.source.len());
+        loop {
+            end = self.source[..end].trim_end().len();
+            match self.comment_ranges.iter().find(|(_, stop)| *stop == end) {
+                Some((start, _)) => end = *start,
+                None => break,
+            }
+        }
+        end.saturating_sub(1)
+    }
+
+    /// # Description
+    /// Writes the comments that start before `position` as lines at the current
+    /// indentation, or at the end of the last line if there was code before them.
+    /// # Returns
+    /// Whether the next line still has to be separated by an empty line
+    fn emit_comments(
+        &mut self,
+        lines: &mut Vec<String>,
+        position: usize,
+        mut separate: bool,
+    ) -> bool {
+        while self
+            .comments
+            .front()
+            .is_some_and(|(start, _)| *start < position)
+        {
+            let (start, text) = self.comments.pop_front().unwrap();
+            if self.is_trailing(start) {
+                if let Some(last) = lines.last_mut() {
+                    last.push(' ');
+                    last.push_str(&text);
+                    continue;
+                }
+            }
+            if !lines.is_empty() && (separate || self.blank_line_before(start)) {
+                lines.push(String::new());
+            }
+            separate = false;
+            lines.push(format!("{}{}", self.indentation(), text));
+        }
+        separate
+    }
+
+    /// # Description
+    /// Writes the comments before `start` and then the item written by `render`, separated
+    /// from the previous lines by an empty line if `separate` or if there is one in the source
+    fn push_item(
+        &mut self,
+        lines: &mut Vec<String>,
+        start: usize,
+        separate: bool,
+        render: impl FnOnce(&mut Self) -> String,
+    ) {
+        let separate = self.emit_comments(lines, start, separate);
+        if !lines.is_empty() && (separate || self.blank_line_before(start)) {
+            lines.push(String::new());
+        }
+
+        let text = render(self);
+        let mut text_lines = text.lines();
+        if let Some(first) = text_lines.next() {
+            lines.push(format!("{}{}", self.indentation(), first));
+        }
+        lines.extend(text_lines.map(str::to_string));
+    }
+
+    /// # Description
+    /// Writes `header` followed by the lines of a body between braces
+    fn braced(&self, header: String, lines: Vec<String>) -> String {
+        if lines.is_empty() {
+            format!("{} {{}}", header)
+        } else {
+            format!(
+                "{} {{\n{}\n{}}}",
+                header,
+                lines.join("\n"),
+                self.indentation()
+            )
+        }
+    }
+
+    /// # Description
+    /// Writes `node` on a single line
+    /// # Returns
+    /// `None` if `node` contains a block, which always takes several lines
+    fn flat<V: VisitableExpression<Self, String>>(&mut self, node: &mut V) -> Option<String> {
+        let (flat, flat_block) = (self.flat, self.flat_block);
+        self.flat = true;
+        self.flat_block = false;
+        let text = node.accept(self);
+        let has_block = self.flat_block;
+        self.flat = flat;
+        self.flat_block = flat_block || has_block;
+
+        (!has_block).then_some(text)
+    }
+
+    /// # Description
+    /// Writes `expression`, parenthesised if it binds looser than `level`
+    fn operand(&mut self, expression: &mut Expression, level: u8) -> String {
+        let text = expression.accept(self);
+        if precedence(expression) < level {
+            format!("({})", text)
+        } else {
+            text
+        }
+    }
+
+    /// # Description
+    /// Writes `items` separated by commas between `open` and `close`, on the same line
+    /// if they fit after the `prefix` characters before them, or one per line otherwise
+    fn delimited(
+        &mut self,
+        open: &str,
+        close: &str,
+        prefix: usize,
+        items: &mut [Expression],
+    ) -> String {
+        if self.flat {
+            let items = items
+                .iter_mut()
+                .map(|item| item.accept(self))
+                .collect::<Vec<_>>();
+            return format!("{}{}{}", open, items.join(", "), close);
+        }
+
+        let flat = items
+            .iter_mut()
+            .map(|item| self.flat(item))
+            .collect::<Option<Vec<_>>>();
+        if let Some(flat) = flat {
+            let flat = format!("{}{}{}", open, flat.join(", "), close);
+            if items.is_empty() || self.fits(prefix + flat.len()) {
+                return flat;
+            }
+        }
+
+        self.indent += 1;
+        let indentation = self.indentation();
+        let items = items
+            .iter_mut()
+            .map(|item| format!("{}{}", indentation, item.accept(self)))
+            .collect::<Vec<_>>();
+        self.indent -= 1;
+
+        format!(
+            "{}\n{}\n{}{}",
+            open,
+            items.join(",\n"),
+            self.indentation(),
+            close
+        )
+    }
+
+    /// # Description
+    /// Writes the parameters of a function or a type like `delimited` writes arguments
+    fn parameter_list(&self, prefix: usize, parameters: &[Identifier]) -> String {
+        let parameters = parameters.iter().map(annotated).collect::<Vec<_>>();
+        let flat = format!("({})", parameters.join(", "));
+        if self.flat || parameters.is_empty() || self.fits(prefix + flat.len()) {
+            return flat;
+        }
+
+        let indentation = " ".repeat((self.indent + 1) * INDENT_WIDTH);
+        let parameters = parameters
+            .iter()
+            .map(|parameter| format!("{}{}", indentation, parameter))
+            .collect::<Vec<_>>();
+        format!("(\n{}\n{})", parameters.join(",\n"), self.indentation())
+    }
+
+    fn call(&mut self, prefix: usize, node: &mut FunctionCall) -> String {
+        let prefix = prefix + node.identifier.id.len();
+        let arguments = self.delimited("(", ")", prefix, &mut node.arguments);
+        format!("{}{}", node.identifier, arguments)
+    }
+
+    /// # Description
+    /// Writes a global function or a method, `keyword` is written before its name
+    fn function_def(&mut self, keyword: &str, node: &mut FunctionDef) -> String {
+        let return_type = type_annotation(&node.identifier.info.ty);
+        let type_parameters = type_parameters(&node.type_parameters);
+        let parameters = self.parameter_list(
+            keyword.len() + node.identifier.id.len() + type_parameters.len() + return_type.len(),
+            &node.parameters,
+        );
+        let body = match &mut node.body {
+            FunctionBody::ArrowExpression(arrow) => {
+                format!(" => {};", arrow.expression.accept(self))
+            }
+            FunctionBody::Block(block) => format!(" {}", block.accept(self)),
+        };
+
+        format!(
+            "{}{}{}{}{}{}",
+            keyword, node.identifier, type_parameters, parameters, return_type, body
+        )
+    }
+}
+
+fn type_annotation(ty: &TypeAnnotation) -> String {
+    match ty {
+        Some(ty) => format!(": {}", ty),
+        None => String::new(),
+    }
+}
+
+/// `<T, U>` after the name of a generic definition, nothing otherwise
+fn type_parameters(parameters: &[tokens::TypeName]) -> String {
+    if parameters.is_empty() {
+        return String::new();
+    }
+    let names = parameters
+        .iter()
+        .map(|parameter| parameter.id.as_str())
+        .collect::<Vec<_>>();
+    format!("<{}>", names.join(", "))
+}
+
+fn annotated(identifier: &Identifier) -> String {
+    format!("{}{}", identifier, type_annotation(&identifier.info.ty))
+}
+
+fn escape(string: &str) -> String {
+    string
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+fn format_import(import: &Import) -> String {
+    match &import.target {
+        ImportTarget::Path(path) => {
+            format!("{} \"{}\";", import.import_token, escape(&path.string))
+        }
+        ImportTarget::Module(module) => format!("{} {};", import.import_token, module),
+    }
+}
+
+fn definition_position(definition: &Definition) -> usize {
+    match definition {
+        Definition::TypeDef(node) => node.type_token.position(),
+        Definition::FunctionDef(node) => node.function_token.position(),
+        Definition::ConstantDef(node) => node.constant_token.position(),
+        Definition::ProtocolDef(node) => node.protocol_token.position(),
+    }
+}
+
+impl<'a> ExpressionVisitor<String> for FormatVisitor<'a> {
+    fn visit_expression(&mut self, node: &mut Expression) -> String {
+        node.accept(self)
+    }
+
+    fn visit_destructive_assignment(&mut self, node: &mut ast::DestructiveAssignment) -> String {
+        let assignee = self.operand(&mut node.lhs, ATOM);
+        let expression = node.rhs.accept(self);
+        format!("{} {} {}", assignee, node.op, expression)
+    }
+
+    fn visit_bin_op(&mut self, node: &mut ast::BinOp) -> String {
+        let (_, lhs_level, rhs_level) = binary_operator_levels(&node.op);
+        let lhs = self.operand(&mut node.lhs, lhs_level);
+        let rhs = self.operand(&mut node.rhs, rhs_level);
+        format!("{} {} {}", lhs, node.op, rhs)
+    }
+
+    fn visit_let_in(&mut self, node: &mut ast::LetIn) -> String {
+        // `let a = 1, b = 2 in ...` is parsed as nested `let`s sharing the `let` token
+        let position = node.let_token.position();
+        let mut assignments = vec![node.assignment.as_mut()];
+        let mut body = node.body.as_mut();
+        while matches!(&*body, Expression::LetIn(inner) if inner.let_token.position() == position) {
+            let Expression::LetIn(inner) = body else {
+                unreachable!()
+            };
+            assignments.push(inner.assignment.as_mut());
+            body = inner.body.as_mut();
+        }
+
+        let flat = if self.flat {
+            let bindings = assignments
+                .iter_mut()
+                .map(|assignment| assignment.accept(self))
+                .collect::<Vec<_>>();
+            Some(bindings.join(", "))
+        } else {
+            assignments
+                .iter_mut()
+                .map(|assignment| self.flat(&mut **assignment))
+                .collect::<Option<Vec<_>>>()
+                .map(|bindings| bindings.join(", "))
+                .filter(|bindings| self.fits(bindings.len() + "let  in".len()))
+        };
+
+        let header = match flat {
+            Some(bindings) => format!("{} {} {}", node.let_token, bindings, node.in_token),
+            None => {
+                self.indent += 1;
+                let indentation = self.indentation();
+                let bindings = assignments
+                    .iter_mut()
+                    .map(|assignment| assignment.accept(self))
+                    .collect::<Vec<_>>();
+                self.indent -= 1;
+                format!(
+                    "{} {}\n{}{}",
+                    node.let_token,
+                    bindings.join(&format!(",\n{}", indentation)),
+                    self.indentation(),
+                    node.in_token
+                )
+            }
+        };
+
+        format!("{} {}", header, self.operand(body, COMPOSITE))
+    }
+
+    fn visit_assignment(&mut self, node: &mut ast::Assignment) -> String {
+        let rhs = node.rhs.accept(self);
+        format!("{} {} {}", annotated(&node.identifier), node.op, rhs)
+    }
+
+    fn visit_if_else(&mut self, node: &mut ast::IfElse) -> String {
+        let condition = node.condition.accept(self);
+        let then_branch = self.operand(&mut node.then_expression, COMPOSITE);
+        let else_branch = self.operand(&mut node.else_expression, COMPOSITE);
+        format!(
+            "{} ({}) {} {} {}",
+            node.if_token, condition, then_branch, node.else_token, else_branch
+        )
+    }
+
+    fn visit_while(&mut self, node: &mut ast::While) -> String {
+        let condition = node.condition.accept(self);
+        let body = node.body.accept(self);
+        format!("{} ({}) {}", node.while_token, condition, body)
+    }
+
+    fn visit_for(&mut self, node: &mut ast::For) -> String {
+        let iterable = node.iterable.accept(self);
+        let body = node.body.accept(self);
+        format!(
+            "{} ({} {} {}) {}",
+            node.for_token,
+            annotated(&node.element),
+            node.in_token,
+            iterable,
+            body
+        )
+    }
+
+    fn visit_block(&mut self, node: &mut ast::Block) -> String {
+        let last = node.body_items.len().saturating_sub(1);
+        let terminator = |i: usize| {
+            if i == last && node.multiple_semicolon_terminated {
+                ";;"
+            } else {
+                ";"
+            }
+        };
+
+        if self.flat {
+            self.flat_block = true;
+            let items = node
+                .body_items
+                .iter_mut()
+                .enumerate()
+                .map(|(i, item)| format!("{}{}", item.accept(self), terminator(i)))
+                .collect::<Vec<_>>();
+            return format!("{{ {} }}", items.join(" "));
+        }
+
+        self.indent += 1;
+        let mut lines = Vec::new();
+        for (i, item) in node.body_items.iter_mut().enumerate() {
+            let start = match item {
+                BlockBodyItem::Expression(expression) => expression.start_position(),
+                BlockBodyItem::ReturnStatement(statement) => statement.return_token.position(),
+            };
+            let terminator = terminator(i);
+            self.push_item(&mut lines, start, false, |visitor| {
+                format!("{}{}", item.accept(visitor), terminator)
+            });
+        }
+        self.emit_comments(&mut lines, node.close_brace.position(), false);
+        self.indent -= 1;
+
+        format!("{{\n{}\n{}}}", lines.join("\n"), self.indentation())
+    }
+
+    fn visit_return_statement(&mut self, node: &mut ast::ReturnStatement) -> String {
+        format!("{} {}", node.return_token, node.expression.accept(self))
+    }
+
+    fn visit_un_op(&mut self, node: &mut ast::UnOp) -> String {
+        let expression = self.operand(&mut node.rhs, UNARY);
+        format!("{}{}", node.op, expression)
+    }
+
+    fn visit_data_member_access(&mut self, node: &mut ast::DataMemberAccess) -> String {
+        let object = self.operand(&mut node.object, ATOM);
+        format!("{}{}{}", object, node.op, node.member)
+    }
+
+    fn visit_function_member_access(&mut self, node: &mut ast::FunctionMemberAccess) -> String {
+        let object = self.operand(&mut node.object, ATOM);
+        let prefix = self.last_line_width(&object) + 1;
+        let member = self.call(prefix, &mut node.member);
+        format!("{}{}{}", object, node.op, member)
+    }
+
+    fn visist_list_indexing(&mut self, node: &mut ast::ListIndexing) -> String {
+        let list = self.operand(&mut node.list, ATOM);
+        let index = node.index.accept(self);
+        format!("{}{}{}{}", list, node.open_brace, index, node.close_brace)
+    }
+
+    fn visit_function_call(&mut self, node: &mut ast::FunctionCall) -> String {
+        self.call(0, node)
+    }
+
+    fn visit_base_call(&mut self, node: &mut ast::BaseCall) -> String {
+        let prefix = node.base_token.to_string().len();
+        let arguments = self.delimited("(", ")", prefix, &mut node.arguments);
+        format!("{}{}", node.base_token, arguments)
+    }
+
+    fn visit_lambda(&mut self, node: &mut ast::Lambda) -> String {
+        let return_type = type_annotation(&node.return_type);
+        let parameters = self.parameter_list(return_type.len(), &node.parameters);
+        format!(
+            "{}{} => {}",
+            parameters,
+            return_type,
+            node.body.accept(self)
+        )
+    }
+
+    fn visit_is_expr(&mut self, node: &mut ast::IsExpr) -> String {
+        let expression = self.operand(&mut node.expression, TYPE_TEST);
+        format!("{} {} {}", expression, node.is_token, node.target)
+    }
+
+    fn visit_as_expr(&mut self, node: &mut ast::AsExpr) -> String {
+        let expression = self.operand(&mut node.expression, TYPE_TEST);
+        format!("{} {} {}", expression, node.as_token, node.target)
+    }
+
+    fn visit_variable(&mut self, node: &mut tokens::Identifier) -> String {
+        node.to_string()
+    }
+
+    fn visit_number_literal(&mut self, node: &mut tokens::NumberLiteral) -> String {
+        node.to_string()
+    }
+
+    fn visit_boolean_literal(&mut self, node: &mut ast::BooleanLiteral) -> String {
+        node.to_string()
+    }
+
+    fn visit_string_literal(&mut self, node: &mut ast::StringLiteral) -> String {
+        format!("\"{}\"", escape(&node.string))
+    }
+
+    fn visit_list_literal(&mut self, node: &mut ast::ListLiteral) -> String {
+        self.delimited("[", "]", 0, &mut node.elements)
+    }
+
+    fn visit_empty_expression(&mut self) -> String {
+        String::new()
+    }
+
+    fn visit_new_expr(&mut self, node: &mut ast::NewExpr) -> String {
+        let mut prefix = format!("{} {}", node.new_token, node.type_name);
+        if !node.type_arguments.is_empty() {
+            let arguments = node
+                .type_arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<_>>();
+            prefix += &format!("<{}>", arguments.join(", "));
+        }
+        let arguments = self.delimited("(", ")", prefix.len(), &mut node.arguments);
+        format!("{}{}", prefix, arguments)
+    }
+}
+
+impl<'a> DefinitionVisitor<String> for FormatVisitor<'a> {
+    fn visit_definition(&mut self, node: &mut Definition) -> String {
+        node.accept(self)
+    }
+
+    fn visit_type_def(&mut self, node: &mut ast::TypeDef) -> String {
+        let mut header = format!(
+            "{} {}{}",
+            node.type_token,
+            node.name.id,
+            type_parameters(&node.type_parameters)
+        );
+        if !node.parameter_list.is_empty() {
+            header += &self.parameter_list(header.len(), &node.parameter_list);
+        }
+        if let Some(inheritance) = &mut node.inheritance_indicator {
+            header += &format!(
+                " {} {}",
+                inheritance.inherits_token, inheritance.parent_name.id
+            );
+            if !inheritance.argument_list.is_empty() {
+                let prefix = self.last_line_width(&header);
+                header += &self.delimited("(", ")", prefix, &mut inheritance.argument_list);
+            }
+        }
+
+        let mut members = node
+            .data_member_defs
+            .iter_mut()
+            .map(|field| (field.identifier.position.start, TypeMember::Field(field)))
+            .chain(
+                node.function_member_defs
+                    .iter_mut()
+                    .map(|method| (method.identifier.position.start, TypeMember::Method(method))),
+            )
+            .collect::<Vec<_>>();
+        members.sort_by_key(|(start, _)| *start);
+
+        let end = self.closing_brace_before(self.body_end);
+        self.indent += 1;
+        let mut lines = Vec::new();
+        let mut previous_is_method = false;
+        for (start, member) in members {
+            let is_method = matches!(member, TypeMember::Method(_));
+            self.push_item(
+                &mut lines,
+                start,
+                previous_is_method || is_method,
+                |visitor| match member {
+                    TypeMember::Field(field) => format!(
+                        "{} {} {};",
+                        annotated(&field.identifier),
+                        field.assignment_op,
+                        field.default_value.accept(visitor)
+                    ),
+                    TypeMember::Method(method) => visitor.function_def("", method),
+                },
+            );
+            previous_is_method = is_method;
+        }
+        self.emit_comments(&mut lines, end, false);
+        self.indent -= 1;
+
+        self.braced(header, lines)
+    }
+
+    fn visit_function_def(&mut self, node: &mut ast::GlobalFunctionDef) -> String {
+        let keyword = format!("{} ", node.function_token);
+        self.function_def(&keyword, &mut node.function_def)
+    }
+
+    fn visit_constant_def(&mut self, node: &mut ast::ConstantDef) -> String {
+        format!(
+            "{} {} {} {};",
+            node.constant_token,
+            annotated(&node.identifier),
+            node.assignment_operator,
+            node.initializer_expression.accept(self)
+        )
+    }
+
+    fn visit_protocol_def(&mut self, node: &mut ast::ProtocolDef) -> String {
+        let mut header = format!(
+            "{} {}{}",
+            node.protocol_token,
+            node.name.id,
+            type_parameters(&node.type_parameters)
+        );
+        if let Some(extension) = &node.extension_indicator {
+            header += &format!(
+                " {} {}",
+                extension.extends_token, extension.extendee_name.id
+            );
+        }
+
+        let end = self.closing_brace_before(self.body_end);
+        self.indent += 1;
+        let mut lines = Vec::new();
+        for signature in &node.function_signatures {
+            self.push_item(
+                &mut lines,
+                signature.identifier.position.start,
+                false,
+                |visitor| {
+                    let return_type = type_annotation(&signature.identifier.info.ty);
+                    let parameters = visitor.parameter_list(
+                        signature.identifier.id.len() + return_type.len(),
+                        &signature.parameters,
+                    );
+                    format!("{}{}{};", signature.identifier, parameters, return_type)
+                },
+            );
+        }
+        self.emit_comments(&mut lines, end, false);
+        self.indent -= 1;
+
+        self.braced(header, lines)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod format_visitor;
+pub use format_visitor::{DEFAULT_WIDTH, FormatVisitor};
+
+mod precedence;
+
+#[cfg(test)]
+pub mod test;
@@ -0,0 +1,70 @@
+use ast::{BinaryOperator, Expression};
+
+// Binding power of each level of the expression grammar, from the loosest
+// (`Expression`) to the tightest (`Atom`)
+pub const EXPRESSION: u8 = 0;
+pub const CONCAT: u8 = 1;
+pub const OR: u8 = 2;
+pub const AND: u8 = 3;
+pub const EQUATION: u8 = 4;
+pub const COMPARISON: u8 = 5;
+pub const TYPE_TEST: u8 = 6;
+pub const ADDITION: u8 = 7;
+pub const TERM: u8 = 8;
+pub const UNARY: u8 = 9;
+pub const POWER: u8 = 10;
+pub const COMPOSITE: u8 = 11;
+pub const ATOM: u8 = 12;
+
+/// # Description
+/// The level of a binary operator, and the levels its left and right operands
+/// must have to be written without parentheses
+pub fn binary_operator_levels(op: &BinaryOperator) -> (u8, u8, u8) {
+    match op {
+        BinaryOperator::At(_) | BinaryOperator::AtAt(_) => (CONCAT, CONCAT, OR),
+        BinaryOperator::Or(_) => (OR, OR, AND),
+        BinaryOperator::And(_) => (AND, AND, EQUATION),
+        BinaryOperator::EqualEqual(_) | BinaryOperator::NotEqual(_) => {
+            (EQUATION, COMPARISON, COMPARISON)
+        }
+        BinaryOperator::Less(_)
+        | BinaryOperator::LessEqual(_)
+        | BinaryOperator::Greater(_)
+        | BinaryOperator::GreaterEqual(_) => (COMPARISON, TYPE_TEST, TYPE_TEST),
+        BinaryOperator::Plus(_) | BinaryOperator::Minus(_) => (ADDITION, ADDITION, TERM),
+        BinaryOperator::Times(_)
+        | BinaryOperator::Divide(_)
+        | BinaryOperator::FloorDivide(_)
+        | BinaryOperator::Modulo(_) => (TERM, TERM, UNARY),
+        BinaryOperator::Power(_) => (POWER, COMPOSITE, UNARY),
+        BinaryOperator::Equal(_) | BinaryOperator::ColonEqual(_) => (EXPRESSION, ATOM, EXPRESSION),
+    }
+}
+
+/// # Description
+/// The level of the grammar `expression` belongs to. Written where a tighter
+/// level is expected, it has to be parenthesised
+pub fn precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::DestructiveAssignment(_) | Expression::Lambda(_) => EXPRESSION,
+        Expression::BinOp(bin_op) => binary_operator_levels(&bin_op.op).0,
+        Expression::IsExpression(_) | Expression::AsExpression(_) => TYPE_TEST,
+        Expression::UnaryOp(_) => UNARY,
+        Expression::LetIn(_)
+        | Expression::IfElse(_)
+        | Expression::While(_)
+        | Expression::For(_) => COMPOSITE,
+        Expression::Block(_)
+        | Expression::NumberLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::ListLiteral(_)
+        | Expression::NewExpression(_)
+        | Expression::FunctionCall(_)
+        | Expression::BaseCall(_)
+        | Expression::DataMemberAccess(_)
+        | Expression::FunctionMemberAccess(_)
+        | Expression::ListIndexing(_)
+        | Expression::Variable(_) => ATOM,
+    }
+}
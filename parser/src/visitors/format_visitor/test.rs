@@ -0,0 +1,255 @@
+use generated_parser::ProgramParser;
+
+use super::{DEFAULT_WIDTH, FormatVisitor};
+
+fn format_with_width(program: &str, width: usize) -> String {
+    let parser = ProgramParser::new();
+    let mut ast = parser.parse(program).unwrap();
+    let comments = parser.comments(program).unwrap();
+
+    FormatVisitor::new(program, comments)
+        .with_width(width)
+        .format_program(&mut ast)
+}
+
+fn format(program: &str) -> String {
+    let formatted = format_with_width(program, DEFAULT_WIDTH);
+    assert_eq!(format_with_width(&formatted, DEFAULT_WIDTH), formatted);
+    formatted
+}
+
+#[test]
+fn formats_expressions_with_only_the_needed_parentheses() {
+    let formatted = format("print((((1+2)*3)@\"a\\\"b\") @@ (-x^2));x:=(y:=2);");
+
+    assert_eq!(
+        formatted,
+        "print((1 + 2) * 3 @ \"a\\\"b\" @@ -x ^ 2);\nx := y := 2;\n"
+    );
+}
+
+#[test]
+fn formats_blocks_and_control_flow() {
+    let formatted = format(
+        "while(x<10){x:=x+1;if(x==5){print(x);}else{print(0);};};for(i in range(0,3)){print(i);};",
+    );
+
+    assert_eq!(
+        formatted,
+        "\
+while (x < 10) {
+    x := x + 1;
+    if (x == 5) {
+        print(x);
+    } else {
+        print(0);
+    };
+};
+for (i in range(0, 3)) {
+    print(i);
+};
+"
+    );
+}
+
+#[test]
+fn formats_definitions() {
+    let formatted = format(
+        "
+        protocol Hashable extends Object2 { hash(): Number; equals(other: Object2): Boolean; }
+        type Point(x: Number, y: Number) inherits Base(x) {
+            x = x; y: Number = y;
+            norm(): Number => sqrt(self.x ^ 2 + self.y ^ 2);
+            translate(dx: Number): Point { return new Point(self.x + dx, self.y); }
+        }
+        constant PI: Number = 3.14;
+        function square(x: Number): Number => x * x;
+        print(square(PI));
+        ",
+    );
+
+    assert_eq!(
+        formatted,
+        "\
+protocol Hashable extends Object2 {
+    hash(): Number;
+    equals(other: Object2): Boolean;
+}
+
+type Point(x: Number, y: Number) inherits Base(x) {
+    x = x;
+    y: Number = y;
+
+    norm(): Number => sqrt(self.x ^ 2 + self.y ^ 2);
+
+    translate(dx: Number): Point {
+        return new Point(self.x + dx, self.y);
+    }
+}
+
+constant PI: Number = 3.14;
+
+function square(x: Number): Number => x * x;
+
+print(square(PI));
+"
+    );
+}
+
+#[test]
+fn formats_base_calls() {
+    let formatted = format("type Square inherits Shape { area(): Number => 2*base( ); }");
+
+    assert_eq!(
+        formatted,
+        "\
+type Square inherits Shape {
+    area(): Number => 2 * base();
+}
+"
+    );
+}
+
+#[test]
+fn formats_imports() {
+    let formatted = format(
+        "
+        import   shapes ;
+        import \"utils/strings.hulk\";
+        function area(): Number => 1;
+        ",
+    );
+
+    assert_eq!(
+        formatted,
+        "\
+import shapes;
+import \"utils/strings.hulk\";
+
+function area(): Number => 1;
+"
+    );
+}
+
+#[test]
+fn formats_generic_definitions() {
+    let formatted = format(
+        "
+        protocol Container<T> { get(i: Number): T; }
+        type Stack<T>(items: T*) { items = items; top(): T => self.items[0]; }
+        function first<T, U>(xs: T*, y: U): T => xs[0];
+        let s: Stack<Number> = new Stack<Number>([1, 2]) in print(s.top());
+        ",
+    );
+
+    assert_eq!(
+        formatted,
+        "\
+protocol Container<T> {
+    get(i: Number): T;
+}
+
+type Stack<T>(items: T*) {
+    items = items;
+
+    top(): T => self.items[0];
+}
+
+function first<T, U>(xs: T*, y: U): T => xs[0];
+
+let s: Stack<Number> = new Stack<Number>([1, 2]) in print(s.top());
+"
+    );
+}
+
+#[test]
+fn wraps_long_argument_lists() {
+    let formatted = format_with_width("print(concatenate(first_word, second_word), 42);", 30);
+
+    assert_eq!(
+        formatted,
+        "\
+print(
+    concatenate(
+        first_word,
+        second_word
+    ),
+    42
+);
+"
+    );
+}
+
+#[test]
+fn wraps_long_let_bindings() {
+    let program = "let first = 1, second = 2, third: Number = 3 in print(first + second + third);";
+
+    assert_eq!(format(program), format!("{}\n", program));
+    assert_eq!(
+        format_with_width(program, 30),
+        "\
+let first = 1,
+    second = 2,
+    third: Number = 3
+in print(first + second + third);
+"
+    );
+}
+
+#[test]
+fn nested_lets_are_not_merged() {
+    assert_eq!(
+        format("let a = 1 in let b = a in print(b);"),
+        "let a = 1 in let b = a in print(b);\n"
+    );
+}
+
+#[test]
+fn preserves_comments() {
+    let formatted = format(
+        "// leading
+type A { // about A
+    x = 1; // trailing
+    // before the end of A
+}
+// about the block
+{
+    print(1);   // first
+
+    // second
+    print(2);
+    // last in block
+};
+// end",
+    );
+
+    assert_eq!(
+        formatted,
+        "\
+// leading
+type A {
+    // about A
+    x = 1; // trailing
+    // before the end of A
+}
+
+// about the block
+{
+    print(1); // first
+
+    // second
+    print(2);
+    // last in block
+};
+// end
+"
+    );
+}
+
+#[test]
+fn keeps_single_blank_lines_between_statements() {
+    assert_eq!(
+        format("print(1);\n\n\n\nprint(2);\nprint(3);"),
+        "print(1);\n\nprint(2);\nprint(3);\n"
+    );
+}
@@ -1 +1,2 @@
 pub mod echo_visitor;
+pub mod format_visitor;
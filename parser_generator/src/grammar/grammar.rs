@@ -5,10 +5,11 @@ use std::{
 };
 
 use crate::{
-    NonTerminalId, Parser, ProductionCompute, TerminalCompute, TerminalId, Token,
-    debugging_helpers::get_name_or_default, table_builder::TableBuilder,
+    Associativity, NonTerminalId, Parser, ParsingTables, Precedence, ProductionCompute,
+    TerminalCompute, TerminalId, Token, debugging_helpers::get_name_or_default,
+    table_builder::TableBuilder,
 };
-use crate::{Production, ProductionId, SymbolId};
+use crate::{Production, ProductionId, StateId, SymbolId, parser::Action};
 
 pub struct Grammar<TokenType: Eq + Hash + Copy + Debug, R> {
     symbol_id_generator: std::ops::RangeFrom<usize>,
@@ -21,6 +22,14 @@ pub struct Grammar<TokenType: Eq + Hash + Copy + Debug, R> {
     production_computes: HashMap<ProductionId, ProductionCompute<R>>,
     terminal_computes: HashMap<TerminalId, TerminalCompute<TokenType, R>>,
     token_type_to_terminal: HashMap<TokenType, TerminalId>,
+    /// terminals the parser skips to when recovering from a syntax error
+    sync_terminals: HashSet<TerminalId>,
+
+    /// precedence of the terminals, and of the names only used in `%prec`, by name
+    precedences: HashMap<String, Precedence>,
+    precedence_levels: usize,
+    /// names whose precedence overrides the one of a production, i.e. `%prec`
+    production_precedences: HashMap<ProductionId, String>,
 
     first_symbol: Option<NonTerminalId>,
 
@@ -44,6 +53,11 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Grammar<TokenType, R> {
             production_computes: HashMap::new(),
             terminal_computes: HashMap::new(),
             token_type_to_terminal: HashMap::new(),
+            sync_terminals: HashSet::new(),
+
+            precedences: HashMap::new(),
+            precedence_levels: 0,
+            production_precedences: HashMap::new(),
 
             first_symbol: None,
 
@@ -97,6 +111,48 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Grammar<TokenType, R> {
         Ok(symbol)
     }
 
+    pub fn define_sync_terminal(&mut self, token_type: TokenType) -> Result<(), String> {
+        let terminal = self.token_type_to_terminal.get(&token_type).ok_or(format!(
+            "Synchronization terminal {:?} is not a defined terminal",
+            token_type
+        ))?;
+
+        self.sync_terminals.insert(*terminal);
+
+        Ok(())
+    }
+
+    /// # Description
+    ///
+    /// Declares a new precedence level, tighter than the ones declared
+    /// before it, for the terminals named in `names`. Names that are not
+    /// terminals can be given to productions with `%prec`
+    pub fn define_precedence(
+        &mut self,
+        associativity: Associativity,
+        names: Vec<String>,
+    ) -> Result<(), String> {
+        self.precedence_levels += 1;
+        let precedence = Precedence::new(self.precedence_levels, associativity);
+
+        for name in names {
+            if self.precedences.contains_key(&name) {
+                return Err(format!("Precedence of {name} is already defined"));
+            }
+            self.precedences.insert(name, precedence);
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///
+    /// Gives `production` the precedence of `name` instead of the one of its
+    /// last terminal, i.e. `%prec name`
+    pub fn define_production_precedence(&mut self, production: ProductionId, name: String) {
+        self.production_precedences.insert(production, name);
+    }
+
     pub fn define_non_terminal(&mut self, name: Option<String>) -> Result<SymbolId, String> {
         self.check_if_defined_name(&name)?;
 
@@ -168,17 +224,45 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Grammar<TokenType, R> {
     pub fn build_parser(mut self) -> Result<Parser<TokenType, R>, Vec<String>> {
         self.check_if_all_non_terminals_have_productions()?;
         let augmented_first_production_id = self.augment()?;
+        let terminal_precedences = self.terminal_precedences()?;
+        let production_precedences = self.production_precedences(&terminal_precedences)?;
 
         let (action_table, goto_table) = TableBuilder::build_tables(
             &self.symbols,
             &self.productions,
+            &terminal_precedences,
+            &production_precedences,
             augmented_first_production_id,
             self.epsilon,
             self.eof,
             self.extra_symbol,
         )?;
 
-        Ok(Parser::new(
+        Ok(self.into_parser(action_table, goto_table))
+    }
+
+    /// # Description
+    ///
+    /// Builds the parser from tables computed ahead of time with
+    /// `Parser::tables`, skipping the construction of the LR item sets.
+    /// Fails if the tables were not computed for this grammar
+    pub fn build_parser_from_tables(
+        mut self,
+        tables: ParsingTables,
+    ) -> Result<Parser<TokenType, R>, Vec<String>> {
+        self.check_if_all_non_terminals_have_productions()?;
+        self.augment()?;
+        tables.check_grammar(&self.symbols, &self.productions)?;
+
+        Ok(self.into_parser(tables.action_table, tables.goto_table))
+    }
+
+    fn into_parser(
+        self,
+        action_table: HashMap<(StateId, TerminalId), Action>,
+        goto_table: HashMap<(StateId, SymbolId), StateId>,
+    ) -> Parser<TokenType, R> {
+        Parser::new(
             action_table,
             self.symbols,
             self.production_computes,
@@ -186,8 +270,76 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Grammar<TokenType, R> {
             self.terminal_computes,
             self.token_type_to_terminal,
             goto_table,
+            self.sync_terminals,
             self.eof,
-        ))
+        )
+    }
+
+    fn terminal_precedences(&self) -> Result<HashMap<TerminalId, Precedence>, Vec<String>> {
+        let mut terminal_precedences = HashMap::new();
+        let mut errors = Vec::new();
+
+        for (name, precedence) in &self.precedences {
+            match self.name_to_symbol.get(name) {
+                Some(SymbolId::TerminalId(terminal)) => {
+                    terminal_precedences.insert(*terminal, *precedence);
+                }
+                Some(SymbolId::NonTerminalId(_)) => errors.push(format!(
+                    "Precedence can only be defined for terminals, but {name} is a non terminal"
+                )),
+                // names without a symbol can only be used through `%prec`
+                None => {
+                    if !self.production_precedences.values().any(|x| x == name) {
+                        errors.push(format!(
+                            "Precedence of {name} is defined for an unknown terminal"
+                        ));
+                    }
+                }
+            }
+        }
+
+        match errors.len() > 0 {
+            true => Err(errors),
+            false => Ok(terminal_precedences),
+        }
+    }
+
+    // the precedence of a production is the one given with `%prec` or, if there
+    // is none, the one of its last terminal
+    fn production_precedences(
+        &self,
+        terminal_precedences: &HashMap<TerminalId, Precedence>,
+    ) -> Result<HashMap<ProductionId, Precedence>, Vec<String>> {
+        let mut production_precedences = HashMap::new();
+        let mut errors = Vec::new();
+
+        for (id, production) in &self.productions {
+            let precedence = match self.production_precedences.get(id) {
+                Some(name) => match self.precedences.get(name) {
+                    Some(precedence) => Some(*precedence),
+                    None => {
+                        errors.push(format!("Precedence of {name} is not defined"));
+                        None
+                    }
+                },
+                None => production
+                    .rhs
+                    .iter()
+                    .rev()
+                    .find_map(|symbol| symbol.as_terminal_id())
+                    .and_then(|terminal| terminal_precedences.get(terminal))
+                    .copied(),
+            };
+
+            if let Some(precedence) = precedence {
+                production_precedences.insert(*id, precedence);
+            }
+        }
+
+        match errors.len() > 0 {
+            true => Err(errors),
+            false => Ok(production_precedences),
+        }
     }
 
     fn check_if_all_non_terminals_have_productions(&self) -> Result<(), Vec<String>> {
@@ -24,5 +24,25 @@ pub trait DefineLexer<T: Hash + Debug + Eq + Copy, Lexer: Lex<T>> {
         }
     }
 
+    /// Tokens that are skipped by the parser but kept in the `trivia` of the
+    /// next token. Lexers that do not keep trivia just skip them
+    fn trivia_rule(&mut self, tok_ty: T, pattern: String) {
+        self.skip_rule(tok_ty, pattern);
+    }
+    fn trivia_rules(&mut self, trivia: Vec<(T, String)>) {
+        for (tok_ty, pattern) in trivia {
+            self.trivia_rule(tok_ty, pattern);
+        }
+    }
+
     fn compile(self) -> Lexer;
+
+    /// Builds the lexer from tables computed ahead of time for the same rules.
+    /// Lexers that have no precomputed tables just compile the rules
+    fn compile_from_tables(self, _tables: &[u8]) -> Result<Lexer, String>
+    where
+        Self: Sized,
+    {
+        Ok(self.compile())
+    }
 }
@@ -25,11 +25,33 @@ macro_rules! prod {
 #[macro_export]
 #[allow(non_snake_case)]
 macro_rules! productions {
-    ($p: ident, $($lhs: ident -> $rhs1:tt $($rhs: ident)* = $action:expr);+ $(;)?) => {{
+    ($p: ident, $($lhs: ident -> $rhs1:tt $($rhs: ident)* $(%prec $prec: ident)? = $action:expr);+ $(;)?) => {{
         $(
-            $crate::prod!($p, $lhs -> $rhs1 $($rhs)*, $action);
+            $crate::productions!(@production $p, $lhs -> $rhs1 $($rhs)* $(%prec $prec)?, $action);
         )+
     }};
+
+    // Internal matchers to define the precedence only for productions with `%prec`
+    (@production $p: ident, $lhs: ident -> $rhs1:tt $($rhs: ident)*, $action:expr) => {
+        $crate::prod!($p, $lhs -> $rhs1 $($rhs)*, $action);
+    };
+    (@production $p: ident, $lhs: ident -> $rhs1:tt $($rhs: ident)* %prec $prec: ident, $action:expr) => {
+        let production = $crate::prod!($p, $lhs -> $rhs1 $($rhs)*, $action);
+        $p.define_production_precedence(production, stringify!($prec).to_string());
+    };
+}
+
+#[macro_export]
+macro_rules! associativity {
+    (left) => {
+        $crate::Associativity::Left
+    };
+    (right) => {
+        $crate::Associativity::Right
+    };
+    (nonassoc) => {
+        $crate::Associativity::NonAssoc
+    };
 }
 
 #[macro_export]
@@ -72,8 +94,14 @@ macro_rules! grammar {
         first_symbol: $first_symbol:ident,
         default_token_action: $tok_action:expr,
 
+        $(
+            precedence: {
+                $(%$associativity:ident $($prec_name:ident)+;)*
+            }
+        )?
+
         productions: {
-            $($lhs:ident -> $rhs1:tt $($rhs:ident)* = $action:expr);+ ;
+            $($lhs:ident -> $rhs1:tt $($rhs:ident)* $(%prec $prec:ident)? = $action:expr);+ ;
         }
 
         terminals: {
@@ -84,6 +112,22 @@ macro_rules! grammar {
             $(($skip_term_name:ident, $skip_re:literal)),* $(,)?
         }
 
+        $(
+            trivia: {
+                $(($trivia_term_name:ident, $trivia_re:literal)),* $(,)?
+            }
+        )?
+
+        $(
+            sync: {
+                $($sync_term_name:ident),* $(,)?
+            }
+        )?
+
+        $(
+            tables: $tables:expr
+        )?
+
     ) => {{
         #[allow(unused_mut)]
         {
@@ -104,13 +148,37 @@ macro_rules! grammar {
                 $(($term_name, $regex $(, $specific_tok_action)?)),+
             );
 
+            // Terminals to synchronize on when recovering from syntax errors
+            $($(
+                p.define_sync_terminal(TokenType::$sync_term_name).unwrap();
+            )*)?
+
+            // Precedence levels, from the loosest to the tightest
+            $($(
+                p.define_precedence(
+                    $crate::associativity!($associativity),
+                    vec![$(stringify!($prec_name).to_string()),+],
+                ).unwrap();
+            )*)?
+
             // Register productions
-            $crate::productions!(p, $($lhs -> $rhs1 $($rhs)* = $action);+);
+            $crate::productions!(p, $($lhs -> $rhs1 $($rhs)* $(%prec $prec)? = $action);+);
 
             let skip_rules = $crate::skip!($(($skip_term_name, $skip_re)),*);
+            let trivia_rules = $crate::skip!($($(($trivia_term_name, $trivia_re)),*)?);
+
+
+            // Tables computed ahead of time, if given, skip building the automata
+            let tables: Option<$crate::PrecomputedTables> = None $(.or($tables))?;
 
+            let parser = match tables {
+                None => p.build_parser(),
+                Some(tables) => $crate::ParsingTables::from_bytes(tables.parser)
+                    .map_err(|err| vec![err])
+                    .and_then(|parsing_tables| p.build_parser_from_tables(parsing_tables)),
+            };
 
-            match p.build_parser() {
+            match parser {
                 Err(err) => {
                     panic!("Parser building errors: \n{}", err.join("\n\n"))
                 },
@@ -119,7 +187,13 @@ macro_rules! grammar {
                     let mut lex_def = LexerDefiner::new();
                     lex_def.rules(term_rules);
                     lex_def.skip_rules(skip_rules);
-                    let lexer = lex_def.compile();
+                    lex_def.trivia_rules(trivia_rules);
+                    let lexer = match tables {
+                        None => lex_def.compile(),
+                        Some(tables) => lex_def
+                            .compile_from_tables(tables.lexer)
+                            .unwrap_or_else(|err| panic!("Lexer building errors: \n{}", err)),
+                    };
 
                     (lexer, parser)
                 }
@@ -10,6 +10,8 @@ mod table_builder;
 mod parser;
 pub use parser::ParseError;
 pub use parser::Parser;
+pub use parser::ParsingTables;
+pub use parser::PrecomputedTables;
 
 mod debugging_helpers;
 
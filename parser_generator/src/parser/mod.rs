@@ -9,3 +9,7 @@ pub use parse_error::ParseError;
 
 mod parser;
 pub use parser::Parser;
+
+mod tables;
+pub use tables::ParsingTables;
+pub use tables::PrecomputedTables;
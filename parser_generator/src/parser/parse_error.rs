@@ -1,38 +1,63 @@
 use std::fmt::Debug;
 
+use error_handler::source_map::SourceMap;
+
 #[derive(Debug)]
 pub enum ParseError<TokenType: Debug> {
-    UnexpectedToken { ty: TokenType, loc: usize },
-    UnexpectedEof,
+    UnexpectedToken {
+        ty: TokenType,
+        loc: usize,
+        /// Where the unexpected token ends
+        end: usize,
+        expected: Vec<TokenType>,
+    },
+    UnexpectedEof {
+        expected: Vec<TokenType>,
+    },
 }
 
 impl<TokenType: Debug> ParseError<TokenType> {
     pub fn to_string(&self, input_program: &str) -> String {
         match self {
-            Self::UnexpectedEof => "Unexpected end of file encountered".to_string(),
-            Self::UnexpectedToken { ty, loc } => {
+            Self::UnexpectedEof { expected } => format!(
+                "Unexpected end of file encountered{}",
+                Self::expected_to_string(expected)
+            ),
+            Self::UnexpectedToken {
+                ty, loc, expected, ..
+            } => {
                 Self::to_string_unexpected_token(input_program, ty, loc)
+                    + &Self::expected_to_string(expected)
             }
         }
     }
 
+    pub fn expected(&self) -> &Vec<TokenType> {
+        match self {
+            Self::UnexpectedToken { expected, .. } | Self::UnexpectedEof { expected } => expected,
+        }
+    }
+
+    fn expected_to_string(expected: &Vec<TokenType>) -> String {
+        if expected.is_empty() {
+            return String::new();
+        }
+
+        let expected = expected
+            .iter()
+            .map(|ty| format!("{:?}", ty))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(", expected one of: {}", expected)
+    }
+
     fn to_string_unexpected_token(input_program: &str, ty: &TokenType, loc: &usize) -> String {
-        let newline_indices = input_program
-            .char_indices()
-            .filter(|(_, char)| char == &'\n')
-            .map(|(index, _)| index)
-            .collect::<Vec<usize>>();
-
-        let (line, column) = if newline_indices.is_empty() {
-            (0, *loc)
-        } else {
-            let line = newline_indices.binary_search(loc);
-
-            match line {
-                Ok(idx) => (idx, 0),
-                Err(idx) => (idx, loc - newline_indices.get(idx - 1).unwrap_or(&0)),
-            }
-        };
+        let source_map = SourceMap::from_text(input_program);
+        let (line, column) = source_map
+            .line_column(*loc)
+            .map(|(_, line, column)| (line, column))
+            .unwrap_or((1, 1));
 
         format!("Unexpected token {:?} at {}:{}", ty, line, column)
     }
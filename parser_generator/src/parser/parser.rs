@@ -1,5 +1,10 @@
-use std::{collections::HashMap, fmt::Debug, hash::Hash};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+};
 
+use error_handler::diagnostic::diagnostic::Span;
 use error_handler::error::error::HulkError;
 use error_handler::error::sintactic::user_error::UserError;
 
@@ -7,12 +12,15 @@ use error_handler::error::sintactic::user_error::UserError;
 use crate::debugging_helpers::get_name_or_default;
 
 use crate::grammar::Lex;
-use crate::parser::{Action, Parse};
+use crate::parser::{Action, Parse, ParsingTables};
 use crate::{
     ParseError, Production, ProductionCompute, ProductionId, StateId, SymbolId, TerminalCompute,
 };
 use crate::{TerminalId, Token};
 
+/// Maximum amount of tokens read when trying out a place to resume parsing from
+const RECOVERY_LOOKAHEAD: usize = 32;
+
 pub struct Parser<TokenType: Eq + Hash + Debug + Copy, R> {
     action_table: HashMap<(StateId, TerminalId), Action>,
     _symbols: HashMap<SymbolId, Option<String>>,
@@ -21,6 +29,7 @@ pub struct Parser<TokenType: Eq + Hash + Debug + Copy, R> {
     terminal_computes: HashMap<TerminalId, TerminalCompute<TokenType, R>>,
     goto: HashMap<(StateId, SymbolId), StateId>,
     token_to_terminal: HashMap<TokenType, TerminalId>,
+    sync_terminals: HashSet<TerminalId>,
 
     eof_id: TerminalId,
 }
@@ -34,6 +43,7 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
         terminal_computes: HashMap<TerminalId, TerminalCompute<TokenType, R>>,
         token_to_terminal: HashMap<TokenType, TerminalId>,
         goto: HashMap<(StateId, SymbolId), StateId>,
+        sync_terminals: HashSet<TerminalId>,
         eof_id: TerminalId,
     ) -> Self {
         Self {
@@ -44,12 +54,27 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
             terminal_computes,
             token_to_terminal,
             goto,
+            sync_terminals,
             eof_id,
         }
     }
 
-    pub fn parse(&self, tokens: Vec<Token<TokenType>>) -> Result<R, ParseError<TokenType>> {
+    /// # Description
+    ///
+    /// The tables the parser runs on, to serialize them and build the parser
+    /// again with `Grammar::build_parser_from_tables`
+    pub fn tables(&self) -> ParsingTables {
+        ParsingTables {
+            action_table: self.action_table.clone(),
+            goto_table: self.goto.clone(),
+            symbols: self._symbols.clone(),
+            productions: self.productions.clone(),
+        }
+    }
+
+    pub fn parse(&self, tokens: Vec<Token<TokenType>>) -> Result<R, Vec<ParseError<TokenType>>> {
         let mut current_parse = Parse::new(tokens);
+        let mut errors = Vec::new();
 
         loop {
             let next_terminal_id = self.get_next_token_terminal_id(&current_parse);
@@ -58,15 +83,12 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
             let action = self.get_next_action(current_state, next_terminal_id);
 
             if let None = action {
-                let next_token = self.get_next_token(&current_parse);
-
-                return match next_token {
-                    Some(tok) => Err(ParseError::UnexpectedToken {
-                        ty: tok.ty,
-                        loc: tok.start,
-                    }),
-                    None => Err(ParseError::UnexpectedEof),
-                };
+                errors.push(self.error_at(&current_parse));
+
+                if !self.recover(&mut current_parse) {
+                    break;
+                }
+                continue;
             }
 
             let action = action.unwrap();
@@ -80,6 +102,10 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
             };
         }
 
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         let answ = current_parse.value_stack.pop().unwrap();
 
         Ok(answ)
@@ -92,25 +118,151 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
     ) -> Result<R, Vec<HulkError>> {
         let toks = lexer.split(input)?;
 
-        self.parse(toks).map_err(|err| {
-            let position = match err {
-                ParseError::UnexpectedToken { loc, .. } => loc,
-                ParseError::UnexpectedEof => input.len(),
-            };
-            vec![UserError::new(err.to_string(input), position).into()]
+        self.parse(toks).map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|err| {
+                    let span = match err {
+                        ParseError::UnexpectedToken { loc, end, .. } => Span::new(loc, end),
+                        ParseError::UnexpectedEof { .. } => Span::from(input.len()),
+                    };
+                    UserError::new(err.to_string(input), span).into()
+                })
+                .collect()
         })
     }
 
+    fn error_at(&self, current_parse: &Parse<TokenType, R>) -> ParseError<TokenType> {
+        let expected = self.get_expected_tokens(self.get_current_state(current_parse));
+
+        match self.get_next_token(current_parse) {
+            Some(tok) => ParseError::UnexpectedToken {
+                ty: tok.ty,
+                loc: tok.start,
+                end: tok.end,
+                expected,
+            },
+            None => ParseError::UnexpectedEof { expected },
+        }
+    }
+
+    /// # Description
+    ///
+    /// Panic mode recovery: skips tokens up to the next synchronization terminal, then
+    /// resumes from the state in the stack that gets the furthest on the remaining input,
+    /// either at the synchronization terminal or right after it, the lowest one on a tie. Once
+    /// an error happened no more semantic values are computed, parsing only goes on to find
+    /// the remaining errors.
+    ///
+    /// Returns false when the input ends before a state to resume from is found
+    fn recover(&self, current_parse: &mut Parse<TokenType, R>) -> bool {
+        current_parse.value_stack.clear();
+        current_parse.discard_values = true;
+
+        let token_count = current_parse.tokens.len();
+
+        loop {
+            while current_parse.token_index < token_count
+                && !self
+                    .sync_terminals
+                    .contains(&self.get_next_token_terminal_id(current_parse))
+            {
+                current_parse.token_index += 1;
+            }
+
+            if current_parse.token_index >= token_count {
+                return false;
+            }
+
+            let sync_index = current_parse.token_index;
+            let mut best_resume: Option<(usize, usize, usize)> = None;
+
+            for token_index in [sync_index, sync_index + 1] {
+                let terminal_id = self.get_terminal_id_at(&current_parse.tokens, token_index);
+
+                // from the bottom of the stack up, so on a tie the stack is
+                // truncated to where the broken construct began instead of
+                // resuming inside of it
+                for depth in 1..=current_parse.state_stack.len() {
+                    let state = current_parse.state_stack[depth - 1];
+                    if self.get_next_action(state, terminal_id).is_none() {
+                        continue;
+                    }
+
+                    let reached = self.trial_parse(
+                        current_parse.state_stack[..depth].to_vec(),
+                        &current_parse.tokens,
+                        token_index,
+                    );
+
+                    if reached > token_index
+                        && best_resume.map_or(true, |(best_reached, _, _)| reached > best_reached)
+                    {
+                        best_resume = Some((reached, depth, token_index));
+                    }
+                }
+            }
+
+            if let Some((_, depth, token_index)) = best_resume {
+                current_parse.state_stack.truncate(depth);
+                current_parse.token_index = token_index;
+                return true;
+            }
+
+            current_parse.token_index += 1;
+        }
+    }
+
+    /// # Description
+    ///
+    /// Runs the automaton on a copy of the state stack, without computing values, and
+    /// returns the index of the first token it could not consume. At most
+    /// `RECOVERY_LOOKAHEAD` tokens are read; reaching the accept action beats any index
+    fn trial_parse(
+        &self,
+        mut state_stack: Vec<StateId>,
+        tokens: &Vec<Token<TokenType>>,
+        mut token_index: usize,
+    ) -> usize {
+        let start = token_index;
+
+        while token_index - start < RECOVERY_LOOKAHEAD {
+            let state = *state_stack.last().unwrap();
+            let terminal_id = self.get_terminal_id_at(tokens, token_index);
+
+            match self.get_next_action(state, terminal_id) {
+                Some(Action::Shift(state_id)) => {
+                    state_stack.push(*state_id);
+                    token_index += 1;
+                }
+                Some(Action::Reduce(production_id)) => {
+                    let production = self.productions.get(production_id).unwrap();
+                    state_stack.truncate(state_stack.len() - production.rhs.len());
+
+                    let lhs = SymbolId::from(production.lhs);
+                    let current_state = *state_stack.last().unwrap();
+                    state_stack.push(*self.goto.get(&(current_state, lhs)).unwrap());
+                }
+                Some(Action::Accept) => return usize::MAX,
+                None => break,
+            }
+        }
+
+        token_index
+    }
+
     fn shift(&self, state_id: StateId, current_parse: &mut Parse<TokenType, R>) {
         let compute = self.get_next_token_terminal_compute(&current_parse);
         let token = self
             .get_next_token(&current_parse)
             .expect("Should not perform shift on end of file");
 
-        let value = compute(token);
+        if !current_parse.discard_values {
+            let value = compute(token);
+            current_parse.value_stack.push(value);
+        }
 
         current_parse.state_stack.push(state_id);
-        current_parse.value_stack.push(value);
         current_parse.token_index += 1;
     }
 
@@ -126,15 +278,20 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
             .expect("production id in parse table should have a production compute function associated to it");
 
         let symbol_count = production.rhs.len();
-        let mut reduced_values = Vec::new();
-        for _ in 0..symbol_count {
-            reduced_values.push(current_parse.value_stack.pop().unwrap());
-            current_parse.state_stack.pop().unwrap();
-        }
-        reduced_values = reduced_values.into_iter().rev().collect();
+        if current_parse.discard_values {
+            let remaining = current_parse.state_stack.len() - symbol_count;
+            current_parse.state_stack.truncate(remaining);
+        } else {
+            let mut reduced_values = Vec::new();
+            for _ in 0..symbol_count {
+                reduced_values.push(current_parse.value_stack.pop().unwrap());
+                current_parse.state_stack.pop().unwrap();
+            }
+            reduced_values = reduced_values.into_iter().rev().collect();
 
-        let new_value = compute(reduced_values);
-        current_parse.value_stack.push(new_value);
+            let new_value = compute(reduced_values);
+            current_parse.value_stack.push(new_value);
+        }
 
         let lhs = SymbolId::from(production.lhs);
         let current_state = *current_parse.state_stack.last().unwrap();
@@ -162,7 +319,11 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
     }
 
     fn get_next_token_terminal_id(&self, current_parse: &Parse<TokenType, R>) -> TerminalId {
-        let token = self.get_next_token(current_parse);
+        self.get_terminal_id_at(&current_parse.tokens, current_parse.token_index)
+    }
+
+    fn get_terminal_id_at(&self, tokens: &Vec<Token<TokenType>>, index: usize) -> TerminalId {
+        let token = tokens.get(index);
 
         if token.is_none() {
             return self.eof_id;
@@ -175,6 +336,28 @@ impl<'a, TokenType: Eq + Hash + Copy + Debug, R> Parser<TokenType, R> {
         *self.token_to_terminal.get(current_token_type).unwrap()
     }
 
+    /// Token types with an action in the given state, sorted by definition order. The end of
+    /// file is not a token type, so it is left out
+    fn get_expected_tokens(&self, state: StateId) -> Vec<TokenType> {
+        let mut terminals = self
+            .action_table
+            .keys()
+            .filter(|(s, terminal)| *s == state && *terminal != self.eof_id)
+            .map(|(_, terminal)| *terminal)
+            .collect::<Vec<_>>();
+        terminals.sort();
+
+        terminals
+            .iter()
+            .filter_map(|terminal| {
+                self.token_to_terminal
+                    .iter()
+                    .find(|(_, t)| *t == terminal)
+                    .map(|(ty, _)| *ty)
+            })
+            .collect()
+    }
+
     fn get_next_token_terminal_compute(
         &self,
         current_parse: &Parse<TokenType, R>,
@@ -247,6 +430,7 @@ impl<TokenType: Eq + Hash + Debug + Copy, R> Debug for Parser<TokenType, R> {
             )
             .field("goto", &self.goto)
             .field("symbol_table", &self.token_to_terminal)
+            .field("sync_terminals", &self.sync_terminals)
             .field("eof_id", &self.eof_id)
             .finish()
     }
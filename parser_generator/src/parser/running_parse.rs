@@ -8,6 +8,8 @@ pub struct Parse<TokenType: Eq + Hash, R> {
     pub state_stack: Vec<StateId>,
     pub value_stack: Vec<R>,
     pub token_index: usize,
+    /// set after a syntax error, the values on the stack are meaningless from then on
+    pub discard_values: bool,
 }
 
 impl<TokenType: Eq + Hash, R> Parse<TokenType, R> {
@@ -17,6 +19,7 @@ impl<TokenType: Eq + Hash, R> Parse<TokenType, R> {
             state_stack: vec![StateId(0)],
             value_stack: Vec::new(),
             token_index: 0,
+            discard_values: false,
         }
     }
 }
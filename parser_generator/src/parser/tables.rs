@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use crate::parser::Action;
+use crate::{NonTerminalId, Production, ProductionId, StateId, SymbolId, TerminalId};
+
+/// Identifies the binary format written by `ParsingTables::to_bytes`
+const MAGIC: &[u8; 4] = b"HPT1";
+
+/// # Description
+///
+/// The parsing tables of a grammar, computed ahead of time (e.g. in a build
+/// script) so a parser can be built without building the LR item sets again.
+/// The symbols and productions they were computed for are kept to check that
+/// they still match the grammar they are loaded with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsingTables {
+    pub(crate) action_table: HashMap<(StateId, TerminalId), Action>,
+    pub(crate) goto_table: HashMap<(StateId, SymbolId), StateId>,
+    pub(crate) symbols: HashMap<SymbolId, Option<String>>,
+    pub(crate) productions: HashMap<ProductionId, Production>,
+}
+
+/// # Description
+///
+/// Serialized tables of a parser and of its lexer, given to `grammar!` to
+/// build both from them instead of from scratch
+#[derive(Debug, Clone, Copy)]
+pub struct PrecomputedTables<'a> {
+    pub parser: &'a [u8],
+    pub lexer: &'a [u8],
+}
+
+impl ParsingTables {
+    /// # Description
+    ///
+    /// Serializes the tables into a compact binary format, which `from_bytes`
+    /// reads back. Entries are sorted, so the same tables always give the same bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer(MAGIC.to_vec());
+
+        let mut symbols: Vec<_> = self.symbols.iter().collect();
+        symbols.sort_by_key(|(symbol, _)| symbol_key(symbol));
+        writer.usize(symbols.len());
+        for (symbol, name) in symbols {
+            writer.symbol(*symbol);
+            match name {
+                Some(name) => {
+                    writer.byte(1);
+                    writer.usize(name.len());
+                    writer.0.extend(name.as_bytes());
+                }
+                None => writer.byte(0),
+            }
+        }
+
+        let mut productions: Vec<_> = self.productions.iter().collect();
+        productions.sort_by_key(|(id, _)| **id);
+        writer.usize(productions.len());
+        for (id, production) in productions {
+            writer.usize(id.id());
+            writer.usize(production.lhs.id());
+            writer.usize(production.rhs.len());
+            for symbol in &production.rhs {
+                writer.symbol(*symbol);
+            }
+        }
+
+        let mut actions: Vec<_> = self.action_table.iter().collect();
+        actions.sort_by_key(|((state, terminal), _)| (state.0, *terminal));
+        writer.usize(actions.len());
+        for ((state, terminal), action) in actions {
+            writer.usize(state.0);
+            writer.usize(terminal.id());
+            match action {
+                Action::Shift(state) => {
+                    writer.byte(0);
+                    writer.usize(state.0);
+                }
+                Action::Reduce(production) => {
+                    writer.byte(1);
+                    writer.usize(production.id());
+                }
+                Action::Accept => writer.byte(2),
+            }
+        }
+
+        let mut gotos: Vec<_> = self.goto_table.iter().collect();
+        gotos.sort_by_key(|((state, symbol), _)| (state.0, symbol_key(symbol)));
+        writer.usize(gotos.len());
+        for ((state, symbol), next) in gotos {
+            writer.usize(state.0);
+            writer.symbol(*symbol);
+            writer.usize(next.0);
+        }
+
+        writer.0
+    }
+
+    /// # Description
+    ///
+    /// Reads tables serialized by `to_bytes`, failing if the bytes are not
+    /// valid serialized tables
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if !bytes.starts_with(MAGIC) {
+            return Err("The given bytes are not parsing tables".to_string());
+        }
+        let mut reader = Reader {
+            bytes,
+            position: MAGIC.len(),
+        };
+
+        let mut symbols = HashMap::new();
+        for _ in 0..reader.usize()? {
+            let symbol = reader.symbol()?;
+            let name = match reader.byte()? {
+                0 => None,
+                _ => {
+                    let len = reader.usize()?;
+                    let name = String::from_utf8(reader.take(len)?.to_vec())
+                        .map_err(|_| "A symbol name is not valid UTF-8".to_string())?;
+                    Some(name)
+                }
+            };
+            symbols.insert(symbol, name);
+        }
+
+        let mut productions = HashMap::new();
+        for _ in 0..reader.usize()? {
+            let id = ProductionId::new(reader.usize()?);
+            let lhs = NonTerminalId::new(reader.usize()?);
+            let mut rhs = Vec::new();
+            for _ in 0..reader.usize()? {
+                rhs.push(reader.symbol()?);
+            }
+            productions.insert(id, Production::new(lhs, rhs));
+        }
+
+        let mut action_table = HashMap::new();
+        for _ in 0..reader.usize()? {
+            let key = (StateId(reader.usize()?), TerminalId::new(reader.usize()?));
+            let action = match reader.byte()? {
+                0 => Action::Shift(StateId(reader.usize()?)),
+                1 => Action::Reduce(ProductionId::new(reader.usize()?)),
+                2 => Action::Accept,
+                tag => return Err(format!("Unknown action {tag} in the parsing tables")),
+            };
+            action_table.insert(key, action);
+        }
+
+        let mut goto_table = HashMap::new();
+        for _ in 0..reader.usize()? {
+            let key = (StateId(reader.usize()?), reader.symbol()?);
+            goto_table.insert(key, StateId(reader.usize()?));
+        }
+
+        if reader.position != bytes.len() {
+            return Err("The parsing tables have trailing bytes".to_string());
+        }
+
+        Ok(Self {
+            action_table,
+            goto_table,
+            symbols,
+            productions,
+        })
+    }
+
+    /// # Description
+    ///
+    /// Fails if the tables were computed for other symbols or productions,
+    /// i.e. the grammar changed since they were computed
+    pub(crate) fn check_grammar(
+        &self,
+        symbols: &HashMap<SymbolId, Option<String>>,
+        productions: &HashMap<ProductionId, Production>,
+    ) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if &self.symbols != symbols {
+            errors.push("The parsing tables were computed for other symbols".to_string());
+        }
+        if &self.productions != productions {
+            errors.push("The parsing tables were computed for other productions".to_string());
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
+
+fn symbol_key(symbol: &SymbolId) -> (u8, usize) {
+    match symbol {
+        SymbolId::TerminalId(terminal) => (0, terminal.id()),
+        SymbolId::NonTerminalId(non_terminal) => (1, non_terminal.id()),
+    }
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn byte(&mut self, value: u8) {
+        self.0.push(value);
+    }
+
+    fn usize(&mut self, value: usize) {
+        self.0.extend((value as u32).to_le_bytes());
+    }
+
+    fn symbol(&mut self, symbol: SymbolId) {
+        let (tag, id) = symbol_key(&symbol);
+        self.byte(tag);
+        self.usize(id);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.position + len;
+        if end > self.bytes.len() {
+            return Err("The parsing tables end unexpectedly".to_string());
+        }
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn usize(&mut self) -> Result<usize, String> {
+        let slice = self.take(4)?;
+        Ok(u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]) as usize)
+    }
+
+    fn symbol(&mut self) -> Result<SymbolId, String> {
+        let tag = self.byte()?;
+        let id = self.usize()?;
+        match tag {
+            0 => Ok(SymbolId::from(TerminalId::new(id))),
+            1 => Ok(SymbolId::from(NonTerminalId::new(id))),
+            _ => Err(format!("Unknown symbol kind {tag} in the parsing tables")),
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
 use crate::table_builder::{
     GotoTable, TableBuilder,
@@ -6,9 +9,18 @@ use crate::table_builder::{
     items::{LR0Item, LR1Item},
 };
 use crate::{
-    StateId, SymbolId, TerminalId, debugging_helpers::get_name_or_default, parser::Action,
+    Associativity, StateId, SymbolId, TerminalId, debugging_helpers::get_name_or_default,
+    parser::Action,
 };
 
+/// How a shift/reduce conflict is resolved by the precedence of its terminal
+/// and production
+enum Resolution {
+    Keep(Action),
+    /// `%nonassoc` operators following each other, which is a syntax error
+    Error,
+}
+
 impl<'b> TableBuilder<'b> {
     pub(super) fn build_action_table(
         &self,
@@ -19,6 +31,9 @@ impl<'b> TableBuilder<'b> {
     ) -> Result<HashMap<(StateId, TerminalId), Action>, Vec<Conflict>> {
         let mut conflicts: Vec<Conflict> = Vec::new();
         let mut action_table: HashMap<(StateId, TerminalId), Action> = HashMap::new();
+        // entries left empty by `%nonassoc`, where the parser reports an error, with the
+        // actions that were resolved into the error
+        let mut error_entries: HashMap<(StateId, TerminalId), [Action; 2]> = HashMap::new();
 
         let final_state = goto_table
             .get(&(
@@ -34,8 +49,13 @@ impl<'b> TableBuilder<'b> {
                         for f in item.follow().iter() {
                             let action_key = (*state_id, *f);
                             let action_to_add = Action::Reduce(*item.core().production_id());
-                            let conflict =
-                                self.add_to_table(action_key, action_to_add, *f, &mut action_table);
+                            let conflict = self.add_to_table(
+                                action_key,
+                                action_to_add,
+                                *f,
+                                &mut action_table,
+                                &mut error_entries,
+                            );
 
                             if conflict.is_some() {
                                 conflicts.push(conflict.unwrap());
@@ -49,8 +69,13 @@ impl<'b> TableBuilder<'b> {
                             let goto_key = (*state_id, symbol);
                             let action_to_add = Action::Shift(*goto_table.get(&goto_key).unwrap());
 
-                            let conflict =
-                                self.add_to_table(action_key, action_to_add, *f, &mut action_table);
+                            let conflict = self.add_to_table(
+                                action_key,
+                                action_to_add,
+                                *f,
+                                &mut action_table,
+                                &mut error_entries,
+                            );
                             if conflict.is_some() {
                                 conflicts.push(conflict.unwrap());
                             }
@@ -75,24 +100,80 @@ impl<'b> TableBuilder<'b> {
         action_to_add: Action,
         f: TerminalId,
         action_table: &mut HashMap<(StateId, TerminalId), Action>,
+        error_entries: &mut HashMap<(StateId, TerminalId), [Action; 2]>,
     ) -> Option<Conflict> {
+        if let Some(resolved) = error_entries.get(&action_key) {
+            if resolved.contains(&action_to_add) {
+                return None;
+            }
+            // the entry already holds a shift, so any other action is a reduction that
+            // conflicts with the one resolved into the error
+            let reduce = resolved
+                .iter()
+                .find(|action| matches!(action, Action::Reduce(_)))
+                .copied()
+                .unwrap_or(resolved[0]);
+            return Some(self.create_conflict(reduce, action_to_add, f));
+        }
+
         if action_table.contains_key(&action_key) {
-            let action = action_table.get(&action_key).unwrap();
-            if action == &action_to_add {
+            let action = *action_table.get(&action_key).unwrap();
+            if action == action_to_add {
                 return None;
             }
 
-            return Some(self.create_conflict(
-                *action_table.get(&action_key).unwrap(),
-                action_to_add,
-                f,
-            ));
+            match self.resolve_by_precedence(action, action_to_add, f) {
+                Some(Resolution::Keep(action)) => {
+                    action_table.insert(action_key, action);
+                    None
+                }
+                Some(Resolution::Error) => {
+                    action_table.remove(&action_key);
+                    error_entries.insert(action_key, [action, action_to_add]);
+                    None
+                }
+                None => Some(self.create_conflict(action, action_to_add, f)),
+            }
         } else {
             action_table.insert(action_key, action_to_add);
             None
         }
     }
 
+    /// # Description
+    ///
+    /// Resolves a shift/reduce conflict on `lookahead` as yacc does: the
+    /// action with the higher precedence is kept, the precedence of the
+    /// reduction being the one of its production. On the same level, `%left`
+    /// reduces, `%right` shifts and `%nonassoc` makes it a syntax error.
+    /// Conflicts where either has no precedence are not resolved
+    fn resolve_by_precedence(
+        &self,
+        action_1: Action,
+        action_2: Action,
+        lookahead: TerminalId,
+    ) -> Option<Resolution> {
+        let (shift, production_id) = match (action_1, action_2) {
+            (Action::Shift(_), Action::Reduce(production_id)) => (action_1, production_id),
+            (Action::Reduce(production_id), Action::Shift(_)) => (action_2, production_id),
+            _ => return None,
+        };
+        let terminal = self.terminal_precedences.get(&lookahead)?;
+        let production = self.production_precedences.get(&production_id)?;
+        let reduce = Action::Reduce(production_id);
+
+        let resolution = match production.level.cmp(&terminal.level) {
+            Ordering::Greater => Resolution::Keep(reduce),
+            Ordering::Less => Resolution::Keep(shift),
+            Ordering::Equal => match terminal.associativity {
+                Associativity::Left => Resolution::Keep(reduce),
+                Associativity::Right => Resolution::Keep(shift),
+                Associativity::NonAssoc => Resolution::Error,
+            },
+        };
+        Some(resolution)
+    }
+
     fn create_conflict(&self, action_1: Action, action_2: Action, follow: TerminalId) -> Conflict {
         match (action_1, action_2) {
             (Action::Accept, _) | (_, Action::Accept) => {
@@ -1,8 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::{
-    NonTerminalId, Production, ProductionId, StateId, SymbolId, TerminalId, parser::Action,
-    table_builder::conflicts::Conflict,
+    NonTerminalId, Precedence, Production, ProductionId, StateId, SymbolId, TerminalId,
+    parser::Action, table_builder::conflicts::Conflict,
 };
 
 pub type ActionTable = HashMap<(StateId, TerminalId), Action>;
@@ -11,6 +11,9 @@ pub type GotoTable = HashMap<(StateId, SymbolId), StateId>;
 pub(crate) struct TableBuilder<'a> {
     pub(super) symbols: &'a HashMap<SymbolId, Option<String>>,
     pub(super) productions: &'a HashMap<ProductionId, Production>,
+    /// precedences used to resolve shift/reduce conflicts
+    pub(super) terminal_precedences: &'a HashMap<TerminalId, Precedence>,
+    pub(super) production_precedences: &'a HashMap<ProductionId, Precedence>,
     pub(super) first_production_id: ProductionId,
 
     pub(super) epsilon: TerminalId,
@@ -26,6 +29,8 @@ impl<'a> TableBuilder<'a> {
     pub(crate) fn build_tables(
         symbols: &'a HashMap<SymbolId, Option<String>>,
         productions: &'a HashMap<ProductionId, Production>,
+        terminal_precedences: &'a HashMap<TerminalId, Precedence>,
+        production_precedences: &'a HashMap<ProductionId, Precedence>,
         first_production_id: ProductionId,
         epsilon: TerminalId,
         eof: TerminalId,
@@ -34,6 +39,8 @@ impl<'a> TableBuilder<'a> {
         let mut generator = Self {
             symbols,
             productions,
+            terminal_precedences,
+            production_precedences,
             first_production_id,
 
             epsilon,
@@ -5,7 +5,7 @@ use crate::test::{
     helpers,
 };
 
-fn parse(input: &str) -> Result<Option<i32>, ParseError<TokenType>> {
+fn parse(input: &str) -> Result<Option<i32>, Vec<ParseError<TokenType>>> {
     let answ = helpers::parse(grammar::lexer_parser, input);
 
     answ
@@ -0,0 +1,94 @@
+use crate::ParseError;
+use crate::parser::Parser;
+use crate::test::LexerWrapper;
+use crate::test::helpers::{LexerDefiner, parse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TokenType {
+    Number,
+    Plus,
+    Semicolon,
+    Whitespace,
+}
+
+fn lexer_parser() -> (LexerWrapper<TokenType>, Parser<TokenType, usize>) {
+    let (lexer, parser) = grammar! {
+        token_type: TokenType,
+        return_type: usize,
+        lexer_definer_type: LexerDefiner,
+        first_symbol: S,
+        default_token_action: |_: &_| 0,
+
+        productions: {
+            S -> S E Semicolon = |v| v[0] + 1;
+            S -> E Semicolon = |_| 1;
+            E -> E Plus Number = |_| 0;
+            E -> Number = |_| 0;
+        }
+
+        terminals: {
+            (Number, r"[0-9]+"),
+            (Plus, r"\+"),
+            (Semicolon, r";"),
+        }
+
+        skip: {
+            (Whitespace, r"(\s|\n)+"),
+        }
+
+        sync: {
+            Semicolon,
+        }
+    };
+
+    (lexer, parser)
+}
+
+fn error_locations(errors: &Vec<ParseError<TokenType>>) -> Vec<Option<usize>> {
+    errors
+        .iter()
+        .map(|err| match err {
+            ParseError::UnexpectedToken { loc, .. } => Some(*loc),
+            ParseError::UnexpectedEof { .. } => None,
+        })
+        .collect()
+}
+
+#[test]
+fn valid_input_still_computes_values() {
+    assert_eq!(parse(lexer_parser, "1; 2 + 3; 4;").unwrap(), 3);
+}
+
+#[test]
+fn reports_every_statement_with_errors() {
+    let errors = parse(lexer_parser, "1 +; 2; 3 3; 4 + 5;").unwrap_err();
+
+    assert_eq!(error_locations(&errors), vec![Some(3), Some(10)]);
+}
+
+#[test]
+fn errors_carry_expected_tokens() {
+    let errors = parse(lexer_parser, "1 + +;").unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].expected(), &vec![TokenType::Number]);
+}
+
+#[test]
+fn error_right_before_end_of_file() {
+    let errors = parse(lexer_parser, "1 +; 2 +").unwrap_err();
+
+    assert_eq!(error_locations(&errors), vec![Some(3), None]);
+    assert_eq!(errors[1].expected(), &vec![TokenType::Number]);
+}
+
+#[test]
+fn unexpected_tokens_show_line_and_column() {
+    let input = "1;\n2 + +;";
+    let errors = parse(lexer_parser, input).unwrap_err();
+
+    assert_eq!(
+        errors[0].to_string(input),
+        "Unexpected token Plus at 2:5, expected one of: Number"
+    );
+}
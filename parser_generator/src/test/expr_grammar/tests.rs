@@ -4,7 +4,7 @@ use crate::test::{
     helpers,
 };
 
-fn parse(input: &str) -> Result<(), ParseError<TokenType>> {
+fn parse(input: &str) -> Result<(), Vec<ParseError<TokenType>>> {
     let answ = helpers::parse(grammar::lexer_parser, input);
     answ
 }
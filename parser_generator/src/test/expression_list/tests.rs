@@ -5,7 +5,7 @@ use crate::test::{
     helpers,
 };
 
-fn parse(input: &str) -> Result<ReturnType, ParseError<TokenType>> {
+fn parse(input: &str) -> Result<ReturnType, Vec<ParseError<TokenType>>> {
     let answ = helpers::parse(grammar::lexer_parser, input);
 
     answ
@@ -1,7 +1,9 @@
 use std::{fmt::Debug, hash::Hash};
 
 use error_handler::error::error::HulkError;
-use lexer::lexer_generator::{lexer::Lexer, lexer_chunk::LexerChunk, rule::Rule};
+use lexer::lexer_generator::{
+    lexer::Lexer, lexer_chunk::LexerChunk, lexer_tables::LexerTables, rule::Rule,
+};
 
 use crate::{
     ParseError, Parser, Token,
@@ -11,7 +13,7 @@ use crate::{
 pub fn parse<T: Hash + Eq + Copy + Debug, R>(
     lexer_parser: impl FnOnce() -> (LexerWrapper<T>, Parser<T, R>),
     input: &str,
-) -> Result<R, ParseError<T>> {
+) -> Result<R, Vec<ParseError<T>>> {
     let (lexer, parser) = lexer_parser();
 
     let tokens = lexer.split(input).unwrap();
@@ -33,6 +35,10 @@ impl<T: Hash + Eq + Copy + Debug> LexerWrapper<T> {
     pub fn new(lexer: Lexer<T>) -> Self {
         Self { lexer }
     }
+
+    pub fn tables(&self) -> Vec<u8> {
+        self.lexer.tables().to_bytes()
+    }
 }
 
 impl<T: Hash + Eq + Copy + Debug> Lex<T> for LexerWrapper<T> {
@@ -65,4 +71,9 @@ impl<T: Hash + Eq + Copy + Debug> DefineLexer<T, LexerWrapper<T>> for LexerDefin
     fn compile(self) -> LexerWrapper<T> {
         LexerWrapper::new(Lexer::new(self.rules))
     }
+
+    fn compile_from_tables(self, tables: &[u8]) -> Result<LexerWrapper<T>, String> {
+        let tables = LexerTables::from_bytes(tables)?;
+        Lexer::from_tables(self.rules, tables).map(LexerWrapper::new)
+    }
 }
@@ -1,10 +1,13 @@
 mod calculator_grammar;
 mod equal_non_terminals;
+mod error_recovery;
 mod expr_grammar;
 mod expression_list;
 mod lrvalue_grammar;
 mod non_lalr_grammar;
 mod optional_semicolon;
+mod precedence_grammar;
+mod precomputed_tables;
 mod undefined_non_terminal;
 
 pub mod helpers;
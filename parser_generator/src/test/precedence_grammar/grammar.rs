@@ -0,0 +1,58 @@
+use crate::{
+    Parser, Token,
+    test::{LexerDefiner, LexerWrapper, precedence_grammar::token_type::TokenType},
+};
+
+/// An ambiguous calculator grammar, whose conflicts are all resolved by the
+/// declared precedences
+pub fn lexer_parser() -> (LexerWrapper<TokenType>, Parser<TokenType, Option<i32>>) {
+    let (lexer, parser) = grammar! {
+        token_type: TokenType,
+        return_type: Option<i32>,
+        lexer_definer_type: LexerDefiner,
+        first_symbol: E,
+        default_token_action: |tok: &Token<TokenType>| match tok.slice.parse::<i32>() {
+            Ok(i) => Some(i),
+            Err(_) => None,
+        },
+
+        precedence: {
+            %nonassoc Less;
+            %left Plus Minus;
+            %left Times Div;
+            %right Negative;
+            %right Caret;
+        }
+
+        productions: {
+            E -> E Less E = |v| Some((v[0].unwrap() < v[2].unwrap()) as i32);
+            E -> E Plus E = |v| Some(v[0].unwrap() + v[2].unwrap());
+            E -> E Minus E = |v| Some(v[0].unwrap() - v[2].unwrap());
+            E -> E Times E = |v| Some(v[0].unwrap() * v[2].unwrap());
+            E -> E Div E = |v| Some(v[0].unwrap() / v[2].unwrap());
+            E -> E Caret E = |v| Some(v[0].unwrap().pow(v[2].unwrap() as u32));
+            E -> Minus E %prec Negative = |v| Some(-v[1].unwrap());
+            E -> Number = |v| v[0];
+            E -> Lpar E Rpar = |v| v[1];
+        }
+
+        terminals: {
+            (Number, r"[0-9]+"),
+            (Plus, r"\+"),
+            (Minus, r"\-"),
+            (Times, r"\*"),
+            (Div, r"/"),
+            (Caret, r"\^"),
+            (Less, r"<"),
+            (Lpar, r"\("),
+            (Rpar, r"\)")
+        }
+
+        skip: {
+            (__Whitespace__, r"\s+"),
+        }
+
+    };
+
+    (lexer, parser)
+}
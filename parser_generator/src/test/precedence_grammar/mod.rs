@@ -0,0 +1,4 @@
+mod grammar;
+mod token_type;
+
+mod tests;
@@ -0,0 +1,130 @@
+use crate::{ParseError, Token};
+
+use crate::test::{
+    LexerDefiner, helpers,
+    precedence_grammar::{grammar, token_type::TokenType},
+};
+
+fn parse(input: &str) -> Result<Option<i32>, Vec<ParseError<TokenType>>> {
+    helpers::parse(grammar::lexer_parser, input)
+}
+
+#[test]
+fn higher_levels_bind_tighter() {
+    assert_eq!(parse("1 + 2 * 3").unwrap(), Some(7));
+    assert_eq!(parse("2 * 3 + 1").unwrap(), Some(7));
+}
+
+#[test]
+fn left_associative_operators_group_to_the_left() {
+    assert_eq!(parse("10 - 4 - 3").unwrap(), Some(3));
+    assert_eq!(parse("16 / 4 / 2").unwrap(), Some(2));
+}
+
+#[test]
+fn right_associative_operators_group_to_the_right() {
+    assert_eq!(parse("2 ^ 3 ^ 2").unwrap(), Some(512));
+}
+
+#[test]
+fn prec_overrides_the_precedence_of_a_production() {
+    // the negation binds tighter than `*` but looser than `^`
+    assert_eq!(parse("-2 * 3 + 10").unwrap(), Some(4));
+    assert_eq!(parse("-2 ^ 2").unwrap(), Some(-4));
+}
+
+#[test]
+fn non_associative_operators_can_not_be_chained() {
+    assert_eq!(parse("1 < 2 + 1").unwrap(), Some(1));
+    assert!(parse("1 < 2 < 3").is_err());
+    assert_eq!(parse("(1 < 2) < 3").unwrap(), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Or shift Caret")]
+fn conflicts_without_precedence_are_reported() {
+    grammar! {
+        token_type: TokenType,
+        return_type: (),
+        lexer_definer_type: LexerDefiner,
+        first_symbol: E,
+        default_token_action: |_: &Token<TokenType>| {},
+
+        precedence: {
+            %left Plus;
+        }
+
+        productions: {
+            E -> E Plus E = |_| {};
+            E -> E Caret E = |_| {};
+            E -> Number = |_| {};
+        }
+
+        terminals: {
+            (Number, r"[0-9]+"),
+            (Plus, r"\+"),
+            (Caret, r"\^"),
+        }
+
+        skip: {}
+    };
+}
+
+#[test]
+#[should_panic(expected = "as well as")]
+fn conflicts_on_non_associative_errors_are_reported() {
+    grammar! {
+        token_type: TokenType,
+        return_type: (),
+        lexer_definer_type: LexerDefiner,
+        first_symbol: S,
+        default_token_action: |_: &Token<TokenType>| {},
+
+        precedence: {
+            %nonassoc Less;
+        }
+
+        productions: {
+            S -> E = |_| {};
+            S -> F Less Number = |_| {};
+            E -> E Less E = |_| {};
+            F -> E Less E = |_| {};
+            E -> Number = |_| {};
+        }
+
+        terminals: {
+            (Number, r"[0-9]+"),
+            (Less, r"<"),
+        }
+
+        skip: {}
+    };
+}
+
+#[test]
+#[should_panic(expected = "Precedence of Star is defined for an unknown terminal")]
+fn precedences_of_unknown_terminals_are_reported() {
+    grammar! {
+        token_type: TokenType,
+        return_type: (),
+        lexer_definer_type: LexerDefiner,
+        first_symbol: E,
+        default_token_action: |_: &Token<TokenType>| {},
+
+        precedence: {
+            %left Plus Star;
+        }
+
+        productions: {
+            E -> E Plus E = |_| {};
+            E -> Number = |_| {};
+        }
+
+        terminals: {
+            (Number, r"[0-9]+"),
+            (Plus, r"\+"),
+        }
+
+        skip: {}
+    };
+}
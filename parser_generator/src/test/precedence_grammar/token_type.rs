@@ -0,0 +1,14 @@
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum TokenType {
+    Number,
+    Plus,
+    Minus,
+    Times,
+    Div,
+    Caret,
+    Less,
+    Lpar,
+    Rpar,
+
+    __Whitespace__,
+}
@@ -0,0 +1,93 @@
+use crate::parser::Parser;
+use crate::test::LexerWrapper;
+use crate::test::helpers::{LexerDefiner, parse};
+use crate::{ParsingTables, PrecomputedTables};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TokenType {
+    Num,
+    Plus,
+    Times,
+    WhiteSpace,
+}
+
+fn lexer_parser(
+    tables: Option<PrecomputedTables>,
+) -> (LexerWrapper<TokenType>, Parser<TokenType, i32>) {
+    grammar! {
+        token_type: TokenType,
+        return_type: i32,
+        lexer_definer_type: LexerDefiner,
+        first_symbol: E,
+        default_token_action: |tok: &crate::Token<TokenType>| tok.slice.parse().unwrap_or(0),
+
+        productions: {
+            E -> E Plus T = |v| v[0] + v[2];
+            E -> T = |v| v[0];
+            T -> T Times Num = |v| v[0] * v[2];
+            T -> Num = |v| v[0];
+        }
+
+        terminals: {
+            (Num, r"[0-9]+"),
+            (Plus, r"\+"),
+            (Times, r"\*"),
+        }
+
+        skip: {
+            (WhiteSpace, r"\s+"),
+        }
+
+        tables: tables
+    }
+}
+
+fn serialized_tables() -> (Vec<u8>, Vec<u8>) {
+    let (lexer, parser) = lexer_parser(None);
+    (parser.tables().to_bytes(), lexer.tables())
+}
+
+#[test]
+fn serialized_tables_are_read_back() {
+    let (_, parser) = lexer_parser(None);
+    let tables = parser.tables();
+
+    assert_eq!(ParsingTables::from_bytes(&tables.to_bytes()), Ok(tables));
+    assert!(ParsingTables::from_bytes(b"not tables").is_err());
+}
+
+#[test]
+fn parser_from_tables_parses_as_the_original() {
+    let (parser_tables, lexer_tables) = serialized_tables();
+    let from_tables = || {
+        lexer_parser(Some(PrecomputedTables {
+            parser: &parser_tables,
+            lexer: &lexer_tables,
+        }))
+    };
+
+    for input in ["1 + 2 * 3", "2 * 3 * 4 + 5", "7"] {
+        assert_eq!(
+            parse(from_tables, input).unwrap(),
+            parse(|| lexer_parser(None), input).unwrap()
+        );
+    }
+    assert_eq!(parse(from_tables, "1 + 2 * 3").unwrap(), 7);
+    assert!(parse(from_tables, "1 + + 2").is_err());
+}
+
+#[test]
+#[should_panic(expected = "The parsing tables were computed for other productions")]
+fn tables_of_another_grammar_are_rejected() {
+    let mut tables = lexer_parser(None).1.tables();
+    tables
+        .productions
+        .values_mut()
+        .for_each(|p| p.rhs.reverse());
+    let lexer_tables = serialized_tables().1;
+
+    lexer_parser(Some(PrecomputedTables {
+        parser: &tables.to_bytes(),
+        lexer: &lexer_tables,
+    }));
+}
@@ -9,6 +9,10 @@ pub(crate) use production::Production;
 pub(crate) use production::ProductionCompute;
 pub use production::ProductionId;
 
+mod precedence;
+pub use precedence::Associativity;
+pub use precedence::Precedence;
+
 mod token;
 pub use token::Token;
 
@@ -0,0 +1,30 @@
+/// # Description
+///
+/// How a sequence of operators of the same precedence level groups, as
+/// declared with `%left`, `%right` or `%nonassoc`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+    /// The operators can not follow each other without parentheses
+    NonAssoc,
+}
+
+/// # Description
+///
+/// Precedence of a terminal or a production, operators of a higher level
+/// bind tighter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Precedence {
+    pub level: usize,
+    pub associativity: Associativity,
+}
+
+impl Precedence {
+    pub fn new(level: usize, associativity: Associativity) -> Self {
+        Self {
+            level,
+            associativity,
+        }
+    }
+}
@@ -9,9 +9,13 @@ impl ProductionId {
     pub fn new(id: usize) -> Self {
         Self(id)
     }
+
+    pub fn id(&self) -> usize {
+        self.0
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Production {
     pub lhs: NonTerminalId,
     pub rhs: Vec<SymbolId>,
@@ -31,7 +31,7 @@ impl From<TerminalId> for SymbolId {
 
 pub type TerminalCompute<TokenType, R> = Box<dyn for<'a> Fn(&'a Token<TokenType>) -> R>;
 
-#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TerminalId(usize);
 
 impl TerminalId {
@@ -39,6 +39,10 @@ impl TerminalId {
         Self(id)
     }
 
+    pub fn id(&self) -> usize {
+        self.0
+    }
+
     pub fn id_string(&self) -> String {
         self.0.to_string()
     }
@@ -52,6 +56,10 @@ impl NonTerminalId {
         Self(id)
     }
 
+    pub fn id(&self) -> usize {
+        self.0
+    }
+
     pub fn id_string(&self) -> String {
         self.0.to_string()
     }
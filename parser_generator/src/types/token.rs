@@ -9,6 +9,8 @@ where
     pub slice: String,
     pub start: usize,
     pub end: usize,
+    /// Tokens ignored by the parser but kept for tools, e.g. the comments right before this token
+    pub trivia: Vec<Token<TokenType>>,
 }
 
 impl<TokenType> Token<TokenType>
@@ -21,6 +23,12 @@ where
             slice,
             start,
             end,
+            trivia: Vec::new(),
         }
     }
+
+    pub fn with_trivia(mut self, trivia: Vec<Token<TokenType>>) -> Self {
+        self.trivia = trivia;
+        self
+    }
 }
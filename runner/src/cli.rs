@@ -0,0 +1,375 @@
+use std::{fmt::Display, path::PathBuf, str::FromStr};
+
+use error_handler::warning::warning::LINT_NAMES;
+use parser::visitors::format_visitor::DEFAULT_WIDTH;
+use semantic_analyzer::lint_config::{ALL_LINTS, LintLevel};
+
+pub const DEFAULT_INPUT: &str = "script.hulk";
+pub const DEFAULT_STD_LIBRARY: &str = "std.hulk";
+
+pub const USAGE: &str = "\
+Usage: runner [OPTIONS] [INPUT]...
+       runner fmt [--check] [--width <N>] [INPUT]...
+       runner repl [OPTIONS] [INPUT]...
+       runner run [OPTIONS] [INPUT]...
+
+Compiles the given HULK source files (script.hulk if none is given) into LLVM IR,
+formats each of them with `fmt`, or evaluates definitions and expressions read
+line by line with `repl`, after loading the inputs. The REPL runs the code with lli.
+`run` executes the program with the interpreter instead, without LLVM
+
+Options:
+  -o <PATH>          Write the output to PATH (defaults to the first input with
+                     the .ll extension for llvm, and to stdout for other stages)
+      --std <PATH>   Load the standard library found at PATH as a module of its
+                     own (default: std.hulk)
+      --no-std       Do not load any standard library
+      --emit <STAGE> Stop after STAGE and emit its result, one of:
+                     tokens, ast, typed-ast, llvm (default: llvm)
+      --no-runtime-checks
+                     Do not check list indexing and divisions at runtime
+      --error-format <FORMAT>
+                     How errors and warnings are written to stderr, one of:
+                     human, json, sarif (default: human)
+  -A, --allow <LINT> Do not report the warnings of LINT
+  -W, --warn <LINT>  Report the warnings of LINT (the default for every lint)
+  -D, --deny <LINT>  Report the warnings of LINT as errors. LINT is one of:
+                     type-tests, unused-variables, unreachable-code,
+                     shadowed-variables, constant-conditions, self-assignments,
+                     object-return-types, or warnings for all of them
+  -h, --help         Print this message
+
+Format options:
+      --check        Do not rewrite the inputs, fail if any of them is not formatted
+      --width <N>    Split the lines longer than N characters when possible (default: 80)
+";
+
+/// # Description
+///
+/// The pipeline stage after which the runner stops, and whose result
+/// is written to the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitStage {
+    Tokens,
+    Ast,
+    TypedAst,
+    Llvm,
+}
+
+impl FromStr for EmitStage {
+    type Err = CliError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tokens" => Ok(EmitStage::Tokens),
+            "ast" => Ok(EmitStage::Ast),
+            "typed-ast" => Ok(EmitStage::TypedAst),
+            "llvm" => Ok(EmitStage::Llvm),
+            _ => Err(CliError::InvalidEmitStage(s.to_string())),
+        }
+    }
+}
+
+impl Display for EmitStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmitStage::Tokens => write!(f, "tokens"),
+            EmitStage::Ast => write!(f, "ast"),
+            EmitStage::TypedAst => write!(f, "typed-ast"),
+            EmitStage::Llvm => write!(f, "llvm"),
+        }
+    }
+}
+
+/// # Description
+///
+/// How errors and warnings are written to stderr: formatted for humans, or
+/// as a JSON array or a SARIF log for tools
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    Human,
+    Json,
+    Sarif,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = CliError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(ErrorFormat::Human),
+            "json" => Ok(ErrorFormat::Json),
+            "sarif" => Ok(ErrorFormat::Sarif),
+            _ => Err(CliError::InvalidErrorFormat(s.to_string())),
+        }
+    }
+}
+
+impl Display for ErrorFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorFormat::Human => write!(f, "human"),
+            ErrorFormat::Json => write!(f, "json"),
+            ErrorFormat::Sarif => write!(f, "sarif"),
+        }
+    }
+}
+
+/// # Description
+///
+/// What the runner does with its inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Compile,
+    /// Rewrites each input formatted, or only checks that it is with `check`
+    Format {
+        check: bool,
+        width: usize,
+    },
+    /// Reads definitions and expressions from stdin and evaluates them
+    Repl,
+    /// Executes the program with the interpreter
+    Run,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    MissingValue(String),
+    UnknownOption(String),
+    InvalidEmitStage(String),
+    InvalidErrorFormat(String),
+    UnknownLint(String),
+    InvalidWidth(String),
+    ConflictingOptions(String, String),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::MissingValue(option) => write!(f, "Option `{}` requires a value", option),
+            CliError::UnknownOption(option) => write!(f, "Unknown option `{}`", option),
+            CliError::InvalidEmitStage(stage) => write!(
+                f,
+                "Invalid emit stage `{}`, expected one of: tokens, ast, typed-ast, llvm",
+                stage
+            ),
+            CliError::InvalidErrorFormat(format) => write!(
+                f,
+                "Invalid error format `{}`, expected one of: human, json, sarif",
+                format
+            ),
+            CliError::UnknownLint(lint) => write!(
+                f,
+                "Unknown lint `{}`, expected one of: {}, {}",
+                lint,
+                LINT_NAMES.join(", "),
+                ALL_LINTS
+            ),
+            CliError::InvalidWidth(width) => write!(
+                f,
+                "Invalid line width `{}`, expected a positive number",
+                width
+            ),
+            CliError::ConflictingOptions(a, b) => {
+                write!(f, "Options `{}` and `{}` cannot be used together", a, b)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// # Description
+///
+/// Options the runner was invoked with, see `USAGE` for their meaning
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliOptions {
+    pub command: Command,
+    pub inputs: Vec<PathBuf>,
+    pub output: Option<PathBuf>,
+    /// `None` when `--no-std` is given
+    pub std_library: Option<PathBuf>,
+    pub emit: EmitStage,
+    /// `false` when `--no-runtime-checks` is given
+    pub runtime_checks: bool,
+    pub error_format: ErrorFormat,
+    /// Levels given to lints with `--allow`, `--warn` and `--deny`, in order
+    pub lint_levels: Vec<(String, LintLevel)>,
+    pub help: bool,
+}
+
+impl Default for CliOptions {
+    fn default() -> Self {
+        Self {
+            command: Command::Compile,
+            inputs: vec![PathBuf::from(DEFAULT_INPUT)],
+            output: None,
+            std_library: Some(PathBuf::from(DEFAULT_STD_LIBRARY)),
+            emit: EmitStage::Llvm,
+            runtime_checks: true,
+            error_format: ErrorFormat::Human,
+            lint_levels: Vec::new(),
+            help: false,
+        }
+    }
+}
+
+impl CliOptions {
+    /// # Description
+    ///
+    /// Parses the command line arguments, excluding the program name
+    ///
+    /// # Examples
+    ///
+    /// `runner a.hulk b.hulk -o out.ll --no-std` compiles `a.hulk` and `b.hulk`,
+    /// each a module with its own global names, into `out.ll` without the
+    /// standard library, and
+    /// `runner fmt --check a.hulk` fails if `a.hulk` is not formatted
+    pub fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut options = CliOptions::default();
+        let mut inputs = Vec::new();
+        let mut std_given = false;
+        let mut no_std_given = false;
+
+        let mut args = args.into_iter().map(Into::into).peekable();
+        if args.next_if(|arg| arg == "fmt").is_some() {
+            options.command = Command::Format {
+                check: false,
+                width: DEFAULT_WIDTH,
+            };
+        } else if args.next_if(|arg| arg == "repl").is_some() {
+            options.command = Command::Repl;
+            // the REPL only loads the inputs it is given
+            options.inputs = Vec::new();
+        } else if args.next_if(|arg| arg == "run").is_some() {
+            options.command = Command::Run;
+        }
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => options.help = true,
+                "-o" => options.output = Some(PathBuf::from(Self::value_of(&arg, args.next())?)),
+                "--std" => {
+                    std_given = true;
+                    options.std_library = Some(PathBuf::from(Self::value_of(&arg, args.next())?));
+                }
+                "--no-std" => {
+                    no_std_given = true;
+                    options.std_library = None;
+                }
+                "--emit" => options.emit = Self::value_of(&arg, args.next())?.parse()?,
+                "--no-runtime-checks" => options.runtime_checks = false,
+                "--error-format" => {
+                    options.error_format = Self::value_of(&arg, args.next())?.parse()?
+                }
+                "-A" | "--allow" | "-W" | "--warn" | "-D" | "--deny" => {
+                    let lint = Self::value_of(&arg, args.next())?;
+                    options.set_lint_level(&arg, lint)?;
+                }
+                "--check" if options.is_format() => options.set_format_check(),
+                "--width" if options.is_format() => {
+                    let width = Self::value_of(&arg, args.next())?;
+                    options.set_format_width(width)?;
+                }
+                "--" => {
+                    inputs.extend(args.by_ref().map(PathBuf::from));
+                }
+                _ => {
+                    if let (Some(value), true) = (arg.strip_prefix("--width="), options.is_format())
+                    {
+                        options.set_format_width(value.to_string())?;
+                    } else if let Some(value) = arg.strip_prefix("--emit=") {
+                        options.emit = value.parse()?;
+                    } else if let Some(value) = arg.strip_prefix("--error-format=") {
+                        options.error_format = value.parse()?;
+                    } else if let Some((option @ ("--allow" | "--warn" | "--deny"), lint)) =
+                        arg.split_once('=')
+                    {
+                        options.set_lint_level(option, lint.to_string())?;
+                    } else if let Some(value) = arg.strip_prefix("--std=") {
+                        std_given = true;
+                        options.std_library = Some(PathBuf::from(value));
+                    } else if arg.starts_with('-') && arg.len() > 1 {
+                        return Err(CliError::UnknownOption(arg));
+                    } else {
+                        inputs.push(PathBuf::from(arg));
+                    }
+                }
+            }
+        }
+
+        if std_given && no_std_given {
+            return Err(CliError::ConflictingOptions(
+                "--std".to_string(),
+                "--no-std".to_string(),
+            ));
+        }
+
+        if !inputs.is_empty() {
+            options.inputs = inputs;
+        }
+
+        Ok(options)
+    }
+
+    fn is_format(&self) -> bool {
+        matches!(self.command, Command::Format { .. })
+    }
+
+    fn set_format_check(&mut self) {
+        if let Command::Format { check, .. } = &mut self.command {
+            *check = true;
+        }
+    }
+
+    fn set_format_width(&mut self, value: String) -> Result<(), CliError> {
+        let parsed = value
+            .parse::<usize>()
+            .ok()
+            .filter(|width| *width > 0)
+            .ok_or(CliError::InvalidWidth(value))?;
+        if let Command::Format { width, .. } = &mut self.command {
+            *width = parsed;
+        }
+        Ok(())
+    }
+
+    fn set_lint_level(&mut self, option: &str, lint: String) -> Result<(), CliError> {
+        if lint != ALL_LINTS && !LINT_NAMES.contains(&lint.as_str()) {
+            return Err(CliError::UnknownLint(lint));
+        }
+        let level = match option {
+            "-A" | "--allow" => LintLevel::Allow,
+            "-W" | "--warn" => LintLevel::Warn,
+            _ => LintLevel::Deny,
+        };
+        self.lint_levels.push((lint, level));
+        Ok(())
+    }
+
+    fn value_of(option: &str, value: Option<String>) -> Result<String, CliError> {
+        value.ok_or_else(|| CliError::MissingValue(option.to_string()))
+    }
+
+    /// # Description
+    ///
+    /// The file the result of the selected stage is written to, `None` means stdout.
+    /// LLVM IR is always written to a file, by default next to the first input
+    pub fn output_path(&self) -> Option<PathBuf> {
+        match (&self.output, self.emit) {
+            (Some(output), _) => Some(output.clone()),
+            (None, EmitStage::Llvm) => {
+                let first = self
+                    .inputs
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT));
+                Some(first.with_extension("ll"))
+            }
+            (None, _) => None,
+        }
+    }
+}
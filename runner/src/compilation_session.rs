@@ -0,0 +1,293 @@
+use ast::Program;
+use error_handler::{
+    diagnostic::diagnostic::Diagnostic, error::error::HulkError, error_handler::ErrorHandler,
+    source_map::SourceMap,
+};
+use generated_parser::TokenType;
+use generator::CodeGenerator;
+use parser::visitors::format_visitor::FormatVisitor;
+use parser_generator::Token;
+use semantic_analyzer::{
+    lint_config::LintLevel, module_scope::ModuleScope, semantic_analyzer::SemanticAnalyzer,
+};
+
+use crate::{compiler::Compiler, module_loader::ModuleLoader};
+
+/// # Description
+///
+/// The stages of the compilation pipeline, in the order they are run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Lexing,
+    Parsing,
+    Analysis,
+    Generation,
+}
+
+/// # Description
+///
+/// A single compilation of a program. It owns everything the pipeline needs
+/// besides the parser (which is shared through the `Compiler`): the sources,
+/// the error handler, the semantic analyzer and the code generator settings.
+///
+/// Each file of the sources is a module of the program, parsing loads the
+/// files they import (see `ModuleLoader`) and links all the modules into a
+/// single AST.
+///
+/// Each stage can be requested on its own, the stages it depends on are run
+/// first if they were not already, and their results are kept, so asking
+/// for the LLVM code after inspecting the typed AST does not redo any work.
+///
+/// Every error found is collected in the session's `ErrorHandler`, the
+/// errors of a failed stage are also returned by the method that ran it.
+/// Once a stage fails, the stages after it are not run.
+pub struct CompilationSession<'a> {
+    compiler: &'a Compiler,
+    source_map: SourceMap,
+    error_handler: ErrorHandler,
+    semantic_analyzer: SemanticAnalyzer,
+    runtime_checks: bool,
+
+    program: Option<Program>,
+    analyzed: bool,
+    llvm: Option<String>,
+    failed_stage: Option<Stage>,
+}
+
+impl<'a> CompilationSession<'a> {
+    pub fn new(compiler: &'a Compiler, source_map: SourceMap) -> Self {
+        let error_handler = ErrorHandler::from_source_map(source_map.clone());
+        let semantic_analyzer = SemanticAnalyzer::new().with_source_map(source_map.clone());
+
+        Self {
+            compiler,
+            source_map,
+            error_handler,
+            semantic_analyzer,
+            runtime_checks: true,
+            program: None,
+            analyzed: false,
+            llvm: None,
+            failed_stage: None,
+        }
+    }
+
+    /// # Description
+    ///
+    /// Whether the generated code checks list indexing and divisions at
+    /// runtime, enabled by default
+    pub fn with_runtime_checks(mut self, enabled: bool) -> Self {
+        self.runtime_checks = enabled;
+        self
+    }
+
+    /// # Description
+    ///
+    /// Sets the level of the given lints, in order, see `LintConfig::set`.
+    /// Unknown lints are ignored
+    pub fn with_lint_levels(mut self, levels: &[(String, LintLevel)]) -> Self {
+        for (lint, level) in levels {
+            self.semantic_analyzer.lint_config.set(lint, *level);
+        }
+        self
+    }
+
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
+    pub fn semantic_analyzer(&self) -> &SemanticAnalyzer {
+        &self.semantic_analyzer
+    }
+
+    /// # Description
+    ///
+    /// The stage that failed, if any
+    pub fn failed_stage(&self) -> Option<Stage> {
+        self.failed_stage
+    }
+
+    /// # Description
+    ///
+    /// Every error found so far, in all stages
+    pub fn diagnostics(&self) -> &[HulkError] {
+        &self.error_handler.errors
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_handler.has_errors()
+    }
+
+    /// # Description
+    ///
+    /// Every error found so far, formatted with the source code they point to
+    pub fn diagnostic_messages(&mut self) -> Vec<String> {
+        self.error_handler.get_error_messages()
+    }
+
+    /// # Description
+    ///
+    /// Every error and warning found so far, in the structured form written
+    /// by the JSON and SARIF emitters
+    pub fn structured_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.error_handler.get_diagnostics()
+    }
+
+    /// # Description
+    ///
+    /// Every warning found so far, formatted with the source code they point to
+    pub fn warning_messages(&mut self) -> Vec<String> {
+        self.error_handler.get_warning_messages()
+    }
+
+    /// # Description
+    ///
+    /// Runs the lexer on the whole program. Tokens are not kept by the
+    /// session, since the parser does its own lexing
+    pub fn tokens(&mut self) -> Result<Vec<Token<TokenType>>, Vec<HulkError>> {
+        let tokens = self.compiler.parser().tokenize(self.source_map.content());
+        tokens.map_err(|errors| self.fail(Stage::Lexing, errors))
+    }
+
+    /// # Description
+    ///
+    /// Parses the program, returning its untyped AST
+    pub fn parse(&mut self) -> Result<&Program, Vec<HulkError>> {
+        self.ensure_parsed()?;
+        Ok(self.program.as_ref().unwrap())
+    }
+
+    /// # Description
+    ///
+    /// Parses and analyzes the program, returning the AST with its type annotations
+    pub fn analyze(&mut self) -> Result<&Program, Vec<HulkError>> {
+        self.ensure_analyzed()?;
+        Ok(self.program.as_ref().unwrap())
+    }
+
+    /// # Description
+    ///
+    /// Runs the whole pipeline, returning the generated LLVM IR
+    pub fn generate_llvm(&mut self) -> Result<&str, Vec<HulkError>> {
+        self.ensure_generated()?;
+        Ok(self.llvm.as_deref().unwrap())
+    }
+
+    /// # Description
+    ///
+    /// Parses the program and writes it back formatted, splitting the lines
+    /// longer than `width` when possible. The comments are kept, so the
+    /// session is meant to hold a single file without standard library, whose
+    /// imports are not loaded
+    pub fn format(&mut self, width: usize) -> Result<String, Vec<HulkError>> {
+        self.check_not_failed(Stage::Parsing)?;
+        let mut program = match self.compiler.parser().parse(self.source_map.content()) {
+            Ok(program) => program,
+            Err(errors) => return Err(self.fail(parsing_stage(&errors), errors)),
+        };
+        let comments = match self.compiler.parser().comments(self.source_map.content()) {
+            Ok(comments) => comments,
+            Err(errors) => return Err(self.fail(Stage::Lexing, errors)),
+        };
+
+        Ok(FormatVisitor::new(self.source_map.content(), comments)
+            .with_width(width)
+            .format_program(&mut program))
+    }
+
+    /// # Description
+    ///
+    /// Takes the AST out of the session, the pipeline can not continue after this
+    pub fn into_program(mut self) -> Option<Program> {
+        self.program.take()
+    }
+
+    fn ensure_parsed(&mut self) -> Result<(), Vec<HulkError>> {
+        if self.program.is_some() {
+            return Ok(());
+        }
+        self.check_not_failed(Stage::Parsing)?;
+
+        let modules = ModuleLoader::new(self.compiler.parser(), &mut self.source_map).load();
+        // the imported files were added to the sources
+        let previous = std::mem::replace(
+            &mut self.error_handler,
+            ErrorHandler::from_source_map(self.source_map.clone()),
+        );
+        self.error_handler.extend_errors(previous.errors);
+        self.error_handler.extend_warnings(previous.warnings);
+
+        match modules {
+            Ok(modules) => {
+                let program = Program::link(modules);
+                self.semantic_analyzer.source_map = self.source_map.clone();
+                self.semantic_analyzer.modules =
+                    ModuleScope::from_imports(self.source_map.clone(), &program.imports);
+                self.program = Some(program);
+                Ok(())
+            }
+            Err(errors) => Err(self.fail(parsing_stage(&errors), errors)),
+        }
+    }
+
+    fn ensure_analyzed(&mut self) -> Result<(), Vec<HulkError>> {
+        self.ensure_parsed()?;
+        if self.analyzed {
+            return Ok(());
+        }
+        self.check_not_failed(Stage::Analysis)?;
+
+        let program = self.program.as_mut().unwrap();
+        let result = self.semantic_analyzer.analyze_program_ast(program);
+        let warnings = std::mem::take(&mut self.semantic_analyzer.warnings);
+        self.error_handler.extend_warnings(warnings);
+        match result {
+            Ok(()) => {
+                self.analyzed = true;
+                Ok(())
+            }
+            Err(errors) => Err(self.fail(Stage::Analysis, errors)),
+        }
+    }
+
+    fn ensure_generated(&mut self) -> Result<(), Vec<HulkError>> {
+        self.ensure_analyzed()?;
+        if self.llvm.is_some() {
+            return Ok(());
+        }
+
+        let code_generator = CodeGenerator::new()
+            .with_runtime_checks(self.runtime_checks)
+            .with_source_map(self.source_map.clone());
+        let program = self.program.as_mut().unwrap();
+        self.llvm = Some(code_generator.generate_code_from_program_ast(program));
+        Ok(())
+    }
+
+    fn check_not_failed(&self, stage: Stage) -> Result<(), Vec<HulkError>> {
+        match self.failed_stage {
+            Some(failed) if failed <= stage => Err(self.diagnostics().to_vec()),
+            _ => Ok(()),
+        }
+    }
+
+    fn fail(&mut self, stage: Stage, errors: Vec<HulkError>) -> Vec<HulkError> {
+        if self.failed_stage.is_none() {
+            self.failed_stage = Some(stage);
+            self.error_handler.extend_errors(errors.clone());
+        }
+        errors
+    }
+}
+
+/// The stage that failed with the errors found parsing
+fn parsing_stage(errors: &[HulkError]) -> Stage {
+    if errors
+        .iter()
+        .any(|error| matches!(error, HulkError::LexicalError(_)))
+    {
+        Stage::Lexing
+    } else {
+        Stage::Parsing
+    }
+}
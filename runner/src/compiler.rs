@@ -0,0 +1,61 @@
+use error_handler::source_map::SourceMap;
+use generated_parser::ProgramParser;
+
+use crate::compilation_session::CompilationSession;
+
+/// # Description
+///
+/// Entry point of the compilation pipeline when HULK is used as a library.
+///
+/// Building the parser tables is expensive, so a `Compiler` is meant to be
+/// created once and reused to compile as many programs as needed, each one
+/// in its own `CompilationSession`
+///
+/// # Examples
+///
+/// ```
+/// use runner::Compiler;
+///
+/// let compiler = Compiler::new();
+/// let mut session = compiler.session_from_str("main.hulk", "print(42);");
+///
+/// let llvm = session.generate_llvm().unwrap();
+/// assert!(llvm.contains("define i32 @main()"));
+/// ```
+pub struct Compiler {
+    parser: ProgramParser,
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Self {
+            parser: ProgramParser::new(),
+        }
+    }
+
+    pub fn parser(&self) -> &ProgramParser {
+        &self.parser
+    }
+
+    /// # Description
+    ///
+    /// Starts a new compilation of the program made up of the files in `source_map`
+    pub fn session(&self, source_map: SourceMap) -> CompilationSession<'_> {
+        CompilationSession::new(self, source_map)
+    }
+
+    /// # Description
+    ///
+    /// Starts a new compilation of a single source file, without standard library
+    pub fn session_from_str(&self, name: &str, text: &str) -> CompilationSession<'_> {
+        let mut source_map = SourceMap::new();
+        source_map.add_file(name, text);
+        self.session(source_map)
+    }
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
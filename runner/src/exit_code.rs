@@ -0,0 +1,41 @@
+use error_handler::error::error::HulkError;
+
+pub const SUCCESS: i32 = 0;
+/// Input or output files could not be read or written
+pub const IO_FAILURE: i32 = 1;
+/// The command line arguments are invalid
+pub const USAGE_FAILURE: i32 = 2;
+pub const LEXICAL_FAILURE: i32 = 3;
+pub const SYNTACTIC_FAILURE: i32 = 4;
+pub const SEMANTIC_FAILURE: i32 = 5;
+/// `fmt --check` found inputs that are not formatted
+pub const FORMAT_FAILURE: i32 = 6;
+/// The program executed by `run` stopped with a runtime error
+pub const RUNTIME_FAILURE: i32 = 7;
+
+/// # Description
+///
+/// Returns the exit code corresponding to a `HulkError` variant
+pub fn exit_code_for_error(error: &HulkError) -> i32 {
+    match error {
+        HulkError::LexicalError(_) => LEXICAL_FAILURE,
+        HulkError::SyntacticError(_) => SYNTACTIC_FAILURE,
+        HulkError::SemanticError(_) | HulkError::DeniedWarning(_) => SEMANTIC_FAILURE,
+    }
+}
+
+/// # Description
+///
+/// Returns the exit code for a list of errors, the earliest pipeline
+/// stage that failed determines the code (lexical < syntactic < semantic)
+///
+/// # Returns
+///
+/// `SUCCESS` if `errors` is empty
+pub fn exit_code_for_errors(errors: &[HulkError]) -> i32 {
+    errors
+        .iter()
+        .map(exit_code_for_error)
+        .min()
+        .unwrap_or(SUCCESS)
+}
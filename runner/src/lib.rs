@@ -1,2 +1,22 @@
+pub mod cli;
+pub use cli::{CliOptions, Command, EmitStage, ErrorFormat};
+
+pub mod exit_code;
+
+pub mod compiler;
+pub use compiler::Compiler;
+
+pub mod module_loader;
+pub use module_loader::ModuleLoader;
+
+pub mod compilation_session;
+pub use compilation_session::{CompilationSession, Stage};
+
+pub mod repl;
+pub use repl::Repl;
+
 pub mod runner;
-pub use runner::run;
+pub use runner::{RunnerError, run};
+
+#[cfg(test)]
+mod test;
@@ -1,8 +1,26 @@
-use runner;
+use runner::{CliOptions, ErrorFormat, RunnerError, cli::USAGE, exit_code};
 
 fn main() {
-    runner::run().unwrap_or_else(|err| {
-        eprintln!("Error: {}", err);
-        std::process::exit(1);
+    let options = CliOptions::parse(std::env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("Error: {}\n\n{}", err, USAGE);
+        std::process::exit(RunnerError::from(err).exit_code());
+    });
+
+    if options.help {
+        print!("{}", USAGE);
+        std::process::exit(exit_code::SUCCESS);
+    }
+
+    runner::run(&options).unwrap_or_else(|err| {
+        // machine readable formats already describe the compilation errors
+        let described = matches!(err, RunnerError::Compilation(_))
+            && options.error_format != ErrorFormat::Human;
+        match err {
+            // reported like the runtime errors of the generated code
+            RunnerError::Runtime(_) => eprintln!("{}", err),
+            _ if !described => eprintln!("Error: {}", err),
+            _ => {}
+        }
+        std::process::exit(err.exit_code());
     });
 }
@@ -0,0 +1,195 @@
+use std::path::{Component, Path, PathBuf};
+
+use ast::{ImportTarget, Program};
+use error_handler::{
+    error::{error::HulkError, semantic::modules::ModuleNotFound},
+    source_map::SourceMap,
+};
+use generated_parser::ProgramParser;
+
+/// Extension of HULK source files, added to the names of imported modules
+pub const EXTENSION: &str = "hulk";
+
+/// # Description
+///
+/// Loads the modules of a program. Each file of the source map is parsed on
+/// its own into an `ast::Program`, whose `file_id` is its id in the source map,
+/// and the files it imports are loaded too, being added to the source map.
+///
+/// `import "path"` names a file by its path relative to the importing file,
+/// and `import name` names the file `name.hulk` next to the importing file or,
+/// when there is none, next to the library files (i.e. the standard library).
+/// Files already in the source map are found by their name, so programs in
+/// memory can import each other without reading the file system.
+///
+/// Modules can import each other in cycles, since definitions can be used
+/// before they are defined anyway.
+pub struct ModuleLoader<'a> {
+    parser: &'a ProgramParser,
+    source_map: &'a mut SourceMap,
+    errors: Vec<HulkError>,
+}
+
+impl<'a> ModuleLoader<'a> {
+    pub fn new(parser: &'a ProgramParser, source_map: &'a mut SourceMap) -> Self {
+        Self {
+            parser,
+            source_map,
+            errors: Vec::new(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Loads every file of the source map, and the files they import, with
+    /// their imports resolved. The modules are returned after the modules they
+    /// import, and otherwise in the order of the source map
+    pub fn load(mut self) -> Result<Vec<Program>, Vec<HulkError>> {
+        let mut modules = Vec::new();
+        // imported files are added to the source map while it is walked
+        let mut file_id = 0;
+        while file_id < self.source_map.files().len() {
+            let file = &self.source_map.files()[file_id];
+            let text = self.source_map.file_text(file_id);
+            match self.parser.parse_at(text, file.start) {
+                Ok(program) => {
+                    let mut program = program.with_file_id(file_id);
+                    self.resolve_imports(&mut program);
+                    modules.push(program);
+                }
+                Err(errors) => self.errors.extend(errors),
+            }
+            file_id += 1;
+        }
+
+        if !self.errors.is_empty() {
+            return Err(self.errors);
+        }
+        Ok(dependency_order(modules))
+    }
+
+    fn resolve_imports(&mut self, program: &mut Program) {
+        let importer = PathBuf::from(&self.source_map.files()[program.file_id].name);
+        let directory = importer.parent().unwrap_or(Path::new("")).to_path_buf();
+
+        for import in &mut program.imports {
+            let (name, candidates) = match &import.target {
+                ImportTarget::Path(path) => {
+                    (path.string.clone(), vec![directory.join(&path.string)])
+                }
+                ImportTarget::Module(module) => {
+                    let file_name = format!("{}.{}", module.id, EXTENSION);
+                    let candidates = std::iter::once(directory.join(&file_name))
+                        .chain(self.library_directories().map(|dir| dir.join(&file_name)))
+                        .collect();
+                    (module.id.clone(), candidates)
+                }
+            };
+
+            import.file_id = candidates
+                .iter()
+                .find_map(|candidate| self.find_or_read(&normalize(candidate)));
+            if import.file_id.is_none() {
+                self.errors
+                    .push(ModuleNotFound::new(name, import.target_position()).into());
+            }
+        }
+    }
+
+    fn library_directories(&self) -> impl Iterator<Item = PathBuf> + '_ {
+        self.source_map
+            .files()
+            .iter()
+            .filter(|file| file.is_library)
+            .map(|file| {
+                Path::new(&file.name)
+                    .parent()
+                    .unwrap_or(Path::new(""))
+                    .to_path_buf()
+            })
+    }
+
+    /// # Description
+    ///
+    /// Id of the file at `path`, which is added to the source map if it is not
+    /// already there
+    fn find_or_read(&mut self, path: &Path) -> Option<usize> {
+        let loaded = self
+            .source_map
+            .files()
+            .iter()
+            .position(|file| normalize(Path::new(&file.name)) == path);
+        if loaded.is_some() {
+            return loaded;
+        }
+
+        let text = std::fs::read_to_string(path).ok()?;
+        Some(self.source_map.add_file(&path.to_string_lossy(), &text))
+    }
+}
+
+/// # Description
+///
+/// Sorts the modules so that every module comes after the modules it imports,
+/// keeping their order otherwise. Modules in an import cycle are left in the
+/// order they are first reached
+fn dependency_order(modules: Vec<Program>) -> Vec<Program> {
+    let mut by_file_id: Vec<Option<Program>> = Vec::new();
+    for module in modules {
+        let file_id = module.file_id;
+        if by_file_id.len() <= file_id {
+            by_file_id.resize_with(file_id + 1, || None);
+        }
+        by_file_id[file_id] = Some(module);
+    }
+
+    let imports: Vec<Vec<usize>> = by_file_id
+        .iter()
+        .map(|module| {
+            module
+                .iter()
+                .flat_map(|module| module.imports.iter().filter_map(|import| import.file_id))
+                .collect()
+        })
+        .collect();
+
+    let mut order = Vec::new();
+    let mut visited = vec![false; by_file_id.len()];
+    for file_id in 0..by_file_id.len() {
+        visit(file_id, &imports, &mut visited, &mut order);
+    }
+
+    order
+        .into_iter()
+        .filter_map(|file_id| by_file_id[file_id].take())
+        .collect()
+}
+
+fn visit(file_id: usize, imports: &[Vec<usize>], visited: &mut [bool], order: &mut Vec<usize>) {
+    if visited[file_id] {
+        return;
+    }
+    visited[file_id] = true;
+    for &imported in &imports[file_id] {
+        visit(imported, imports, visited, order);
+    }
+    order.push(file_id);
+}
+
+/// # Description
+///
+/// Removes the `.` components of `path`, and the `..` components along with
+/// the component before them, without looking at the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
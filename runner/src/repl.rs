@@ -0,0 +1,398 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead, IsTerminal, Write},
+    process::{Command as Process, Stdio},
+};
+
+use ast::{
+    Definition, Expression, FunctionCall, Identifier, Program,
+    typing::{BuiltInType, Type, TypeAnnotation, to_string},
+};
+use error_handler::{
+    error::{error::HulkError, sintactic::syntactic_error::SyntacticError},
+    error_handler::ErrorHandler,
+    source_map::SourceMap,
+};
+use generator::CodeGenerator;
+use semantic_analyzer::{lint_config::LintLevel, semantic_analyzer::SemanticAnalyzer};
+
+use crate::{
+    cli::CliOptions,
+    compiler::Compiler,
+    runner::{RunnerError, read_input},
+};
+
+pub const PROMPT: &str = "hulk> ";
+pub const CONTINUATION_PROMPT: &str = "...   ";
+
+/// The LLVM interpreter the evaluated expressions are run with
+pub const LLI: &str = "lli";
+
+#[derive(Debug)]
+pub enum ReplError {
+    /// Errors found in the input, and the same errors formatted with the
+    /// code they point to
+    Compilation {
+        errors: Vec<HulkError>,
+        messages: Vec<String>,
+    },
+    /// The program exited with a failure, e.g. a failed runtime check, with
+    /// what it wrote to stderr
+    Runtime(String),
+    /// The LLVM interpreter could not be run
+    Io(io::Error),
+}
+
+impl Display for ReplError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplError::Compilation { messages, .. } => write!(f, "{}", messages.join("\n")),
+            ReplError::Runtime(stderr) => write!(f, "{}", stderr),
+            ReplError::Io(err) => write!(f, "Could not run `{}`: {}", LLI, err),
+        }
+    }
+}
+
+impl std::error::Error for ReplError {}
+
+/// # Description
+///
+/// The result of evaluating a top level expression: what the program wrote
+/// to stdout, which ends with the value when it can be printed, and the type
+/// inferred for the expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation {
+    pub output: String,
+    pub ty: TypeAnnotation,
+}
+
+impl Display for Evaluation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.output.is_empty() {
+            write!(f, ": {}", to_string(&self.ty))
+        } else {
+            write!(f, "{} : {}", self.output, to_string(&self.ty))
+        }
+    }
+}
+
+/// # Description
+///
+/// Read-eval-print loop for HULK. Each input is parsed and analyzed on its
+/// own, the semantic analyzer is kept between inputs so the types, functions
+/// and constants defined by an input can be used by the ones after it.
+///
+/// Every accepted input is kept in a source map as a library file, and the
+/// next input is parsed as if it followed them, so positions never overlap
+/// and errors point to the input they were found in. An input that fails
+/// the analysis is dropped, and the analyzer goes back to its state before
+/// that input.
+///
+/// Expressions are compiled together with every definition accepted so far
+/// and run with `lli`, one program per expression
+pub struct Repl<'a> {
+    compiler: &'a Compiler,
+    source_map: SourceMap,
+    semantic_analyzer: SemanticAnalyzer,
+    definitions: Vec<Definition>,
+    runtime_checks: bool,
+    /// Lines of an input that is not complete yet
+    pending: String,
+    inputs: usize,
+    warnings: Vec<String>,
+}
+
+impl<'a> Repl<'a> {
+    pub fn new(compiler: &'a Compiler) -> Self {
+        Self {
+            compiler,
+            source_map: SourceMap::new(),
+            semantic_analyzer: SemanticAnalyzer::new(),
+            definitions: Vec::new(),
+            runtime_checks: true,
+            pending: String::new(),
+            inputs: 0,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Whether the evaluated code checks list indexing and divisions at
+    /// runtime, enabled by default
+    pub fn with_runtime_checks(mut self, enabled: bool) -> Self {
+        self.runtime_checks = enabled;
+        self
+    }
+
+    /// # Description
+    ///
+    /// Sets the level of the given lints, in order, see `LintConfig::set`.
+    /// Unknown lints are ignored
+    pub fn with_lint_levels(mut self, levels: &[(String, LintLevel)]) -> Self {
+        for (lint, level) in levels {
+            self.semantic_analyzer.lint_config.set(lint, *level);
+        }
+        self
+    }
+
+    /// # Description
+    ///
+    /// Whether the lines fed so far are an incomplete input, waiting for more
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    pub fn prompt(&self) -> &'static str {
+        if self.is_pending() {
+            CONTINUATION_PROMPT
+        } else {
+            PROMPT
+        }
+    }
+
+    /// # Description
+    ///
+    /// The warnings found since the last call, formatted with the code they
+    /// point to
+    pub fn take_warnings(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// # Description
+    ///
+    /// Adds a line to the current input. Returns `None` while the input is
+    /// incomplete (i.e. it can only be parsed if more code follows), and the
+    /// result of evaluating it otherwise. The `;` after the last expression
+    /// of an input may be left out
+    pub fn feed_line(&mut self, line: &str) -> Option<Result<Vec<Evaluation>, ReplError>> {
+        self.pending += line;
+        self.pending.push('\n');
+        if self.pending.trim().is_empty() {
+            self.pending.clear();
+            return Some(Ok(Vec::new()));
+        }
+
+        let name = format!("repl:{}", self.inputs + 1);
+        let text = match self.parse(&name, &self.pending) {
+            Err(errors) if is_incomplete(&errors) => {
+                let terminated = format!("{};", self.pending.trim_end());
+                if self.parse(&name, &terminated).is_err() {
+                    return None;
+                }
+                self.pending.clear();
+                terminated
+            }
+            _ => std::mem::take(&mut self.pending),
+        };
+
+        Some(self.eval(&name, &text))
+    }
+
+    /// # Description
+    ///
+    /// Evaluates a whole input, e.g. a file. `name` is the name errors in it
+    /// are reported with
+    pub fn eval(&mut self, name: &str, text: &str) -> Result<Vec<Evaluation>, ReplError> {
+        match self.parse(name, text) {
+            Ok(program) => self.evaluate(name, text, program),
+            Err(errors) => Err(self.compilation_error(name, text, errors)),
+        }
+    }
+
+    /// Parses `text` as if it followed every input accepted so far
+    fn parse(&self, name: &str, text: &str) -> Result<Program, Vec<HulkError>> {
+        let start = self.next_source_map(name, text).user_code_offset();
+        self.compiler.parser().parse_at(text, start)
+    }
+
+    fn evaluate(
+        &mut self,
+        name: &str,
+        text: &str,
+        mut program: Program,
+    ) -> Result<Vec<Evaluation>, ReplError> {
+        let source_map = self.next_source_map(name, text);
+
+        let snapshot = self.semantic_analyzer.clone();
+        self.semantic_analyzer.errors.clear();
+        self.semantic_analyzer.source_map = source_map.clone();
+        let result = self.semantic_analyzer.analyze_program_ast(&mut program);
+
+        let mut error_handler = ErrorHandler::from_source_map(source_map.clone());
+        error_handler.extend_warnings(std::mem::take(&mut self.semantic_analyzer.warnings));
+        self.warnings.extend(error_handler.get_warning_messages());
+        if let Err(errors) = result {
+            self.semantic_analyzer = snapshot;
+            return Err(self.compilation_error(name, text, errors));
+        }
+
+        self.source_map.add_library(name, text);
+        self.inputs += 1;
+        self.definitions.extend(program.definitions);
+
+        let types = std::mem::take(&mut self.semantic_analyzer.expression_types);
+        let mut evaluations = Vec::new();
+        for (expression, ty) in program.expressions.into_iter().zip(types) {
+            let output = self.run(&source_map, printed(expression, &ty))?;
+            evaluations.push(Evaluation { output, ty });
+        }
+
+        Ok(evaluations)
+    }
+
+    /// # Description
+    ///
+    /// Compiles `expression` with every definition accepted so far and runs
+    /// it, returning what it wrote to stdout without the last line break
+    fn run(&mut self, source_map: &SourceMap, expression: Expression) -> Result<String, ReplError> {
+        let mut program = Program::new(std::mem::take(&mut self.definitions), vec![expression]);
+        let llvm = CodeGenerator::new()
+            .with_runtime_checks(self.runtime_checks)
+            .with_source_map(source_map.clone())
+            .generate_code_from_program_ast(&mut program);
+        self.definitions = program.definitions;
+
+        let mut lli = Process::new(LLI)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(ReplError::Io)?;
+        lli.stdin
+            .take()
+            .unwrap()
+            .write_all(llvm.as_bytes())
+            .map_err(ReplError::Io)?;
+        let output = lli.wait_with_output().map_err(ReplError::Io)?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ReplError::Runtime(stderr.trim_end().to_string()));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout.strip_suffix('\n').unwrap_or(&stdout).to_string())
+    }
+
+    /// The source map of the inputs accepted so far followed by `text`
+    fn next_source_map(&self, name: &str, text: &str) -> SourceMap {
+        let mut source_map = self.source_map.clone();
+        source_map.add_file(name, text);
+        source_map
+    }
+
+    fn compilation_error(&self, name: &str, text: &str, errors: Vec<HulkError>) -> ReplError {
+        let source_map = self.next_source_map(name, text);
+        let mut error_handler = ErrorHandler::from_source_map(source_map);
+        error_handler.extend_errors(errors.clone());
+        ReplError::Compilation {
+            errors,
+            messages: error_handler.get_error_messages(),
+        }
+    }
+}
+
+/// # Description
+///
+/// Whether the input can only be parsed if more code follows it, i.e. every
+/// error found is an unexpected end of file
+fn is_incomplete(errors: &[HulkError]) -> bool {
+    !errors.is_empty()
+        && errors.iter().all(|error| {
+            matches!(
+                error,
+                HulkError::SyntacticError(SyntacticError::UnrecognizedEof(_))
+            )
+        })
+}
+
+/// # Description
+///
+/// Wraps `expression` in a call to `print` when its value can be printed.
+/// Calls to `print` already print their value, they are left as they are
+fn printed(expression: Expression, ty: &TypeAnnotation) -> Expression {
+    let printable = matches!(
+        ty,
+        Some(Type::BuiltIn(
+            BuiltInType::Number | BuiltInType::String | BuiltInType::Bool
+        ))
+    );
+    let prints =
+        matches!(&expression, Expression::FunctionCall(call) if call.identifier.id == "print");
+    if !printable || prints {
+        return expression;
+    }
+
+    Expression::FunctionCall(FunctionCall::new(
+        Identifier::new(0, 0, "print"),
+        vec![expression],
+    ))
+}
+
+/// # Description
+///
+/// Runs the REPL on stdin: the standard library and the inputs are loaded
+/// first, then each line is read and evaluated, until the end of the input.
+/// Values are written to stdout, and errors and warnings to stderr. The
+/// prompts are only shown when stdin is a terminal
+pub fn run(options: &CliOptions) -> Result<(), RunnerError> {
+    let compiler = Compiler::new();
+    let mut repl = Repl::new(&compiler)
+        .with_runtime_checks(options.runtime_checks)
+        .with_lint_levels(&options.lint_levels);
+
+    let files = options.std_library.iter().chain(&options.inputs);
+    for path in files {
+        let text = read_input(path)?;
+        let result = repl.eval(&path.to_string_lossy(), &text);
+        // the definitions of an input are kept even if running its expressions fails
+        if let Err(ReplError::Compilation { errors, .. }) = report(&mut repl, result) {
+            return Err(RunnerError::Compilation(errors));
+        }
+    }
+
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let mut lines = stdin.lock();
+    loop {
+        if interactive {
+            print!("{}", repl.prompt());
+            io::stdout().flush().ok();
+        }
+        let mut line = String::new();
+        match lines.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => return Err(RunnerError::Io("stdin".into(), err)),
+        }
+
+        if let Some(result) = repl.feed_line(line.trim_end_matches(['\n', '\r'])) {
+            // errors in the input are reported, the REPL goes on
+            let _ = report(&mut repl, result);
+        }
+    }
+
+    if interactive {
+        println!();
+    }
+    Ok(())
+}
+
+/// Writes the warnings and the result of an evaluation
+fn report(repl: &mut Repl, result: Result<Vec<Evaluation>, ReplError>) -> Result<(), ReplError> {
+    for warning in repl.take_warnings() {
+        eprintln!("{}", warning);
+    }
+    match result {
+        Ok(evaluations) => {
+            for evaluation in evaluations {
+                println!("{}", evaluation);
+            }
+            Ok(())
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            Err(err)
+        }
+    }
+}
@@ -1,56 +1,241 @@
-use error_handler::error_handler::ErrorHandler;
-use generated_parser::ProgramParser;
-use generator::CodeGenerator;
+use std::{fmt::Display, path::PathBuf};
 
-use semantic_analyzer::semantic_analyzer::SemanticAnalyzer;
+use error_handler::{
+    diagnostic::{json::to_json, sarif::to_sarif},
+    error::error::HulkError,
+    source_map::SourceMap,
+};
+use interpreter::{Interpreter, RuntimeError};
 
-fn write_output(target_file: &str, content: &str) -> Result<(), std::io::Error> {
-    std::fs::write(target_file, content)?;
-    Ok(())
+use crate::{
+    cli::{CliError, CliOptions, Command, EmitStage, ErrorFormat},
+    compilation_session::CompilationSession,
+    compiler::Compiler,
+    exit_code, repl,
+};
+
+#[derive(Debug)]
+pub enum RunnerError {
+    Cli(CliError),
+    Io(PathBuf, std::io::Error),
+    Compilation(Vec<HulkError>),
+    /// Inputs that `fmt --check` found not formatted
+    Unformatted(Vec<PathBuf>),
+    /// The error that stopped the program executed by `run`
+    Runtime(RuntimeError),
 }
 
-pub fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let file = "script.hulk";
-    let std_library = "std.hulk";
-    let output_file = "script.ll";
+impl RunnerError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            RunnerError::Cli(_) => exit_code::USAGE_FAILURE,
+            RunnerError::Io(_, _) => exit_code::IO_FAILURE,
+            RunnerError::Compilation(errors) => exit_code::exit_code_for_errors(errors),
+            RunnerError::Unformatted(_) => exit_code::FORMAT_FAILURE,
+            RunnerError::Runtime(_) => exit_code::RUNTIME_FAILURE,
+        }
+    }
+}
 
-    let std_library_content = std::fs::read_to_string(std_library)?;
-    let program_content = std::fs::read_to_string(file)?;
+impl Display for RunnerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunnerError::Cli(err) => write!(f, "{}", err),
+            RunnerError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            RunnerError::Compilation(errors) => match exit_code::exit_code_for_errors(errors) {
+                exit_code::LEXICAL_FAILURE => write!(f, "Lexical errors found"),
+                exit_code::SYNTACTIC_FAILURE => write!(f, "Sintactic errors found"),
+                _ => write!(f, "Semantic errors found"),
+            },
+            RunnerError::Unformatted(paths) => {
+                write!(f, "{} file(s) would be reformatted", paths.len())
+            }
+            RunnerError::Runtime(err) => write!(f, "{}", err),
+        }
+    }
+}
 
-    let content = format!("{}\n{}", std_library_content, program_content);
+impl std::error::Error for RunnerError {}
 
-    //  NOTE: the parser -> semantic analyzer -> generator steps will eventually
-    //        be abstracted away into a single struct or function
+impl From<CliError> for RunnerError {
+    fn from(err: CliError) -> Self {
+        RunnerError::Cli(err)
+    }
+}
 
-    let mut error_handler = ErrorHandler::new(&content, std_library_content.chars().count() + 1);
-    let p = ProgramParser::new();
-    let ast = p.parse(&content);
+pub(crate) fn read_input(path: &PathBuf) -> Result<String, RunnerError> {
+    std::fs::read_to_string(path).map_err(|err| RunnerError::Io(path.clone(), err))
+}
 
-    let mut ast = match ast {
-        Ok(ast) => ast,
-        Err(errors) => {
-            error_handler.extend_errors(errors);
-            for err in error_handler.get_error_messages() {
-                println!("{}", err);
+fn write_output(target_file: Option<PathBuf>, content: &str) -> Result<(), RunnerError> {
+    match target_file {
+        Some(path) => std::fs::write(&path, content).map_err(|err| RunnerError::Io(path, err)),
+        None => {
+            print!("{}", content);
+            Ok(())
+        }
+    }
+}
+
+/// # Description
+///
+/// Writes the errors and warnings found to stderr in `format`. JSON and SARIF
+/// are written as a single document, even if nothing was found
+fn report_diagnostics(
+    session: &mut CompilationSession,
+    format: ErrorFormat,
+    errors: Option<&[HulkError]>,
+) {
+    match format {
+        ErrorFormat::Human => {
+            for warning in session.warning_messages() {
+                eprintln!("{}", warning);
+            }
+            if errors.is_some() {
+                for err in session.diagnostic_messages() {
+                    eprintln!("{}", err);
+                }
             }
-            return Err("Sintactic errors found".into());
         }
-    };
+        ErrorFormat::Json => {
+            let diagnostics = session.structured_diagnostics();
+            eprintln!("{}", to_json(&diagnostics, session.source_map()));
+        }
+        ErrorFormat::Sarif => {
+            let diagnostics = session.structured_diagnostics();
+            eprintln!("{}", to_sarif(&diagnostics, session.source_map()));
+        }
+    }
+}
 
-    let mut semantic_analyzer = SemanticAnalyzer::new();
-    let analysis_result = semantic_analyzer.analyze_program_ast(&mut ast);
+/// The standard library (if any) followed by the inputs
+fn read_sources(options: &CliOptions) -> Result<SourceMap, RunnerError> {
+    let mut source_map = SourceMap::new();
+    if let Some(path) = &options.std_library {
+        source_map.add_library(&path.to_string_lossy(), &read_input(path)?);
+    }
+    for input in &options.inputs {
+        source_map.add_file(&input.to_string_lossy(), &read_input(input)?);
+    }
+    Ok(source_map)
+}
+
+/// # Description
+///
+/// Runs the compilation pipeline described by `options`: the standard library
+/// (if any), the inputs and the files they import are linked in order, and
+/// the pipeline stops after the stage selected with `--emit`, writing its
+/// result to the output.
+///
+/// Errors and warnings found in the source are printed to stderr, in the
+/// format selected with `--error-format`.
+pub fn run(options: &CliOptions) -> Result<(), RunnerError> {
+    match options.command {
+        Command::Format { check, width } => return format_inputs(options, check, width),
+        Command::Repl => return repl::run(options),
+        Command::Run => return interpret(options),
+        Command::Compile => {}
+    }
+
+    let source_map = read_sources(options)?;
+    let compiler = Compiler::new();
+    let mut session = compiler
+        .session(source_map)
+        .with_runtime_checks(options.runtime_checks)
+        .with_lint_levels(&options.lint_levels);
 
-    if let Err(errors) = analysis_result {
-        error_handler.extend_errors(errors);
-        for err in error_handler.get_error_messages() {
-            println!("{}", err);
+    let output = match options.emit {
+        EmitStage::Tokens => session.tokens().map(|tokens| {
+            tokens
+                .iter()
+                .map(|token| {
+                    format!(
+                        "{:?} {:?} {}..{}\n",
+                        token.ty, token.slice, token.start, token.end
+                    )
+                })
+                .collect::<String>()
+        }),
+        EmitStage::Ast => session.parse().map(|ast| format!("{:#?}\n", ast)),
+        EmitStage::TypedAst => session.analyze().map(|ast| format!("{:#?}\n", ast)),
+        EmitStage::Llvm => session.generate_llvm().map(|llvm| llvm.to_string()),
+    };
+
+    match output {
+        Ok(output) => {
+            report_diagnostics(&mut session, options.error_format, None);
+            write_output(options.output_path(), &output)
+        }
+        Err(errors) => {
+            report_diagnostics(&mut session, options.error_format, Some(&errors));
+            Err(RunnerError::Compilation(errors))
         }
-        return Err("Semantic errors found".into());
     }
+}
+
+/// # Description
+///
+/// Analyzes the program like the compilation does, then executes it with
+/// the interpreter, which writes what the program prints to stdout
+fn interpret(options: &CliOptions) -> Result<(), RunnerError> {
+    let source_map = read_sources(options)?;
+    let compiler = Compiler::new();
+    let mut session = compiler
+        .session(source_map)
+        .with_lint_levels(&options.lint_levels);
+
+    let analyzed = session.analyze().map(|_| ());
+    if let Err(errors) = analyzed {
+        report_diagnostics(&mut session, options.error_format, Some(&errors));
+        return Err(RunnerError::Compilation(errors));
+    }
+    report_diagnostics(&mut session, options.error_format, None);
+
+    // with the imported files
+    let source_map = session.source_map().clone();
+    let mut program = session.into_program().expect("The program was analyzed");
+    let mut interpreter = Interpreter::new(std::io::stdout().lock())
+        .with_source_map(source_map)
+        .with_runtime_checks(options.runtime_checks);
+    interpreter
+        .run_program(&mut program)
+        .map_err(RunnerError::Runtime)
+}
+
+/// # Description
+///
+/// Formats each input on its own, without the standard library, rewriting
+/// the ones whose formatting changes. With `check` nothing is written, and
+/// the inputs that are not formatted are reported instead
+fn format_inputs(options: &CliOptions, check: bool, width: usize) -> Result<(), RunnerError> {
+    let compiler = Compiler::new();
+    let mut unformatted = Vec::new();
 
-    let code_generator = CodeGenerator::new();
-    let generated_code = code_generator.generate_code_from_program_ast(&mut ast);
+    for input in &options.inputs {
+        let source = read_input(input)?;
+        let mut session = compiler.session_from_str(&input.to_string_lossy(), &source);
+        let formatted = match session.format(width) {
+            Ok(formatted) => formatted,
+            Err(errors) => {
+                report_diagnostics(&mut session, options.error_format, Some(&errors));
+                return Err(RunnerError::Compilation(errors));
+            }
+        };
 
-    write_output(output_file, &generated_code)?;
-    Ok(())
+        if formatted == source {
+            continue;
+        }
+        if check {
+            eprintln!("{} is not formatted", input.display());
+            unformatted.push(input.clone());
+        } else {
+            write_output(Some(input.clone()), &formatted)?;
+        }
+    }
+
+    if unformatted.is_empty() {
+        Ok(())
+    } else {
+        Err(RunnerError::Unformatted(unformatted))
+    }
 }
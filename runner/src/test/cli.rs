@@ -0,0 +1,208 @@
+use std::path::PathBuf;
+
+use semantic_analyzer::lint_config::LintLevel;
+
+use crate::cli::{CliError, CliOptions, Command, EmitStage, ErrorFormat};
+
+#[test]
+fn defaults() {
+    let options = CliOptions::parse(Vec::<String>::new()).unwrap();
+
+    assert_eq!(options.inputs, vec![PathBuf::from("script.hulk")]);
+    assert_eq!(options.std_library, Some(PathBuf::from("std.hulk")));
+    assert_eq!(options.emit, EmitStage::Llvm);
+    assert_eq!(options.output_path(), Some(PathBuf::from("script.ll")));
+}
+
+#[test]
+fn inputs_and_output() {
+    let options = CliOptions::parse(["a.hulk", "-o", "out.ll", "b.hulk"]).unwrap();
+
+    assert_eq!(
+        options.inputs,
+        vec![PathBuf::from("a.hulk"), PathBuf::from("b.hulk")]
+    );
+    assert_eq!(options.output_path(), Some(PathBuf::from("out.ll")));
+}
+
+#[test]
+fn llvm_output_defaults_to_first_input() {
+    let options = CliOptions::parse(["dir/main.hulk", "other.hulk"]).unwrap();
+
+    assert_eq!(options.output_path(), Some(PathBuf::from("dir/main.ll")));
+}
+
+#[test]
+fn other_stages_default_to_stdout() {
+    let options = CliOptions::parse(["main.hulk", "--emit", "ast"]).unwrap();
+
+    assert_eq!(options.emit, EmitStage::Ast);
+    assert_eq!(options.output_path(), None);
+}
+
+#[test]
+fn emit_stages() {
+    for (arg, stage) in [
+        ("tokens", EmitStage::Tokens),
+        ("ast", EmitStage::Ast),
+        ("typed-ast", EmitStage::TypedAst),
+        ("llvm", EmitStage::Llvm),
+    ] {
+        assert_eq!(CliOptions::parse(["--emit", arg]).unwrap().emit, stage);
+        let joined = format!("--emit={}", arg);
+        assert_eq!(CliOptions::parse([joined]).unwrap().emit, stage);
+    }
+}
+
+#[test]
+fn std_library() {
+    let options = CliOptions::parse(["--std", "lib/std.hulk"]).unwrap();
+    assert_eq!(options.std_library, Some(PathBuf::from("lib/std.hulk")));
+
+    let options = CliOptions::parse(["--no-std"]).unwrap();
+    assert_eq!(options.std_library, None);
+}
+
+#[test]
+fn runtime_checks() {
+    assert!(CliOptions::parse(["main.hulk"]).unwrap().runtime_checks);
+    assert!(
+        !CliOptions::parse(["main.hulk", "--no-runtime-checks"])
+            .unwrap()
+            .runtime_checks
+    );
+}
+
+#[test]
+fn error_formats() {
+    assert_eq!(
+        CliOptions::parse(["main.hulk"]).unwrap().error_format,
+        ErrorFormat::Human
+    );
+    for (arg, format) in [
+        ("human", ErrorFormat::Human),
+        ("json", ErrorFormat::Json),
+        ("sarif", ErrorFormat::Sarif),
+    ] {
+        assert_eq!(
+            CliOptions::parse(["--error-format", arg])
+                .unwrap()
+                .error_format,
+            format
+        );
+        let joined = format!("--error-format={}", arg);
+        assert_eq!(CliOptions::parse([joined]).unwrap().error_format, format);
+    }
+    assert_eq!(
+        CliOptions::parse(["--error-format", "xml"]),
+        Err(CliError::InvalidErrorFormat("xml".to_string()))
+    );
+}
+
+#[test]
+fn lint_levels() {
+    let options = CliOptions::parse([
+        "--deny",
+        "warnings",
+        "-A",
+        "shadowed-variables",
+        "--warn=unused-variables",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        options.lint_levels,
+        vec![
+            ("warnings".to_string(), LintLevel::Deny),
+            ("shadowed-variables".to_string(), LintLevel::Allow),
+            ("unused-variables".to_string(), LintLevel::Warn),
+        ]
+    );
+    assert_eq!(
+        CliOptions::parse(["-D", "unused"]),
+        Err(CliError::UnknownLint("unused".to_string()))
+    );
+    assert_eq!(
+        CliOptions::parse(["--allow"]),
+        Err(CliError::MissingValue("--allow".to_string()))
+    );
+}
+
+#[test]
+fn std_and_no_std_conflict() {
+    let err = CliOptions::parse(["--std", "std.hulk", "--no-std"]).unwrap_err();
+
+    assert_eq!(
+        err,
+        CliError::ConflictingOptions("--std".to_string(), "--no-std".to_string())
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    assert_eq!(
+        CliOptions::parse(["-o"]).unwrap_err(),
+        CliError::MissingValue("-o".to_string())
+    );
+    assert_eq!(
+        CliOptions::parse(["--emit", "bytecode"]).unwrap_err(),
+        CliError::InvalidEmitStage("bytecode".to_string())
+    );
+    assert_eq!(
+        CliOptions::parse(["--verbose"]).unwrap_err(),
+        CliError::UnknownOption("--verbose".to_string())
+    );
+}
+
+#[test]
+fn double_dash_ends_options() {
+    let options = CliOptions::parse(["--", "-weird.hulk"]).unwrap();
+
+    assert_eq!(options.inputs, vec![PathBuf::from("-weird.hulk")]);
+}
+
+#[test]
+fn format_command() {
+    assert_eq!(
+        CliOptions::parse(["main.hulk"]).unwrap().command,
+        Command::Compile
+    );
+
+    let options = CliOptions::parse(["fmt", "a.hulk", "--check", "--width=100"]).unwrap();
+    assert_eq!(
+        options.command,
+        Command::Format {
+            check: true,
+            width: 100
+        }
+    );
+    assert_eq!(options.inputs, vec![PathBuf::from("a.hulk")]);
+
+    assert_eq!(
+        CliOptions::parse(["fmt", "--width", "0"]).unwrap_err(),
+        CliError::InvalidWidth("0".to_string())
+    );
+    assert_eq!(
+        CliOptions::parse(["--check"]).unwrap_err(),
+        CliError::UnknownOption("--check".to_string())
+    );
+}
+
+#[test]
+fn repl_command() {
+    let options = CliOptions::parse(["repl", "--no-std"]).unwrap();
+    assert_eq!(options.command, Command::Repl);
+    assert_eq!(options.std_library, None);
+    assert!(options.inputs.is_empty());
+
+    let options = CliOptions::parse(["repl", "defs.hulk"]).unwrap();
+    assert_eq!(options.inputs, vec![PathBuf::from("defs.hulk")]);
+}
+
+#[test]
+fn run_command() {
+    let options = CliOptions::parse(["run", "--no-runtime-checks", "a.hulk"]).unwrap();
+    assert_eq!(options.command, Command::Run);
+    assert!(!options.runtime_checks);
+    assert_eq!(options.inputs, vec![PathBuf::from("a.hulk")]);
+}
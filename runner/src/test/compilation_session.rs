@@ -0,0 +1,326 @@
+use std::{
+    io::Write,
+    process::{Command as Process, Stdio},
+};
+
+use error_handler::{error::error::HulkError, source_map::SourceMap};
+
+use semantic_analyzer::lint_config::LintLevel;
+
+use crate::{Compiler, Stage, repl::LLI};
+
+#[test]
+fn stages_in_memory() {
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", "let x = 1 in print(x + 2);");
+
+    assert_eq!(session.tokens().unwrap().len(), 12);
+    assert_eq!(session.parse().unwrap().expressions.len(), 1);
+
+    let typed = session.analyze().unwrap();
+    let assignment = &typed.expressions[0].as_let_in().unwrap().assignment;
+    assert_eq!(
+        assignment.identifier.info.ty.as_ref().unwrap().to_string(),
+        "Number"
+    );
+
+    assert!(
+        session
+            .generate_llvm()
+            .unwrap()
+            .contains("define i32 @main()")
+    );
+    assert!(!session.has_errors());
+    assert_eq!(session.failed_stage(), None);
+}
+
+#[test]
+fn compiler_is_reusable() {
+    let compiler = Compiler::new();
+
+    for program in ["print(1);", "print(\"hello\");", "print(true);"] {
+        let mut session = compiler.session_from_str("main.hulk", program);
+        session.generate_llvm().unwrap();
+    }
+}
+
+#[test]
+fn lexical_errors_are_collected() {
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", "print(1 $ 2);");
+
+    let errors = session.generate_llvm().unwrap_err();
+
+    assert!(matches!(errors[0], HulkError::LexicalError(_)));
+    assert_eq!(session.failed_stage(), Some(Stage::Lexing));
+    assert_eq!(session.diagnostics().len(), errors.len());
+}
+
+#[test]
+fn semantic_errors_are_collected() {
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", "print(1 + true);");
+
+    assert!(session.parse().is_ok());
+    let errors = session.analyze().unwrap_err();
+
+    assert!(matches!(errors[0], HulkError::SemanticError(_)));
+    assert_eq!(session.failed_stage(), Some(Stage::Analysis));
+
+    let messages = session.diagnostic_messages();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains("line 1:9"));
+}
+
+#[test]
+fn warnings_are_collected() {
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", "print(1 is String);");
+
+    assert!(session.generate_llvm().is_ok());
+    assert!(!session.has_errors());
+
+    let warnings = session.warning_messages();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("always false"));
+    assert!(warnings[0].contains("line 1:9"));
+}
+
+#[test]
+fn later_stages_are_not_run_after_failure() {
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", "print(1 + true);");
+
+    session.analyze().unwrap_err();
+    session.generate_llvm().unwrap_err();
+
+    assert_eq!(session.diagnostics().len(), 1);
+    assert!(session.parse().is_ok());
+}
+
+#[test]
+fn errors_are_relative_to_user_code() {
+    let mut source_map = SourceMap::new();
+    source_map.add_library("std.hulk", "function two(): Number => 2;");
+    source_map.add_file("main.hulk", "print(two());\nprint(two() + true);");
+
+    let compiler = Compiler::new();
+    let mut session = compiler.session(source_map);
+
+    session.analyze().unwrap_err();
+
+    let messages = session.diagnostic_messages();
+    assert!(messages[0].contains("line 2:13"));
+}
+
+#[test]
+fn errors_point_at_their_module() {
+    let mut source_map = SourceMap::new();
+    source_map.add_file(
+        "shapes.hulk",
+        "type Square(side: Number) {\n    side = side + true;\n}",
+    );
+    source_map.add_file("main.hulk", "import shapes;\nnew Square(2);");
+
+    let compiler = Compiler::new();
+    let mut session = compiler.session(source_map);
+
+    session.analyze().unwrap_err();
+
+    let messages = session.diagnostic_messages();
+    assert!(
+        messages[0].contains("shapes.hulk, line 2:"),
+        "{}",
+        messages[0]
+    );
+}
+
+#[test]
+fn definitions_need_an_import() {
+    let mut source_map = SourceMap::new();
+    source_map.add_file("shapes.hulk", "type Square(side: Number) { side = side; }");
+    source_map.add_file("main.hulk", "new Square(2);");
+
+    let compiler = Compiler::new();
+    let mut session = compiler.session(source_map);
+
+    let errors = session.analyze().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("which is not imported"));
+
+    let messages = session.diagnostic_messages();
+    assert!(
+        messages[0].contains("main.hulk, line 1:"),
+        "{}",
+        messages[0]
+    );
+}
+
+#[test]
+fn same_names_run_the_definition_of_their_module() {
+    let mut source_map = SourceMap::new();
+    source_map.add_file(
+        "circles.hulk",
+        "type Shape(r: Number) { r = r; area(): Number => 3 * self.r * self.r; }\n\
+         function name(): String => \"circle\";",
+    );
+    source_map.add_file(
+        "squares.hulk",
+        "type Shape(side: Number) { side = side; area(): Number => self.side * self.side; }\n\
+         function name(): String => \"square\";\n\
+         function square(side: Number): Number => new Shape(side).area();",
+    );
+    source_map.add_file(
+        "main.hulk",
+        "import circles;\nimport squares;\n\
+         function name(): String => \"main\";\n\
+         print(name());\n\
+         print(square(3));",
+    );
+
+    let compiler = Compiler::new();
+    let mut session = compiler.session(source_map);
+    let llvm = session.generate_llvm().unwrap().to_string();
+
+    assert_eq!(run_lli(&llvm), "main\n9.000000\n");
+}
+
+#[test]
+fn protocol_typed_constructor_parameters_run() {
+    let program = "protocol Named { name(): String; }\n\
+         type Person(first: String) { first = first; name(): String => self.first; }\n\
+         type Student(first: String) inherits Person(first) {}\n\
+         type Holder(named: Named, person: Person) {\n\
+             named = named;\n\
+             person = person;\n\
+             names(): String => self.named.name() @ self.person.name();\n\
+         }\n\
+         print(new Holder(new Person(\"ab\"), new Student(\"cd\")).names());";
+
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", program);
+    let llvm = session.generate_llvm().unwrap().to_string();
+
+    assert_eq!(run_lli(&llvm), "abcd\n");
+}
+
+#[test]
+fn modules_are_loaded_once() {
+    let mut source_map = SourceMap::new();
+    source_map.add_file("two.hulk", "function two(): Number => 2;");
+    source_map.add_file(
+        "main.hulk",
+        "import two;\nimport \"two.hulk\";\nprint(two());",
+    );
+
+    let compiler = Compiler::new();
+    let mut session = compiler.session(source_map);
+
+    session.analyze().unwrap();
+    assert_eq!(session.source_map().files().len(), 2);
+}
+
+#[test]
+fn missing_modules_are_reported() {
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", "import nowhere;\nprint(1);");
+
+    let errors = session.parse().unwrap_err();
+    assert!(errors[0].to_string().contains("Module `nowhere` not found"));
+    assert_eq!(session.failed_stage(), Some(Stage::Parsing));
+}
+
+#[test]
+fn source_map_files() {
+    let mut source_map = SourceMap::new();
+    let std = source_map.add_library("std.hulk", "function two(): Number => 2;");
+    let main = source_map.add_file("main.hulk", "print(two());");
+
+    assert_eq!(source_map.file_text(std), "function two(): Number => 2;");
+    assert_eq!(source_map.file_text(main), "print(two());");
+    assert_eq!(source_map.user_code_offset(), 29);
+    assert_eq!(source_map.file_at(0).unwrap().name, "std.hulk");
+    assert_eq!(source_map.file_at(29).unwrap().name, "main.hulk");
+}
+
+#[test]
+fn source_map_line_column() {
+    let mut source_map = SourceMap::new();
+    source_map.add_library("std.hulk", "function two(): Number => 2;");
+    source_map.add_file("main.hulk", "print(two());\nprint([1][2]);");
+
+    let (file, line, column) = source_map.line_column(52).unwrap();
+    assert_eq!(file.name, "main.hulk");
+    assert_eq!((line, column), (2, 10));
+    assert_eq!(source_map.line_column(3).unwrap().2, 4);
+    assert_eq!(source_map.line_text(52), "print([1][2]);");
+}
+
+#[test]
+fn source_map_columns_count_characters() {
+    let mut source_map = SourceMap::new();
+    source_map.add_file("first.hulk", "print(\"ñandú\");");
+    source_map.add_file("second.hulk", "\n  print(\"é\" @ 2);");
+
+    // the `)` after "ñandú"
+    let (file, line, column) = source_map.line_column(15).unwrap();
+    assert_eq!(file.name, "first.hulk");
+    assert_eq!((line, column), (1, 14));
+
+    // the `2` in the second line of the second file
+    let position = source_map.content().find('2').unwrap();
+    let (file, line, column) = source_map.line_column(position).unwrap();
+    assert_eq!(file.name, "second.hulk");
+    assert_eq!((line, column), (2, 15));
+}
+
+#[test]
+fn errors_point_at_non_ascii_lines() {
+    let compiler = Compiler::new();
+    let mut session = compiler.session_from_str("main.hulk", "print(\"ñú\" + true);");
+
+    session.analyze().unwrap_err();
+
+    let messages = session.diagnostic_messages();
+    assert!(messages[0].contains("line 1:12"), "{}", messages[0]);
+    assert!(
+        messages[0].contains(&format!(
+            "  1 | print(\"ñú\" + true);\n  |   {}^\n",
+            " ".repeat(11)
+        )),
+        "{}",
+        messages[0]
+    );
+}
+
+#[test]
+fn denied_lints_fail_the_analysis() {
+    let compiler = Compiler::new();
+    let mut session = compiler
+        .session_from_str("main.hulk", "let x = 1 in print(2);")
+        .with_lint_levels(&[("unused-variables".to_string(), LintLevel::Deny)]);
+
+    let errors = session.analyze().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], HulkError::DeniedWarning(_)));
+    assert_eq!(session.failed_stage(), Some(Stage::Analysis));
+
+    let messages = session.diagnostic_messages();
+    assert!(messages[0].contains("`unused-variables` lint is denied"));
+}
+
+/// What the LLVM IR `llvm` prints when run with lli
+fn run_lli(llvm: &str) -> String {
+    let mut lli = Process::new(LLI)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    lli.stdin
+        .take()
+        .unwrap()
+        .write_all(llvm.as_bytes())
+        .unwrap();
+    let output = lli.wait_with_output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
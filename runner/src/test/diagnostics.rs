@@ -0,0 +1,56 @@
+use error_handler::{
+    diagnostic::diagnostic::{Diagnostic, Severity, Span},
+    source_map::SourceMap,
+};
+
+use crate::Compiler;
+
+fn session_diagnostics(program: &str) -> Vec<Diagnostic> {
+    let mut source_map = SourceMap::new();
+    source_map.add_library("std.hulk", "function two(): Number => 2;");
+    source_map.add_file("main.hulk", program);
+
+    let compiler = Compiler::new();
+    let mut session = compiler.session(source_map.clone());
+    let _ = session.analyze();
+
+    session.structured_diagnostics()
+}
+
+#[test]
+fn errors_have_codes_and_spans() {
+    let diagnostics = session_diagnostics("print(two());\nprint(count + 1);");
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(diagnostics[0].code, "E0313");
+    assert_eq!(diagnostics[0].message, "Variable `count` is not defined.");
+    assert_eq!(diagnostics[0].span, Span::new(29 + 20, 29 + 25));
+}
+
+#[test]
+fn warnings_are_diagnostics() {
+    let diagnostics = session_diagnostics("let x = 1 in print(x is Number);");
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(diagnostics[0].code, "W0001");
+}
+
+#[test]
+fn syntax_errors_span_the_unexpected_token() {
+    let diagnostics = session_diagnostics("print(two());\nlet x = in x;");
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].span, Span::new(29 + 22, 29 + 24));
+}
+
+#[test]
+fn lexical_errors_span_the_invalid_character() {
+    let diagnostics = session_diagnostics("print(two());\nprint(1 ¤ 2);");
+
+    assert_eq!(
+        diagnostics[0].span,
+        Span::new(29 + 22, 29 + 22 + '¤'.len_utf8())
+    );
+}
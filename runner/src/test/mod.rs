@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+mod cli;
+mod compilation_session;
+mod diagnostics;
+mod repl;
+mod run;
+
+/// # Description
+///
+/// Writes `content` to a fresh file in the system's temporary directory
+/// and returns its path
+fn temp_file(name: &str, content: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("hulk_runner_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
@@ -0,0 +1,104 @@
+use crate::{
+    compiler::Compiler,
+    repl::{Evaluation, Repl, ReplError},
+};
+
+/// Feeds each line to `repl`, returning the result of the last one
+fn feed(repl: &mut Repl, lines: &[&str]) -> Option<Result<Vec<Evaluation>, ReplError>> {
+    lines
+        .iter()
+        .map(|line| repl.feed_line(line))
+        .last()
+        .flatten()
+}
+
+fn evaluate(repl: &mut Repl, line: &str) -> Vec<String> {
+    feed(repl, &[line])
+        .expect("the input should be complete")
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn prints_value_and_type() {
+    let compiler = Compiler::new();
+    let mut repl = Repl::new(&compiler);
+
+    assert_eq!(evaluate(&mut repl, "1 + 2;"), vec!["3.000000 : Number"]);
+    assert_eq!(evaluate(&mut repl, r#""a" @ "b";"#), vec!["ab : String"]);
+    assert_eq!(evaluate(&mut repl, "1 < 2;"), vec!["true : Boolean"]);
+    assert_eq!(evaluate(&mut repl, "[1, 2];"), vec![": Number*"]);
+}
+
+#[test]
+fn keeps_definitions_between_inputs() {
+    let compiler = Compiler::new();
+    let mut repl = Repl::new(&compiler);
+
+    assert!(evaluate(&mut repl, "function square(x: Number): Number => x * x;").is_empty());
+    assert!(evaluate(&mut repl, "constant TEN: Number = 10;").is_empty());
+    assert_eq!(
+        evaluate(&mut repl, "square(TEN);"),
+        vec!["100.000000 : Number"]
+    );
+}
+
+#[test]
+fn waits_for_incomplete_inputs() {
+    let compiler = Compiler::new();
+    let mut repl = Repl::new(&compiler);
+
+    assert!(
+        repl.feed_line("function add(a: Number, b: Number): Number {")
+            .is_none()
+    );
+    assert!(repl.is_pending());
+    assert!(repl.feed_line("    a + b;").is_none());
+    let result = repl.feed_line("}").unwrap().unwrap();
+    assert!(result.is_empty());
+    assert!(!repl.is_pending());
+
+    let result = feed(&mut repl, &["let x = 1 in", "    add(x, 2);"]).unwrap();
+    assert_eq!(result.unwrap()[0].to_string(), "3.000000 : Number");
+}
+
+#[test]
+fn last_semicolon_is_optional() {
+    let compiler = Compiler::new();
+    let mut repl = Repl::new(&compiler);
+
+    assert_eq!(evaluate(&mut repl, "2 * 4"), vec!["8.000000 : Number"]);
+    assert_eq!(
+        evaluate(&mut repl, "print(1); 2"),
+        vec!["1.000000 : Number", "2.000000 : Number"]
+    );
+}
+
+#[test]
+fn rejected_inputs_leave_no_definitions() {
+    let compiler = Compiler::new();
+    let mut repl = Repl::new(&compiler);
+
+    let result = feed(&mut repl, &[r#"function f(): Number => "one";"#]).unwrap();
+    assert!(matches!(result, Err(ReplError::Compilation { .. })));
+
+    // `f` would be defined already if the analysis was not rolled back
+    assert!(evaluate(&mut repl, "function f(): Number => 1;").is_empty());
+    assert_eq!(evaluate(&mut repl, "f()"), vec!["1.000000 : Number"]);
+}
+
+#[test]
+fn errors_point_to_their_input() {
+    let compiler = Compiler::new();
+    let mut repl = Repl::new(&compiler);
+    evaluate(&mut repl, "function f(): Number => 1;");
+
+    let Some(Err(ReplError::Compilation { messages, .. })) = repl.feed_line("f() + g()") else {
+        panic!("`g` is not defined");
+    };
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains("line 1:"), "{}", messages[0]);
+    assert!(messages[0].contains("f() + g()"), "{}", messages[0]);
+}
@@ -0,0 +1,233 @@
+use crate::{
+    cli::{CliOptions, Command, EmitStage, ErrorFormat},
+    exit_code,
+    runner::run,
+};
+
+use super::temp_file;
+
+fn options_for(name: &str, program: &str, emit: EmitStage) -> CliOptions {
+    let input = temp_file(name, program);
+    let output = input.with_extension("out");
+
+    CliOptions {
+        command: Command::Compile,
+        inputs: vec![input],
+        output: Some(output),
+        std_library: None,
+        emit,
+        runtime_checks: true,
+        error_format: ErrorFormat::Human,
+        lint_levels: Vec::new(),
+        help: false,
+    }
+}
+
+#[test]
+fn emits_llvm() {
+    let options = options_for("emits_llvm.hulk", "print(1 + 2);", EmitStage::Llvm);
+
+    run(&options).unwrap();
+
+    let llvm = std::fs::read_to_string(options.output_path().unwrap()).unwrap();
+    assert!(llvm.contains("define i32 @main()"));
+}
+
+#[test]
+fn emits_tokens() {
+    let options = options_for("emits_tokens.hulk", "let x = 1 in x;", EmitStage::Tokens);
+
+    run(&options).unwrap();
+
+    let tokens = std::fs::read_to_string(options.output_path().unwrap()).unwrap();
+    assert_eq!(tokens.lines().next(), Some("Let \"let\" 0..3"));
+    assert_eq!(tokens.lines().count(), 7);
+}
+
+#[test]
+fn emits_typed_ast() {
+    let options = options_for(
+        "emits_typed_ast.hulk",
+        "let x = 1 in x;",
+        EmitStage::TypedAst,
+    );
+
+    run(&options).unwrap();
+
+    let ast = std::fs::read_to_string(options.output_path().unwrap()).unwrap();
+    assert!(ast.contains("Number"));
+}
+
+#[test]
+fn links_std_and_imported_inputs() {
+    let std_library = temp_file("link_std.hulk", "function two(): Number => 2;");
+    let first = temp_file("link_first.hulk", "function three(): Number => 3;");
+    let second = temp_file(
+        "link_second.hulk",
+        "import link_first;\nprint(two() + three());",
+    );
+
+    let options = CliOptions {
+        command: Command::Compile,
+        inputs: vec![first, second.clone()],
+        output: Some(second.with_extension("ll")),
+        std_library: Some(std_library),
+        emit: EmitStage::Llvm,
+        runtime_checks: true,
+        error_format: ErrorFormat::Human,
+        lint_levels: Vec::new(),
+        help: false,
+    };
+
+    run(&options).unwrap();
+}
+
+#[test]
+fn loads_imported_files() {
+    temp_file(
+        "loaded_shapes.hulk",
+        "type Square(side: Number) { side = side; area(): Number => self.side ^ 2; }",
+    );
+    temp_file(
+        "loaded_area.hulk",
+        "import \"./loaded_shapes.hulk\";\nfunction area(s: Square): Number => s.area();",
+    );
+    let mut options = options_for(
+        "loaded_main.hulk",
+        "import loaded_area;\nimport loaded_shapes;\nprint(area(new Square(3)));",
+        EmitStage::Llvm,
+    );
+    options.command = Command::Run;
+
+    run(&options).unwrap();
+}
+
+#[test]
+fn imports_can_be_cyclic() {
+    temp_file(
+        "cyclic_even.hulk",
+        "import cyclic_odd;\nfunction even(n: Number): Boolean => if (n == 0) true else odd(n - 1);",
+    );
+    temp_file(
+        "cyclic_odd.hulk",
+        "import cyclic_even;\nfunction odd(n: Number): Boolean => if (n == 0) false else even(n - 1);",
+    );
+    let options = options_for(
+        "cyclic_main.hulk",
+        "import cyclic_even;\nprint(even(4));",
+        EmitStage::Llvm,
+    );
+
+    run(&options).unwrap();
+}
+
+#[test]
+fn missing_module_exit_code() {
+    let options = options_for(
+        "importer.hulk",
+        "import nowhere;\nprint(1);",
+        EmitStage::Llvm,
+    );
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::SEMANTIC_FAILURE);
+}
+
+#[test]
+fn definitions_must_be_imported() {
+    let library = temp_file("unimported_lib.hulk", "function four(): Number => 4;");
+    let mut options = options_for("unimported_main.hulk", "print(four());", EmitStage::Llvm);
+    options.inputs.insert(0, library);
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::SEMANTIC_FAILURE);
+}
+
+#[test]
+fn lexical_error_exit_code() {
+    let options = options_for("lexical.hulk", "let x = 1 in x $ 2;", EmitStage::Llvm);
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::LEXICAL_FAILURE);
+}
+
+#[test]
+fn syntactic_error_exit_code() {
+    let options = options_for("syntactic.hulk", "let x = in x;", EmitStage::Llvm);
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::SYNTACTIC_FAILURE);
+}
+
+#[test]
+fn semantic_error_exit_code() {
+    let options = options_for("semantic.hulk", "let x = 1 in x + true;", EmitStage::Llvm);
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::SEMANTIC_FAILURE);
+}
+
+#[test]
+fn semantic_errors_not_reported_for_ast() {
+    let options = options_for("ast_only.hulk", "let x = 1 in x + true;", EmitStage::Ast);
+
+    run(&options).unwrap();
+}
+
+#[test]
+fn missing_input_exit_code() {
+    let mut options = options_for("missing.hulk", "", EmitStage::Llvm);
+    options.inputs[0].set_extension("missing");
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::IO_FAILURE);
+}
+
+#[test]
+fn interprets_without_llvm() {
+    let mut options = options_for("interpreted.hulk", "print(40 + 2);", EmitStage::Llvm);
+    options.command = Command::Run;
+
+    run(&options).unwrap();
+    assert!(!options.output_path().unwrap().exists());
+}
+
+#[test]
+fn runtime_error_exit_code() {
+    let mut options = options_for(
+        "runtime.hulk",
+        "let zero = 0 in print(1 / zero);",
+        EmitStage::Llvm,
+    );
+    options.command = Command::Run;
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::RUNTIME_FAILURE);
+    assert!(err.to_string().contains("runtime.hulk:1:25"));
+}
+
+#[test]
+fn formats_inputs_in_place() {
+    let mut options = options_for("format.hulk", "// answer\nprint( 40+2 ) ;", EmitStage::Llvm);
+    options.command = Command::Format {
+        check: true,
+        width: 80,
+    };
+
+    let err = run(&options).unwrap_err();
+    assert_eq!(err.exit_code(), exit_code::FORMAT_FAILURE);
+
+    options.command = Command::Format {
+        check: false,
+        width: 80,
+    };
+    run(&options).unwrap();
+    let formatted = std::fs::read_to_string(&options.inputs[0]).unwrap();
+    assert_eq!(formatted, "// answer\nprint(40 + 2);\n");
+
+    options.command = Command::Format {
+        check: true,
+        width: 80,
+    };
+    run(&options).unwrap();
+}
@@ -1,4 +1,9 @@
-use ast::{FunctionDef, Identifier, typing::FunctorType};
+use std::collections::HashMap;
+
+use ast::{
+    FunctionDef, FunctionSignature, Identifier,
+    typing::{FunctorType, Type, substitute},
+};
 
 /// # Description
 /// `FuncInfo` is a struct that encapsulates information about a function definition.
@@ -9,25 +14,52 @@ use ast::{FunctionDef, Identifier, typing::FunctorType};
 /// # Parameters
 /// - `name`: The identifier representing the function's name.
 /// - `parameters`: A vector of identifiers representing the function's parameters.
-/// - `functor_type`: The type information of the function, including parameter and return types.
+/// - `type_parameters`: The names of the type parameters of a generic function.
 ///
 /// # Methods
 /// - `new(name, parameters)`: Constructs a new `FuncInfo` from a function name and its parameters.
 /// - `get_type_wrapper_name(info)`: Returns a string representing the type wrapper name for the function.
 /// - `get_var_instance_name(info)`: Returns a string representing the variable instance name for the function.
+/// - `substitute(bindings)`: Returns the function with its type parameters replaced by the bound types.
 ///
 /// # Conversion
-/// Implements `From<&FunctionDef>` for `FuncInfo`, allowing conversion from a function definition AST node.
+/// Implements `From<&FunctionDef>` and `From<&FunctionSignature>` for `FuncInfo`, allowing conversion from a
+/// function definition or a protocol method signature AST node.
 ///
 #[derive(Clone)]
 pub struct FuncInfo {
     pub name: Identifier,
     pub parameters: Vec<Identifier>,
+    pub type_parameters: Vec<String>,
 }
 
 impl FuncInfo {
     pub fn new(name: Identifier, parameters: Vec<Identifier>) -> Self {
-        Self { name, parameters }
+        Self {
+            name,
+            parameters,
+            type_parameters: Vec::new(),
+        }
+    }
+
+    /// Builds the info of a function value of type `functor` named by `name`,
+    /// its parameters are named after their position.
+    pub fn from_functor(name: Identifier, functor: &FunctorType) -> Self {
+        let parameters = functor
+            .parameter_types
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                let mut param = name.clone();
+                param.id = format!("${}", i);
+                param.info.ty = ty.clone();
+                param
+            })
+            .collect();
+        let mut name = name;
+        name.info.ty = *functor.return_type.clone();
+
+        Self::new(name, parameters)
     }
 
     pub fn get_functor_type(&self) -> FunctorType {
@@ -40,6 +72,17 @@ impl FuncInfo {
         )
     }
 
+    /// Returns the function with the types in `bindings` in place of the type parameters
+    /// they are bound to, e.g. `first(xs: Number*): Number` from `first<T>(xs: T*): T`.
+    pub fn substitute(&self, bindings: &HashMap<String, Type>) -> Self {
+        let mut instance = self.clone();
+        for parameter in &mut instance.parameters {
+            parameter.info.ty = substitute(&parameter.info.ty, bindings);
+        }
+        instance.name.info.ty = substitute(&instance.name.info.ty, bindings);
+        instance
+    }
+
     /// Returns the type wrapper name for the function.
     /// Example: If the function name is `foo`, the wrapper name will be `$fooTypeWrapper`.
     pub fn get_type_wrapper_name(info: &FuncInfo) -> String {
@@ -58,6 +101,17 @@ impl From<&FunctionDef> for FuncInfo {
         Self {
             name: func_def.identifier.clone(),
             parameters: func_def.parameters.clone(),
+            type_parameters: func_def
+                .type_parameters
+                .iter()
+                .map(|parameter| parameter.id.clone())
+                .collect(),
         }
     }
 }
+
+impl From<&FunctionSignature> for FuncInfo {
+    fn from(signature: &FunctionSignature) -> Self {
+        Self::new(signature.identifier.clone(), signature.parameters.clone())
+    }
+}
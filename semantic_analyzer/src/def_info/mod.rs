@@ -4,6 +4,9 @@ pub use definition_info::DefinitionInfo;
 pub mod func_info;
 pub use func_info::FuncInfo;
 
+pub mod protocol_info;
+pub use protocol_info::ProtocolInfo;
+
 pub mod type_info;
 pub use type_info::{DefinedTypeInfo, TypeInfo};
 
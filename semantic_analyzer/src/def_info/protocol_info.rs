@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result};
+
+use ast::TypeName;
+
+use super::FuncInfo;
+
+/// # Description
+/// `ProtocolInfo` encapsulates information about a protocol definition.
+/// Types are never declared to implement a protocol, they conform to it
+/// structurally, by having (or inheriting) a compatible method for each of
+/// the protocol's methods.
+/// # Fields
+/// - `name`: The name of the protocol.
+/// - `parent`: The protocol this one extends, if any.
+/// - `methods`: The methods declared in the protocol, without the extended ones.
+/// - `type_parameters`: The names of the type parameters of a generic protocol.
+#[derive(Clone)]
+pub struct ProtocolInfo {
+    pub name: TypeName,
+    pub parent: Option<TypeName>,
+    pub methods: HashMap<String, FuncInfo>,
+    pub type_parameters: Vec<String>,
+}
+
+impl ProtocolInfo {
+    pub fn new(
+        name: TypeName,
+        parent: Option<TypeName>,
+        methods: HashMap<String, FuncInfo>,
+    ) -> Self {
+        ProtocolInfo {
+            name,
+            parent,
+            methods,
+            type_parameters: Vec::new(),
+        }
+    }
+
+    pub fn with_type_parameters(mut self, type_parameters: Vec<String>) -> Self {
+        self.type_parameters = type_parameters;
+        self
+    }
+}
+
+impl Display for ProtocolInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.name.id)
+    }
+}
@@ -3,22 +3,28 @@ use std::fmt::Display;
 
 use ast::{
     TypeName,
-    typing::{BuiltInType, Type, TypeAnnotation},
+    token_position::TokenPosition,
+    typing::{BuiltInType, Type, TypeAnnotation, substitute},
 };
 use std::fmt::{Formatter, Result};
 
-use super::DefinitionInfo;
+use super::{DefinitionInfo, ProtocolInfo};
 
 /// # Description
 /// `TypeInfo` is an enum that encapsulates information about a type definition.
-/// It can represent either a built-in type or a user-defined type.
+/// It can represent a built-in type, a user-defined type or a protocol.
 /// # Variants
 /// - `BuiltIn`: Represents a built-in type, such as `Number`, `Boolean`, etc.
 /// - `Defined`: Represents a user-defined type, which includes its name, members, and argument types.
+/// - `Protocol`: Represents a protocol, which includes its name, the protocol it extends and its methods.
+/// - `Parameter`: Represents a type parameter of a generic type, protocol or function. Inside of
+///   its definition it is an opaque type, that only conforms to itself and to `Object`.
 #[derive(Clone)]
 pub enum TypeInfo {
     BuiltIn(BuiltInType),
     Defined(DefinedTypeInfo),
+    Protocol(ProtocolInfo),
+    Parameter(TypeName),
 }
 
 impl TypeInfo {
@@ -54,10 +60,47 @@ impl TypeInfo {
         }
     }
 
+    pub fn as_protocol(&self) -> Option<&ProtocolInfo> {
+        if let Self::Protocol(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_protocol_mut(&mut self) -> Option<&mut ProtocolInfo> {
+        if let Self::Protocol(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Where the type or protocol is defined, `None` for built-in types
+    pub fn definition_position(&self) -> Option<TokenPosition> {
+        match self {
+            TypeInfo::Defined(ty) => Some(ty.name.position),
+            TypeInfo::Protocol(protocol) => Some(protocol.name.position),
+            TypeInfo::Parameter(name) => Some(name.position),
+            TypeInfo::BuiltIn(_) => None,
+        }
+    }
+
     pub fn get_type_annotation(&self) -> TypeAnnotation {
         match self {
             TypeInfo::Defined(ty) => Some(Type::Defined(ty.name.clone())),
             TypeInfo::BuiltIn(ty) => Some(Type::BuiltIn(ty.clone())),
+            TypeInfo::Protocol(protocol) => Some(Type::Defined(protocol.name.clone())),
+            TypeInfo::Parameter(name) => Some(Type::Defined(name.clone())),
+        }
+    }
+
+    /// Names of the type parameters of a generic type or protocol, empty otherwise
+    pub fn type_parameters(&self) -> &[String] {
+        match self {
+            TypeInfo::Defined(ty) => &ty.type_parameters,
+            TypeInfo::Protocol(protocol) => &protocol.type_parameters,
+            TypeInfo::BuiltIn(_) | TypeInfo::Parameter(_) => &[],
         }
     }
 }
@@ -67,6 +110,7 @@ pub struct DefinedTypeInfo {
     pub name: TypeName,
     pub members: HashMap<String, DefinitionInfo>,
     pub arguments_types: Vec<TypeAnnotation>,
+    pub type_parameters: Vec<String>,
 }
 
 impl DefinedTypeInfo {
@@ -79,8 +123,27 @@ impl DefinedTypeInfo {
             name,
             members,
             arguments_types,
+            type_parameters: Vec::new(),
         }
     }
+
+    pub fn with_type_parameters(mut self, type_parameters: Vec<String>) -> Self {
+        self.type_parameters = type_parameters;
+        self
+    }
+
+    /// # Description
+    /// The constructor of the type applied to `bindings` of its type parameters,
+    /// e.g. the one of `Stack<Number>` from the one of `Stack<T>`.
+    pub fn substitute(&self, bindings: &HashMap<String, Type>) -> Self {
+        let mut instance = self.clone();
+        instance.arguments_types = self
+            .arguments_types
+            .iter()
+            .map(|ty| substitute(ty, bindings))
+            .collect();
+        instance
+    }
 }
 
 impl Display for DefinedTypeInfo {
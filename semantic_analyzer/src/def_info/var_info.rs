@@ -1,6 +1,8 @@
+use std::collections::HashMap;
+
 use ast::{
     Identifier, TokenPosition, TypeName,
-    typing::{Type, TypeAnnotation},
+    typing::{Type, TypeAnnotation, substitute},
 };
 
 /// # Description
@@ -66,13 +68,32 @@ impl VarInfo {
         res
     }
 
-    pub fn new_self_instance(type_name: &TypeName) -> Self {
+    /// `self` of a generic type is the type applied to its own type parameters
+    pub fn new_self_instance(type_name: &TypeName, type_parameters: &[TypeName]) -> Self {
+        let ty = if type_parameters.is_empty() {
+            Type::Defined(type_name.clone())
+        } else {
+            Type::Generic(
+                type_name.clone(),
+                type_parameters
+                    .iter()
+                    .map(|parameter| Type::Defined(parameter.clone()))
+                    .collect(),
+            )
+        };
         VarInfo {
             name: "self".to_string(),
             is_defined: true,
             position: type_name.position.clone(),
-            ty: Some(Type::Defined(type_name.clone())),
+            ty: Some(ty),
             is_constant: true,
         }
     }
+
+    /// The variable with the types in `bindings` in place of the type parameters they are bound to
+    pub fn substitute(&self, bindings: &HashMap<String, Type>) -> Self {
+        let mut instance = self.clone();
+        instance.ty = substitute(&self.ty, bindings);
+        instance
+    }
 }
@@ -7,11 +7,26 @@ pub mod typing;
 pub mod graph_utils;
 
 pub mod def_info;
+
+pub mod suggestions;
+
+pub mod lint_config;
+
+pub mod module_scope;
 #[cfg(test)]
 pub mod test {
+    pub mod base_calls;
+    pub mod builtins;
     pub mod definitions;
+    pub mod generics;
     pub mod goblal_definitions;
     pub mod inheritance;
+    pub mod lambdas;
+    pub mod lints;
+    pub mod modules;
+    pub mod protocols;
+    pub mod suggestions;
     pub mod type_checking;
     pub mod type_definition;
+    pub mod type_tests;
 }
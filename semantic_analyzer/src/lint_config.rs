@@ -0,0 +1,85 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use error_handler::warning::warning::LINT_NAMES;
+
+/// Name that sets the level of every lint at once
+pub const ALL_LINTS: &str = "warnings";
+
+/// # Description
+///
+/// What is done with the warnings of a lint: they are dropped, reported as
+/// warnings, or reported as errors that make the analysis fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl FromStr for LintLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+impl Display for LintLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warn"),
+            LintLevel::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// # Description
+///
+/// The level of each lint, every lint warns by default
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    levels: HashMap<&'static str, LintLevel>,
+}
+
+impl LintConfig {
+    pub fn new() -> Self {
+        Self {
+            levels: LINT_NAMES
+                .iter()
+                .map(|lint| (*lint, LintLevel::Warn))
+                .collect(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Sets the level of `lint`, or of every lint if it is `ALL_LINTS`.
+    /// Levels set later override the ones set before
+    ///
+    /// # Returns
+    ///
+    /// `false` if there is no lint named `lint`
+    pub fn set(&mut self, lint: &str, level: LintLevel) -> bool {
+        if lint == ALL_LINTS {
+            self.levels.values_mut().for_each(|l| *l = level);
+            return true;
+        }
+        match self.levels.get_mut(lint) {
+            Some(l) => {
+                *l = level;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn level(&self, lint: &str) -> LintLevel {
+        self.levels.get(lint).copied().unwrap_or(LintLevel::Warn)
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use ast::{Import, token_position::TokenPosition};
+use error_handler::{
+    error::{
+        error::HulkError,
+        semantic::modules::{AmbiguousName, DefinitionNotImported},
+    },
+    source_map::SourceMap,
+};
+
+/// # Description
+///
+/// The modules of a program linked from several files, i.e. the files of its
+/// source map, and the modules each of them can use. The global definitions of
+/// a module can be used in the module itself and in the modules that import it
+/// directly, those of library modules (the standard library) can be used
+/// everywhere.
+///
+/// Each module has its own global names. A name defined in several modules is
+/// qualified with the name of each module that defines it (e.g. `Square` of
+/// `shapes.hulk` becomes `shapes.Square`), and its uses are qualified with the
+/// module they refer to: the module they are in if it defines the name, or
+/// else the imported module that does.
+///
+/// A program that was not linked from modules has an empty scope, in which every
+/// definition can be used anywhere.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleScope {
+    source_map: SourceMap,
+    /// Modules imported by each module, by file id
+    imports: HashMap<usize, HashSet<usize>>,
+    /// Modules that declare each global name, in the order they are declared
+    declarations: HashMap<String, Vec<usize>>,
+    /// Module and position of each global definition, by qualified name
+    definitions: HashMap<String, (usize, TokenPosition)>,
+}
+
+impl ModuleScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Description
+    ///
+    /// The scope of a program made up of the files in `source_map`, `imports`
+    /// being the resolved imports of all of its modules
+    pub fn from_imports(source_map: SourceMap, imports: &[Import]) -> Self {
+        let mut scope = Self {
+            source_map,
+            ..Self::default()
+        };
+        for import in imports {
+            let (Some(importer), Some(imported)) = (
+                scope.source_map.file_id_at(import.position()),
+                import.file_id,
+            ) else {
+                continue;
+            };
+            scope.imports.entry(importer).or_default().insert(imported);
+        }
+        scope
+    }
+
+    /// # Description
+    ///
+    /// Records that the global name `name` is declared at `position`, before the
+    /// names are qualified
+    pub fn declare(&mut self, name: &str, position: TokenPosition) {
+        let Some(module) = self.source_map.file_id_at(position.start) else {
+            return;
+        };
+        let modules = self.declarations.entry(name.to_string()).or_default();
+        if !modules.contains(&module) {
+            modules.push(module);
+        }
+    }
+
+    /// # Description
+    ///
+    /// Name of the global definition of `name` declared at `position`, qualified
+    /// with its module when other modules declare the same name
+    pub fn qualified_definition(&self, name: &str, position: TokenPosition) -> String {
+        match self.source_map.file_id_at(position.start) {
+            Some(module) if self.is_qualified(name) => self.qualify(name, module),
+            _ => name.to_string(),
+        }
+    }
+
+    /// # Description
+    ///
+    /// Name of the global definition that `name`, used at `position`, refers to:
+    /// the one of the module it is used in, or else the one of the imported
+    /// module that declares it. When no module it can see declares it, it refers
+    /// to the first declaration, which `check_visible` reports as not imported
+    pub fn resolve(&self, name: &str, position: TokenPosition) -> Result<String, HulkError> {
+        if !self.is_qualified(name) {
+            return Ok(name.to_string());
+        }
+        let declarations = &self.declarations[name];
+        let Some(user) = self.source_map.file_id_at(position.start) else {
+            return Ok(name.to_string());
+        };
+        if declarations.contains(&user) {
+            return Ok(self.qualify(name, user));
+        }
+
+        let imported = declarations
+            .iter()
+            .filter(|module| self.imports(user, **module))
+            .copied()
+            .collect::<Vec<_>>();
+        match imported.as_slice() {
+            [] => Ok(self.qualify(name, declarations[0])),
+            [module] => Ok(self.qualify(name, *module)),
+            _ => {
+                let modules = imported
+                    .iter()
+                    .map(|module| self.source_map.files()[*module].name.clone())
+                    .collect();
+                Err(AmbiguousName::new(name.to_string(), modules, position).into())
+            }
+        }
+    }
+
+    /// # Description
+    ///
+    /// Records the global definition of `name`, found at `position`, `name`
+    /// being already qualified
+    pub fn define(&mut self, name: &str, position: TokenPosition) {
+        let Some(module) = self.source_map.file_id_at(position.start) else {
+            return;
+        };
+        self.definitions
+            .entry(name.to_string())
+            .or_insert((module, position));
+    }
+
+    /// # Description
+    ///
+    /// Whether the global definition of `name` can be used at `position`.
+    /// Names that were not recorded (builtins, undefined names, local
+    /// variables, ...) are always visible
+    pub fn is_visible(&self, name: &str, position: TokenPosition) -> bool {
+        let Some(&(module, _)) = self.definitions.get(name) else {
+            return true;
+        };
+        let Some(user) = self.source_map.file_id_at(position.start) else {
+            return true;
+        };
+
+        module == user || self.imports(user, module) || self.source_map.files()[module].is_library
+    }
+
+    /// # Description
+    ///
+    /// Checks that the global definition of `name` can be used at `position`,
+    /// see `is_visible`
+    pub fn check_visible(&self, name: &str, position: TokenPosition) -> Result<(), HulkError> {
+        if self.is_visible(name, position) {
+            return Ok(());
+        }
+
+        let (module, definition) = self.definitions[name];
+        let module_name = self.source_map.files()[module].name.clone();
+        Err(DefinitionNotImported::new(
+            unqualified(name).to_string(),
+            module_name,
+            position,
+            definition,
+        )
+        .into())
+    }
+
+    /// # Description
+    ///
+    /// The names among `names` that can be used at `position`, without their
+    /// module, e.g. the candidates of a "did you mean" note
+    pub fn visible_names<'b>(&self, names: Vec<&'b str>, position: TokenPosition) -> Vec<&'b str> {
+        names
+            .into_iter()
+            .filter(|name| self.is_visible(name, position))
+            .map(unqualified)
+            .collect()
+    }
+
+    fn imports(&self, user: usize, module: usize) -> bool {
+        self.imports
+            .get(&user)
+            .is_some_and(|imports| imports.contains(&module))
+    }
+
+    /// Whether `name` is declared in several modules, none of them a library
+    fn is_qualified(&self, name: &str) -> bool {
+        self.declarations.get(name).is_some_and(|modules| {
+            modules.len() > 1
+                && modules
+                    .iter()
+                    .all(|module| !self.source_map.files()[*module].is_library)
+        })
+    }
+
+    fn qualify(&self, name: &str, module: usize) -> String {
+        let file = Path::new(&self.source_map.files()[module].name);
+        let module_name = file.file_stem().unwrap_or_default().to_string_lossy();
+        format!("{}.{}", module_name, name)
+    }
+}
+
+/// # Description
+///
+/// `name` without the module it is qualified with, if any
+pub fn unqualified(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
@@ -1,25 +1,45 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use ast::{VisitableDefinition, VisitableExpression, typing::TypeAnnotation};
+use ast::{
+    Definition, VisitableDefinition, VisitableExpression, token_position::TokenPosition,
+    typing::TypeAnnotation,
+};
+use error_handler::diagnostic::diagnostic::Span;
 use error_handler::error::error::HulkError;
 use error_handler::error::semantic::inheritance::InheritanceCycle;
+use error_handler::source_map::SourceMap;
+use error_handler::warning::warning::{HulkWarning, HulkWarningTrait};
 use generator::context::Context;
 
 use crate::def_info::{FuncInfo, TypeInfo, VarInfo};
 
 use crate::graph_utils::dfs::get_cycle;
+use crate::lint_config::{LintConfig, LintLevel};
+use crate::module_scope::ModuleScope;
 use crate::typing::sort_definitions::sort_definitions;
 use crate::visitors::type_visitor::TypeVisitor;
 use crate::visitors::{
-    AnnotationVisitor, GlobalDefinerVisitor, InheritanceVisitor, SemanticVisitor,
+    AnnotationVisitor, GlobalDefinerVisitor, InheritanceVisitor, LintVisitor, NamespaceVisitor,
+    SemanticVisitor,
 };
 
+#[derive(Clone)]
 pub struct SemanticAnalyzer {
     pub type_definitions: Context<TypeInfo>,
     pub type_hierarchy: HashMap<String, TypeAnnotation>,
+    pub protocol_hierarchy: HashMap<String, TypeAnnotation>,
     pub func_definitions: Context<FuncInfo>,
     pub var_definitions: Context<VarInfo>,
     pub errors: Vec<HulkError>,
+    pub warnings: Vec<HulkWarning>,
+    pub lint_config: LintConfig,
+    /// The files the program comes from, lints are not reported in library files
+    pub source_map: SourceMap,
+    /// The modules the program was linked from, empty for single file programs
+    pub modules: ModuleScope,
+    /// Types inferred for the top level expressions of the last program
+    /// analyzed, in order
+    pub expression_types: Vec<TypeAnnotation>,
 }
 
 impl SemanticAnalyzer {
@@ -27,21 +47,106 @@ impl SemanticAnalyzer {
         Self {
             type_definitions: Context::new_one_frame(),
             type_hierarchy: HashMap::new(),
+            protocol_hierarchy: HashMap::new(),
             var_definitions: Context::new_one_frame(),
             func_definitions: Context::new_one_frame(),
             errors: Vec::new(),
+            warnings: Vec::new(),
+            lint_config: LintConfig::new(),
+            source_map: SourceMap::new(),
+            modules: ModuleScope::new(),
+            expression_types: Vec::new(),
         }
     }
 
+    pub fn with_lint_config(mut self, lint_config: LintConfig) -> Self {
+        self.lint_config = lint_config;
+        self
+    }
+
+    pub fn with_source_map(mut self, source_map: SourceMap) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    pub fn with_modules(mut self, modules: ModuleScope) -> Self {
+        self.modules = modules;
+        self
+    }
+
+    /// # Description
+    /// Checks the program and annotates its AST with the types of its expressions. When
+    /// the program is well typed, the lints are run over the typed AST. Warnings are kept
+    /// in `warnings`, except those of denied lints, which are reported as errors.
     pub fn analyze_program_ast(
         &mut self,
         program: &mut ast::Program,
     ) -> Result<(), Vec<HulkError>> {
+        // functions without a return type annotation, read before the analysis infers them
+        let inferred_return_types = inferred_return_types(&program.definitions);
+
+        if self.check_program(program).is_ok() {
+            let mut lint_visitor = LintVisitor::new(&mut self.warnings, &inferred_return_types);
+            program
+                .definitions
+                .iter_mut()
+                .for_each(|x| x.accept(&mut lint_visitor));
+            program
+                .expressions
+                .iter_mut()
+                .for_each(|x| x.accept(&mut lint_visitor));
+        }
+        self.apply_lint_levels();
+
+        if self.errors.len() > 0 {
+            return Err(self.errors.clone());
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    /// Drops the warnings of allowed lints and those in the standard library, and turns
+    /// the warnings of denied lints into errors.
+    fn apply_lint_levels(&mut self) {
+        for warning in std::mem::take(&mut self.warnings) {
+            let position = warning.get_position();
+            if self
+                .source_map
+                .file_at(position)
+                .is_some_and(|file| file.is_library)
+            {
+                continue;
+            }
+            match self.lint_config.level(warning.lint()) {
+                LintLevel::Allow => {}
+                LintLevel::Warn => self.warnings.push(warning),
+                LintLevel::Deny => self.errors.push(HulkError::DeniedWarning(warning)),
+            }
+        }
+    }
+
+    fn check_program(&mut self, program: &mut ast::Program) -> Result<(), Vec<HulkError>> {
+        // Qualify the global names that several modules declare with their module
+        for definition in &program.definitions {
+            if let Some((name, position)) = declared_name(definition) {
+                self.modules.declare(name, position);
+            }
+        }
+        let mut namespace_visitor = NamespaceVisitor::new(&self.modules, &mut self.errors);
+        for definition in &mut program.definitions {
+            definition.accept(&mut namespace_visitor);
+        }
+        for expression in &mut program.expressions {
+            expression.accept(&mut namespace_visitor);
+        }
+
         // Define types in the global context
         let mut type_definer_visitor = GlobalDefinerVisitor::new(
             &mut self.type_definitions,
             &mut self.var_definitions,
             &mut self.func_definitions,
+            &mut self.modules,
             &mut self.errors,
         );
 
@@ -54,7 +159,9 @@ impl SemanticAnalyzer {
         // Define inheritance relationships
         let mut inheritance_visitor = InheritanceVisitor::new(
             &mut self.type_hierarchy,
+            &mut self.protocol_hierarchy,
             &mut self.type_definitions,
+            &self.modules,
             &mut self.errors,
         );
         for definition in &mut program.definitions {
@@ -63,7 +170,15 @@ impl SemanticAnalyzer {
 
         // Check for cycles in the inheritance graph
         if let Some(cycle) = get_cycle(&inheritance_visitor.type_hierarchy) {
-            self.errors.push(InheritanceCycle::new(cycle, 0).into());
+            self.errors
+                .push(inheritance_cycle(cycle, &program.definitions).into());
+            return Err(self.errors.clone());
+        }
+
+        // Protocol extensions must not form cycles either
+        if let Some(cycle) = get_cycle(&inheritance_visitor.protocol_hierarchy) {
+            self.errors
+                .push(inheritance_cycle(cycle, &program.definitions).into());
             return Err(self.errors.clone());
         }
 
@@ -77,6 +192,7 @@ impl SemanticAnalyzer {
         let mut annotation_visitor = AnnotationVisitor::new(
             &mut self.type_definitions,
             &mut self.func_definitions,
+            &self.modules,
             &mut self.errors,
         );
 
@@ -91,9 +207,12 @@ impl SemanticAnalyzer {
         let mut semantic_visitor = SemanticVisitor::new(
             &mut self.type_definitions,
             &mut self.type_hierarchy,
+            &mut self.protocol_hierarchy,
             &mut self.var_definitions,
             &mut self.func_definitions,
+            &self.modules,
             &mut self.errors,
+            &mut self.warnings,
         );
 
         // visit constants first to ensure they are defined before use
@@ -113,9 +232,12 @@ impl SemanticAnalyzer {
             definition.accept(&mut semantic_visitor);
         }
 
-        for expression in &mut program.expressions {
-            expression.accept(&mut semantic_visitor);
-        }
+        let expression_types = program
+            .expressions
+            .iter_mut()
+            .map(|expression| expression.accept(&mut semantic_visitor))
+            .collect();
+        self.expression_types = expression_types;
 
         let mut type_visitor = TypeVisitor::new(&mut self.errors);
         program
@@ -134,3 +256,86 @@ impl SemanticAnalyzer {
         Ok(())
     }
 }
+
+/// # Description
+/// Builds the error of an inheritance `cycle` of types or protocols, located
+/// at the definition of its first member and labeled with where each member
+/// names its parent.
+fn inheritance_cycle(cycle: Vec<String>, definitions: &[Definition]) -> InheritanceCycle {
+    // Where each type or protocol is defined, and where it names its parent
+    let positions: HashMap<&str, (TokenPosition, Option<TokenPosition>)> = definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::TypeDef(type_def) => Some((
+                type_def.name.id.as_str(),
+                (
+                    type_def.name.position,
+                    type_def
+                        .inheritance_indicator
+                        .as_ref()
+                        .map(|inheritance| inheritance.parent_name.position),
+                ),
+            )),
+            Definition::ProtocolDef(protocol_def) => Some((
+                protocol_def.name.id.as_str(),
+                (
+                    protocol_def.name.position,
+                    protocol_def
+                        .extension_indicator
+                        .as_ref()
+                        .map(|extension| extension.extendee_name.position),
+                ),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    let position = cycle
+        .first()
+        .and_then(|name| positions.get(name.as_str()))
+        .map(|(position, _)| Span::from(*position))
+        .unwrap_or(Span::from(0));
+    let parent_positions = cycle[..cycle.len().saturating_sub(1)]
+        .iter()
+        .filter_map(|name| positions.get(name.as_str()).and_then(|(_, parent)| *parent))
+        .map(Span::from)
+        .collect();
+
+    InheritanceCycle::new(cycle, position).with_parent_positions(parent_positions)
+}
+
+/// # Description
+/// Positions of the names of the functions and methods without a return type annotation.
+fn inferred_return_types(definitions: &[Definition]) -> HashSet<usize> {
+    let mut positions = HashSet::new();
+    for definition in definitions {
+        let functions = match definition {
+            Definition::TypeDef(type_def) => type_def.function_member_defs.iter().collect(),
+            Definition::FunctionDef(function_def) => vec![&function_def.function_def],
+            _ => Vec::new(),
+        };
+        positions.extend(
+            functions
+                .into_iter()
+                .filter(|function| function.identifier.info.ty.is_none())
+                .map(|function| function.identifier.position.start),
+        );
+    }
+    positions
+}
+
+/// # Description
+/// The global name a definition declares and where it does, constants are not global names.
+fn declared_name(definition: &Definition) -> Option<(&str, TokenPosition)> {
+    match definition {
+        Definition::TypeDef(type_def) => Some((&type_def.name.id, type_def.name.position)),
+        Definition::ProtocolDef(protocol_def) => {
+            Some((&protocol_def.name.id, protocol_def.name.position))
+        }
+        Definition::FunctionDef(function_def) => Some((
+            &function_def.function_def.identifier.id,
+            function_def.function_def.identifier.position,
+        )),
+        Definition::ConstantDef(_) => None,
+    }
+}
@@ -0,0 +1,38 @@
+/// # Description
+///
+/// Levenshtein distance between `a` and `b`, counted in characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// # Description
+///
+/// The candidate closest to `name`, if it is close enough to be a typo of it:
+/// at most one edit for every three characters of `name`. Ties go to the
+/// first candidate in alphabetical order, so suggestions are deterministic
+pub fn closest_name<'a, I>(name: &str, candidates: I) -> Option<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name && !candidate.starts_with('$'))
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate.to_string())
+}
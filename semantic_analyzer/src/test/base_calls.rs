@@ -0,0 +1,100 @@
+use ast::typing::{BuiltInType, Type};
+use error_handler::error::{error::HulkError, semantic::semantic_error::SemanticError};
+use generated_parser::ProgramParser;
+
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+const HIERARCHY: &str = "
+    type Shape() {
+        area(): Number => 0;
+        scaled(factor: Number): Number => factor;
+    }
+    type Polygon() inherits Shape() { }
+";
+
+fn analyze(program: &str) -> Result<ast::Program, Vec<HulkError>> {
+    let p = ProgramParser::new();
+    let mut answ = p.parse(&format!("{}{}", HIERARCHY, program)).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer.analyze_program_ast(&mut answ)?;
+    Ok(answ)
+}
+
+/// The body of the only method of `Square`
+fn square_method_body(answ: &ast::Program) -> &ast::Expression {
+    let square = answ
+        .definitions
+        .iter()
+        .find_map(|d| d.as_type_def().filter(|t| t.name.id == "Square"))
+        .unwrap();
+    let body = square.function_member_defs[0].body.as_arrow_expression();
+    &body.unwrap().expression
+}
+
+#[test]
+fn base_call_has_the_parent_return_type() {
+    let answ = analyze("type Square() inherits Shape() { area(): Number => base() + 1; }").unwrap();
+
+    let body = square_method_body(&answ);
+    let base_call = body.as_bin_op().unwrap().lhs.as_base_call().unwrap();
+    assert_eq!(base_call.method.as_deref(), Some("area"));
+    assert_eq!(base_call.parent_type.as_ref().unwrap().to_string(), "Shape");
+    assert_eq!(base_call.ty, Some(Type::BuiltIn(BuiltInType::Number)));
+}
+
+#[test]
+fn base_call_finds_inherited_implementations() {
+    let answ = analyze("type Square() inherits Polygon() { area(): Number => base(); }").unwrap();
+
+    let base_call = square_method_body(&answ).as_base_call().unwrap();
+    assert_eq!(
+        base_call.parent_type.as_ref().unwrap().to_string(),
+        "Polygon"
+    );
+}
+
+#[test]
+fn base_call_arguments_are_checked() {
+    let errors = analyze(
+        "type Square() inherits Shape() { scaled(factor: Number): Number => base(\"two\"); }",
+    )
+    .unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0],
+        HulkError::SemanticError(SemanticError::FuncParamInvalidType(_))
+    ));
+}
+
+#[test]
+fn base_call_can_be_captured_by_lambdas() {
+    let answ = analyze(
+        "type Square() inherits Shape() {
+            area(): Number => let f = () => base() in f();
+        }",
+    );
+
+    assert!(answ.is_ok(), "Errors: {:?}", answ.err());
+}
+
+#[test]
+fn base_call_outside_a_method() {
+    let errors = analyze("function area(): Number => base();").unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code(), "E0343");
+}
+
+#[test]
+fn base_call_without_a_parent_implementation() {
+    let errors =
+        analyze("type Square() inherits Shape() { side(): Number => base(); }").unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0],
+        HulkError::SemanticError(SemanticError::BaseMethodNotFound(_))
+    ));
+}
@@ -0,0 +1,65 @@
+use ast::typing::{BuiltInType, Type};
+use error_handler::error_handler::ErrorHandler;
+use generated_parser::ProgramParser;
+
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+fn analyze_and_get_errors(program: &str) -> Vec<String> {
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+    error_handler.get_raw_errors()
+}
+
+#[test]
+fn builtin_calls_have_their_return_type() {
+    let program = r#"
+        let x = sqrt(2) in let s = substring("hello", 1, 3) in { x + length(s); };
+    "#;
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let let_in = answ.expressions[0].as_let_in().unwrap();
+    assert_eq!(
+        let_in.assignment.identifier.info.ty,
+        Some(Type::BuiltIn(BuiltInType::Number))
+    );
+}
+
+#[test]
+fn builtin_with_wrong_argument_type() {
+    let errors = analyze_and_get_errors("length(3);");
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("length"), "{}", errors[0]);
+}
+
+#[test]
+fn builtin_with_wrong_argument_count() {
+    let errors = analyze_and_get_errors("substring(\"abc\", 1);");
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("substring"), "{}", errors[0]);
+}
+
+#[test]
+fn builtins_can_not_be_redefined() {
+    let errors = analyze_and_get_errors(
+        "
+        function sqrt(x: Number): Number => x;
+        print(sqrt(4));
+        ",
+    );
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("sqrt"), "{}", errors[0]);
+}
@@ -0,0 +1,250 @@
+use ast::typing::{BuiltInType, Type};
+use error_handler::error_handler::ErrorHandler;
+use generated_parser::ProgramParser;
+
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+const BOX: &str = r"
+    type Box<T>(value: T) {
+        value = value;
+        get(): T => self.value;
+        set(value: T): T => self.value := value;
+    }
+";
+
+fn errors_of(program: &str) -> Vec<String> {
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+    error_handler.get_raw_errors()
+}
+
+#[test]
+fn generic_type_instantiation() {
+    let program = format!(
+        "{}
+        let b = new Box<Number>(3) in b.get();",
+        BOX
+    );
+    let p = ProgramParser::new();
+    let mut answ = p.parse(&program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let let_in = answ.expressions[0].as_let_in().unwrap();
+    let (name, arguments) = let_in
+        .assignment
+        .identifier
+        .info
+        .ty
+        .as_ref()
+        .unwrap()
+        .as_generic()
+        .unwrap();
+    assert_eq!(name.id, "Box");
+    assert_eq!(arguments, &vec![Type::BuiltIn(BuiltInType::Number)]);
+    assert_eq!(
+        semantic_analyzer.expression_types[0],
+        Some(Type::BuiltIn(BuiltInType::Number))
+    );
+}
+
+#[test]
+fn inferred_type_arguments() {
+    let program = format!(
+        "{}
+        let b: Box<String> = new Box('hello') in b.set('world');",
+        BOX
+    )
+    .replace('\'', "\"");
+    let p = ProgramParser::new();
+    let mut answ = p.parse(&program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let new_expr = answ.expressions[0]
+        .as_let_in()
+        .unwrap()
+        .assignment
+        .rhs
+        .as_new_expression()
+        .unwrap();
+    assert_eq!(
+        new_expr.type_arguments,
+        vec![Type::BuiltIn(BuiltInType::String)]
+    );
+}
+
+#[test]
+fn generic_function_call() {
+    let program = r"
+        function first<T>(xs: T*): T => xs[0];
+
+        first([1, 2, 3]) + 1;
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let call = answ.expressions[0]
+        .as_bin_op()
+        .unwrap()
+        .lhs
+        .as_function_call()
+        .unwrap();
+    assert_eq!(
+        call.type_arguments,
+        vec![Type::BuiltIn(BuiltInType::Number)]
+    );
+}
+
+#[test]
+fn type_arguments_are_invariant() {
+    let program = format!(
+        "{}
+        let b: Box<String> = new Box(3) in b;",
+        BOX
+    );
+
+    assert_eq!(
+        errors_of(&program),
+        vec!["Semantic Error: Cannot assign `Box<Number>` to `Box<String>`.".to_string()]
+    );
+}
+
+#[test]
+fn type_parameters_are_opaque() {
+    let program = r"
+        function increment<T>(x: T): Number => x + 1;
+
+        increment(3);
+    ";
+
+    assert_eq!(
+        errors_of(program),
+        vec!["Semantic Error: Cannot apply `+` to operands of type `T` and `Number`.".to_string()]
+    );
+}
+
+#[test]
+fn type_parameters_are_scoped_to_their_definition() {
+    let program = format!(
+        "{}
+        let x: T = 3 in x;",
+        BOX
+    );
+
+    assert_eq!(
+        errors_of(&program),
+        vec!["Semantic Error: Type or protocol `T` is not defined.".to_string()]
+    );
+}
+
+#[test]
+fn generic_type_without_type_arguments() {
+    let program = format!(
+        "{}
+        let b: Box = new Box(3) in b;",
+        BOX
+    );
+
+    assert_eq!(
+        errors_of(&program),
+        vec![
+            "Semantic Error: `Box` has 1 type parameters, but 0 type arguments were provided."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn uninferable_type_parameter() {
+    let program = r"
+        function ignore<T>(n: Number): Number => n;
+
+        ignore(3);
+    ";
+
+    assert_eq!(
+        errors_of(program),
+        vec![
+            "Semantic Error: Cannot infer type parameter `T` of `ignore` from its arguments."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn generic_protocol_conformance() {
+    let program = format!(
+        "{}
+        protocol Container<T> {{
+            get(): T;
+        }}
+
+        function unwrap(c: Container<Number>): Number => c.get();
+
+        unwrap(new Box(3));
+        unwrap(new Box('three'));",
+        BOX
+    )
+    .replace('\'', "\"");
+
+    assert_eq!(
+        errors_of(&program),
+        vec![
+            "Semantic Error: Function `unwrap` expects parameter `0` of type `Container<Number>`, but got `Box<String>`."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn type_parameter_clashes_with_type() {
+    let program = r"
+        type T {}
+
+        type Box<T>(value: T) {
+            value = value;
+        }
+
+        3;
+    ";
+
+    assert_eq!(
+        errors_of(program),
+        vec!["Semantic Error: Already exists a type or protocol `T`.".to_string()]
+    );
+}
+
+#[test]
+fn inheriting_from_generic_type() {
+    let program = format!(
+        "{}
+        type NumberBox inherits Box {{}}
+
+        3;",
+        BOX
+    );
+
+    assert_eq!(
+        errors_of(&program),
+        vec![
+            "Semantic Error: `Box` has 1 type parameters, but 0 type arguments were provided."
+                .to_string()
+        ]
+    );
+}
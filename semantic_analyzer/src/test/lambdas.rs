@@ -0,0 +1,195 @@
+use ast::typing::{BuiltInType, FunctorType, Type};
+use error_handler::error_handler::ErrorHandler;
+use generated_parser::ProgramParser;
+
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+#[test]
+fn lambda_type_is_inferred() {
+    let program = r"
+        let f = (x: Number) => x * 2 in f(3);
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let let_in = answ.expressions[0].as_let_in().unwrap();
+    assert_eq!(
+        let_in.assignment.identifier.info.ty,
+        Some(Type::Functor(FunctorType::new(
+            vec![Some(Type::BuiltIn(BuiltInType::Number))],
+            Some(Type::BuiltIn(BuiltInType::Number)),
+        )))
+    );
+    assert_eq!(
+        let_in.body.as_function_call().unwrap().identifier.info.ty,
+        Some(Type::BuiltIn(BuiltInType::Number))
+    );
+}
+
+#[test]
+fn lambda_captures_outer_variables() {
+    let program = r"
+        let k = 3, f = (x: Number) => x * k in f(2);
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let lambda = answ.expressions[0]
+        .as_let_in()
+        .unwrap()
+        .body
+        .as_let_in()
+        .unwrap()
+        .assignment
+        .rhs
+        .as_lambda()
+        .unwrap();
+    let captures: Vec<&str> = lambda.captures.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(captures, vec!["k"]);
+    assert_eq!(
+        lambda.captures[0].info.ty,
+        Some(Type::BuiltIn(BuiltInType::Number))
+    );
+}
+
+#[test]
+fn nested_lambda_captures_propagate() {
+    let program = r"
+        let a = 1 in
+            let f = (x: Number) => (y: Number) => x + y + a in
+                let g = f(1) in g(2);
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let outer = answ.expressions[0]
+        .as_let_in()
+        .unwrap()
+        .body
+        .as_let_in()
+        .unwrap()
+        .assignment
+        .rhs
+        .as_lambda()
+        .unwrap();
+    let inner = outer.body.as_lambda().unwrap();
+
+    let outer_captures: Vec<&str> = outer.captures.iter().map(|c| c.id.as_str()).collect();
+    let inner_captures: Vec<&str> = inner.captures.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(outer_captures, vec!["a"]);
+    assert_eq!(inner_captures, vec!["x", "a"]);
+}
+
+#[test]
+fn lambda_passed_to_function() {
+    let program = r"
+        function apply(f: (Number) -> Number, x: Number): Number => f(x);
+
+        apply((y: Number) => y + 1, 2);
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+}
+
+#[test]
+fn functor_types_are_contravariant_in_parameters() {
+    let program = r"
+        type Animal {}
+        type Dog inherits Animal {}
+
+        let f: (Dog) -> Animal = (a: Animal): Dog => new Dog() in f(new Dog());
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+}
+
+#[test]
+fn functor_types_are_not_covariant_in_parameters() {
+    let program = r"
+        type Animal {}
+        type Dog inherits Animal {}
+
+        let f: (Animal) -> Animal = (d: Dog) => d in 1;
+    ";
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec!["Semantic Error: Cannot assign `(Dog) -> Dog` to `(Animal) -> Animal`.".to_string()]
+    );
+}
+
+#[test]
+fn lambda_argument_type_mismatch() {
+    let program = r"
+        let f = (x: Number) => x in f('a');
+    "
+    .replace('\'', "\"");
+    let program = program.as_str();
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec![
+            "Semantic Error: Function `f` expects parameter `0` of type `Number`, but got `String`."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn lambda_return_type_mismatch() {
+    let program = r"
+        (x: Number): String => x;
+    ";
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec!["Semantic Error: Lambda returns `String` but `Number` was found.".to_string()]
+    );
+}
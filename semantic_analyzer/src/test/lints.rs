@@ -0,0 +1,161 @@
+use error_handler::error::error::HulkError;
+use error_handler::source_map::SourceMap;
+use error_handler::warning::warning::HulkWarning;
+use generated_parser::ProgramParser;
+
+use crate::lint_config::{LintConfig, LintLevel};
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+fn analyze_with(program: &str, lint_config: LintConfig) -> SemanticAnalyzer {
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+    let mut semantic_analyzer = SemanticAnalyzer::new().with_lint_config(lint_config);
+    let _ = semantic_analyzer.analyze_program_ast(&mut answ);
+    semantic_analyzer
+}
+
+fn lints(program: &str) -> Vec<&'static str> {
+    let semantic_analyzer = analyze_with(program, LintConfig::new());
+    assert!(
+        semantic_analyzer.errors.is_empty(),
+        "Errors: {:?}",
+        semantic_analyzer.errors
+    );
+    semantic_analyzer
+        .warnings
+        .iter()
+        .map(|warning| warning.lint())
+        .collect()
+}
+
+#[test]
+fn clean_program_has_no_warnings() {
+    let program = "
+        function add(a: Number, b: Number): Number => a + b;
+        let x = 1 in print(add(x, 2));
+    ";
+
+    assert!(lints(program).is_empty());
+}
+
+#[test]
+fn unused_variables_and_parameters() {
+    let semantic_analyzer = analyze_with(
+        "
+        function first(a: Number, b: Number): Number => a;
+        let x = 1 in print(first(3, 4));
+        ",
+        LintConfig::new(),
+    );
+
+    let unused = semantic_analyzer
+        .warnings
+        .iter()
+        .filter_map(|warning| match warning {
+            HulkWarning::UnusedVariable(w) => Some((w.name.as_str(), w.is_parameter)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(unused, vec![("b", true), ("x", false)]);
+}
+
+#[test]
+fn variables_used_in_lambdas_and_calls_are_used() {
+    let program = "
+        let n = 2, f = (x: Number): Number => x * n in print(f(1));
+    ";
+
+    assert!(lints(program).is_empty());
+}
+
+#[test]
+fn assigning_a_variable_does_not_use_it() {
+    assert_eq!(lints("let x = 1 in { x := 2; };"), vec!["unused-variables"]);
+}
+
+#[test]
+fn unreachable_code_after_return() {
+    let program = "
+        function f(): Number {
+            return 1;
+            print(2);
+        }
+        print(f());
+    ";
+
+    assert_eq!(lints(program), vec!["unreachable-code"]);
+}
+
+#[test]
+fn shadowed_variables() {
+    assert_eq!(
+        lints("let x = 1 in let x = x + 1 in print(x);"),
+        vec!["shadowed-variables"]
+    );
+}
+
+#[test]
+fn constant_if_conditions() {
+    assert_eq!(
+        lints("print(if (false) 1 else 2);"),
+        vec!["constant-conditions"]
+    );
+}
+
+#[test]
+fn self_assignments() {
+    let program = "
+        type Counter() {
+            count = 0;
+            reset(): Number { self.count := self.count; }
+        }
+        let x = 1 in { x := x; print(x); };
+    ";
+
+    assert_eq!(lints(program), vec!["self-assignments", "self-assignments"]);
+}
+
+#[test]
+fn allowed_lints_are_dropped() {
+    let mut lint_config = LintConfig::new();
+    lint_config.set("unused-variables", LintLevel::Allow);
+
+    let semantic_analyzer = analyze_with("let x = 1 in print(2);", lint_config);
+
+    assert!(semantic_analyzer.warnings.is_empty());
+    assert!(semantic_analyzer.errors.is_empty());
+}
+
+#[test]
+fn denied_lints_are_errors() {
+    let mut lint_config = LintConfig::new();
+    lint_config.set("warnings", LintLevel::Deny);
+    lint_config.set("shadowed-variables", LintLevel::Warn);
+
+    let semantic_analyzer = analyze_with("let x = 1 in let x = 2 in print(3);", lint_config);
+
+    assert_eq!(semantic_analyzer.warnings.len(), 1);
+    assert_eq!(semantic_analyzer.warnings[0].lint(), "shadowed-variables");
+    assert_eq!(semantic_analyzer.errors.len(), 2);
+    assert!(
+        semantic_analyzer
+            .errors
+            .iter()
+            .all(|error| matches!(error, HulkError::DeniedWarning(_)))
+    );
+}
+
+#[test]
+fn lints_are_not_reported_in_library_files() {
+    let mut source_map = SourceMap::new();
+    source_map.add_library("std.hulk", "function lib(a: Number): Number => 1;");
+    source_map.add_file("main.hulk", "let x = 1 in print(lib(2));");
+
+    let mut program = ProgramParser::new().parse(source_map.content()).unwrap();
+    let mut semantic_analyzer = SemanticAnalyzer::new().with_source_map(source_map);
+    let _ = semantic_analyzer.analyze_program_ast(&mut program);
+
+    assert_eq!(semantic_analyzer.warnings.len(), 1);
+    assert_eq!(semantic_analyzer.warnings[0].lint(), "unused-variables");
+    assert!(semantic_analyzer.warnings[0].message().contains("`x`"));
+}
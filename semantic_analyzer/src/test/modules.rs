@@ -0,0 +1,265 @@
+use crate::{module_scope::ModuleScope, semantic_analyzer::SemanticAnalyzer};
+use ast::Program;
+use error_handler::{error::error::HulkError, error_handler::ErrorHandler, source_map::SourceMap};
+use generated_parser::ProgramParser;
+
+/// # Description
+///
+/// Analyzes the modules `(name, text)` linked in order, the first one being a
+/// library module when `library` is set. Each import names the module by its
+/// name as written in `modules`
+fn analyze_modules(library: bool, modules: &[(&str, &str)]) -> Vec<String> {
+    let (errors, source_map) = module_errors(library, modules);
+    let mut error_handler = ErrorHandler::new(source_map.content(), 0);
+    error_handler.extend_errors(errors);
+    error_handler.get_raw_errors()
+}
+
+/// The errors found by `analyze_modules`, with the source map of the modules
+fn module_errors(library: bool, modules: &[(&str, &str)]) -> (Vec<HulkError>, SourceMap) {
+    let mut source_map = SourceMap::new();
+    for (index, (name, text)) in modules.iter().enumerate() {
+        if library && index == 0 {
+            source_map.add_library(name, text);
+        } else {
+            source_map.add_file(name, text);
+        }
+    }
+
+    let p = ProgramParser::new();
+    let programs = (0..modules.len())
+        .map(|file_id| {
+            let start = source_map.files()[file_id].start;
+            let mut program = p
+                .parse_at(source_map.file_text(file_id), start)
+                .unwrap()
+                .with_file_id(file_id);
+            for import in &mut program.imports {
+                let target = import.target_position().start;
+                let name = &source_map.content()[target..];
+                import.file_id = modules
+                    .iter()
+                    .position(|(module, _)| name.starts_with(module.trim_end_matches(".hulk")));
+            }
+            program
+        })
+        .collect();
+    let mut program = Program::link(programs);
+
+    let mut semantic_analyzer = SemanticAnalyzer::new().with_modules(ModuleScope::from_imports(
+        source_map.clone(),
+        &program.imports,
+    ));
+    let _ = semantic_analyzer.analyze_program_ast(&mut program);
+
+    (semantic_analyzer.errors, source_map)
+}
+
+#[test]
+fn imported_definitions() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("shapes.hulk", "type Square(side: Number) { side = side; }"),
+            (
+                "main.hulk",
+                "import shapes;\nfunction make(): Square => new Square(2);\nmake();",
+            ),
+        ],
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn definitions_not_imported() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("numbers.hulk", "function two(): Number => 2;"),
+            ("main.hulk", "print(two());"),
+        ],
+    );
+    assert_eq!(
+        errors,
+        vec![
+            "Semantic Error: `two` is defined in `numbers.hulk`, which is not imported."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn imports_are_not_transitive() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("numbers.hulk", "function two(): Number => 2;"),
+            (
+                "four.hulk",
+                "import numbers;\nfunction four(): Number => two() * 2;",
+            ),
+            ("main.hulk", "import four;\nprint(four() + two());"),
+        ],
+    );
+    assert_eq!(
+        errors,
+        vec![
+            "Semantic Error: `two` is defined in `numbers.hulk`, which is not imported."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn library_definitions_are_visible() {
+    let errors = analyze_modules(
+        true,
+        &[
+            ("std.hulk", "function two(): Number => 2;"),
+            ("main.hulk", "print(two());"),
+        ],
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn inheriting_needs_an_import() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("shapes.hulk", "type Shape { area(): Number => 0; }"),
+            ("main.hulk", "type Square inherits Shape { }\nprint(1);"),
+        ],
+    );
+    assert_eq!(
+        errors,
+        vec![
+            "Semantic Error: `Shape` is defined in `shapes.hulk`, which is not imported."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn annotations_need_an_import() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("shapes.hulk", "type Shape { area(): Number => 0; }"),
+            (
+                "main.hulk",
+                "function area(s: Shape): Number => 0;\nprint(1);",
+            ),
+        ],
+    );
+    assert!(
+        errors[0].contains("`Shape` is defined in `shapes.hulk`"),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn modules_can_define_the_same_name() {
+    let errors = analyze_modules(
+        false,
+        &[
+            (
+                "circles.hulk",
+                "function area(r: Number): Number => 3 * r * r;",
+            ),
+            (
+                "squares.hulk",
+                "function area(side: String): String => side @ side;",
+            ),
+            ("main.hulk", "import circles;\nprint(area(2) + 1);"),
+        ],
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn own_definitions_come_before_imported_ones() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("shapes.hulk", "type Shape { name(): String => \"shape\"; }"),
+            (
+                "main.hulk",
+                "import shapes;\ntype Shape { sides(): Number => 4; }\nprint(new Shape().sides());",
+            ),
+        ],
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn qualified_names_in_annotations() {
+    let errors = analyze_modules(
+        false,
+        &[
+            (
+                "a.hulk",
+                "type Point(x: Number) { x = x; getX(): Number => self.x; }",
+            ),
+            ("b.hulk", "type Point { }"),
+            (
+                "main.hulk",
+                "import a;\nfunction x(p: Point): Number => p.getX();\nprint(x(new Point(1)));",
+            ),
+        ],
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn names_imported_from_several_modules_are_ambiguous() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("a.hulk", "function one(): Number => 1;"),
+            ("b.hulk", "function one(): Number => 1;"),
+            ("main.hulk", "import a;\nimport b;\nprint(one());"),
+        ],
+    );
+    assert_eq!(
+        errors,
+        vec![
+            "Semantic Error: `one` is defined in several imported modules: `a.hulk`, `b.hulk`."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn same_name_not_imported() {
+    let errors = analyze_modules(
+        false,
+        &[
+            ("a.hulk", "function one(): Number => 1;"),
+            ("b.hulk", "function one(): Number => 1;"),
+            ("main.hulk", "print(one());"),
+        ],
+    );
+    assert_eq!(
+        errors,
+        vec!["Semantic Error: `one` is defined in `a.hulk`, which is not imported.".to_string()]
+    );
+}
+
+#[test]
+fn suggestions_only_name_visible_definitions() {
+    let (errors, _) = module_errors(
+        false,
+        &[
+            ("numbers.hulk", "function total(): Number => 2;"),
+            (
+                "main.hulk",
+                "function count(): Number => 1;\nprint(totl());",
+            ),
+        ],
+    );
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].notes().is_empty(), "{:?}", errors[0].notes());
+}
@@ -0,0 +1,401 @@
+use ast::typing::{BuiltInType, Type};
+use error_handler::{error::error::HulkErrorTrait, error_handler::ErrorHandler};
+use generated_parser::ProgramParser;
+
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+#[test]
+fn protocol_definition() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+
+        protocol Equatable extends Hashable {
+            equals(other: Equatable): Boolean;
+        }
+
+        3;
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let equatable = semantic_analyzer
+        .type_definitions
+        .get_value("Equatable")
+        .unwrap()
+        .as_protocol()
+        .unwrap();
+    assert_eq!(equatable.parent.as_ref().unwrap().id, "Hashable");
+    assert!(equatable.methods.contains_key("equals"));
+
+    assert!(!semantic_analyzer.type_hierarchy.contains_key("Hashable"));
+    assert_eq!(
+        semantic_analyzer
+            .protocol_hierarchy
+            .get("Equatable")
+            .cloned(),
+        Some(Some(Type::Defined(equatable.parent.clone().unwrap())))
+    );
+}
+
+#[test]
+fn structural_conformance() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+
+        type Point(x: Number, y: Number) {
+            x = x;
+            y = y;
+            hash(): Number => self.x * 31 + self.y;
+        }
+
+        function hash_twice(h: Hashable): Number => h.hash() * 2;
+
+        let h: Hashable = new Point(1, 2) in hash_twice(h);
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let let_in = answ.expressions[0].as_let_in().unwrap();
+    assert_eq!(
+        let_in.assignment.identifier.info.ty,
+        Some(Type::Defined(ast::TypeName {
+            id: "Hashable".to_string(),
+            position: let_in.assignment.identifier.position.clone(),
+        }))
+    );
+}
+
+#[test]
+fn conformance_through_inheritance_and_extension() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+
+        protocol Equatable extends Hashable {
+            equals(other: Number): Boolean;
+        }
+
+        type Base {
+            hash(): Number => 42;
+        }
+
+        type Derived inherits Base {
+            equals(other: Number): Boolean => true;
+        }
+
+        function check(e: Equatable): Boolean => e.equals(1) && e.hash() == 42;
+        function as_hashable(e: Equatable): Hashable => e;
+
+        check(new Derived());
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+}
+
+#[test]
+fn non_conforming_argument() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+
+        type Point {
+            hash(): String => 'point';
+        }
+
+        function hash_twice(h: Hashable): Number => h.hash() * 2;
+
+        hash_twice(new Point());
+    "
+    .replace('\'', "\"");
+    let program = program.as_str();
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec![
+            "Semantic Error: Function `hash_twice` expects parameter `0` of type `Hashable`, but got `Point`."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn protocol_typed_constructor_parameter() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+
+        type Point {
+            hash(): Number => 1;
+        }
+
+        type Colored inherits Point {}
+
+        type Holder(h: Hashable, p: Point) {
+            h = h;
+            p = p;
+        }
+
+        new Holder(new Point(), new Colored());
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+}
+
+#[test]
+fn non_conforming_constructor_argument() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+
+        type Point {
+            hash(): String => 'point';
+        }
+
+        type Holder(h: Hashable) {
+            h = h;
+        }
+
+        new Holder(new Point());
+    "
+    .replace('\'', "\"");
+    let program = program.as_str();
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    // the error points at the argument
+    assert_eq!(
+        semantic_analyzer.errors[0].get_position(),
+        program.find("new Point()").unwrap()
+    );
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec![
+            "Semantic Error: Type `Holder` expects parameter `0` of type `Hashable`, but got `Point`."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn protocol_does_not_conform_to_type() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+
+        type Point {
+            hash(): Number => 1;
+        }
+
+        let h: Hashable = new Point() in let p: Point = h in p;
+    ";
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(error_handler.get_raw_errors().len(), 1);
+}
+
+#[test]
+fn extending_undefined_protocol() {
+    let program = r"
+        protocol Equatable extends Hashable {
+            equals(other: Number): Boolean;
+        }
+        3;
+    ";
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec!["Semantic Error: Type or protocol `Hashable` is not defined.".to_string()]
+    );
+}
+
+#[test]
+fn extending_a_type() {
+    let program = r"
+        type Point {}
+        protocol Equatable extends Point {
+            equals(other: Number): Boolean;
+        }
+        3;
+    ";
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec![
+            "Semantic Error: `Point` is not a protocol and cannot be extended by one.".to_string()
+        ]
+    );
+}
+
+#[test]
+fn type_inheriting_protocol() {
+    let program = r"
+        protocol Hashable {
+            hash(): Number;
+        }
+        type Point inherits Hashable {
+            hash(): Number => 1;
+        }
+        3;
+    ";
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors(),
+        vec![
+            "Semantic Error: Type `Point` cannot inherit from protocol `Hashable`, types conform to protocols implicitly."
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn protocol_extension_cycle() {
+    let program = r"
+        protocol A extends B {
+            a(): Number;
+        }
+        protocol B extends A {
+            b(): Number;
+        }
+        3;
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+
+    assert_eq!(semantic_analyzer.errors.len(), 1);
+    assert!(
+        semantic_analyzer.errors[0]
+            .to_string()
+            .contains("Inheritance cycle detected")
+    );
+}
+
+#[test]
+fn protocol_method_return_type() {
+    let program = r"
+        protocol Named {
+            name(): String;
+        }
+        type Dog {
+            name(): String => 'dog';
+        }
+        let n: Named = new Dog() in n.name();
+    "
+    .replace('\'', "\"");
+    let p = ProgramParser::new();
+    let mut answ = p.parse(&program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    let let_in = answ.expressions[0].as_let_in().unwrap();
+    let call = let_in.body.as_function_member_access().unwrap();
+    assert_eq!(
+        call.member.identifier.info.ty,
+        Some(Type::BuiltIn(BuiltInType::String))
+    );
+}
+
+#[test]
+fn self_referencing_protocol_conformance() {
+    let program = r"
+        protocol Comparable {
+            compare(other: Comparable): Number;
+        }
+
+        type Age(value: Number) {
+            value = value;
+            get(): Number => self.value;
+            compare(other: Comparable): Number => 0;
+        }
+
+        let c: Comparable = new Age(3) in c.compare(new Age(4));
+    ";
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+}
@@ -0,0 +1,138 @@
+use error_handler::diagnostic::diagnostic::Span;
+use error_handler::error::error::HulkError;
+use error_handler::error_handler::ErrorHandler;
+use generated_parser::ProgramParser;
+
+use crate::semantic_analyzer::SemanticAnalyzer;
+use crate::suggestions::{closest_name, edit_distance};
+
+fn analyze_and_get_errors(program: &str) -> Vec<HulkError> {
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    semantic_analyzer.errors
+}
+
+fn formatted_errors(program: &str) -> Vec<String> {
+    let mut error_handler = ErrorHandler::new(program, 0);
+    error_handler.extend_errors(analyze_and_get_errors(program));
+    error_handler.get_error_messages()
+}
+
+#[test]
+fn edit_distance_counts_edits() {
+    assert_eq!(edit_distance("count", "count"), 0);
+    assert_eq!(edit_distance("count", "cout"), 1);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "abc"), 3);
+}
+
+#[test]
+fn closest_name_ignores_far_and_internal_names() {
+    let candidates = ["counter", "total", "$tmp0"];
+
+    assert_eq!(
+        closest_name("countr", candidates),
+        Some("counter".to_string())
+    );
+    assert_eq!(closest_name("tmp0", candidates), None);
+    assert_eq!(closest_name("xyz", candidates), None);
+}
+
+#[test]
+fn undefined_variable_suggests_visible_variable() {
+    let errors = analyze_and_get_errors("let counter = 1 in print(countr);");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].notes(), vec!["did you mean `counter`?"]);
+}
+
+#[test]
+fn undefined_function_suggests_builtins() {
+    let errors = analyze_and_get_errors("print(sqr(4));");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].notes(), vec!["did you mean `sqrt`?"]);
+}
+
+#[test]
+fn method_not_found_suggests_inherited_method() {
+    let errors = analyze_and_get_errors(
+        "
+        type Animal { speak(): String => \"...\"; }
+        type Dog inherits Animal { }
+        let d = new Dog() in print(d.speek());
+        ",
+    );
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].notes(), vec!["did you mean `speak`?"]);
+}
+
+#[test]
+fn undefined_type_suggests_defined_type() {
+    let errors = analyze_and_get_errors(
+        "
+        type Point { x = 0; }
+        let p = new Pont() in print(p);
+        ",
+    );
+
+    let undefined_type = errors
+        .iter()
+        .find(|error| error.message().contains("Pont"))
+        .expect("Should report the undefined type");
+    assert_eq!(undefined_type.notes(), vec!["did you mean `Point`?"]);
+}
+
+#[test]
+fn redefined_function_points_to_first_definition() {
+    let program = "
+        function f(): Number => 1;
+        function f(): Number => 2;
+        print(f());
+        ";
+    let errors = analyze_and_get_errors(program);
+
+    assert_eq!(errors.len(), 1);
+    let labels = errors[0].labels();
+    assert_eq!(labels.len(), 1);
+    let first_definition = program.find("f()").unwrap();
+    assert_eq!(
+        labels[0].0,
+        Span::new(first_definition, first_definition + 1)
+    );
+    assert!(
+        labels[0].1.contains("first defined here"),
+        "{}",
+        labels[0].1
+    );
+}
+
+#[test]
+fn inheritance_cycle_labels_every_parent() {
+    let program = "
+        type A inherits B { }
+        type B inherits A { }
+        print(1);
+        ";
+    let errors = analyze_and_get_errors(program);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].labels().len(), 2);
+}
+
+#[test]
+fn field_override_shows_both_snippets() {
+    let program = "type A { x = 1; }\ntype B inherits A { x = 2; }\nprint(1);";
+    let errors = formatted_errors(program);
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("--> line 2:"), "{}", errors[0]);
+    assert!(errors[0].contains("  2 | type B"), "{}", errors[0]);
+    assert!(errors[0].contains("  1 | type A"), "{}", errors[0]);
+    assert!(errors[0].contains("in a parent type"), "{}", errors[0]);
+}
@@ -432,7 +432,6 @@ pub fn call_var_with_method_name() {
 }
 
 #[test]
-#[ignore = "for is disabled"]
 pub fn iterate_non_iterable() {
     let program = "
             for(a in 3){
@@ -780,3 +779,73 @@ fn matrix_typing() {
 
     assert!(result.is_ok(), "Errors: {:?}", result.unwrap_err())
 }
+
+#[test]
+fn for_over_list_typing() {
+    let p = ProgramParser::new();
+    let mut answ = p.parse("for (x in [1, 2, 3]) { x + 1; };").unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer.analyze_program_ast(&mut answ).unwrap();
+
+    let for_exp = answ.expressions[0].as_for().unwrap();
+
+    assert_eq!(
+        for_exp.element.info.ty,
+        Some(Type::BuiltIn(BuiltInType::Number))
+    );
+    assert_eq!(
+        for_exp.iterable_type,
+        Some(Type::Iterable(Box::new(Type::BuiltIn(BuiltInType::Number))))
+    );
+}
+
+#[test]
+fn for_over_iterator_typing() {
+    let program = r#"
+        type Words() {
+            i = 0;
+            next(): Boolean { self.i := self.i + 1; return self.i < 3; }
+            current(): String => "word";
+        }
+
+        for (w in new Words()) { w @ "!"; };
+    "#;
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.unwrap_err());
+
+    let for_exp = answ.expressions[0].as_for().unwrap();
+    assert_eq!(
+        for_exp.element.info.ty,
+        Some(Type::BuiltIn(BuiltInType::String))
+    );
+}
+
+#[test]
+fn for_over_type_without_current() {
+    let program = r"
+        type Counter() {
+            i = 0;
+            next(): Boolean { self.i := self.i + 1; return self.i < 3; }
+        }
+
+        for (x in new Counter()) { x; };
+    ";
+    let mut error_handler = ErrorHandler::new(program, 0);
+    let p = ProgramParser::new();
+    let mut answ = p.parse(program).unwrap();
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    semantic_analyzer
+        .analyze_program_ast(&mut answ)
+        .expect_err("Should return an error");
+    error_handler.extend_errors(semantic_analyzer.errors);
+
+    assert_eq!(
+        error_handler.get_raw_errors()[0],
+        "Semantic Error: Cannot iterate over type `Counter`."
+    );
+}
@@ -0,0 +1,127 @@
+use ast::typing::{BuiltInType, Type};
+use error_handler::warning::warning::HulkWarning;
+use generated_parser::ProgramParser;
+
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+const HIERARCHY: &str = "
+    protocol Speaker {
+        talk(): Number;
+    }
+
+    type Animal() { }
+    type Dog() inherits Animal() { talk(): Number => 1; }
+    type Cat() inherits Animal() { }
+";
+
+fn analyze(program: &str) -> (SemanticAnalyzer, ast::Program) {
+    let p = ProgramParser::new();
+    let mut answ = p.parse(&format!("{}{}", HIERARCHY, program)).unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+    assert!(result.is_ok(), "Errors: {:?}", result.err());
+
+    (semantic_analyzer, answ)
+}
+
+#[test]
+fn is_expression_is_boolean() {
+    let (semantic_analyzer, answ) = analyze("let a: Animal = new Dog(), b = a is Dog in b;");
+
+    assert!(semantic_analyzer.warnings.is_empty());
+    let inner = answ.expressions[0]
+        .as_let_in()
+        .unwrap()
+        .body
+        .as_let_in()
+        .unwrap();
+    assert_eq!(
+        inner.assignment.identifier.info.ty,
+        Some(Type::BuiltIn(BuiltInType::Bool))
+    );
+
+    let is_expr = inner.assignment.rhs.as_is_expression().unwrap();
+    assert_eq!(is_expr.obj_type.as_ref().unwrap().to_string(), "Animal");
+}
+
+#[test]
+fn as_expression_has_target_type() {
+    let (semantic_analyzer, answ) = analyze("let a: Animal = new Dog(), b = a as Dog in b.talk();");
+
+    assert!(semantic_analyzer.warnings.is_empty());
+    let inner = answ.expressions[0]
+        .as_let_in()
+        .unwrap()
+        .body
+        .as_let_in()
+        .unwrap();
+    assert_eq!(
+        inner
+            .assignment
+            .identifier
+            .info
+            .ty
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "Dog"
+    );
+}
+
+#[test]
+fn upcast_test_is_always_true() {
+    let (semantic_analyzer, _) = analyze("let d = new Dog() in d is Animal;");
+
+    assert_eq!(semantic_analyzer.warnings.len(), 1);
+    assert!(matches!(
+        semantic_analyzer.warnings[0],
+        HulkWarning::TypeTestAlwaysTrue(_)
+    ));
+}
+
+#[test]
+fn unrelated_types_test_is_always_false() {
+    let (semantic_analyzer, _) = analyze("let d = new Dog() in d is Cat;");
+
+    assert_eq!(semantic_analyzer.warnings.len(), 1);
+    assert!(matches!(
+        semantic_analyzer.warnings[0],
+        HulkWarning::TypeTestAlwaysFalse(_)
+    ));
+}
+
+#[test]
+fn builtin_type_tests() {
+    let (semantic_analyzer, _) = analyze("{ 1 is Number; 1 is String; };");
+
+    assert_eq!(semantic_analyzer.warnings.len(), 2);
+    assert!(matches!(
+        semantic_analyzer.warnings[0],
+        HulkWarning::TypeTestAlwaysTrue(_)
+    ));
+    assert!(matches!(
+        semantic_analyzer.warnings[1],
+        HulkWarning::TypeTestAlwaysFalse(_)
+    ));
+}
+
+#[test]
+fn protocol_tests_are_not_decided_statically() {
+    let (semantic_analyzer, _) = analyze("let a: Animal = new Dog() in a is Speaker;");
+
+    assert!(semantic_analyzer.warnings.is_empty());
+}
+
+#[test]
+fn undefined_target_type() {
+    let p = ProgramParser::new();
+    let mut answ = p.parse("let x = 1 in x as Unicorn;").unwrap();
+
+    let mut semantic_analyzer = SemanticAnalyzer::new();
+    let result = semantic_analyzer.analyze_program_ast(&mut answ);
+
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("Unicorn"), "{}", errors[0]);
+}
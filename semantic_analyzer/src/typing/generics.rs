@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use ast::typing::{FunctorType, Type, TypeAnnotation};
 
 pub trait GenericType {
@@ -21,3 +23,67 @@ impl GenericType for FunctorType {
         generics
     }
 }
+
+/// # Description
+/// Binds each of the type `parameters` to the type argument in its position.
+pub fn bind_type_parameters(parameters: &[String], arguments: &[Type]) -> HashMap<String, Type> {
+    parameters
+        .iter()
+        .cloned()
+        .zip(arguments.iter().cloned())
+        .collect()
+}
+
+/// # Description
+/// Infers the type arguments of a generic definition by matching the type `expected` by it,
+/// written in terms of its type `parameters`, against the type `provided` for it. The first
+/// type a parameter is matched against is the one bound to it.
+/// # Example
+/// Matching `T*` against `Number*` binds `T` to `Number`.
+pub fn infer_type_arguments(
+    expected: &TypeAnnotation,
+    provided: &TypeAnnotation,
+    parameters: &[String],
+    bindings: &mut HashMap<String, Type>,
+) {
+    let (Some(expected), Some(provided)) = (expected, provided) else {
+        return;
+    };
+    match (expected, provided) {
+        (Type::Defined(name), _) if parameters.contains(&name.id) => {
+            bindings
+                .entry(name.id.clone())
+                .or_insert_with(|| provided.clone());
+        }
+        (Type::Iterable(expected), Type::Iterable(provided)) => infer_type_arguments(
+            &Some(expected.as_ref().clone()),
+            &Some(provided.as_ref().clone()),
+            parameters,
+            bindings,
+        ),
+        (Type::Generic(expected_name, expected), Type::Generic(provided_name, provided))
+            if expected_name == provided_name =>
+        {
+            for (expected, provided) in expected.iter().zip(provided) {
+                infer_type_arguments(
+                    &Some(expected.clone()),
+                    &Some(provided.clone()),
+                    parameters,
+                    bindings,
+                );
+            }
+        }
+        (Type::Functor(expected), Type::Functor(provided))
+            if expected.parameter_types.len() == provided.parameter_types.len() =>
+        {
+            for (expected, provided) in expected
+                .generic_params()
+                .iter()
+                .zip(provided.generic_params())
+            {
+                infer_type_arguments(expected, &provided, parameters, bindings);
+            }
+        }
+        _ => {}
+    }
+}
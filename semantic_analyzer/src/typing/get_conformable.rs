@@ -1,16 +1,30 @@
-use ast::typing::{Type, TypeAnnotation, to_string};
+use ast::{
+    token_position::TokenPosition,
+    typing::{Type, TypeAnnotation, to_string},
+};
 use error_handler::error::{
     error::HulkError,
-    semantic::{definition::UndefinedTypeOrProtocol, inheritance::ObjectAnnotationError},
+    semantic::{
+        definition::UndefinedTypeOrProtocol, generics::TypeArgumentsInvalidAmount,
+        inheritance::ObjectAnnotationError,
+    },
 };
 
+use crate::module_scope::ModuleScope;
+
 pub trait GetConformable {
     fn is_type_defined(&self, ty: &Type) -> bool;
 
+    /// The modules of the program, types can only be used where they are visible
+    fn modules(&self) -> &ModuleScope;
+
+    /// Amount of type parameters of the type or protocol named `name`, 0 if it is not generic
+    fn type_parameters_amount(&self, name: &str) -> usize;
+
     fn get_conformable(
         &self,
         annotation: &TypeAnnotation,
-        position: usize,
+        position: TokenPosition,
     ) -> Result<TypeAnnotation, HulkError> {
         let ty = if let Some(annotation) = annotation.as_ref() {
             annotation
@@ -25,10 +39,42 @@ pub trait GetConformable {
         if let Type::Iterable(inner) = ty {
             return self.get_conformable(&Some(inner.as_ref().clone()), position);
         };
-
-        if self.is_type_defined(&ty) {
+        if let Type::Functor(functor) = ty {
+            for param in &functor.parameter_types {
+                self.get_conformable(param, position)?;
+            }
+            self.get_conformable(&functor.return_type, position)?;
             return Ok(annotation.clone());
         }
-        Err(UndefinedTypeOrProtocol::new(ty.to_string(), position).into())
+
+        let type_arguments = match ty {
+            Type::Generic(name, arguments) => {
+                if !self.is_type_defined(&Type::Defined(name.clone())) {
+                    return Err(UndefinedTypeOrProtocol::new(name.id.clone(), position).into());
+                }
+                for argument in arguments {
+                    self.get_conformable(&Some(argument.clone()), position)?;
+                }
+                arguments.len()
+            }
+            _ if self.is_type_defined(&ty) => 0,
+            _ => return Err(UndefinedTypeOrProtocol::new(ty.to_string(), position).into()),
+        };
+
+        let name = ty.definition_name();
+        self.modules().check_visible(&name, position)?;
+
+        // generic types can only be used applied to all of their type arguments
+        let type_parameters = self.type_parameters_amount(&name);
+        if type_parameters != type_arguments {
+            return Err(TypeArgumentsInvalidAmount::new(
+                name,
+                type_parameters,
+                type_arguments,
+                position,
+            )
+            .into());
+        }
+        Ok(annotation.clone())
     }
 }
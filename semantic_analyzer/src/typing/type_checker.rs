@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
 
 use ast::{
     BinaryOperator, UnaryOperator,
@@ -14,7 +17,10 @@ use error_handler::error::{
     },
 };
 
-use super::{generics::GenericType, get_binary_op_functor_type, get_unary_op_functor_type};
+use super::{
+    generics::{GenericType, bind_type_parameters},
+    get_binary_op_functor_type, get_unary_op_functor_type,
+};
 use crate::{
     def_info::{DefinedTypeInfo, FuncInfo},
     graph_utils::{lca::LCA, parent_map_to_adj},
@@ -25,6 +31,8 @@ use crate::{
 /// the types conform to the expected types in the type hierarchy.
 /// It uses a Lowest Common Ancestor (LCA) algorithm to determine
 /// the relationships between types in the hierarchy.
+/// Protocols live outside of the hierarchy, a type conforms to a protocol when it has
+/// (or inherits) a compatible method for each method of the protocol.
 /// It also provides methods to check type conformance for binary and unary operations,
 /// as well as function calls and type constructors.
 /// # Fields
@@ -32,17 +40,30 @@ use crate::{
 /// - `type_names`: A vector of type names in the order of their ids.
 /// - `type_definitions`: A mapping from type names to their TypeAnnotation.
 /// - `lca`: An instance of the LCA algorithm to find the lowest common ancestor of two types in the hierarchy.
+/// - `protocol_methods`: A mapping from protocol names to their methods, including the extended ones.
+/// - `type_methods`: A mapping from type names to their methods, including the inherited ones.
+/// - `type_parameters`: A mapping from the names of the generic types and protocols to the names
+///   of their type parameters.
+/// - `assumed_conformances`: The (type, protocol) pairs whose conformance is being checked, used to
+///   assume they conform when a method signature refers back to them.
 pub struct TypeChecker {
     type_ids: HashMap<String, usize>,
     type_names: Vec<String>,
     type_definitions: HashMap<String, TypeAnnotation>,
     lca: LCA,
+    protocol_methods: HashMap<String, HashMap<String, FuncInfo>>,
+    type_methods: HashMap<String, HashMap<String, FuncInfo>>,
+    type_parameters: HashMap<String, Vec<String>>,
+    assumed_conformances: RefCell<HashSet<(String, String)>>,
 }
 
 impl TypeChecker {
     pub fn new(
         type_hierarchy: &HashMap<String, TypeAnnotation>,
         type_definitions: HashMap<String, TypeAnnotation>,
+        protocol_methods: HashMap<String, HashMap<String, FuncInfo>>,
+        type_methods: HashMap<String, HashMap<String, FuncInfo>>,
+        type_parameters: HashMap<String, Vec<String>>,
     ) -> Self {
         let mut type_ids = HashMap::new();
         let mut type_names = Vec::new();
@@ -59,14 +80,81 @@ impl TypeChecker {
             type_names,
             type_definitions,
             lca,
+            protocol_methods,
+            type_methods,
+            type_parameters,
+            assumed_conformances: RefCell::new(HashSet::new()),
+        }
+    }
+
+    fn is_protocol(&self, ty: &Type) -> bool {
+        matches!(ty, Type::Defined(name) | Type::Generic(name, _) if self.protocol_methods.contains_key(&name.id))
+    }
+
+    fn is_generic(&self, type_name: &str) -> bool {
+        self.type_parameters.contains_key(type_name)
+    }
+
+    /// # Description
+    /// Binds the type parameters of the generic type or protocol `ty` is applied to
+    /// to its type arguments.
+    fn type_argument_bindings(&self, ty: &Type) -> HashMap<String, Type> {
+        match ty {
+            Type::Generic(name, arguments) => self
+                .type_parameters
+                .get(&name.id)
+                .map(|parameters| bind_type_parameters(parameters, arguments))
+                .unwrap_or_default(),
+            _ => HashMap::new(),
         }
     }
 
+    /// # Description
+    /// Checks if type `a` structurally conforms to `protocol`, i.e. for every method
+    /// of the protocol, `a` has a method that implements a variant of it.
+    /// While a pair is being checked it is assumed to conform, so that protocols whose
+    /// methods refer to themselves (e.g. `equals(other: Equatable)`) can be satisfied.
+    fn conforms_to_protocol(&self, a: &Type, protocol: &Type) -> bool {
+        let Some(required) = self.protocol_methods.get(&protocol.definition_name()) else {
+            return false;
+        };
+        let a_methods = match a {
+            Type::Defined(name) | Type::Generic(name, _) => self
+                .protocol_methods
+                .get(&name.id)
+                .or_else(|| self.type_methods.get(&name.id)),
+            _ => None,
+        };
+
+        let key = (a.to_string(), protocol.to_string());
+        if self.assumed_conformances.borrow().contains(&key) {
+            return true;
+        }
+        self.assumed_conformances.borrow_mut().insert(key.clone());
+
+        // generic methods are compared applied to the type arguments of each side
+        let a_bindings = self.type_argument_bindings(a);
+        let protocol_bindings = self.type_argument_bindings(protocol);
+        let result = required.values().all(|method| {
+            a_methods
+                .and_then(|methods| methods.get(&method.name.id))
+                .is_some_and(|a_method| {
+                    self.implements_variant(
+                        &a_method.substitute(&a_bindings),
+                        &method.substitute(&protocol_bindings),
+                    )
+                })
+        });
+
+        self.assumed_conformances.borrow_mut().remove(&key);
+        result
+    }
+
     /// # Description
     /// Converts a type to its id in the type tree graph
     /// Note: it asumes that ty is defined, will panic if it is not
     fn type_to_id(&self, ty: &Type) -> usize {
-        let type_name = ty.to_string();
+        let type_name = ty.definition_name();
         let id = self.type_ids.get(&type_name);
         *id.unwrap()
     }
@@ -78,6 +166,13 @@ impl TypeChecker {
     /// * If `T1` inherits `T2` then `T1` conforms to `T2`.
     /// * If `T1` conforms to `T2` and `T2` conforms to `T3` then `T1` conforms to `T3`.
     /// * The only types that conform to `Number`, `String`, and `Boolean`, are respectively those same types.
+    /// * `T` conforms to a protocol `P` if for every method of `P`, `T` has a method with the same name,
+    ///   the same amount of parameters, contravariant parameter types and a covariant return type.
+    /// * A protocol only conforms to protocols (by the same rule) and to `Object`.
+    /// * A functor type `(A1, ..., An) -> R` conforms to `(B1, ..., Bn) -> S` if every `Bi`
+    ///   conforms to `Ai` and `R` conforms to `S`.
+    /// * `G<A1, ..., An>` conforms to `G<B1, ..., Bn>` if every `Ai` is the same type as `Bi`,
+    ///   type arguments are invariant.
     ///
     /// # Parameters
     /// - `a`: The first type annotation to check.
@@ -96,7 +191,25 @@ impl TypeChecker {
                 }
                 (Type::Iterable(_), _) | (_, Type::Iterable(_)) => false,
                 (Type::Functor(a_functor), Type::Functor(b_functor)) => {
-                    self.conforms_many(&a_functor.generic_params(), &b_functor.generic_params())
+                    // parameters are contravariant and the return type is covariant
+                    self.conforms_many(&b_functor.parameter_types, &a_functor.parameter_types)
+                        && self.conforms(&a_functor.return_type, &b_functor.return_type)
+                }
+                (Type::Functor(_), _) | (_, Type::Functor(_)) => false,
+                (Type::Generic(a_name, a_arguments), Type::Generic(b_name, b_arguments))
+                    if a_name == b_name =>
+                {
+                    a_arguments.len() == b_arguments.len()
+                        && a_arguments.iter().zip(b_arguments).all(|(a_arg, b_arg)| {
+                            let (a_arg, b_arg) = (Some(a_arg.clone()), Some(b_arg.clone()));
+                            self.conforms(&a_arg, &b_arg) && self.conforms(&b_arg, &a_arg)
+                        })
+                }
+                (_, b_protocol) if self.is_protocol(b_protocol) => {
+                    a == b || self.conforms_to_protocol(a, b_protocol)
+                }
+                (a_protocol, _) if self.is_protocol(a_protocol) => {
+                    matches!(b, Type::BuiltIn(BuiltInType::Object))
                 }
                 (_, _) => {
                     let a_id = self.type_to_id(&a);
@@ -165,6 +278,16 @@ impl TypeChecker {
                         }
                         return None;
                     }
+                    (Type::Functor(_), Type::Functor(_)) => {
+                        let (a, b) = (Some(a.clone()), Some(b.clone()));
+                        if self.conforms(&a, &b) {
+                            return b;
+                        }
+                        if self.conforms(&b, &a) {
+                            return a;
+                        }
+                        return Some(Type::BuiltIn(BuiltInType::Object));
+                    }
                     (Type::Functor(_), _)
                     | (_, Type::Functor(_))
                     | (_, Type::Iterable(_))
@@ -174,10 +297,28 @@ impl TypeChecker {
                     _ => {}
                 }
 
+                if self.is_protocol(a) || self.is_protocol(b) {
+                    let (a, b) = (Some(a.clone()), Some(b.clone()));
+                    if self.conforms(&a, &b) {
+                        return b;
+                    }
+                    if self.conforms(&b, &a) {
+                        return a;
+                    }
+                    return Some(Type::BuiltIn(BuiltInType::Object));
+                }
+
                 let a_id = self.type_to_id(a);
                 let b_id = self.type_to_id(b);
                 let common = self.lca.get_lca(a_id, b_id);
                 let common_name = self.type_names.get(common);
+                // the common type is generic when both are applied to different type arguments
+                if common_name.is_some_and(|name| self.is_generic(name)) {
+                    if a == b {
+                        return Some(a.clone());
+                    }
+                    return Some(Type::BuiltIn(BuiltInType::Object));
+                }
                 if let Some(common_name) = common_name {
                     if let Some(common_type) = self.type_definitions.get(common_name) {
                         return common_type.clone();
@@ -214,7 +355,7 @@ impl TypeChecker {
                     op.to_string(),
                     to_string(left),
                     to_string(right),
-                    op.position(),
+                    op.token_position(),
                 )
                 .into(),
             );
@@ -256,7 +397,7 @@ impl TypeChecker {
                     op.to_string(),
                     to_string(left),
                     to_string(right),
-                    op.position(),
+                    op.token_position(),
                 )
                 .into(),
             );
@@ -284,7 +425,9 @@ impl TypeChecker {
         let functor = get_unary_op_functor_type(&op);
 
         if !self.conforms(&operand, &functor.parameter_types[0]) {
-            errors.push(UnOpError::new(op.to_string(), to_string(operand), op.position()).into());
+            errors.push(
+                UnOpError::new(op.to_string(), to_string(operand), op.token_position()).into(),
+            );
         }
         *functor.return_type.clone()
     }
@@ -314,7 +457,7 @@ impl TypeChecker {
                     fn_info.name.id.clone(),
                     functor.parameter_types.len(),
                     parameters.len(),
-                    fn_info.name.position.start,
+                    fn_info.name.position,
                 )
                 .into(),
             );
@@ -333,7 +476,7 @@ impl TypeChecker {
                         i,
                         to_string(expected),
                         to_string(provided),
-                        fn_info.name.position.start,
+                        fn_info.name.position,
                     )
                     .into(),
                 );
@@ -352,6 +495,7 @@ impl TypeChecker {
     /// # Parameters
     /// - `type_definition`: The type definition containing the expected types.
     /// - `parameters`: The parameters passed to the type constructor call.
+    /// - `positions`: Where each of the parameters starts in the source code.
     /// # Returns
     /// `Ok(())` if the type constructor call conforms to the expected types,
     /// `Err(Vec<String>)` containing error messages if the types do not conform.
@@ -362,6 +506,7 @@ impl TypeChecker {
         &self,
         type_definition: &DefinedTypeInfo,
         parameters: &Vec<TypeAnnotation>,
+        positions: &[usize],
     ) -> Result<(), Vec<HulkError>> {
         let mut errors = Vec::new();
         if type_definition.arguments_types.len() != parameters.len() {
@@ -370,26 +515,27 @@ impl TypeChecker {
                     type_definition.name.id.clone(),
                     type_definition.arguments_types.len(),
                     parameters.len(),
-                    type_definition.name.position.start,
+                    type_definition.name.position,
                 )
                 .into(),
             );
             return Err(errors);
         }
-        for (i, (expected, provided)) in type_definition
+        for (i, ((expected, provided), position)) in type_definition
             .arguments_types
             .iter()
             .zip(parameters.iter())
+            .zip(positions)
             .enumerate()
         {
-            if !self.conforms(expected, provided) {
+            if !self.conforms(provided, expected) {
                 errors.push(
                     TypeParamInvalidType::new(
                         type_definition.name.id.clone(),
                         i,
                         to_string(expected),
                         to_string(provided),
-                        type_definition.name.position.start,
+                        *position,
                     )
                     .into(),
                 );
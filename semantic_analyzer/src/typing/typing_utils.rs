@@ -17,7 +17,8 @@ pub fn get_binary_op_functor_type(op: &BinaryOperator) -> FunctorType {
         | BinaryOperator::Divide(_)
         | BinaryOperator::FloorDivide(_)
         | BinaryOperator::Times(_)
-        | BinaryOperator::Modulo(_) => FunctorType::new(
+        | BinaryOperator::Modulo(_)
+        | BinaryOperator::Power(_) => FunctorType::new(
             vec![
                 Some(Type::BuiltIn(BuiltInType::Number)),
                 Some(Type::BuiltIn(BuiltInType::Number)),
@@ -1,9 +1,10 @@
 use ast::{
-    Assignment, BinOp, Block, BooleanLiteral, ConstantDef, DataMemberAccess, Definition,
-    DefinitionVisitor, DestructiveAssignment, Expression, ExpressionVisitor, For, FunctionCall,
-    FunctionMemberAccess, GlobalFunctionDef, Identifier, IfElse, LetIn, ListIndexing, ListLiteral,
-    NewExpr, NumberLiteral, ProtocolDef, ReturnStatement, StringLiteral, TypeDef, UnOp,
-    VisitableDefinition, VisitableExpression, While,
+    AsExpr, Assignment, BaseCall, BinOp, Block, BooleanLiteral, ConstantDef, DataMemberAccess,
+    Definition, DefinitionVisitor, DestructiveAssignment, Expression, ExpressionVisitor, For,
+    FunctionCall, FunctionMemberAccess, GlobalFunctionDef, Identifier, IfElse, IsExpr, Lambda,
+    LetIn, ListIndexing, ListLiteral, NewExpr, NumberLiteral, ProtocolDef, ReturnStatement,
+    StringLiteral, TypeDef, TypeName, UnOp, VisitableDefinition, VisitableExpression, While,
+    token_position::TokenPositionTrait,
     typing::{Type, TypeAnnotation},
 };
 use error_handler::error::error::HulkError;
@@ -11,30 +12,44 @@ use generator::context::Context;
 
 use crate::{
     def_info::{DefinedTypeInfo, FuncInfo, TypeInfo, VarInfo},
+    module_scope::ModuleScope,
     typing::get_conformable::GetConformable,
 };
 
 pub struct AnnotationVisitor<'a> {
     pub type_definitions: &'a mut Context<TypeInfo>,
     pub func_definitions: &'a mut Context<FuncInfo>,
+    pub modules: &'a ModuleScope,
     pub errors: &'a mut Vec<HulkError>,
+    /// The type parameters of the generic definition being visited
+    type_parameters: Vec<String>,
 }
 
 impl<'a> AnnotationVisitor<'a> {
     pub fn new(
         type_definitions: &'a mut Context<TypeInfo>,
         func_definitions: &'a mut Context<FuncInfo>,
+        modules: &'a ModuleScope,
         errors: &'a mut Vec<HulkError>,
     ) -> Self {
         Self {
             type_definitions,
             func_definitions,
+            modules,
             errors,
+            type_parameters: Vec::new(),
         }
     }
 
+    fn set_type_parameters(&mut self, type_parameters: &[TypeName]) {
+        self.type_parameters = type_parameters
+            .iter()
+            .map(|parameter| parameter.id.clone())
+            .collect();
+    }
+
     fn fix_annotation(&mut self, id: &mut Identifier) {
-        match self.get_conformable(&id.info.ty, id.position.start) {
+        match self.get_conformable(&id.info.ty, id.position) {
             Ok(_) => {}
             Err(message) => {
                 self.errors.push(message);
@@ -69,6 +84,21 @@ impl<'a> AnnotationVisitor<'a> {
             .expect("Function should be a variable")
     }
 
+    fn get_protocol_method_unsafe_mut(
+        &mut self,
+        protocol_id: &str,
+        func_id: &str,
+    ) -> &mut FuncInfo {
+        self.type_definitions
+            .get_value_mut(&protocol_id)
+            .expect("Protocol definition not found")
+            .as_protocol_mut()
+            .expect("Protocol definition should be a protocol")
+            .methods
+            .get_mut(func_id)
+            .expect("Function not found in protocol definition")
+    }
+
     fn get_func_unsafe_mut(&mut self, func_id: &str) -> &mut FuncInfo {
         self.func_definitions
             .get_value_mut(&func_id)
@@ -78,7 +108,22 @@ impl<'a> AnnotationVisitor<'a> {
 
 impl<'a> GetConformable for AnnotationVisitor<'a> {
     fn is_type_defined(&self, ty: &Type) -> bool {
-        self.type_definitions.is_defined(&ty.to_string())
+        match self.type_definitions.get_value(&ty.to_string()) {
+            // type parameters are only visible inside of their definition
+            Some(TypeInfo::Parameter(name)) => self.type_parameters.contains(&name.id),
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    fn modules(&self) -> &ModuleScope {
+        self.modules
+    }
+
+    fn type_parameters_amount(&self, name: &str) -> usize {
+        self.type_definitions
+            .get_value(name)
+            .map_or(0, |info| info.type_parameters().len())
     }
 }
 
@@ -88,10 +133,11 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
     }
 
     fn visit_type_def(&mut self, node: &mut TypeDef) -> () {
+        self.set_type_parameters(&node.type_parameters);
         // check type arguments
         let mut args_to_fix = vec![];
         for (i, arg) in node.parameter_list.iter_mut().enumerate() {
-            match self.get_conformable(&arg.info.ty, arg.position.start) {
+            match self.get_conformable(&arg.info.ty, arg.position) {
                 Ok(_) => {}
                 Err(message) => {
                     self.errors.push(message);
@@ -112,8 +158,7 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
         // check fields declarations
         let mut members_to_fix = vec![];
         for member in node.data_member_defs.iter_mut() {
-            match self.get_conformable(&member.identifier.info.ty, member.identifier.position.start)
-            {
+            match self.get_conformable(&member.identifier.info.ty, member.identifier.position) {
                 Ok(_) => {}
                 Err(message) => {
                     self.errors.push(message);
@@ -134,7 +179,7 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
         for func in &mut node.function_member_defs {
             // check return type
             let mut fix_return_type = false;
-            match self.get_conformable(&func.identifier.info.ty, func.identifier.position.start) {
+            match self.get_conformable(&func.identifier.info.ty, func.identifier.position) {
                 Ok(_) => {}
                 Err(message) => {
                     self.errors.push(message);
@@ -146,7 +191,7 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
             // check arg types
             let mut args_to_fix = vec![];
             for (i, arg) in &mut func.parameters.iter_mut().enumerate() {
-                match self.get_conformable(&arg.info.ty, arg.position.start) {
+                match self.get_conformable(&arg.info.ty, arg.position) {
                     Ok(_) => {}
                     Err(message) => {
                         self.errors.push(message);
@@ -165,13 +210,15 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
             // check body
             func.body.accept(self);
         }
+        self.type_parameters.clear();
     }
 
     fn visit_function_def(&mut self, node: &mut GlobalFunctionDef) -> () {
+        self.set_type_parameters(&node.function_def.type_parameters);
         let func = &mut node.function_def;
         // check return type
         let mut fix_return_type = false;
-        match self.get_conformable(&func.identifier.info.ty, func.identifier.position.start) {
+        match self.get_conformable(&func.identifier.info.ty, func.identifier.position) {
             Ok(_) => {}
             Err(message) => {
                 self.errors.push(message);
@@ -183,7 +230,7 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
         // check arg types
         let mut args_to_fix = vec![];
         for (i, arg) in &mut func.parameters.iter_mut().enumerate() {
-            match self.get_conformable(&arg.info.ty, arg.position.start) {
+            match self.get_conformable(&arg.info.ty, arg.position) {
                 Ok(_) => {}
                 Err(message) => {
                     self.errors.push(message);
@@ -200,6 +247,7 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
             func_info.name.info.ty = None;
         }
         node.function_def.body.accept(self);
+        self.type_parameters.clear();
     }
 
     fn visit_constant_def(&mut self, node: &mut ConstantDef) -> () {
@@ -207,8 +255,43 @@ impl<'a> DefinitionVisitor<()> for AnnotationVisitor<'a> {
         node.initializer_expression.accept(self);
     }
 
-    fn visit_protocol_def(&mut self, _node: &mut ProtocolDef) -> () {
-        todo!()
+    fn visit_protocol_def(&mut self, node: &mut ProtocolDef) -> () {
+        self.set_type_parameters(&node.type_parameters);
+        for signature in &mut node.function_signatures {
+            // check return type
+            let mut fix_return_type = false;
+            match self.get_conformable(&signature.identifier.info.ty, signature.identifier.position)
+            {
+                Ok(_) => {}
+                Err(message) => {
+                    self.errors.push(message);
+                    signature.identifier.info.ty = None;
+                    fix_return_type = true;
+                }
+            }
+
+            // check arg types
+            let mut args_to_fix = vec![];
+            for (i, arg) in &mut signature.parameters.iter_mut().enumerate() {
+                match self.get_conformable(&arg.info.ty, arg.position) {
+                    Ok(_) => {}
+                    Err(message) => {
+                        self.errors.push(message);
+                        arg.info.ty = None;
+                        args_to_fix.push(i);
+                    }
+                }
+            }
+            let func_info =
+                self.get_protocol_method_unsafe_mut(&node.name.id, &signature.identifier.id);
+            for i in args_to_fix {
+                func_info.parameters[i].info.ty = None;
+            }
+            if fix_return_type {
+                func_info.name.info.ty = None;
+            }
+        }
+        self.type_parameters.clear();
     }
 }
 
@@ -233,6 +316,18 @@ impl<'a> ExpressionVisitor<()> for AnnotationVisitor<'a> {
     }
 
     fn visit_new_expr(&mut self, node: &mut NewExpr) -> () {
+        let invalid_type_argument = node.type_arguments.iter().find_map(|type_argument| {
+            self.get_conformable(
+                &Some(type_argument.clone()),
+                node.new_token.token_position(),
+            )
+            .err()
+        });
+        if let Some(message) = invalid_type_argument {
+            self.errors.push(message);
+            // the semantic visitor infers them instead
+            node.type_arguments.clear();
+        }
         for arg in &mut node.arguments {
             arg.accept(self);
         }
@@ -293,6 +388,29 @@ impl<'a> ExpressionVisitor<()> for AnnotationVisitor<'a> {
         }
     }
 
+    fn visit_lambda(&mut self, node: &mut Lambda) -> () {
+        for param in &mut node.parameters {
+            self.fix_annotation(param);
+        }
+        if let Err(message) = self.get_conformable(&node.return_type, node.arrow.token_position()) {
+            self.errors.push(message);
+            node.return_type = None;
+        }
+        node.body.accept(self);
+    }
+
+    fn visit_is_expr(&mut self, node: &mut IsExpr) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_as_expr(&mut self, node: &mut AsExpr) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_base_call(&mut self, node: &mut BaseCall) -> () {
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
     fn visit_variable(&mut self, node: &mut Identifier) -> () {
         self.fix_annotation(node);
     }
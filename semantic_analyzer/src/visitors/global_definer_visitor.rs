@@ -9,21 +9,26 @@ use error_handler::error::{
         type_definition::{TypeMemberAlreadyDefined, TypeOrProtocolAlreadyDefined},
     },
 };
+use generator::builtins::find_builtin;
 use generator::context::Context;
 
 use std::collections::HashMap;
 
-use crate::def_info::{DefinedTypeInfo, DefinitionInfo, FuncInfo, TypeInfo, VarInfo};
+use crate::def_info::{DefinedTypeInfo, DefinitionInfo, FuncInfo, ProtocolInfo, TypeInfo, VarInfo};
+use crate::module_scope::ModuleScope;
 
 /// # Description
 /// Defines types, functions and protocols in the global context. It only looks at the names of the types.
 /// Setting the inheritance relationship between types and vitisiting fields and functions of the types is left for another visitor.
 /// This aims to solve the problem of recursive types, allowing the use of the type before it is defined, types that reference each
 /// other in a recursive manner, etc.
+/// The module each definition belongs to is recorded in `modules`, so the definitions can only be used
+/// from the modules that import it.
 pub struct GlobalDefinerVisitor<'a> {
     pub type_definitions: &'a mut Context<TypeInfo>,
     pub var_definitions: &'a mut Context<VarInfo>,
     pub func_defintions: &'a mut Context<FuncInfo>,
+    pub modules: &'a mut ModuleScope,
     pub errors: &'a mut Vec<HulkError>,
 }
 
@@ -32,12 +37,14 @@ impl<'a> GlobalDefinerVisitor<'a> {
         type_definitions: &'a mut Context<TypeInfo>,
         var_definitions: &'a mut Context<VarInfo>,
         func_defintions: &'a mut Context<FuncInfo>,
+        modules: &'a mut ModuleScope,
         errors: &'a mut Vec<HulkError>,
     ) -> Self {
         let instance = GlobalDefinerVisitor {
             type_definitions,
             var_definitions,
             func_defintions,
+            modules,
             errors,
         };
         let built_ins = vec![
@@ -53,6 +60,36 @@ impl<'a> GlobalDefinerVisitor<'a> {
         }
         return instance;
     }
+
+    /// # Description
+    /// Defines the type parameters of a generic definition. Generic definitions can share
+    /// the names of their type parameters, but they can't clash with types or protocols.
+    fn define_type_parameters(&mut self, type_parameters: &[TypeName]) {
+        for (i, parameter) in type_parameters.iter().enumerate() {
+            let repeated = type_parameters[..i]
+                .iter()
+                .find(|previous| previous.id == parameter.id);
+            let previous = if let Some(repeated) = repeated {
+                Some(repeated.position)
+            } else {
+                match self.type_definitions.get_value(&parameter.id) {
+                    None => {
+                        self.type_definitions
+                            .define(parameter.id.clone(), TypeInfo::Parameter(parameter.clone()));
+                        continue;
+                    }
+                    Some(TypeInfo::Parameter(_)) => continue,
+                    Some(info) => info.definition_position(),
+                }
+            };
+            let mut error =
+                TypeOrProtocolAlreadyDefined::new(parameter.id.clone(), parameter.position);
+            if let Some(position) = previous {
+                error = error.with_previous_definition(position);
+            }
+            self.errors.push(error.into());
+        }
+    }
 }
 
 impl<'a> DefinitionVisitor<()> for GlobalDefinerVisitor<'a> {
@@ -62,14 +99,14 @@ impl<'a> DefinitionVisitor<()> for GlobalDefinerVisitor<'a> {
 
     fn visit_type_def(&mut self, node: &mut ast::TypeDef) -> () {
         let type_name = node.name.id.clone();
-        if self.type_definitions.is_defined(&type_name) {
-            self.errors.push(
-                TypeOrProtocolAlreadyDefined::new(
-                    type_name.clone(),
-                    node.name.position.start.clone(),
-                )
-                .into(),
-            );
+        self.define_type_parameters(&node.type_parameters);
+        if let Some(previous) = self.type_definitions.get_value(&type_name) {
+            let mut error =
+                TypeOrProtocolAlreadyDefined::new(type_name.clone(), node.name.position);
+            if let Some(position) = previous.definition_position() {
+                error = error.with_previous_definition(position);
+            }
+            self.errors.push(error.into());
             return;
         }
 
@@ -82,7 +119,7 @@ impl<'a> DefinitionVisitor<()> for GlobalDefinerVisitor<'a> {
                     TypeMemberAlreadyDefined::new(
                         member_name.clone(),
                         type_name.clone(),
-                        member.identifier.position.start.clone(),
+                        member.identifier.position,
                     )
                     .into(),
                 );
@@ -105,7 +142,7 @@ impl<'a> DefinitionVisitor<()> for GlobalDefinerVisitor<'a> {
                     TypeMemberAlreadyDefined::new(
                         member_name.clone(),
                         type_name.clone(),
-                        member.identifier.position.start.clone(),
+                        member.identifier.position,
                     )
                     .into(),
                 );
@@ -119,31 +156,39 @@ impl<'a> DefinitionVisitor<()> for GlobalDefinerVisitor<'a> {
             .iter()
             .map(|id| id.info.ty.clone())
             .collect();
-        let type_def = DefinedTypeInfo::new(node.name.clone(), members_info, arguments_types);
+        let type_def = DefinedTypeInfo::new(node.name.clone(), members_info, arguments_types)
+            .with_type_parameters(type_parameter_names(&node.type_parameters));
+        self.modules.define(&type_name, node.name.position);
         self.type_definitions
             .define(type_name, TypeInfo::Defined(type_def));
     }
 
     fn visit_function_def(&mut self, node: &mut ast::GlobalFunctionDef) -> () {
         // Check if the function is already defined
-        if self
+        let previous = self
             .func_defintions
-            .is_defined(&node.function_def.identifier.id)
-            || vec!["print"] // Here we can add more built-in functions that are not allowed to be redefined
-                .contains(&node.function_def.identifier.id.as_str())
-        {
-            self.errors.push(
-                FuncAlreadyDefined::new(
-                    node.function_def.identifier.id.clone(),
-                    node.function_def.identifier.position.start.clone(),
-                )
-                .into(),
+            .get_value(&node.function_def.identifier.id)
+            .map(|info| info.name.position);
+        if previous.is_some() || find_builtin(&node.function_def.identifier.id).is_some() {
+            let mut error = FuncAlreadyDefined::new(
+                node.function_def.identifier.id.clone(),
+                node.function_def.identifier.position,
             );
+            if let Some(position) = previous {
+                error = error.with_previous_definition(position);
+            }
+            self.errors.push(error.into());
             return;
         }
 
+        self.define_type_parameters(&node.function_def.type_parameters);
+
         // Define function information in global context
         let func_info = FuncInfo::from(&node.function_def);
+        self.modules.define(
+            &node.function_def.identifier.id,
+            node.function_def.identifier.position,
+        );
         self.func_defintions
             .define(node.function_def.identifier.id.clone(), func_info.clone());
 
@@ -177,7 +222,52 @@ impl<'a> DefinitionVisitor<()> for GlobalDefinerVisitor<'a> {
 
     fn visit_constant_def(&mut self, _node: &mut ast::ConstantDef) -> () {}
 
-    fn visit_protocol_def(&mut self, _node: &mut ast::ProtocolDef) -> () {
-        todo!()
+    fn visit_protocol_def(&mut self, node: &mut ast::ProtocolDef) -> () {
+        let protocol_name = node.name.id.clone();
+        self.define_type_parameters(&node.type_parameters);
+        if let Some(previous) = self.type_definitions.get_value(&protocol_name) {
+            let mut error =
+                TypeOrProtocolAlreadyDefined::new(protocol_name.clone(), node.name.position);
+            if let Some(position) = previous.definition_position() {
+                error = error.with_previous_definition(position);
+            }
+            self.errors.push(error.into());
+            return;
+        }
+
+        let mut methods_info: HashMap<String, FuncInfo> = HashMap::new();
+
+        for signature in &node.function_signatures {
+            let method_name = signature.identifier.id.clone();
+            if methods_info.contains_key(&method_name) {
+                self.errors.push(
+                    TypeMemberAlreadyDefined::new(
+                        method_name.clone(),
+                        protocol_name.clone(),
+                        signature.identifier.position,
+                    )
+                    .into(),
+                );
+                continue;
+            }
+            methods_info.insert(method_name, FuncInfo::from(signature));
+        }
+
+        let parent = node
+            .extension_indicator
+            .as_ref()
+            .map(|extension| extension.extendee_name.clone());
+        let protocol_def = ProtocolInfo::new(node.name.clone(), parent, methods_info)
+            .with_type_parameters(type_parameter_names(&node.type_parameters));
+        self.modules.define(&protocol_name, node.name.position);
+        self.type_definitions
+            .define(protocol_name, TypeInfo::Protocol(protocol_def));
     }
 }
+
+fn type_parameter_names(type_parameters: &[TypeName]) -> Vec<String> {
+    type_parameters
+        .iter()
+        .map(|parameter| parameter.id.clone())
+        .collect()
+}
@@ -1,41 +1,56 @@
 use std::collections::HashMap;
 
 use ast::{
-    ConstantDef, DefinitionVisitor, GlobalFunctionDef, ProtocolDef, TypeDef, VisitableDefinition,
+    ConstantDef, DefinitionVisitor, GlobalFunctionDef, ProtocolDef, TypeDef, TypeName,
+    VisitableDefinition,
     typing::{BuiltInType, Type, TypeAnnotation},
 };
 use error_handler::error::{
     error::HulkError,
-    semantic::{definition::UndefinedType, inheritance::InheritanceInvalidParent},
+    semantic::{
+        definition::{UndefinedType, UndefinedTypeOrProtocol},
+        generics::TypeArgumentsInvalidAmount,
+        inheritance::{InheritanceInvalidParent, ProtocolInvalidParent, TypeInheritsProtocol},
+    },
 };
 use generator::context::Context;
 
-use crate::def_info::TypeInfo;
+use crate::{def_info::TypeInfo, module_scope::ModuleScope, suggestions::closest_name};
 
 /// # Description
-/// Visitor that sets the inheritance relationship between types.
+/// Visitor that sets the inheritance relationship between types, and the extension relationship
+/// between protocols. Protocols are kept out of the type hierarchy, since types conform to them
+/// structurally instead of inheriting from them.
 /// It assumes that the built-in types are already defined in the context.
 /// It also assumes that the type definitions are already defined in the context.
 /// It does not visit fields or functions of the types, that is left for another visitor.
 /// # Arguments
 /// * `type_hierarchy` - A mutable reference to a HashMap that holds the inheritance relationship between types.
+/// * `protocol_hierarchy` - A mutable reference to a HashMap that holds the extension relationship between protocols.
 /// * `type_definitions` - A mutable reference to a context that holds the type definitions.
+/// * `modules` - The modules of the program, parents must be visible from the module of their children.
 /// * `errors` - A mutable reference to a vector that holds the errors encountered during the visit.
 pub struct InheritanceVisitor<'a> {
     pub type_hierarchy: &'a mut HashMap<String, TypeAnnotation>,
+    pub protocol_hierarchy: &'a mut HashMap<String, TypeAnnotation>,
     pub type_definitions: &'a mut Context<TypeInfo>,
+    pub modules: &'a ModuleScope,
     pub errors: &'a mut Vec<HulkError>,
 }
 
 impl<'a> InheritanceVisitor<'a> {
     pub fn new(
         type_hierarchy: &'a mut HashMap<String, TypeAnnotation>,
+        protocol_hierarchy: &'a mut HashMap<String, TypeAnnotation>,
         type_definitions: &'a mut Context<TypeInfo>,
+        modules: &'a ModuleScope,
         errors: &'a mut Vec<HulkError>,
     ) -> Self {
         let instance = InheritanceVisitor {
             type_hierarchy,
+            protocol_hierarchy,
             type_definitions,
+            modules,
             errors,
         };
         // We asume that the built-in types are already defined in the context
@@ -59,6 +74,17 @@ impl<'a> InheritanceVisitor<'a> {
         }
         return instance;
     }
+
+    /// # Description
+    /// Type parameters are opaque types, their only parent is `Object`.
+    fn add_type_parameters(&mut self, type_parameters: &[TypeName]) {
+        for parameter in type_parameters {
+            self.type_hierarchy.insert(
+                parameter.id.clone(),
+                Some(Type::BuiltIn(BuiltInType::Object)),
+            );
+        }
+    }
 }
 
 impl<'a> DefinitionVisitor<()> for InheritanceVisitor<'a> {
@@ -67,34 +93,66 @@ impl<'a> DefinitionVisitor<()> for InheritanceVisitor<'a> {
     }
 
     fn visit_type_def(&mut self, node: &mut TypeDef) -> () {
+        self.add_type_parameters(&node.type_parameters);
         match &node.inheritance_indicator {
             Some(inheritance) => {
                 let parent_name = inheritance.parent_name.id.clone();
                 let class_name = node.name.id.clone();
+                if let Err(error) = self
+                    .modules
+                    .check_visible(&parent_name, inheritance.parent_name.position)
+                {
+                    self.errors.push(error);
+                }
 
                 match self.type_definitions.get_value(&parent_name) {
                     Some(parent_type) => match parent_type {
-                        TypeInfo::BuiltIn(_) => {
+                        TypeInfo::BuiltIn(_) | TypeInfo::Parameter(_) => {
                             self.errors.push(
                                 InheritanceInvalidParent::new(
                                     parent_name.clone(),
-                                    node.name.position.start.clone(),
+                                    node.name.position,
                                 )
                                 .into(),
                             );
                         }
                         TypeInfo::Defined(parent_def) => {
+                            // generic types can't be inherited from
+                            if !parent_def.type_parameters.is_empty() {
+                                self.errors.push(
+                                    TypeArgumentsInvalidAmount::new(
+                                        parent_name.clone(),
+                                        parent_def.type_parameters.len(),
+                                        0,
+                                        inheritance.parent_name.position,
+                                    )
+                                    .into(),
+                                );
+                            }
                             self.type_hierarchy
                                 .insert(class_name, Some(Type::Defined(parent_def.name.clone())));
                         }
+                        TypeInfo::Protocol(_) => {
+                            self.errors.push(
+                                TypeInheritsProtocol::new(
+                                    class_name,
+                                    parent_name.clone(),
+                                    node.name.position,
+                                )
+                                .into(),
+                            );
+                        }
                     },
                     None => {
+                        let candidates = self.modules.visible_names(
+                            self.type_definitions.visible_ids(),
+                            inheritance.parent_name.position,
+                        );
+                        let suggestion = closest_name(&parent_name, candidates);
                         self.errors.push(
-                            UndefinedType::new(
-                                parent_name.clone(),
-                                node.name.position.start.clone(),
-                            )
-                            .into(),
+                            UndefinedType::new(parent_name.clone(), node.name.position)
+                                .with_suggestion(suggestion)
+                                .into(),
                         );
                     }
                 }
@@ -110,11 +168,62 @@ impl<'a> DefinitionVisitor<()> for InheritanceVisitor<'a> {
         }
     }
 
-    fn visit_function_def(&mut self, _node: &mut GlobalFunctionDef) -> () {}
+    fn visit_function_def(&mut self, node: &mut GlobalFunctionDef) -> () {
+        self.add_type_parameters(&node.function_def.type_parameters);
+    }
 
     fn visit_constant_def(&mut self, _node: &mut ConstantDef) -> () {}
 
-    fn visit_protocol_def(&mut self, _node: &mut ProtocolDef) -> () {
-        todo!()
+    fn visit_protocol_def(&mut self, node: &mut ProtocolDef) -> () {
+        self.add_type_parameters(&node.type_parameters);
+        let protocol_name = node.name.id.clone();
+        let Some(extension) = &node.extension_indicator else {
+            self.protocol_hierarchy.insert(protocol_name, None);
+            return;
+        };
+
+        let parent_name = extension.extendee_name.id.clone();
+        if let Err(error) = self
+            .modules
+            .check_visible(&parent_name, extension.extendee_name.position)
+        {
+            self.errors.push(error);
+        }
+        match self.type_definitions.get_value(&parent_name) {
+            Some(TypeInfo::Protocol(parent_def)) => {
+                // generic protocols can't be extended
+                if !parent_def.type_parameters.is_empty() {
+                    self.errors.push(
+                        TypeArgumentsInvalidAmount::new(
+                            parent_name.clone(),
+                            parent_def.type_parameters.len(),
+                            0,
+                            extension.extendee_name.position,
+                        )
+                        .into(),
+                    );
+                }
+                self.protocol_hierarchy
+                    .insert(protocol_name, Some(Type::Defined(parent_def.name.clone())));
+            }
+            Some(_) => {
+                self.errors.push(
+                    ProtocolInvalidParent::new(
+                        parent_name.clone(),
+                        extension.extendee_name.position,
+                    )
+                    .into(),
+                );
+            }
+            None => {
+                self.errors.push(
+                    UndefinedTypeOrProtocol::new(
+                        parent_name.clone(),
+                        extension.extendee_name.position,
+                    )
+                    .into(),
+                );
+            }
+        }
     }
 }
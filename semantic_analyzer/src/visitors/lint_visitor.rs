@@ -0,0 +1,336 @@
+use std::collections::HashSet;
+
+use ast::{
+    BlockBodyItem, BooleanLiteral, DefinitionVisitor, Expression, ExpressionVisitor, FunctionDef,
+    Identifier, VisitableDefinition, VisitableExpression,
+    token_position::{TokenPosition, TokenPositionTrait},
+    typing::{BuiltInType, Type},
+};
+use error_handler::warning::{
+    lint::{
+        ConstantCondition, ObjectReturnType, SelfAssignment, ShadowedVariable, UnreachableCode,
+        UnusedVariable,
+    },
+    warning::HulkWarning,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BindingKind {
+    Variable,
+    Parameter,
+    LoopElement,
+}
+
+struct Binding {
+    name: String,
+    position: TokenPosition,
+    kind: BindingKind,
+    used: bool,
+}
+
+/// # Description
+/// Visitor that looks for suspicious code in the typed AST: unused variables and parameters,
+/// unreachable code, shadowed variables, constant `if` conditions, self-assignments, and
+/// functions whose inferred return type is `Object`. It only produces warnings, their level
+/// is applied by the `SemanticAnalyzer`.
+/// # Arguments
+/// * `warnings` - A mutable reference to a vector that holds the warnings found.
+/// * `inferred_return_types` - Positions of the functions and methods without a return type annotation.
+/// * `scopes` - The variables visible from the current expression, innermost scope last.
+pub struct LintVisitor<'a> {
+    pub warnings: &'a mut Vec<HulkWarning>,
+    inferred_return_types: &'a HashSet<usize>,
+    scopes: Vec<Vec<Binding>>,
+}
+
+impl<'a> LintVisitor<'a> {
+    pub fn new(
+        warnings: &'a mut Vec<HulkWarning>,
+        inferred_return_types: &'a HashSet<usize>,
+    ) -> Self {
+        LintVisitor {
+            warnings,
+            inferred_return_types,
+            scopes: Vec::new(),
+        }
+    }
+
+    fn push_scope(&mut self, identifiers: &[Identifier], kind: BindingKind) {
+        let scope = identifiers
+            .iter()
+            .map(|identifier| Binding {
+                name: identifier.id.clone(),
+                position: identifier.position,
+                kind,
+                used: false,
+            })
+            .collect();
+        self.scopes.push(scope);
+    }
+
+    /// Pops the innermost scope, warning about its unused bindings
+    fn pop_scope(&mut self) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+        for binding in scope {
+            if binding.used || binding.kind == BindingKind::LoopElement {
+                continue;
+            }
+            self.warnings.push(
+                UnusedVariable::new(
+                    binding.name,
+                    binding.kind == BindingKind::Parameter,
+                    binding.position,
+                )
+                .into(),
+            );
+        }
+    }
+
+    fn mark_used(&mut self, name: &str) {
+        let binding = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .flat_map(|scope| scope.iter_mut().rev())
+            .find(|binding| binding.name == name);
+        if let Some(binding) = binding {
+            binding.used = true;
+        }
+    }
+
+    fn is_visible(&self, name: &str) -> bool {
+        self.scopes
+            .iter()
+            .flatten()
+            .any(|binding| binding.name == name)
+    }
+
+    fn handle_fn_def(&mut self, fn_def: &mut FunctionDef) {
+        if self
+            .inferred_return_types
+            .contains(&fn_def.identifier.position.start)
+            && fn_def.identifier.info.ty == Some(Type::BuiltIn(BuiltInType::Object))
+        {
+            self.warnings.push(
+                ObjectReturnType::new(fn_def.identifier.id.clone(), fn_def.identifier.position)
+                    .into(),
+            );
+        }
+
+        self.push_scope(&fn_def.parameters, BindingKind::Parameter);
+        fn_def.body.accept(self);
+        self.pop_scope();
+    }
+}
+
+/// # Description
+/// Whether `lhs` and `rhs` are the same variable, or the same field of the same object
+fn is_same_place(lhs: &Expression, rhs: &Expression) -> bool {
+    match (lhs, rhs) {
+        (Expression::Variable(lhs), Expression::Variable(rhs)) => lhs.id == rhs.id,
+        (Expression::DataMemberAccess(lhs), Expression::DataMemberAccess(rhs)) => {
+            lhs.member.id == rhs.member.id && is_same_place(&lhs.object, &rhs.object)
+        }
+        _ => false,
+    }
+}
+
+/// # Description
+/// How `place` is written in the source code, for the places of `is_same_place`
+fn place_name(place: &Expression) -> String {
+    match place {
+        Expression::Variable(variable) => variable.id.clone(),
+        Expression::DataMemberAccess(access) => {
+            format!("{}.{}", place_name(&access.object), access.member.id)
+        }
+        _ => String::new(),
+    }
+}
+
+impl<'a> ExpressionVisitor<()> for LintVisitor<'a> {
+    fn visit_expression(&mut self, node: &mut Expression) -> () {
+        node.accept(self);
+    }
+
+    fn visit_destructive_assignment(&mut self, node: &mut ast::DestructiveAssignment) -> () {
+        if is_same_place(&node.lhs, &node.rhs) {
+            self.warnings
+                .push(SelfAssignment::new(place_name(&node.lhs), node.op.token_position()).into());
+        }
+        // assigning a variable does not read it
+        if node.lhs.as_variable().is_none() {
+            node.lhs.accept(self);
+        }
+        node.rhs.accept(self);
+    }
+
+    fn visit_bin_op(&mut self, node: &mut ast::BinOp) -> () {
+        node.lhs.accept(self);
+        node.rhs.accept(self);
+    }
+
+    fn visit_let_in(&mut self, node: &mut ast::LetIn) -> () {
+        // the variable is not visible in its own initializer
+        node.assignment.accept(self);
+
+        let identifier = &node.assignment.identifier;
+        if self.is_visible(&identifier.id) {
+            self.warnings
+                .push(ShadowedVariable::new(identifier.id.clone(), identifier.position).into());
+        }
+
+        self.push_scope(std::slice::from_ref(identifier), BindingKind::Variable);
+        node.body.accept(self);
+        self.pop_scope();
+    }
+
+    fn visit_new_expr(&mut self, node: &mut ast::NewExpr) -> () {
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_assignment(&mut self, node: &mut ast::Assignment) -> () {
+        node.rhs.accept(self);
+    }
+
+    fn visit_if_else(&mut self, node: &mut ast::IfElse) -> () {
+        if let Expression::BooleanLiteral(literal) = node.condition.as_ref() {
+            let value = matches!(literal, BooleanLiteral::True(_));
+            self.warnings
+                .push(ConstantCondition::new(value, node.if_token.token_position()).into());
+        }
+        node.condition.accept(self);
+        node.then_expression.accept(self);
+        node.else_expression.accept(self);
+    }
+
+    fn visit_while(&mut self, node: &mut ast::While) -> () {
+        node.condition.accept(self);
+        node.body.accept(self);
+    }
+
+    fn visit_for(&mut self, node: &mut ast::For) -> () {
+        node.iterable.accept(self);
+        self.push_scope(
+            std::slice::from_ref(&node.element),
+            BindingKind::LoopElement,
+        );
+        node.body.accept(self);
+        self.pop_scope();
+    }
+
+    fn visit_block(&mut self, node: &mut ast::Block) -> () {
+        let last = node.body_items.len().saturating_sub(1);
+        for (i, item) in node.body_items.iter_mut().enumerate() {
+            if let BlockBodyItem::ReturnStatement(statement) = item {
+                if i < last {
+                    self.warnings
+                        .push(UnreachableCode::new(statement.return_token.token_position()).into());
+                }
+            }
+            item.accept(self);
+        }
+    }
+
+    fn visit_return_statement(&mut self, node: &mut ast::ReturnStatement) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_un_op(&mut self, node: &mut ast::UnOp) -> () {
+        node.rhs.accept(self);
+    }
+
+    fn visit_data_member_access(&mut self, node: &mut ast::DataMemberAccess) -> () {
+        node.object.accept(self);
+    }
+
+    fn visit_function_member_access(&mut self, node: &mut ast::FunctionMemberAccess) -> () {
+        node.object.accept(self);
+        node.member
+            .arguments
+            .iter_mut()
+            .for_each(|x| x.accept(self));
+    }
+
+    fn visist_list_indexing(&mut self, node: &mut ast::ListIndexing) -> () {
+        node.list.accept(self);
+        node.index.accept(self);
+    }
+
+    fn visit_function_call(&mut self, node: &mut ast::FunctionCall) -> () {
+        // variables holding functions are called like global functions
+        self.mark_used(&node.identifier.id);
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_lambda(&mut self, node: &mut ast::Lambda) -> () {
+        self.push_scope(&node.parameters, BindingKind::Parameter);
+        node.body.accept(self);
+        self.pop_scope();
+    }
+
+    fn visit_is_expr(&mut self, node: &mut ast::IsExpr) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_as_expr(&mut self, node: &mut ast::AsExpr) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_base_call(&mut self, node: &mut ast::BaseCall) -> () {
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_variable(&mut self, node: &mut Identifier) -> () {
+        self.mark_used(&node.id);
+    }
+
+    fn visit_number_literal(&mut self, _node: &mut ast::NumberLiteral) -> () {}
+
+    fn visit_boolean_literal(&mut self, _node: &mut ast::BooleanLiteral) -> () {}
+
+    fn visit_string_literal(&mut self, _node: &mut ast::StringLiteral) -> () {}
+
+    fn visit_list_literal(&mut self, node: &mut ast::ListLiteral) -> () {
+        node.elements.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_empty_expression(&mut self) -> () {}
+}
+
+impl<'a> DefinitionVisitor<()> for LintVisitor<'a> {
+    fn visit_definition(&mut self, node: &mut ast::Definition) -> () {
+        node.accept(self);
+    }
+
+    fn visit_type_def(&mut self, node: &mut ast::TypeDef) -> () {
+        // the parameters of a type are only visible in the arguments of its parent and in
+        // the initializers of its fields
+        self.push_scope(&node.parameter_list, BindingKind::Parameter);
+        if let Some(inheritance) = &mut node.inheritance_indicator {
+            inheritance
+                .argument_list
+                .iter_mut()
+                .for_each(|x| x.accept(self));
+        }
+        node.data_member_defs
+            .iter_mut()
+            .for_each(|x| x.default_value.accept(self));
+        self.pop_scope();
+
+        node.function_member_defs
+            .iter_mut()
+            .for_each(|method| self.handle_fn_def(method));
+    }
+
+    fn visit_function_def(&mut self, node: &mut ast::GlobalFunctionDef) -> () {
+        self.handle_fn_def(&mut node.function_def);
+    }
+
+    fn visit_constant_def(&mut self, node: &mut ast::ConstantDef) -> () {
+        node.initializer_expression.accept(self);
+    }
+
+    fn visit_protocol_def(&mut self, _node: &mut ast::ProtocolDef) -> () {}
+}
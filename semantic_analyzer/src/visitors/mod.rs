@@ -11,3 +11,9 @@ pub mod annotation_visitor;
 pub use annotation_visitor::AnnotationVisitor;
 
 pub mod type_visitor;
+
+pub mod namespace_visitor;
+pub use namespace_visitor::NamespaceVisitor;
+
+pub mod lint_visitor;
+pub use lint_visitor::LintVisitor;
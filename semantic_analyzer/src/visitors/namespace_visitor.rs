@@ -0,0 +1,325 @@
+use ast::{
+    DefinitionVisitor, Expression, ExpressionVisitor, FunctionDef, Identifier, TypeName,
+    VisitableDefinition, VisitableExpression,
+    token_position::{TokenPosition, TokenPositionTrait},
+    typing::{Type, TypeAnnotation},
+};
+use error_handler::error::error::HulkError;
+
+use crate::module_scope::ModuleScope;
+
+/// # Description
+/// Visitor that qualifies the global names of a program linked from modules with the module
+/// they belong to, see `ModuleScope`. Definitions are renamed to their qualified names, and so
+/// are the uses of global names: function calls, `new` expressions, parents of types and
+/// protocols and type annotations. It must run before the definitions are collected.
+/// # Arguments
+/// * `modules` - The modules of the program, with the global names they declare.
+/// * `errors` - A mutable reference to a vector that holds the errors found.
+/// * `scopes` - The variables visible from the current expression, which shadow global
+///   functions, innermost scope last.
+/// * `type_parameters` - The type parameters of the generic definition being visited.
+pub struct NamespaceVisitor<'a> {
+    pub modules: &'a ModuleScope,
+    pub errors: &'a mut Vec<HulkError>,
+    scopes: Vec<Vec<String>>,
+    type_parameters: Vec<String>,
+}
+
+impl<'a> NamespaceVisitor<'a> {
+    pub fn new(modules: &'a ModuleScope, errors: &'a mut Vec<HulkError>) -> Self {
+        Self {
+            modules,
+            errors,
+            scopes: Vec::new(),
+            type_parameters: Vec::new(),
+        }
+    }
+
+    fn push_scope(&mut self, identifiers: &[Identifier]) {
+        self.scopes
+            .push(identifiers.iter().map(|x| x.id.clone()).collect());
+    }
+
+    fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn is_local(&self, name: &str) -> bool {
+        self.scopes
+            .iter()
+            .flatten()
+            .any(|variable| variable == name)
+    }
+
+    fn set_type_parameters(&mut self, type_parameters: &[TypeName]) {
+        self.type_parameters = type_parameters
+            .iter()
+            .map(|parameter| parameter.id.clone())
+            .collect();
+    }
+
+    /// # Description
+    /// Replaces `name`, used at `position`, with the global name it refers to.
+    fn qualify(&mut self, name: &mut String, position: TokenPosition) {
+        match self.modules.resolve(name, position) {
+            Ok(qualified) => *name = qualified,
+            Err(error) => self.errors.push(error),
+        }
+    }
+
+    fn qualify_type_name(&mut self, name: &mut TypeName) {
+        if self.type_parameters.contains(&name.id) {
+            return;
+        }
+        self.qualify(&mut name.id, name.position);
+    }
+
+    fn qualify_type(&mut self, ty: &mut Type) {
+        match ty {
+            Type::BuiltIn(_) => {}
+            Type::Defined(name) => self.qualify_type_name(name),
+            Type::Iterable(inner) => self.qualify_type(inner),
+            Type::Functor(functor) => {
+                functor
+                    .parameter_types
+                    .iter_mut()
+                    .for_each(|parameter| self.qualify_annotation(parameter));
+                self.qualify_annotation(&mut functor.return_type);
+            }
+            Type::Generic(name, arguments) => {
+                self.qualify_type_name(name);
+                arguments
+                    .iter_mut()
+                    .for_each(|argument| self.qualify_type(argument));
+            }
+        }
+    }
+
+    fn qualify_annotation(&mut self, annotation: &mut TypeAnnotation) {
+        if let Some(ty) = annotation {
+            self.qualify_type(ty);
+        }
+    }
+
+    fn qualify_identifiers(&mut self, identifiers: &mut [Identifier]) {
+        identifiers
+            .iter_mut()
+            .for_each(|identifier| self.qualify_annotation(&mut identifier.info.ty));
+    }
+
+    fn handle_fn_def(&mut self, fn_def: &mut FunctionDef) {
+        // methods can have type parameters of their own
+        let outer_type_parameters = self.type_parameters.len();
+        self.type_parameters
+            .extend(fn_def.type_parameters.iter().map(|x| x.id.clone()));
+        self.qualify_annotation(&mut fn_def.identifier.info.ty);
+        self.qualify_identifiers(&mut fn_def.parameters);
+
+        self.push_scope(&fn_def.parameters);
+        fn_def.body.accept(self);
+        self.pop_scope();
+        self.type_parameters.truncate(outer_type_parameters);
+    }
+}
+
+impl<'a> ExpressionVisitor<()> for NamespaceVisitor<'a> {
+    fn visit_expression(&mut self, node: &mut Expression) -> () {
+        node.accept(self);
+    }
+
+    fn visit_destructive_assignment(&mut self, node: &mut ast::DestructiveAssignment) -> () {
+        node.lhs.accept(self);
+        node.rhs.accept(self);
+    }
+
+    fn visit_bin_op(&mut self, node: &mut ast::BinOp) -> () {
+        node.lhs.accept(self);
+        node.rhs.accept(self);
+    }
+
+    fn visit_let_in(&mut self, node: &mut ast::LetIn) -> () {
+        node.assignment.accept(self);
+
+        self.push_scope(std::slice::from_ref(&node.assignment.identifier));
+        node.body.accept(self);
+        self.pop_scope();
+    }
+
+    fn visit_new_expr(&mut self, node: &mut ast::NewExpr) -> () {
+        if !self.type_parameters.contains(&node.type_name) {
+            let position = node.new_token.token_position();
+            self.qualify(&mut node.type_name, position);
+        }
+        node.type_arguments
+            .iter_mut()
+            .for_each(|argument| self.qualify_type(argument));
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_assignment(&mut self, node: &mut ast::Assignment) -> () {
+        self.qualify_annotation(&mut node.identifier.info.ty);
+        node.rhs.accept(self);
+    }
+
+    fn visit_if_else(&mut self, node: &mut ast::IfElse) -> () {
+        node.condition.accept(self);
+        node.then_expression.accept(self);
+        node.else_expression.accept(self);
+    }
+
+    fn visit_while(&mut self, node: &mut ast::While) -> () {
+        node.condition.accept(self);
+        node.body.accept(self);
+    }
+
+    fn visit_for(&mut self, node: &mut ast::For) -> () {
+        node.iterable.accept(self);
+        self.qualify_annotation(&mut node.element.info.ty);
+
+        self.push_scope(std::slice::from_ref(&node.element));
+        node.body.accept(self);
+        self.pop_scope();
+    }
+
+    fn visit_block(&mut self, node: &mut ast::Block) -> () {
+        node.body_items.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_return_statement(&mut self, node: &mut ast::ReturnStatement) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_un_op(&mut self, node: &mut ast::UnOp) -> () {
+        node.rhs.accept(self);
+    }
+
+    fn visit_data_member_access(&mut self, node: &mut ast::DataMemberAccess) -> () {
+        node.object.accept(self);
+    }
+
+    fn visit_function_member_access(&mut self, node: &mut ast::FunctionMemberAccess) -> () {
+        node.object.accept(self);
+        node.member
+            .arguments
+            .iter_mut()
+            .for_each(|x| x.accept(self));
+    }
+
+    fn visist_list_indexing(&mut self, node: &mut ast::ListIndexing) -> () {
+        node.list.accept(self);
+        node.index.accept(self);
+    }
+
+    fn visit_function_call(&mut self, node: &mut ast::FunctionCall) -> () {
+        // variables holding function values shadow global functions
+        if !self.is_local(&node.identifier.id) {
+            let position = node.identifier.position;
+            self.qualify(&mut node.identifier.id, position);
+        }
+        node.type_arguments
+            .iter_mut()
+            .for_each(|argument| self.qualify_type(argument));
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_lambda(&mut self, node: &mut ast::Lambda) -> () {
+        self.qualify_identifiers(&mut node.parameters);
+        self.qualify_annotation(&mut node.return_type);
+
+        self.push_scope(&node.parameters);
+        node.body.accept(self);
+        self.pop_scope();
+    }
+
+    fn visit_is_expr(&mut self, node: &mut ast::IsExpr) -> () {
+        node.expression.accept(self);
+        self.qualify_type(&mut node.target);
+    }
+
+    fn visit_as_expr(&mut self, node: &mut ast::AsExpr) -> () {
+        node.expression.accept(self);
+        self.qualify_type(&mut node.target);
+    }
+
+    fn visit_base_call(&mut self, node: &mut ast::BaseCall) -> () {
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_variable(&mut self, _node: &mut Identifier) -> () {}
+
+    fn visit_number_literal(&mut self, _node: &mut ast::NumberLiteral) -> () {}
+
+    fn visit_boolean_literal(&mut self, _node: &mut ast::BooleanLiteral) -> () {}
+
+    fn visit_string_literal(&mut self, _node: &mut ast::StringLiteral) -> () {}
+
+    fn visit_list_literal(&mut self, node: &mut ast::ListLiteral) -> () {
+        node.elements.iter_mut().for_each(|x| x.accept(self));
+    }
+
+    fn visit_empty_expression(&mut self) -> () {}
+}
+
+impl<'a> DefinitionVisitor<()> for NamespaceVisitor<'a> {
+    fn visit_definition(&mut self, node: &mut ast::Definition) -> () {
+        node.accept(self);
+    }
+
+    fn visit_type_def(&mut self, node: &mut ast::TypeDef) -> () {
+        node.name.id = self
+            .modules
+            .qualified_definition(&node.name.id, node.name.position);
+        self.set_type_parameters(&node.type_parameters);
+        self.qualify_identifiers(&mut node.parameter_list);
+
+        // the parameters of a type are only visible in the arguments of its parent and in
+        // the initializers of its fields
+        self.push_scope(&node.parameter_list);
+        if let Some(inheritance) = &mut node.inheritance_indicator {
+            self.qualify_type_name(&mut inheritance.parent_name);
+            inheritance
+                .argument_list
+                .iter_mut()
+                .for_each(|x| x.accept(self));
+        }
+        for member in &mut node.data_member_defs {
+            self.qualify_annotation(&mut member.identifier.info.ty);
+            member.default_value.accept(self);
+        }
+        self.pop_scope();
+
+        node.function_member_defs
+            .iter_mut()
+            .for_each(|method| self.handle_fn_def(method));
+        self.type_parameters.clear();
+    }
+
+    fn visit_function_def(&mut self, node: &mut ast::GlobalFunctionDef) -> () {
+        let identifier = &mut node.function_def.identifier;
+        identifier.id = self
+            .modules
+            .qualified_definition(&identifier.id, identifier.position);
+        self.handle_fn_def(&mut node.function_def);
+    }
+
+    fn visit_constant_def(&mut self, node: &mut ast::ConstantDef) -> () {
+        self.qualify_annotation(&mut node.identifier.info.ty);
+        node.initializer_expression.accept(self);
+    }
+
+    fn visit_protocol_def(&mut self, node: &mut ast::ProtocolDef) -> () {
+        node.name.id = self
+            .modules
+            .qualified_definition(&node.name.id, node.name.position);
+        self.set_type_parameters(&node.type_parameters);
+        if let Some(extension) = &mut node.extension_indicator {
+            self.qualify_type_name(&mut extension.extendee_name);
+        }
+        for signature in &mut node.function_signatures {
+            self.qualify_annotation(&mut signature.identifier.info.ty);
+            self.qualify_identifiers(&mut signature.parameters);
+        }
+        self.type_parameters.clear();
+    }
+}
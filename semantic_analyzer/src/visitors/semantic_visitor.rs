@@ -1,11 +1,18 @@
+mod base_call;
+mod builtin_call;
 mod check_override;
 mod destructive_assignment;
 mod find_member_info;
 mod find_method_info;
 mod function_call;
 mod function_def;
+mod generics;
 mod get_conformable;
+pub(crate) mod iterable;
+mod lambda;
 mod print;
+mod suggest;
+mod type_test;
 mod var_definition;
 
 use std::collections::HashMap;
@@ -29,12 +36,16 @@ use error_handler::error::semantic::variable_definition::VarAlreadyDefined;
 use error_handler::error::{
     error::HulkError, semantic::destructive_assignment::InvalidReassigmentExpression,
 };
+use error_handler::warning::warning::HulkWarning;
+use generator::builtins::find_builtin;
 use generator::context::Context;
 
 use crate::{
     def_info::{FuncInfo, TypeInfo, VarInfo},
+    module_scope::ModuleScope,
     typing::TypeChecker,
 };
+use lambda::LambdaScope;
 
 /// # Description
 /// Visitor that performs semantic analysis on the AST.
@@ -42,28 +53,43 @@ use crate::{
 /// # Arguments
 /// * `type_definitions` - A mutable reference to a context that holds the type definitions.
 /// * `type_hierarchy` - A reference to a HashMap that holds the inheritance relationship between types.
+/// * `protocol_hierarchy` - A reference to a HashMap that holds the extension relationship between protocols.
 /// * `var_definitions` - A mutable reference to a context that holds the variable definitions.
 /// * `func_definitions` - A mutable reference to a context that holds the function definitions.
+/// * `modules` - The modules of the program, global definitions can only be used where they are visible.
 /// * `errors` - A mutable reference to a vector that holds the errors encountered during the visit.
+/// * `warnings` - A mutable reference to a vector that holds the warnings encountered during the visit.
+/// * `lambda_scopes` - The lambda expressions whose bodies are being checked, innermost last.
+/// * `type_parameters` - The type parameters of the generic definition being checked.
+/// * `current_method` - The type and name of the method being checked, where `base` can be called.
 /// # Note
 /// This visitor assumes that the type definitions and variable definitions are already defined in the context.
 /// It does not define types or variables, it only checks for their correctness.
 pub struct SemanticVisitor<'a> {
     pub type_definitions: &'a mut Context<TypeInfo>,
     pub type_hierarchy: &'a HashMap<String, TypeAnnotation>,
+    pub protocol_hierarchy: &'a HashMap<String, TypeAnnotation>,
     pub var_definitions: &'a mut Context<VarInfo>,
     pub func_definitions: &'a mut Context<FuncInfo>,
+    pub modules: &'a ModuleScope,
     pub type_checker: TypeChecker,
     pub errors: &'a mut Vec<HulkError>,
+    pub warnings: &'a mut Vec<HulkWarning>,
+    lambda_scopes: Vec<LambdaScope>,
+    type_parameters: Vec<String>,
+    current_method: Option<(String, String)>,
 }
 
 impl<'a> SemanticVisitor<'a> {
     pub fn new(
         type_definitions: &'a mut Context<TypeInfo>,
         type_hierarchy: &'a HashMap<String, TypeAnnotation>,
+        protocol_hierarchy: &'a HashMap<String, TypeAnnotation>,
         var_definitions: &'a mut Context<VarInfo>,
         func_definitions: &'a mut Context<FuncInfo>,
+        modules: &'a ModuleScope,
         errors: &'a mut Vec<HulkError>,
+        warnings: &'a mut Vec<HulkWarning>,
     ) -> Self {
         let mut flattened_hierarchy = HashMap::new();
         for type_key in type_hierarchy.keys() {
@@ -74,15 +100,84 @@ impl<'a> SemanticVisitor<'a> {
             flattened_hierarchy.insert(type_key.clone(), type_info.get_type_annotation());
         }
 
+        let type_parameters = type_hierarchy
+            .keys()
+            .chain(protocol_hierarchy.keys())
+            .filter_map(|name| {
+                let type_parameters = type_definitions.get_value(name)?.type_parameters();
+                (!type_parameters.is_empty()).then(|| (name.clone(), type_parameters.to_vec()))
+            })
+            .collect();
+
+        let protocol_methods = protocol_hierarchy
+            .keys()
+            .map(|name| {
+                let methods =
+                    Self::collect_methods(type_definitions, protocol_hierarchy, name.clone());
+                (name.clone(), methods)
+            })
+            .collect();
+        let type_methods = type_hierarchy
+            .keys()
+            .map(|name| {
+                let methods = Self::collect_methods(type_definitions, type_hierarchy, name.clone());
+                (name.clone(), methods)
+            })
+            .collect();
+
         SemanticVisitor {
             type_definitions,
             type_hierarchy,
+            protocol_hierarchy,
             var_definitions,
             func_definitions,
-            type_checker: TypeChecker::new(type_hierarchy, flattened_hierarchy),
+            modules,
+            type_checker: TypeChecker::new(
+                type_hierarchy,
+                flattened_hierarchy,
+                protocol_methods,
+                type_methods,
+                type_parameters,
+            ),
             errors,
+            warnings,
+            lambda_scopes: Vec::new(),
+            type_parameters: Vec::new(),
+            current_method: None,
         }
     }
+
+    /// # Description
+    /// Collects the methods of a type or protocol, including the ones it inherits or extends,
+    /// methods closer to `name` shadow the ones defined by its ancestors.
+    fn collect_methods(
+        type_definitions: &Context<TypeInfo>,
+        hierarchy: &HashMap<String, TypeAnnotation>,
+        name: String,
+    ) -> HashMap<String, FuncInfo> {
+        let mut methods = HashMap::new();
+        let mut current = Some(name);
+        while let Some(name) = current {
+            let own_methods: Vec<&FuncInfo> = match type_definitions.get_value(&name) {
+                Some(TypeInfo::Defined(def)) => {
+                    def.members.values().filter_map(|d| d.as_func()).collect()
+                }
+                Some(TypeInfo::Protocol(def)) => def.methods.values().collect(),
+                _ => vec![],
+            };
+            for method in own_methods {
+                methods
+                    .entry(method.name.id.clone())
+                    .or_insert_with(|| method.clone());
+            }
+            current = hierarchy
+                .get(&name)
+                .cloned()
+                .flatten()
+                .map(|t| t.to_string());
+        }
+        methods
+    }
 }
 
 impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
@@ -108,11 +203,13 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
             Expression::DataMemberAccess(member) => {
                 self.handle_field_reassign(&member.member, &assignee_type, &expr_type)
             }
-            Expression::ListIndexing(_) => {
-                self.handle_list_element_reassign(&assignee_type, &expr_type, node.op.position())
-            }
+            Expression::ListIndexing(_) => self.handle_list_element_reassign(
+                &assignee_type,
+                &expr_type,
+                node.op.token_position(),
+            ),
             _ => {
-                let error = InvalidReassigmentExpression::new(node.op.position());
+                let error = InvalidReassigmentExpression::new(node.op.token_position());
                 self.errors.push(error.into());
                 None
             }
@@ -161,7 +258,7 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
 
         if common_type == Some(Type::BuiltIn(BuiltInType::Object)) {
             self.errors
-                .push(InvalidIfElseType::new(node.if_token.position()).into());
+                .push(InvalidIfElseType::new(node.if_token.token_position()).into());
         }
         common_type
     }
@@ -177,21 +274,22 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
         self.var_definitions.push_open_frame();
 
         let iterable_type = node.iterable.accept(self);
-        let identifier_type = match &iterable_type {
-            Some(Type::Iterable(inner_type)) => Some(inner_type.as_ref().clone()),
-            ty => {
-                self.errors
-                    .push(NonIterableType::new(to_string(ty), node.element.position.start).into());
-                None
+        node.iterable_type = iterable_type.clone();
+
+        let element_type = match self.get_iterable_element_type(&iterable_type) {
+            Some(element_type) => element_type,
+            None => {
+                self.errors.push(
+                    NonIterableType::new(to_string(&iterable_type), node.element.position).into(),
+                );
+                // the element is typed as Object so its uses don't report it
+                // again, and the loop is left without an iterable type
+                node.iterable_type = None;
+                Some(Type::BuiltIn(BuiltInType::Object))
             }
         };
 
-        self.var_definitions.define(
-            node.element.id.clone(),
-            VarInfo::new_from_identifier(&node.element, true, iterable_type.clone()),
-        );
-
-        self.handle_var_definition(&mut node.element, identifier_type, true);
+        self.handle_var_definition(&mut node.element, element_type, true);
         let result = node.body.accept(self);
 
         self.var_definitions.pop_frame();
@@ -204,12 +302,15 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
         let def_info = self.var_definitions.get_value(&node.id);
         match def_info {
             Some(def) => {
-                node.set_type_if_none(def.ty.clone());
+                let ty = def.ty.clone();
+                node.set_type_if_none(ty.clone());
                 node.info.definition_pos = Some(def.position.clone());
-                def.ty.clone()
+                self.handle_capture(node);
+                ty
             }
             None => {
-                let error = UndefinedVariable::new(node.id.clone(), node.position.start);
+                let error = UndefinedVariable::new(node.id.clone(), node.position)
+                    .with_suggestion(self.suggest_variable(&node.id));
                 self.errors.push(error.into());
                 None
             }
@@ -242,7 +343,7 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
         }
         if result_type == Some(Type::BuiltIn(BuiltInType::Object)) {
             self.errors
-                .push(InvalidListLiteralType::new(node.left_bracket.position()).into());
+                .push(InvalidListLiteralType::new(node.left_bracket.token_position()).into());
         }
         match result_type {
             Some(result_type) => {
@@ -268,9 +369,12 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
 
         // Resolve the member info
         let member_info = self.find_member_info(member_name.clone(), &Some(ty.clone()));
-        let Some(member_info) = member_info.cloned() else {
+        let Some(member_info) = member_info else {
+            let suggestion = self.suggest_member(&member_name, &Some(ty), true);
             self.errors.push(
-                FieldNotFound::new(node.member.id.clone(), node.member.position.start).into(),
+                FieldNotFound::new(node.member.id.clone(), node.member.position)
+                    .with_suggestion(suggestion)
+                    .into(),
             );
             return None;
         };
@@ -291,12 +395,8 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
             }
         }
         self.errors.push(
-            AccessingPrivateMember::new(
-                member_name,
-                to_string(&Some(ty)),
-                node.member.position.start,
-            )
-            .into(),
+            AccessingPrivateMember::new(member_name, to_string(&Some(ty)), node.member.position)
+                .into(),
         );
         member_info.ty.clone()
     }
@@ -314,10 +414,14 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
         // annotate object
         node.obj_type = Some(ty.clone());
 
-        let func_info = self.find_method_info(func_name.clone(), &Some(ty));
+        let func_info = self.find_method_info(func_name.clone(), &Some(ty.clone()));
         let Some(func_info) = func_info else {
-            self.errors
-                .push(MethodNotFound::new(func_name, node.member.identifier.position.start).into());
+            let suggestion = self.suggest_member(&func_name, &Some(ty), false);
+            self.errors.push(
+                MethodNotFound::new(func_name, node.member.identifier.position)
+                    .with_suggestion(suggestion)
+                    .into(),
+            );
             return None;
         };
         return self.handle_function_call(
@@ -340,15 +444,26 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
 
         let index_type = node.index.accept(self);
         if index_type != Some(Type::BuiltIn(BuiltInType::Number)) {
-            let error = InvalidIndexing::new(to_string(&index_type), node.open_brace.position());
+            let error =
+                InvalidIndexing::new(to_string(&index_type), node.open_brace.token_position());
             self.errors.push(error.into());
         };
         return member_type;
     }
 
     fn visit_function_call(&mut self, node: &mut FunctionCall) -> TypeAnnotation {
-        if node.identifier.id == "print" {
-            return self.handle_print(&mut node.arguments, node.identifier.position.start);
+        // Variables holding function values shadow global functions
+        let closure_type = self
+            .var_definitions
+            .get_value(&node.identifier.id)
+            .and_then(|def| def.ty.as_ref())
+            .and_then(|ty| ty.as_functor())
+            .cloned();
+        if let Some(functor) = closure_type {
+            return self.handle_closure_call(functor, node);
+        }
+        if let Some(builtin) = find_builtin(&node.identifier.id) {
+            return self.handle_builtin_call(builtin, node);
         }
         // Check if the function is defined
         let function_def = self
@@ -357,13 +472,41 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
             .cloned();
         let Some(fn_info) = function_def else {
             let error =
-                UndefinedFunction::new(node.identifier.id.clone(), node.identifier.position.start);
+                UndefinedFunction::new(node.identifier.id.clone(), node.identifier.position)
+                    .with_suggestion(
+                        self.suggest_function(&node.identifier.id, node.identifier.position),
+                    );
             self.errors.push(error.into());
             return None;
         };
+        if let Err(error) = self
+            .modules
+            .check_visible(&node.identifier.id, node.identifier.position)
+        {
+            self.errors.push(error);
+        }
+        if !fn_info.type_parameters.is_empty() {
+            return self.handle_generic_function_call(fn_info, node);
+        }
         self.handle_function_call(fn_info, &mut node.identifier, &mut node.arguments)
     }
 
+    fn visit_lambda(&mut self, node: &mut Lambda) -> TypeAnnotation {
+        self.handle_lambda(node)
+    }
+
+    fn visit_is_expr(&mut self, node: &mut IsExpr) -> TypeAnnotation {
+        self.handle_is_expr(node)
+    }
+
+    fn visit_as_expr(&mut self, node: &mut AsExpr) -> TypeAnnotation {
+        self.handle_as_expr(node)
+    }
+
+    fn visit_base_call(&mut self, node: &mut BaseCall) -> TypeAnnotation {
+        self.handle_base_call(node)
+    }
+
     fn visit_return_statement(&mut self, node: &mut ReturnStatement) -> TypeAnnotation {
         node.expression.accept(self)
     }
@@ -388,22 +531,45 @@ impl<'a> ExpressionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
             .and_then(|d| d.as_defined())
             .cloned();
         if let Some(type_def) = type_def {
+            if let Err(error) = self
+                .modules
+                .check_visible(&node.type_name, node.new_token.token_position())
+            {
+                self.errors.push(error);
+            }
             let mut parameter_types = Vec::new();
             for arg in &mut node.arguments {
                 parameter_types.push(arg.accept(self));
             }
-            let constructor_check_result = self
-                .type_checker
-                .check_type_constructor(&type_def, &parameter_types);
+            let (constructor, ty) =
+                if type_def.type_parameters.is_empty() && node.type_arguments.is_empty() {
+                    (type_def.clone(), Type::Defined(type_def.name.clone()))
+                } else {
+                    self.instantiate_generic_type(&type_def, node, &parameter_types)?
+                };
+            let positions: Vec<usize> = node
+                .arguments
+                .iter()
+                .map(|arg| arg.start_position())
+                .collect();
+            let constructor_check_result = self.type_checker.check_type_constructor(
+                &constructor,
+                &parameter_types,
+                &positions,
+            );
             if let Err(errors) = constructor_check_result {
                 for error in errors {
                     self.errors.push(error);
                 }
             }
-            return Some(Type::Defined(type_def.name.clone()));
+            return Some(ty);
         }
-        self.errors
-            .push(UndefinedType::new(node.type_name.clone(), node.new_token.position()).into());
+        let suggestion = self.suggest_type(&node.type_name, node.new_token.token_position());
+        self.errors.push(
+            UndefinedType::new(node.type_name.clone(), node.new_token.token_position())
+                .with_suggestion(suggestion)
+                .into(),
+        );
         None
     }
 }
@@ -414,6 +580,11 @@ impl<'a> DefinitionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
     }
 
     fn visit_type_def(&mut self, node: &mut TypeDef) -> TypeAnnotation {
+        self.type_parameters = node
+            .type_parameters
+            .iter()
+            .map(|parameter| parameter.id.clone())
+            .collect();
         // Define the parameters
         self.var_definitions.push_closed_frame();
         for param in &node.parameter_list {
@@ -441,9 +612,14 @@ impl<'a> DefinitionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
                 .iter_mut()
                 .map(|arg| arg.accept(self))
                 .collect();
-            let constructor_check_result = self
-                .type_checker
-                .check_type_constructor(&parent_type, &argument_types);
+            let positions: Vec<usize> = inheritance
+                .argument_list
+                .iter()
+                .map(|arg| arg.start_position())
+                .collect();
+            let constructor_check_result =
+                self.type_checker
+                    .check_type_constructor(&parent_type, &argument_types, &positions);
             if let Err(errors) = constructor_check_result {
                 for error in errors {
                     self.errors.push(error);
@@ -456,14 +632,17 @@ impl<'a> DefinitionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
             let member_type = member.default_value.accept(self);
 
             if !self.check_field_override(&member.identifier.id, &node.name.id) {
-                self.errors.push(
-                    FieldOverride::new(
-                        member.identifier.id.clone(),
-                        node.name.id.clone(),
-                        member.identifier.position.start,
-                    )
-                    .into(),
+                let mut error = FieldOverride::new(
+                    member.identifier.id.clone(),
+                    node.name.id.clone(),
+                    member.identifier.position,
                 );
+                if let Some(position) =
+                    self.parent_field_position(&member.identifier.id, &node.name.id)
+                {
+                    error = error.with_parent_definition(position);
+                }
+                self.errors.push(error.into());
             }
             self.handle_field_definition(&mut member.identifier, member_type.clone());
 
@@ -492,30 +671,45 @@ impl<'a> DefinitionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
         for method in &mut node.function_member_defs {
             self.var_definitions.push_closed_frame();
             // Define Reference to self
-            self.var_definitions
-                .define("self".to_string(), VarInfo::new_self_instance(&node.name));
+            self.var_definitions.define(
+                "self".to_string(),
+                VarInfo::new_self_instance(&node.name, &node.type_parameters),
+            );
             if !self.check_method_override(&method.identifier.id, &node.name.id) {
-                self.errors.push(
-                    InvalidMethodOverride::new(
-                        method.identifier.id.clone(),
-                        node.name.id.clone(),
-                        method.identifier.position.start,
-                    )
-                    .into(),
+                let mut error = InvalidMethodOverride::new(
+                    method.identifier.id.clone(),
+                    node.name.id.clone(),
+                    method.identifier.position,
                 );
+                if let Some(position) =
+                    self.parent_method_position(&method.identifier.id, &node.name.id)
+                {
+                    error = error.with_parent_definition(position);
+                }
+                self.errors.push(error.into());
             }
+            self.current_method = Some((node.name.id.clone(), method.identifier.id.clone()));
             self.handle_fn_def(method, Some(&node.name));
+            self.current_method = None;
             self.var_definitions.pop_frame();
         }
 
         self.var_definitions.pop_frame();
+        self.type_parameters.clear();
         None
     }
 
     fn visit_function_def(&mut self, node: &mut GlobalFunctionDef) -> TypeAnnotation {
+        self.type_parameters = node
+            .function_def
+            .type_parameters
+            .iter()
+            .map(|parameter| parameter.id.clone())
+            .collect();
         self.var_definitions.push_open_frame();
         self.handle_fn_def(&mut node.function_def, None);
         self.var_definitions.pop_frame();
+        self.type_parameters.clear();
         None
     }
 
@@ -531,9 +725,10 @@ impl<'a> DefinitionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
         self.func_definitions.pop_frame();
         self.type_definitions.pop_frame();
 
-        if self.var_definitions.is_defined(&node.identifier.id) {
+        if let Some(previous) = self.var_definitions.get_value(&node.identifier.id) {
             let error =
-                VarAlreadyDefined::new(node.identifier.id.clone(), node.identifier.position.start);
+                VarAlreadyDefined::new(node.identifier.id.clone(), node.identifier.position)
+                    .with_previous_definition(previous.position);
             self.errors.push(error.into());
         } else {
             let var_info =
@@ -547,6 +742,7 @@ impl<'a> DefinitionVisitor<TypeAnnotation> for SemanticVisitor<'a> {
     }
 
     fn visit_protocol_def(&mut self, _node: &mut ProtocolDef) -> TypeAnnotation {
-        todo!()
+        // Protocols only declare signatures, which were already checked by the annotation visitor
+        None
     }
 }
@@ -0,0 +1,45 @@
+use ast::{
+    BaseCall, ExpressionVisitor, VisitableExpression, tokens::token_position::TokenPositionTrait,
+    typing::TypeAnnotation,
+};
+use error_handler::error::semantic::override_error::{BaseMethodNotFound, BaseOutsideMethod};
+
+use super::SemanticVisitor;
+
+impl<'a> SemanticVisitor<'a> {
+    /// # Description
+    /// Handles the semantic analysis of `base(arguments)`, a call to the implementation of the
+    /// current method in the parent type. The arguments are checked against the signature of
+    /// that implementation, and the node is annotated with the method and the parent type.
+    /// # Returns
+    /// The return type of the parent implementation.
+    pub(crate) fn handle_base_call(&mut self, node: &mut BaseCall) -> TypeAnnotation {
+        let position = node.base_token.token_position();
+        let Some((type_name, method)) = self.current_method.clone() else {
+            self.errors.push(BaseOutsideMethod::new(position).into());
+            node.arguments.iter_mut().for_each(|arg| {
+                arg.accept(self);
+            });
+            return None;
+        };
+        // `base` uses `self`, which lambdas in the method have to capture
+        self.visit_variable(&mut node.receiver);
+
+        let parent_type = self.type_hierarchy.get(&type_name).cloned().flatten();
+        let Some(fn_info) = self.find_method_info(method.clone(), &parent_type) else {
+            self.errors
+                .push(BaseMethodNotFound::new(method, type_name, position).into());
+            node.arguments.iter_mut().for_each(|arg| {
+                arg.accept(self);
+            });
+            return None;
+        };
+
+        let mut identifier = fn_info.name.clone();
+        let ty = self.handle_function_call(fn_info, &mut identifier, &mut node.arguments);
+        node.method = Some(method);
+        node.parent_type = parent_type;
+        node.ty = ty.clone();
+        ty
+    }
+}
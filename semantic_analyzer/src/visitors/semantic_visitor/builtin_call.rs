@@ -0,0 +1,63 @@
+use ast::{
+    FunctionCall, VisitableExpression,
+    typing::{Type, TypeAnnotation, to_string},
+};
+use error_handler::error::semantic::function::{FuncParamInvalidType, FuncParamsInvalidAmount};
+use generator::builtins::{Builtin, BuiltinLowering};
+
+use super::SemanticVisitor;
+
+impl<'a> SemanticVisitor<'a> {
+    /// # Description
+    /// Handles the semantic analysis for calls to the builtin functions.
+    /// It checks the arguments against the signature in the builtin registry,
+    /// `print` keeps its own checks since it accepts any printable value.
+    /// # Arguments
+    /// - `builtin`: The registry entry of the called builtin.
+    /// - `node`: A mutable reference to the `FunctionCall` node.
+    /// # Returns
+    /// A `TypeAnnotation` representing the return type of the builtin.
+    pub(crate) fn handle_builtin_call(
+        &mut self,
+        builtin: &Builtin,
+        node: &mut FunctionCall,
+    ) -> TypeAnnotation {
+        let position = node.identifier.position;
+        if builtin.lowering == BuiltinLowering::Print {
+            return self.handle_print(&mut node.arguments, position);
+        }
+
+        if node.arguments.len() != builtin.parameters.len() {
+            let error = FuncParamsInvalidAmount::new(
+                builtin.name.to_string(),
+                builtin.parameters.len(),
+                node.arguments.len(),
+                position,
+            );
+            self.errors.push(error.into());
+        }
+
+        for (index, (arg, param)) in node
+            .arguments
+            .iter_mut()
+            .zip(builtin.parameters)
+            .enumerate()
+        {
+            let arg_type = arg.accept(self);
+            if arg_type.is_some() && arg_type != Some(Type::BuiltIn(param.clone())) {
+                let error = FuncParamInvalidType::new(
+                    builtin.name.to_string(),
+                    index,
+                    param.to_string(),
+                    to_string(&arg_type),
+                    position,
+                );
+                self.errors.push(error.into());
+            }
+        }
+
+        let return_type = Some(Type::BuiltIn(builtin.return_type.clone()));
+        node.identifier.set_type_if_none(return_type.clone());
+        return_type
+    }
+}
@@ -53,7 +53,7 @@ impl<'a> SemanticVisitor<'a> {
         };
 
         self.type_checker
-            .implements_variant(method_info, overriden_method)
+            .implements_variant(method_info, &overriden_method)
     }
 
     pub(crate) fn check_field_override(&self, field_name: &str, type_name: &str) -> bool {
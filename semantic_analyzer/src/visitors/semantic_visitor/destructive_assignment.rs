@@ -1,5 +1,6 @@
 use ast::{
     Identifier,
+    token_position::TokenPosition,
     typing::{TypeAnnotation, to_string},
 };
 use error_handler::error::semantic::{
@@ -32,12 +33,13 @@ impl<'a> SemanticVisitor<'a> {
         let def_value = self.var_definitions.get_value(&variable_id);
         match def_value {
             None => {
-                let error = UndefinedVariable::new(variable_id.clone(), variable.position.start);
+                let error = UndefinedVariable::new(variable_id.clone(), variable.position)
+                    .with_suggestion(self.suggest_variable(&variable_id));
                 self.errors.push(error.into());
                 expr_type.clone()
             }
             Some(def) if def.is_constant => {
-                let error = InvalidAssigmentTarget::new(variable_id, variable.position.start);
+                let error = InvalidAssigmentTarget::new(variable_id, variable.position);
 
                 self.errors.push(error.into());
                 assignee_type.clone()
@@ -47,7 +49,7 @@ impl<'a> SemanticVisitor<'a> {
                     variable_id,
                     to_string(&def.ty),
                     to_string(&expr_type),
-                    variable.position.start,
+                    variable.position,
                 );
                 self.errors.push(error.into());
                 assignee_type.clone()
@@ -80,7 +82,7 @@ impl<'a> SemanticVisitor<'a> {
                 member_name,
                 to_string(&assignee_type),
                 to_string(&expr_type),
-                field.position.start,
+                field.position,
             );
             self.errors.push(error.into());
         }
@@ -100,7 +102,7 @@ impl<'a> SemanticVisitor<'a> {
         &mut self,
         assignee_type: &TypeAnnotation,
         expr_type: &TypeAnnotation,
-        position: usize,
+        position: TokenPosition,
     ) -> TypeAnnotation {
         if !self.type_checker.conforms(&expr_type, &assignee_type) {
             let error = ListInvalidReassignmentType::new(
@@ -7,23 +7,24 @@ use super::SemanticVisitor;
 impl<'a> SemanticVisitor<'a> {
     /// # Description
     /// `find_member_info` searches for a member's definition information
-    /// within a type definition.
+    /// within a type definition. The type parameters of a generic type are
+    /// replaced by the type arguments of `ty`.
     /// # Parameters
     /// - `member_name`: The name of the member to search for.
     /// - `ty`: The type annotation to start the search from.
     /// # Returns
-    /// - `Option<&VarInfo>`: Returns an `Option` containing a reference to the `VarInfo`
+    /// - `Option<VarInfo>`: Returns an `Option` containing the `VarInfo`
     /// if the member is found, or `None` if it is not found.
     pub(crate) fn find_member_info(
         &self,
         member_name: String,
         ty: &TypeAnnotation,
-    ) -> Option<&VarInfo> {
+    ) -> Option<VarInfo> {
         let Some(ty) = ty else {
             return None;
         };
 
-        let type_name = ty.to_string();
+        let type_name = ty.definition_name();
         // Get the type definition by its name
         let Some(type_def) = self
             .type_definitions
@@ -34,21 +35,26 @@ impl<'a> SemanticVisitor<'a> {
             return None;
         };
 
-        type_def.members.get(&member_name).and_then(|d| d.as_var())
+        let bindings = self.type_argument_bindings(ty);
+        type_def
+            .members
+            .get(&member_name)
+            .and_then(|d| d.as_var())
+            .map(|info| info.substitute(&bindings))
     }
 
     pub(crate) fn find_member_info_lookup(
         &self,
         member_name: String,
         ty: &TypeAnnotation,
-    ) -> Option<&VarInfo> {
+    ) -> Option<VarInfo> {
         let mut current_type = ty.clone();
         loop {
             let Some(ty) = &current_type else {
                 break;
             };
 
-            let type_name = ty.to_string();
+            let type_name = ty.definition_name();
             let type_def = self.type_definitions.get_value(&type_name);
 
             let Some(type_def) = type_def.and_then(|d| d.as_defined()) else {
@@ -56,7 +62,7 @@ impl<'a> SemanticVisitor<'a> {
                 continue;
             };
             if let Some(info) = type_def.members.get(&member_name).and_then(|d| d.as_var()) {
-                return Some(info);
+                return Some(info.substitute(&self.type_argument_bindings(ty)));
             }
             // Try parent type
             let parent_type = self
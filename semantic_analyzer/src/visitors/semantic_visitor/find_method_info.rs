@@ -1,38 +1,50 @@
-use ast::typing::TypeAnnotation;
+use std::collections::HashMap;
 
-use crate::def_info::FuncInfo;
+use ast::typing::{Type, TypeAnnotation};
+
+use crate::{def_info::FuncInfo, typing::generics::bind_type_parameters};
 
 use super::SemanticVisitor;
 
 impl<'a> SemanticVisitor<'a> {
     /// Searches for a medthod's definition information within a type definition or its parent types.
     ///
-    /// Traverses the type hierarchy, looking for the specified method name.
+    /// Traverses the type hierarchy, looking for the specified method name. If `ty` is a protocol,
+    /// the protocols it extends are traversed instead. The type parameters of a generic type or
+    /// protocol are replaced by the type arguments of `ty`.
     ///
     /// # Parameters
     /// - `member_name`: The name of the member to search for.
     /// - `ty`: The type annotation to start the search from.
     ///
     /// # Returns
-    /// An `Option<&FuncInfo>`: A reference to the `FuncInfo` if the method is found, or `None` if it is not found.
+    /// An `Option<FuncInfo>`: The `FuncInfo` if the method is found, or `None` if it is not found.
     pub(crate) fn find_method_info(
         &self,
         member_name: String,
         ty: &TypeAnnotation,
-    ) -> Option<&FuncInfo> {
+    ) -> Option<FuncInfo> {
         let mut current_type = ty.clone();
         loop {
             let Some(ty) = &current_type else { break };
 
-            let type_name = ty.to_string();
+            let type_name = ty.definition_name();
             let type_def = self.type_definitions.get_value(&type_name);
 
+            if let Some(protocol_def) = type_def.and_then(|d| d.as_protocol()) {
+                if let Some(info) = protocol_def.methods.get(&member_name) {
+                    return Some(info.substitute(&self.type_argument_bindings(ty)));
+                }
+                current_type = self.protocol_hierarchy.get(&type_name).cloned().flatten();
+                continue;
+            }
+
             let Some(type_def) = type_def.and_then(|d| d.as_defined()) else {
                 current_type = None;
                 continue;
             };
             if let Some(info) = type_def.members.get(&member_name).and_then(|d| d.as_func()) {
-                return Some(info);
+                return Some(info.substitute(&self.type_argument_bindings(ty)));
             }
             // Try parent type
             let parent_type = self.type_hierarchy.get(&type_name).cloned().expect(&format!(
@@ -43,4 +55,18 @@ impl<'a> SemanticVisitor<'a> {
         }
         None
     }
+
+    /// # Description
+    /// Binds the type parameters of the generic type or protocol `ty` is applied to
+    /// to its type arguments, e.g. `T` to `Number` for `Stack<Number>`.
+    pub(crate) fn type_argument_bindings(&self, ty: &Type) -> HashMap<String, Type> {
+        let Type::Generic(name, arguments) = ty else {
+            return HashMap::new();
+        };
+        let type_parameters = self
+            .type_definitions
+            .get_value(&name.id)
+            .map_or(&[][..], |info| info.type_parameters());
+        bind_type_parameters(type_parameters, arguments)
+    }
 }
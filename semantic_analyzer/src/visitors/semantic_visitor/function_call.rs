@@ -21,13 +21,23 @@ impl<'a> SemanticVisitor<'a> {
         identifier: &mut Identifier,
         arguments: &mut Vec<Expression>,
     ) -> TypeAnnotation {
-        let fn_info = fn_info.clone();
-
         let parameter_types: Vec<TypeAnnotation> =
             arguments.iter_mut().map(|arg| arg.accept(self)).collect();
+        self.check_function_call(fn_info, identifier, &parameter_types)
+    }
+
+    /// # Description
+    /// Checks a call to the function `fn_info` with arguments of types `parameter_types`,
+    /// and sets the type of the identifier to the return type of the function.
+    pub(crate) fn check_function_call(
+        &mut self,
+        fn_info: FuncInfo,
+        identifier: &mut Identifier,
+        parameter_types: &Vec<TypeAnnotation>,
+    ) -> TypeAnnotation {
         let fn_check_result = self
             .type_checker
-            .check_functor_call(&fn_info, &parameter_types);
+            .check_functor_call(&fn_info, parameter_types);
         if let Err(errors) = fn_check_result {
             for error in errors {
                 self.errors.push(error);
@@ -69,7 +69,7 @@ impl<'a> SemanticVisitor<'a> {
                     fn_def.identifier.id.clone(),
                     to_string(&fn_info.get_functor_type().return_type),
                     to_string(&body_type),
-                    fn_def.identifier.position.start,
+                    fn_def.identifier.position,
                 )
                 .into(),
             );
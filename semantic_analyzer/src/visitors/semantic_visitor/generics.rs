@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use ast::{
+    FunctionCall, NewExpr, VisitableExpression,
+    token_position::{TokenPosition, TokenPositionTrait},
+    typing::{Type, TypeAnnotation},
+};
+use error_handler::error::semantic::generics::{
+    TypeArgumentsInvalidAmount, UninferableTypeParameter,
+};
+
+use crate::{
+    def_info::{DefinedTypeInfo, FuncInfo},
+    typing::generics::{bind_type_parameters, infer_type_arguments},
+};
+
+use super::SemanticVisitor;
+
+impl<'a> SemanticVisitor<'a> {
+    /// # Description
+    /// Handles the semantic analysis of a call to a generic global function. Its type arguments
+    /// are inferred from the types of the arguments of the call, and annotated in the node.
+    /// The call is then checked against the function applied to them.
+    /// # Returns
+    /// The return type of the function applied to the inferred type arguments.
+    pub(crate) fn handle_generic_function_call(
+        &mut self,
+        fn_info: FuncInfo,
+        node: &mut FunctionCall,
+    ) -> TypeAnnotation {
+        let parameter_types: Vec<TypeAnnotation> = node
+            .arguments
+            .iter_mut()
+            .map(|arg| arg.accept(self))
+            .collect();
+        let expected_types: Vec<TypeAnnotation> = fn_info
+            .parameters
+            .iter()
+            .map(|param| param.info.ty.clone())
+            .collect();
+
+        let type_arguments = self.infer_type_arguments(
+            &fn_info.name.id,
+            &fn_info.type_parameters,
+            &expected_types,
+            &parameter_types,
+            node.identifier.position,
+        )?;
+        let bindings = bind_type_parameters(&fn_info.type_parameters, &type_arguments);
+        node.type_arguments = type_arguments;
+
+        self.check_function_call(
+            fn_info.substitute(&bindings),
+            &mut node.identifier,
+            &parameter_types,
+        )
+    }
+
+    /// # Description
+    /// Resolves the type arguments of a `new` expression of the generic type `type_def`.
+    /// When they are not written they are inferred from the types of the arguments of the
+    /// constructor, and annotated in the node.
+    /// # Returns
+    /// The constructor of the type applied to its type arguments, and the type constructed.
+    /// `None` if the type arguments could not be resolved.
+    pub(crate) fn instantiate_generic_type(
+        &mut self,
+        type_def: &DefinedTypeInfo,
+        node: &mut NewExpr,
+        parameter_types: &[TypeAnnotation],
+    ) -> Option<(DefinedTypeInfo, Type)> {
+        let position = node.new_token.token_position();
+        if node.type_arguments.is_empty() {
+            node.type_arguments = self.infer_type_arguments(
+                &type_def.name.id,
+                &type_def.type_parameters,
+                &type_def.arguments_types,
+                parameter_types,
+                position,
+            )?;
+        } else if node.type_arguments.len() != type_def.type_parameters.len() {
+            self.errors.push(
+                TypeArgumentsInvalidAmount::new(
+                    type_def.name.id.clone(),
+                    type_def.type_parameters.len(),
+                    node.type_arguments.len(),
+                    position,
+                )
+                .into(),
+            );
+            return None;
+        }
+
+        let bindings = bind_type_parameters(&type_def.type_parameters, &node.type_arguments);
+        let ty = Type::Generic(type_def.name.clone(), node.type_arguments.clone());
+        Some((type_def.substitute(&bindings), ty))
+    }
+
+    /// # Description
+    /// Infers the type arguments of the generic definition `name` by matching the types
+    /// `expected` by it against the types `provided` for them. Reports the first type
+    /// parameter that can't be inferred.
+    fn infer_type_arguments(
+        &mut self,
+        name: &str,
+        type_parameters: &[String],
+        expected: &[TypeAnnotation],
+        provided: &[TypeAnnotation],
+        position: TokenPosition,
+    ) -> Option<Vec<Type>> {
+        let mut bindings = HashMap::new();
+        for (expected, provided) in expected.iter().zip(provided) {
+            infer_type_arguments(expected, provided, type_parameters, &mut bindings);
+        }
+
+        let mut type_arguments = Vec::new();
+        for parameter in type_parameters {
+            let Some(ty) = bindings.remove(parameter) else {
+                self.errors.push(
+                    UninferableTypeParameter::new(name.to_string(), parameter.clone(), position)
+                        .into(),
+                );
+                return None;
+            };
+            type_arguments.push(ty);
+        }
+        Some(type_arguments)
+    }
+}
@@ -1,11 +1,28 @@
 use ast::typing::Type;
 
-use crate::typing::get_conformable::GetConformable;
+use crate::{
+    def_info::TypeInfo, module_scope::ModuleScope, typing::get_conformable::GetConformable,
+};
 
 use super::SemanticVisitor;
 
 impl<'a> GetConformable for SemanticVisitor<'a> {
     fn is_type_defined(&self, ty: &Type) -> bool {
-        self.type_definitions.is_defined(&ty.to_string())
+        match self.type_definitions.get_value(&ty.to_string()) {
+            // type parameters are only visible inside of their definition
+            Some(TypeInfo::Parameter(name)) => self.type_parameters.contains(&name.id),
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    fn modules(&self) -> &ModuleScope {
+        self.modules
+    }
+
+    fn type_parameters_amount(&self, name: &str) -> usize {
+        self.type_definitions
+            .get_value(name)
+            .map_or(0, |info| info.type_parameters().len())
     }
 }
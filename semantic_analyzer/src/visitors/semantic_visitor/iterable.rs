@@ -0,0 +1,40 @@
+use ast::typing::{BuiltInType, Type, TypeAnnotation};
+
+use super::SemanticVisitor;
+
+pub const ITERATOR_NEXT_METHOD: &str = "next";
+pub const ITERATOR_CURRENT_METHOD: &str = "current";
+
+impl<'a> SemanticVisitor<'a> {
+    /// # Description
+    /// Returns the type of the elements obtained when iterating over a value of type `ty`.
+    /// Lists (`T*`) are iterable, and so are the types that implement the iterator
+    /// protocol, i.e. that have (or inherit) the methods `next(): Boolean`,
+    /// which advances the iterator, and `current()`, which returns the current element.
+    /// # Arguments
+    /// - `ty`: The type of the value being iterated.
+    /// # Returns
+    /// `Some(element_type)` if `ty` is iterable, `None` otherwise. An unresolved `ty` is
+    /// considered iterable over unresolved elements, to avoid propagating errors.
+    pub(crate) fn get_iterable_element_type(&self, ty: &TypeAnnotation) -> Option<TypeAnnotation> {
+        let Some(ty) = ty else {
+            return Some(None);
+        };
+
+        if let Type::Iterable(inner) = ty {
+            return Some(Some(inner.as_ref().clone()));
+        }
+
+        let ty = Some(ty.clone());
+        let next = self.find_method_info(ITERATOR_NEXT_METHOD.to_string(), &ty)?;
+        let current = self.find_method_info(ITERATOR_CURRENT_METHOD.to_string(), &ty)?;
+
+        let next_returns_bool = next.name.info.ty.is_none()
+            || next.name.info.ty == Some(Type::BuiltIn(BuiltInType::Bool));
+        if !next.parameters.is_empty() || !current.parameters.is_empty() || !next_returns_bool {
+            return None;
+        }
+
+        Some(current.name.info.ty.clone())
+    }
+}
@@ -0,0 +1,109 @@
+use ast::{
+    FunctionCall, Identifier, Lambda, VisitableExpression,
+    token_position::TokenPositionTrait,
+    typing::{FunctorType, Type, TypeAnnotation, to_string},
+};
+use error_handler::error::semantic::function::LambdaReturnTypeInvalid;
+
+use crate::def_info::{FuncInfo, VarInfo};
+
+use super::SemanticVisitor;
+
+/// # Description
+/// Keeps track of the variables a lambda expression closes over while its body is being checked.
+/// # Fields
+/// - `base_frame`: The index of the variable frame where the lambda parameters are defined,
+///   variables found in frames below it are captured.
+/// - `captures`: The captured variables, annotated with their types.
+pub(crate) struct LambdaScope {
+    base_frame: usize,
+    captures: Vec<Identifier>,
+}
+
+impl<'a> SemanticVisitor<'a> {
+    /// # Description
+    /// Handles the semantic analysis for lambda expressions.
+    /// Defines the parameters in a new frame, checks the body against the annotated return type
+    /// (inferring it when there is no annotation) and stores the captured variables in the AST.
+    /// # Arguments
+    /// - `node`: A mutable reference to the `Lambda` node.
+    /// # Returns
+    /// The functor type of the lambda.
+    pub(crate) fn handle_lambda(&mut self, node: &mut Lambda) -> TypeAnnotation {
+        self.var_definitions.push_open_frame();
+        for param in &node.parameters {
+            self.var_definitions.define(
+                param.id.clone(),
+                VarInfo::new_from_identifier(param, true, None),
+            );
+        }
+        self.lambda_scopes.push(LambdaScope {
+            base_frame: self.var_definitions.depth() - 1,
+            captures: Vec::new(),
+        });
+
+        let body_type = node.body.accept(self);
+
+        let scope = self
+            .lambda_scopes
+            .pop()
+            .expect("Lambda scope should have been pushed");
+        self.var_definitions.pop_frame();
+
+        if !self.type_checker.conforms(&body_type, &node.return_type) {
+            self.errors.push(
+                LambdaReturnTypeInvalid::new(
+                    to_string(&node.return_type),
+                    to_string(&body_type),
+                    node.arrow.token_position(),
+                )
+                .into(),
+            );
+        }
+        if node.return_type.is_none() {
+            node.return_type = body_type;
+        }
+        node.captures = scope.captures;
+
+        Some(node.get_functor_type())
+    }
+
+    /// # Description
+    /// Records `identifier` as captured by every lambda being checked that is nested
+    /// inside the frame where the variable is defined.
+    /// # Arguments
+    /// - `identifier`: The identifier of the referenced variable, annotated with its type.
+    pub(crate) fn handle_capture(&mut self, identifier: &Identifier) {
+        let Some(frame) = self.var_definitions.get_frame_index(&identifier.id) else {
+            return;
+        };
+        for scope in &mut self.lambda_scopes {
+            if frame < scope.base_frame && !scope.captures.iter().any(|c| c.id == identifier.id) {
+                scope.captures.push(identifier.clone());
+            }
+        }
+    }
+
+    /// # Description
+    /// Handles calls to variables holding a function value.
+    /// The arguments are checked against the functor type of the variable, and the call is
+    /// annotated with it so that the generator can call through the closure.
+    /// # Arguments
+    /// - `functor`: The functor type of the called variable.
+    /// - `node`: A mutable reference to the `FunctionCall` node.
+    /// # Returns
+    /// The return type of the functor.
+    pub(crate) fn handle_closure_call(
+        &mut self,
+        functor: FunctorType,
+        node: &mut FunctionCall,
+    ) -> TypeAnnotation {
+        let mut closure = node.identifier.clone();
+        closure.info.ty = Some(Type::Functor(functor.clone()));
+        self.handle_capture(&closure);
+        node.closure_type = closure.info.ty;
+
+        let fn_info = FuncInfo::from_functor(node.identifier.clone(), &functor);
+        self.handle_function_call(fn_info, &mut node.identifier, &mut node.arguments)
+    }
+}
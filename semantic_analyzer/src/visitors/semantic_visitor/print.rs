@@ -1,5 +1,6 @@
 use ast::{
     Expression, VisitableExpression,
+    token_position::TokenPosition,
     typing::{BuiltInType, Type, TypeAnnotation, to_string},
 };
 use error_handler::error::semantic::function::{FuncParamInvalidType, FuncParamsInvalidAmount};
@@ -17,7 +18,7 @@ impl<'a> SemanticVisitor<'a> {
     pub(crate) fn handle_print(
         &mut self,
         arguments: &mut Vec<Expression>,
-        position: usize,
+        position: TokenPosition,
     ) -> TypeAnnotation {
         if arguments.len() != 1 {
             let error =
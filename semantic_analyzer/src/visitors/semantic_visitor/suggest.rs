@@ -0,0 +1,117 @@
+use ast::{
+    token_position::TokenPosition,
+    typing::{Type, TypeAnnotation},
+};
+use generator::builtins::BUILTINS;
+
+use crate::suggestions::closest_name;
+
+use super::SemanticVisitor;
+
+impl<'a> SemanticVisitor<'a> {
+    /// # Description
+    /// The visible variable whose name is closest to `name`, for "did you mean" notes.
+    pub(crate) fn suggest_variable(&self, name: &str) -> Option<String> {
+        closest_name(name, self.var_definitions.visible_ids())
+    }
+
+    /// # Description
+    /// The global or builtin function whose name is closest to `name`, among those that can
+    /// be used at `position`.
+    pub(crate) fn suggest_function(&self, name: &str, position: TokenPosition) -> Option<String> {
+        let builtins = BUILTINS.iter().map(|builtin| builtin.name);
+        let functions = self
+            .modules
+            .visible_names(self.func_definitions.visible_ids(), position);
+        closest_name(name, functions.into_iter().chain(builtins))
+    }
+
+    /// # Description
+    /// The type or protocol whose name is closest to `name`, among those that can be used at
+    /// `position`.
+    pub(crate) fn suggest_type(&self, name: &str, position: TokenPosition) -> Option<String> {
+        closest_name(
+            name,
+            self.modules
+                .visible_names(self.type_definitions.visible_ids(), position),
+        )
+    }
+
+    /// # Description
+    /// The member of `ty` or of its parent types whose name is closest to `name`.
+    /// Only fields are considered when `fields` is true, only methods otherwise.
+    pub(crate) fn suggest_member(
+        &self,
+        name: &str,
+        ty: &TypeAnnotation,
+        fields: bool,
+    ) -> Option<String> {
+        let mut candidates = Vec::new();
+        let mut current_type = ty.clone();
+        while let Some(ty) = &current_type {
+            let type_name = ty.to_string();
+            let type_def = self.type_definitions.get_value(&type_name);
+
+            if let Some(protocol_def) = type_def.and_then(|d| d.as_protocol()) {
+                if !fields {
+                    candidates.extend(protocol_def.methods.keys().map(String::as_str));
+                }
+                current_type = self.protocol_hierarchy.get(&type_name).cloned().flatten();
+                continue;
+            }
+
+            let Some(type_def) = type_def.and_then(|d| d.as_defined()) else {
+                break;
+            };
+            candidates.extend(
+                type_def
+                    .members
+                    .iter()
+                    .filter(|(_, info)| {
+                        if fields {
+                            info.as_var().is_some()
+                        } else {
+                            info.as_func().is_some()
+                        }
+                    })
+                    .map(|(member_name, _)| member_name.as_str()),
+            );
+            current_type = self.type_hierarchy.get(&type_name).cloned().flatten();
+        }
+
+        closest_name(name, candidates)
+    }
+
+    /// # Description
+    /// The parent of the type `type_name`, if it is a defined type.
+    fn defined_parent(&self, type_name: &str) -> TypeAnnotation {
+        match self.type_hierarchy.get(type_name).cloned().flatten() {
+            Some(Type::Defined(parent)) => Some(Type::Defined(parent)),
+            _ => None,
+        }
+    }
+
+    /// # Description
+    /// Where the field `field_name` is defined in a parent of the type `type_name`.
+    pub(crate) fn parent_field_position(
+        &self,
+        field_name: &str,
+        type_name: &str,
+    ) -> Option<TokenPosition> {
+        let parent = self.defined_parent(type_name);
+        self.find_member_info_lookup(field_name.to_string(), &parent)
+            .map(|info| info.position)
+    }
+
+    /// # Description
+    /// Where the method `method_name` is defined in a parent of the type `type_name`.
+    pub(crate) fn parent_method_position(
+        &self,
+        method_name: &str,
+        type_name: &str,
+    ) -> Option<TokenPosition> {
+        let parent = self.defined_parent(type_name);
+        self.find_method_info(method_name.to_string(), &parent)
+            .map(|info| info.name.position)
+    }
+}
@@ -0,0 +1,78 @@
+use ast::{
+    AsExpr, IsExpr, VisitableExpression,
+    tokens::token_position::{TokenPosition, TokenPositionTrait},
+    typing::{BuiltInType, Type, TypeAnnotation},
+};
+use error_handler::warning::type_test::{TypeTestAlwaysFalse, TypeTestAlwaysTrue};
+
+use crate::{def_info::TypeInfo, typing::get_conformable::GetConformable};
+
+use super::SemanticVisitor;
+
+impl<'a> SemanticVisitor<'a> {
+    /// # Description
+    /// Handles the semantic analysis of `expression is Type`, the result is always a `Boolean`.
+    pub(crate) fn handle_is_expr(&mut self, node: &mut IsExpr) -> TypeAnnotation {
+        let value_type = node.expression.accept(self);
+        node.obj_type = value_type.clone();
+        self.check_type_test(&value_type, &node.target, node.is_token.token_position());
+
+        Some(Type::BuiltIn(BuiltInType::Bool))
+    }
+
+    /// # Description
+    /// Handles the semantic analysis of `expression as Type`, the result has the target type,
+    /// or no type if the target type does not exist.
+    pub(crate) fn handle_as_expr(&mut self, node: &mut AsExpr) -> TypeAnnotation {
+        let value_type = node.expression.accept(self);
+        node.obj_type = value_type.clone();
+        if !self.check_type_test(&value_type, &node.target, node.as_token.token_position()) {
+            return None;
+        }
+
+        Some(node.target.clone())
+    }
+
+    /// # Description
+    /// Checks that `target` is defined and warns if the outcome of testing a value of type
+    /// `value_type` against it is known statically:
+    /// - it is always true if `value_type` conforms to `target`
+    /// - it is always false if neither type conforms to the other, as long as none of them
+    ///   is a protocol, since a subtype of `value_type` could still conform to a protocol
+    /// # Returns
+    /// `false` if `target` is not defined
+    fn check_type_test(
+        &mut self,
+        value_type: &TypeAnnotation,
+        target: &Type,
+        position: TokenPosition,
+    ) -> bool {
+        let target_type = Some(target.clone());
+        if let Err(error) = self.get_conformable(&target_type, position) {
+            self.errors.push(error);
+            return false;
+        }
+        let Some(value) = value_type else {
+            return true;
+        };
+
+        if self.type_checker.conforms(value_type, &target_type) {
+            let warning = TypeTestAlwaysTrue::new(value.to_string(), target.to_string(), position);
+            self.warnings.push(warning.into());
+        } else if !self.type_checker.conforms(&target_type, value_type)
+            && !self.is_protocol(value)
+            && !self.is_protocol(target)
+        {
+            let warning = TypeTestAlwaysFalse::new(value.to_string(), target.to_string(), position);
+            self.warnings.push(warning.into());
+        }
+        true
+    }
+
+    fn is_protocol(&self, ty: &Type) -> bool {
+        matches!(
+            self.type_definitions.get_value(&ty.definition_name()),
+            Some(TypeInfo::Protocol(_))
+        )
+    }
+}
@@ -34,13 +34,18 @@ impl<'a> SemanticVisitor<'a> {
             let error = VarDefinitionTypeMismatch::new(
                 to_string(&right_type),
                 to_string(&identifier.info.ty),
-                identifier.position.start,
+                identifier.position,
             );
             self.errors.push(error.into());
         }
 
-        if !shadoweable && self.var_definitions.is_defined(&identifier.id) {
-            let error = VarAlreadyDefined::new(identifier.id.clone(), identifier.position.start);
+        let previous = self
+            .var_definitions
+            .get_value(&identifier.id)
+            .map(|info| info.position);
+        if let (false, Some(previous)) = (shadoweable, previous) {
+            let error = VarAlreadyDefined::new(identifier.id.clone(), identifier.position)
+                .with_previous_definition(previous);
             self.errors.push(error.into());
         } else {
             let var_info = if shadoweable {
@@ -66,7 +71,7 @@ impl<'a> SemanticVisitor<'a> {
             let error = VarDefinitionTypeMismatch::new(
                 to_string(&right_type),
                 to_string(&identifier.info.ty),
-                identifier.position.start,
+                identifier.position,
             );
             self.errors.push(error.into());
         }
@@ -19,17 +19,13 @@ impl<'a> TypeVisitor<'a> {
     fn handle_identifier(&mut self, id: &Identifier) {
         if id.info.ty.is_none() {
             self.errors
-                .push(NeedsAnAnnotation::new(id.id.clone(), id.position.start).into());
+                .push(NeedsAnAnnotation::new(id.id.clone(), id.position).into());
             return;
         }
         if !id.info.ty.as_ref().unwrap().is_specific() {
             self.errors.push(
-                NeedsMoreSpecificType::new(
-                    id.id.clone(),
-                    to_string(&id.info.ty),
-                    id.position.start,
-                )
-                .into(),
+                NeedsMoreSpecificType::new(id.id.clone(), to_string(&id.info.ty), id.position)
+                    .into(),
             );
         }
     }
@@ -60,6 +56,18 @@ impl<'a> ExpressionVisitor<()> for TypeVisitor<'a> {
         node.body.accept(self);
     }
 
+    fn visit_is_expr(&mut self, node: &mut ast::IsExpr) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_as_expr(&mut self, node: &mut ast::AsExpr) -> () {
+        node.expression.accept(self);
+    }
+
+    fn visit_base_call(&mut self, node: &mut ast::BaseCall) -> () {
+        node.arguments.iter_mut().for_each(|x| x.accept(self));
+    }
+
     fn visit_new_expr(&mut self, node: &mut ast::NewExpr) -> () {
         node.arguments.iter_mut().for_each(|x| x.accept(self));
     }
@@ -84,7 +92,10 @@ impl<'a> ExpressionVisitor<()> for TypeVisitor<'a> {
     }
 
     fn visit_for(&mut self, node: &mut ast::For) -> () {
-        self.handle_identifier(&node.element);
+        // without an iterable type the iterable was already reported
+        if node.iterable_type.is_some() {
+            self.handle_identifier(&node.element);
+        }
         node.iterable.accept(self);
         node.body.accept(self);
     }
@@ -121,6 +132,13 @@ impl<'a> ExpressionVisitor<()> for TypeVisitor<'a> {
         node.arguments.iter_mut().for_each(|x| x.accept(self));
     }
 
+    fn visit_lambda(&mut self, node: &mut ast::Lambda) -> () {
+        node.parameters
+            .iter()
+            .for_each(|param| self.handle_identifier(param));
+        node.body.accept(self);
+    }
+
     fn visit_variable(&mut self, _node: &mut ast::Identifier) -> () {}
 
     fn visit_number_literal(&mut self, _node: &mut ast::NumberLiteral) -> () {}
@@ -132,7 +150,7 @@ impl<'a> ExpressionVisitor<()> for TypeVisitor<'a> {
     fn visit_list_literal(&mut self, node: &mut ast::ListLiteral) -> () {
         if node.list_type.is_none() {
             self.errors
-                .push(UnknownListType::new(node.left_bracket.position()).into());
+                .push(UnknownListType::new(node.left_bracket.token_position()).into());
         }
         node.elements.iter_mut().for_each(|x| x.accept(self));
     }
@@ -176,7 +194,12 @@ impl<'a> DefinitionVisitor<()> for TypeVisitor<'a> {
         node.initializer_expression.accept(self);
     }
 
-    fn visit_protocol_def(&mut self, _node: &mut ast::ProtocolDef) -> () {
-        todo!()
+    fn visit_protocol_def(&mut self, node: &mut ast::ProtocolDef) -> () {
+        node.function_signatures.iter_mut().for_each(|signature| {
+            signature
+                .parameters
+                .iter()
+                .for_each(|x| self.handle_identifier(x))
+        });
     }
 }